@@ -0,0 +1,86 @@
+use crate::commands::command::Command;
+use crate::core::prelude::*;
+use crate::i18n::get_command_translation;
+use crate::output::archive;
+
+/// Messages shown by a bare `archive`
+const DEFAULT_TAIL: usize = 50;
+
+#[derive(Debug)]
+pub struct ArchiveCommand;
+
+impl ArchiveCommand {
+    fn show(limit: usize) -> Result<String> {
+        let path = archive::archive_path()?.display().to_string();
+        let (entries, total) = archive::tail(limit)?;
+        if total == 0 {
+            return Ok(get_command_translation(
+                "system.commands.archive.empty",
+                &[&path],
+            ));
+        }
+
+        let header = get_command_translation(
+            "system.commands.archive.header",
+            &[&path, &entries.len().to_string(), &total.to_string()],
+        );
+        Ok(format!("{}\n{}", header, entries.join("\n")))
+    }
+}
+
+impl Command for ArchiveCommand {
+    fn name(&self) -> &'static str {
+        "archive"
+    }
+
+    fn description(&self) -> &'static str {
+        "View messages dropped from the output buffer"
+    }
+
+    fn matches(&self, command: &str) -> bool {
+        command
+            .split_whitespace()
+            .next()
+            .is_some_and(|first| first.eq_ignore_ascii_case("archive"))
+    }
+
+    fn execute_sync(&self, args: &[&str]) -> Result<String> {
+        match args.first().map(|a| a.to_lowercase()).as_deref() {
+            None => Self::show(DEFAULT_TAIL),
+            Some("path") => Ok(archive::archive_path()?.display().to_string()),
+            Some("clear") => {
+                let key = if archive::clear()? {
+                    "system.commands.archive.cleared"
+                } else {
+                    "system.commands.archive.nothing_to_clear"
+                };
+                Ok(get_command_translation(key, &[]))
+            }
+            Some(arg) => match arg.parse::<usize>() {
+                Ok(limit) if limit > 0 => Self::show(limit),
+                _ => Err(AppError::Validation(format!(
+                    "Unknown archive argument '{}'. Use archive [N|path|clear]",
+                    arg
+                ))),
+            },
+        }
+    }
+
+    fn priority(&self) -> u8 {
+        60
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_archive_rejects_unknown_arguments() {
+        let cmd = ArchiveCommand;
+        assert!(cmd.matches("archive 20"));
+        assert!(!cmd.matches("archives"));
+        assert!(cmd.execute_sync(&["0"]).is_err());
+        assert!(cmd.execute_sync(&["open"]).is_err());
+    }
+}
@@ -0,0 +1,3 @@
+// src/commands/archive/mod.rs
+pub mod command;
+pub use command::ArchiveCommand;
@@ -1,14 +1,202 @@
 // src/commands/cleanup/command.rs
 use crate::commands::command::Command;
+use crate::commands::parsing::parse_duration;
 use crate::core::prelude::*;
+use crate::server::persistence::CleanupType;
 use crate::server::types::{ServerContext, ServerStatus};
+use std::time::SystemTime;
+
+/// Age filter from `--older-than <duration>`; keeps the raw text for messages
+#[derive(Debug, Clone, Default)]
+struct AgeFilter {
+    max_age: Option<Duration>,
+    label: String,
+}
+
+impl AgeFilter {
+    /// True if an item last touched at `modified` may be removed
+    fn allows(&self, modified: SystemTime) -> bool {
+        match self.max_age {
+            Some(max_age) => modified.elapsed().map(|age| age > max_age).unwrap_or(false),
+            None => true,
+        }
+    }
+
+    fn allows_timestamp(&self, unix_secs: u64) -> bool {
+        self.allows(std::time::UNIX_EPOCH + Duration::from_secs(unix_secs))
+    }
+
+    /// Flag suffix re-appended to the forced command after confirmation
+    fn flag(&self) -> String {
+        if self.max_age.is_some() {
+            format!(" --older-than {}", self.label)
+        } else {
+            String::new()
+        }
+    }
+
+    /// Puts a line naming the active filter in front of a message
+    fn annotate(&self, msg: String) -> String {
+        if self.max_age.is_some() {
+            format!(
+                "{}\n{}",
+                crate::i18n::get_command_translation(
+                    "system.commands.cleanup.older_than_filter",
+                    &[&self.label],
+                ),
+                msg
+            )
+        } else {
+            msg
+        }
+    }
+}
+
+/// Something `cleanup -i` can offer for removal
+#[derive(Debug, Clone, PartialEq)]
+enum CleanupItem {
+    Server {
+        id: String,
+        name: String,
+        status: ServerStatus,
+    },
+    Www(std::path::PathBuf),
+    Log(std::path::PathBuf),
+}
+
+/// Which part of the data directory a file cleanup walks
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FileScope<'a> {
+    /// `.rss/servers/*.log` and rotated `.gz` archives
+    Logs,
+    /// Everything in `www/` except dotfiles
+    Www,
+    /// `www/` directories belonging to one server
+    WwwServer(&'a str),
+}
+
+impl CleanupItem {
+    fn path(&self) -> Option<&std::path::Path> {
+        match self {
+            Self::Server { .. } => None,
+            Self::Www(path) | Self::Log(path) => Some(path),
+        }
+    }
+
+    fn label(&self) -> String {
+        let file_name = |path: &std::path::Path| {
+            path.file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default()
+        };
+        match self {
+            Self::Server { id, name, status } => crate::i18n::get_translation(
+                "system.commands.cleanup.item_server",
+                &[name, &id[..id.len().min(8)], &status.to_string()],
+            ),
+            Self::Www(path) => crate::i18n::get_translation(
+                "system.commands.cleanup.item_www",
+                &[&file_name(path)],
+            ),
+            Self::Log(path) => crate::i18n::get_translation(
+                "system.commands.cleanup.item_log",
+                &[&file_name(path)],
+            ),
+        }
+    }
+}
+
+/// Per-item cleanup state, advanced by one answer per submit
+#[derive(Debug, Default)]
+struct InteractiveSession {
+    items: Vec<CleanupItem>,
+    position: usize,
+    removed: usize,
+    kept: usize,
+}
+
+impl InteractiveSession {
+    fn new(items: Vec<CleanupItem>) -> Self {
+        Self {
+            items,
+            ..Default::default()
+        }
+    }
+
+    fn current(&self) -> Option<&CleanupItem> {
+        self.items.get(self.position)
+    }
+
+    fn is_finished(&self) -> bool {
+        self.position >= self.items.len()
+    }
+
+    /// Applies y/n/a/q to the current item and returns what should be removed
+    fn answer(&mut self, answer: &str) -> Vec<CleanupItem> {
+        let Some(current) = self.current().cloned() else {
+            return Vec::new();
+        };
+
+        match answer {
+            "y" => {
+                self.position += 1;
+                self.removed += 1;
+                vec![current]
+            }
+            "n" => {
+                self.position += 1;
+                self.kept += 1;
+                Vec::new()
+            }
+            "a" => {
+                let rest = self.items[self.position..].to_vec();
+                self.removed += rest.len();
+                self.position = self.items.len();
+                rest
+            }
+            _ => {
+                self.kept += self.items.len() - self.position;
+                self.position = self.items.len();
+                Vec::new()
+            }
+        }
+    }
+
+    /// Confirmation signal for the current item
+    fn prompt(&self) -> Option<String> {
+        self.current().map(|item| {
+            format!(
+                "{}{}{}",
+                crate::core::constants::SIG_CONFIRM_PREFIX,
+                crate::core::constants::SIG_CONFIRM_CLEANUP_ITEM,
+                crate::i18n::get_command_translation(
+                    "system.commands.cleanup.interactive_item",
+                    &[
+                        &(self.position + 1).to_string(),
+                        &self.items.len().to_string(),
+                        &item.label(),
+                    ],
+                )
+            )
+        })
+    }
+
+    fn summary(&self) -> String {
+        crate::i18n::get_command_translation(
+            "system.commands.cleanup.interactive_done",
+            &[&self.removed.to_string(), &self.kept.to_string()],
+        )
+    }
+}
 
 #[derive(Debug, Default)]
-pub struct CleanupCommand;
+pub struct CleanupCommand {
+    session: std::sync::Mutex<Option<InteractiveSession>>,
+}
 
 impl CleanupCommand {
     pub fn new() -> Self {
-        Self
+        Self::default()
     }
 }
 
@@ -27,6 +215,14 @@ impl Command for CleanupCommand {
 
     fn execute_sync(&self, args: &[&str]) -> Result<String> {
         let ctx = crate::server::shared::get_shared_context();
+        let (mut args, filter) = Self::extract_age_filter(args)?;
+        let dry_run = args.contains(&"--dry-run");
+        args.retain(|arg| *arg != "--dry-run");
+        let args = args.as_slice();
+
+        if dry_run {
+            return self.dry_run(ctx, args, &filter);
+        }
 
         match args.first() {
             Some(&"stopped") => {
@@ -35,8 +231,9 @@ impl Command for CleanupCommand {
                     &[],
                 );
                 Ok(format!(
-                    "__CONFIRM:__CLEANUP__cleanup --force-stopped__{}",
-                    msg
+                    "__CONFIRM:__CLEANUP__cleanup --force-stopped{}__{}",
+                    filter.flag(),
+                    filter.annotate(msg)
                 ))
             }
             Some(&"failed") => {
@@ -45,8 +242,9 @@ impl Command for CleanupCommand {
                     &[],
                 );
                 Ok(format!(
-                    "__CONFIRM:__CLEANUP__cleanup --force-failed__{}",
-                    msg
+                    "__CONFIRM:__CLEANUP__cleanup --force-failed{}__{}",
+                    filter.flag(),
+                    filter.annotate(msg)
                 ))
             }
             Some(&"logs") => {
@@ -55,8 +253,9 @@ impl Command for CleanupCommand {
                     &[],
                 );
                 Ok(format!(
-                    "__CONFIRM:__CLEANUP__cleanup --force-logs__{}",
-                    msg
+                    "__CONFIRM:__CLEANUP__cleanup --force-logs{}__{}",
+                    filter.flag(),
+                    filter.annotate(msg)
                 ))
             }
             Some(&"all") => {
@@ -64,7 +263,11 @@ impl Command for CleanupCommand {
                     "system.commands.cleanup.confirm_all",
                     &[],
                 );
-                Ok(format!("__CONFIRM:__CLEANUP__cleanup --force-all__{}", msg))
+                Ok(format!(
+                    "__CONFIRM:__CLEANUP__cleanup --force-all{}__{}",
+                    filter.flag(),
+                    filter.annotate(msg)
+                ))
             }
             Some(&"www") => {
                 if let Some(&server_name) = args.get(1) {
@@ -73,15 +276,21 @@ impl Command for CleanupCommand {
                         &[server_name],
                     );
                     Ok(format!(
-                        "__CONFIRM:__CLEANUP__cleanup --force-www {}__{}",
-                        server_name, msg
+                        "__CONFIRM:__CLEANUP__cleanup --force-www {}{}__{}",
+                        server_name,
+                        filter.flag(),
+                        filter.annotate(msg)
                     ))
                 } else {
                     let msg = crate::i18n::get_command_translation(
                         "system.commands.cleanup.confirm_www_all",
                         &[],
                     );
-                    Ok(format!("__CONFIRM:__CLEANUP__cleanup --force-www__{}", msg))
+                    Ok(format!(
+                        "__CONFIRM:__CLEANUP__cleanup --force-www{}__{}",
+                        filter.flag(),
+                        filter.annotate(msg)
+                    ))
                 }
             }
             None => {
@@ -91,17 +300,23 @@ impl Command for CleanupCommand {
                     &[],
                 );
                 Ok(format!(
-                    "__CONFIRM:__CLEANUP__cleanup --force-stopped__{}",
-                    msg
+                    "__CONFIRM:__CLEANUP__cleanup --force-stopped{}__{}",
+                    filter.flag(),
+                    filter.annotate(msg)
                 ))
             }
 
+            Some(&"-i") | Some(&"--interactive") | Some(&"interactive") => {
+                Ok(self.start_interactive(ctx, &filter))
+            }
+            Some(&"--answer") => Ok(self.answer_interactive(ctx, args.get(1).copied())),
+
             // Force-Commands (direct execution without confirmation)
-            Some(&"--force-stopped") => Ok(self.cleanup_stopped_servers(ctx)),
-            Some(&"--force-failed") => Ok(self.cleanup_failed_servers(ctx)),
+            Some(&"--force-stopped") => Ok(self.cleanup_stopped_servers(ctx, &filter)),
+            Some(&"--force-failed") => Ok(self.cleanup_failed_servers(ctx, &filter)),
             Some(&"--force-logs") => {
                 tokio::spawn(async move {
-                    match Self::cleanup_all_server_logs().await {
+                    match Self::cleanup_all_server_logs(filter.max_age).await {
                         Ok(msg) => log::info!("Log cleanup result: {}", msg),
                         Err(e) => log::error!("Log cleanup failed: {}", e),
                     }
@@ -114,8 +329,9 @@ impl Command for CleanupCommand {
             Some(&"--force-www") => {
                 if let Some(&server_name) = args.get(1) {
                     let name = server_name.to_string();
+                    let max_age = filter.max_age;
                     tokio::spawn(async move {
-                        match Self::cleanup_www_by_name(&name).await {
+                        match Self::cleanup_www_by_name(&name, max_age).await {
                             Ok(msg) => log::info!("WWW cleanup result: {}", msg),
                             Err(e) => log::error!("WWW cleanup failed: {}", e),
                         }
@@ -126,7 +342,7 @@ impl Command for CleanupCommand {
                     ))
                 } else {
                     tokio::spawn(async move {
-                        match Self::cleanup_www_directory().await {
+                        match Self::cleanup_www_directory(filter.max_age).await {
                             Ok(msg) => log::info!("WWW cleanup result: {}", msg),
                             Err(e) => log::error!("WWW cleanup failed: {}", e),
                         }
@@ -139,21 +355,22 @@ impl Command for CleanupCommand {
             }
             Some(&"--force-all") => {
                 // Complete cleanup now includes WWW cleanup
-                let stopped = self.cleanup_stopped_servers(ctx);
-                let failed = self.cleanup_failed_servers(ctx);
+                let stopped = self.cleanup_stopped_servers(ctx, &filter);
+                let failed = self.cleanup_failed_servers(ctx, &filter);
+                let max_age = filter.max_age;
 
                 // Start async cleanup tasks for www and logs
                 tokio::spawn(async move {
                     // WWW cleanup is now included in "all"
                     let www_cleanup = async {
-                        match Self::cleanup_www_directory().await {
+                        match Self::cleanup_www_directory(max_age).await {
                             Ok(msg) => log::info!("WWW cleanup result: {}", msg),
                             Err(e) => log::error!("WWW cleanup failed: {}", e),
                         }
                     };
 
                     let log_cleanup = async {
-                        match Self::cleanup_all_server_logs().await {
+                        match Self::cleanup_all_server_logs(max_age).await {
                             Ok(msg) => log::info!("Log cleanup result: {}", msg),
                             Err(e) => log::error!("Log cleanup failed: {}", e),
                         }
@@ -183,95 +400,391 @@ impl Command for CleanupCommand {
 }
 
 impl CleanupCommand {
-    fn cleanup_stopped_servers(&self, ctx: &ServerContext) -> String {
-        let registry = crate::server::shared::get_persistent_registry();
+    /// Strips `--older-than <duration>` from the arguments
+    fn extract_age_filter<'a>(args: &[&'a str]) -> Result<(Vec<&'a str>, AgeFilter)> {
+        let mut remaining = Vec::with_capacity(args.len());
+        let mut filter = AgeFilter::default();
+        let mut iter = args.iter();
 
-        tokio::spawn(async move {
-            if let Ok(_servers) = registry.load_servers().await {
-                if let Ok((_updated_servers, removed_count)) = registry
-                    .cleanup_servers(crate::server::persistence::CleanupType::Stopped)
-                    .await
-                {
-                    if removed_count > 0 {
-                        log::info!(
-                            "Removed {} stopped servers from persistent registry",
-                            removed_count
-                        );
-                    }
-                }
+        while let Some(&arg) = iter.next() {
+            if arg == "--older-than" {
+                let value = iter.next().copied().unwrap_or_default();
+                let max_age = parse_duration(value).ok_or_else(|| {
+                    AppError::Validation(crate::i18n::get_command_translation(
+                        "system.commands.cleanup.invalid_duration",
+                        &[value],
+                    ))
+                })?;
+                filter = AgeFilter {
+                    max_age: Some(max_age),
+                    label: value.to_lowercase(),
+                };
+            } else {
+                remaining.push(arg);
             }
-        });
+        }
+
+        Ok((remaining, filter))
+    }
+
+    fn start_interactive(&self, ctx: &ServerContext, filter: &AgeFilter) -> String {
+        let items = self.collect_interactive_items(ctx, filter);
+        if items.is_empty() {
+            return filter.annotate(crate::i18n::get_command_translation(
+                "system.commands.cleanup.interactive_empty",
+                &[],
+            ));
+        }
 
-        let mut servers = match ctx.servers.write() {
-            Ok(s) => s,
+        let session = InteractiveSession::new(items);
+        let prompt = session.prompt().unwrap_or_default();
+        match self.session.lock() {
+            Ok(mut guard) => *guard = Some(session),
             Err(e) => {
-                log::error!("servers lock poisoned: {}", e);
-                return "Error: server lock poisoned".to_string();
+                log::error!("cleanup session lock poisoned: {}", e);
+                return "Error: cleanup session lock poisoned".to_string();
             }
+        }
+        prompt
+    }
+
+    fn answer_interactive(&self, ctx: &ServerContext, answer: Option<&str>) -> String {
+        let mut guard = match self.session.lock() {
+            Ok(guard) => guard,
+            Err(e) => {
+                log::error!("cleanup session lock poisoned: {}", e);
+                return "Error: cleanup session lock poisoned".to_string();
+            }
+        };
+        let Some(session) = guard.as_mut() else {
+            return crate::i18n::get_command_translation(
+                "system.commands.cleanup.interactive_none",
+                &[],
+            );
         };
-        let initial_count = servers.len();
-        servers.retain(|_, server| server.status != ServerStatus::Stopped);
-        let removed_count = initial_count - servers.len();
 
-        if removed_count > 0 {
-            crate::i18n::get_command_translation(
-                "system.commands.cleanup.stopped_success",
-                &[&removed_count.to_string()],
-            )
+        for item in session.answer(answer.unwrap_or("q")) {
+            Self::remove_item(ctx, &item);
+        }
+
+        if session.is_finished() {
+            let summary = session.summary();
+            *guard = None;
+            summary
         } else {
-            crate::i18n::get_command_translation("system.commands.cleanup.no_stopped", &[])
+            session.prompt().unwrap_or_default()
         }
     }
 
-    fn cleanup_failed_servers(&self, ctx: &ServerContext) -> String {
-        let registry = crate::server::shared::get_persistent_registry();
+    /// Stopped/failed servers, www entries and server logs that pass the age filter
+    fn collect_interactive_items(
+        &self,
+        ctx: &ServerContext,
+        filter: &AgeFilter,
+    ) -> Vec<CleanupItem> {
+        self.plan(
+            ctx,
+            &[CleanupType::Stopped, CleanupType::Failed],
+            &[FileScope::Www, FileScope::Logs],
+            filter,
+        )
+        .into_iter()
+        .map(|(item, _)| item)
+        .collect()
+    }
 
-        tokio::spawn(async move {
-            if let Ok(_servers) = registry.load_servers().await {
-                if let Ok((_updated_servers, removed_count)) = registry
-                    .cleanup_servers(crate::server::persistence::CleanupType::Failed)
-                    .await
-                {
-                    if removed_count > 0 {
-                        log::info!(
-                            "Removed {} failed servers from persistent registry",
-                            removed_count
-                        );
-                    }
-                }
+    /// Everything a cleanup of `types` and `scopes` would remove, with the bytes
+    /// each item frees. Shared by `--dry-run`, `-i` and the force paths.
+    fn plan(
+        &self,
+        ctx: &ServerContext,
+        types: &[CleanupType],
+        scopes: &[FileScope],
+        filter: &AgeFilter,
+    ) -> Vec<(CleanupItem, u64)> {
+        let mut items: Vec<(CleanupItem, u64)> = Self::collect_servers(ctx, types, filter)
+            .into_iter()
+            .map(|item| (item, 0))
+            .collect();
+
+        for scope in scopes {
+            let files = tokio::task::block_in_place(|| {
+                tokio::runtime::Handle::current().block_on(Self::collect_files(*scope, filter))
+            });
+            match files {
+                Ok(files) => items.extend(files),
+                Err(e) => log::warn!("Cleanup scan of {:?} failed: {}", scope, e),
             }
-        });
+        }
+        items
+    }
+
+    /// Servers matching one of `types`: live servers by their current status,
+    /// plus registry entries without a live server. Sorted by name.
+    fn collect_servers(
+        ctx: &ServerContext,
+        types: &[CleanupType],
+        filter: &AgeFilter,
+    ) -> Vec<CleanupItem> {
+        if types.is_empty() {
+            return Vec::new();
+        }
+        let selected = |status: ServerStatus| types.iter().any(|t| t.matches(status));
+
+        let registry = crate::server::shared::get_persistent_registry();
+        let persisted = tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current().block_on(registry.load_servers())
+        })
+        .unwrap_or_default();
 
-        let mut servers = match ctx.servers.write() {
-            Ok(s) => s,
+        let servers = match ctx.servers.read() {
+            Ok(servers) => servers,
             Err(e) => {
                 log::error!("servers lock poisoned: {}", e);
-                return "Error: server lock poisoned".to_string();
+                return Vec::new();
             }
         };
-        let initial_count = servers.len();
-        servers.retain(|_, server| server.status != ServerStatus::Failed);
-        let removed_count = initial_count - servers.len();
 
-        if removed_count > 0 {
+        let live = servers.values().filter(|s| selected(s.status)).map(|s| {
+            let last_activity = persisted
+                .get(&s.id)
+                .map(|p| p.last_activity_timestamp())
+                .unwrap_or(s.created_timestamp);
+            (&s.id, &s.name, s.status, last_activity)
+        });
+        let registry_only = persisted
+            .values()
+            .filter(|p| !servers.contains_key(&p.id) && selected(p.status))
+            .map(|p| (&p.id, &p.name, p.status, p.last_activity_timestamp()));
+
+        let mut candidates: Vec<_> = live
+            .chain(registry_only)
+            .filter(|(_, _, _, last_activity)| filter.allows_timestamp(*last_activity))
+            .collect();
+        candidates.sort_by(|a, b| a.1.cmp(b.1));
+        candidates
+            .into_iter()
+            .map(|(id, name, status, _)| CleanupItem::Server {
+                id: id.clone(),
+                name: name.clone(),
+                status,
+            })
+            .collect()
+    }
+
+    /// Files/directories in `scope` that pass the filter, sorted by path.
+    /// A missing directory yields nothing.
+    async fn collect_files(
+        scope: FileScope<'_>,
+        filter: &AgeFilter,
+    ) -> Result<Vec<(CleanupItem, u64)>> {
+        let base_dir = crate::core::paths::base_dir()?;
+        let dir = match scope {
+            FileScope::Logs => base_dir.join(".rss").join("servers"),
+            FileScope::Www | FileScope::WwwServer(_) => base_dir.join("www"),
+        };
+
+        let mut found = Vec::new();
+        let Ok(mut entries) = tokio::fs::read_dir(&dir).await else {
+            return Ok(found);
+        };
+
+        while let Some(entry) = entries.next_entry().await.map_err(AppError::Io)? {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+            let metadata = tokio::fs::metadata(&path).await.map_err(AppError::Io)?;
+
+            let selected = match scope {
+                FileScope::Logs => {
+                    metadata.is_file() && (name.ends_with(".log") || name.ends_with(".gz"))
+                }
+                // Skip system files (starting with .)
+                FileScope::Www => {
+                    !name.starts_with('.') && (metadata.is_dir() || metadata.is_file())
+                }
+                FileScope::WwwServer(server_name) => {
+                    metadata.is_dir() && Self::matches_server_name(&name, server_name)
+                }
+            };
+            if !selected {
+                continue;
+            }
+
+            if filter.max_age.is_some() {
+                let last_modified = if metadata.is_dir() {
+                    Self::latest_modification(&path).await.ok()
+                } else {
+                    metadata.modified().ok()
+                };
+                if !last_modified.is_some_and(|m| filter.allows(m)) {
+                    continue;
+                }
+            }
+
+            let bytes = if metadata.is_dir() {
+                Self::calculate_directory_size(&path).await.unwrap_or(0)
+            } else {
+                metadata.len()
+            };
+            let item = match scope {
+                FileScope::Logs => CleanupItem::Log(path),
+                FileScope::Www | FileScope::WwwServer(_) => CleanupItem::Www(path),
+            };
+            found.push((item, bytes));
+        }
+
+        found.sort_by(|a, b| a.0.path().cmp(&b.0.path()));
+        Ok(found)
+    }
+
+    /// `cleanup <target> --dry-run`: lists what the target would remove
+    fn dry_run(&self, ctx: &ServerContext, args: &[&str], filter: &AgeFilter) -> Result<String> {
+        let target = args.first().map(|arg| arg.trim_start_matches("--force-"));
+        let server_name = args.get(1).copied();
+
+        let (types, scopes): (&[CleanupType], Vec<FileScope>) = match target {
+            None | Some("stopped") => (&[CleanupType::Stopped], Vec::new()),
+            Some("failed") => (&[CleanupType::Failed], Vec::new()),
+            Some("logs") => (&[], vec![FileScope::Logs]),
+            Some("www") => (
+                &[],
+                vec![server_name.map_or(FileScope::Www, FileScope::WwwServer)],
+            ),
+            Some("all") | Some("-i") | Some("--interactive") | Some("interactive") => (
+                &[CleanupType::Stopped, CleanupType::Failed],
+                vec![FileScope::Www, FileScope::Logs],
+            ),
+            _ => {
+                return Err(AppError::Validation(crate::i18n::get_command_translation(
+                    "system.commands.cleanup.usage",
+                    &[],
+                )))
+            }
+        };
+
+        let items = self.plan(ctx, types, &scopes, filter);
+        Ok(filter.annotate(Self::render_dry_run(&items)))
+    }
+
+    fn render_dry_run(items: &[(CleanupItem, u64)]) -> String {
+        if items.is_empty() {
+            return crate::i18n::get_command_translation(
+                "system.commands.cleanup.dry_run_empty",
+                &[],
+            );
+        }
+
+        let total: u64 = items.iter().map(|(_, bytes)| bytes).sum();
+        let mut lines = vec![crate::i18n::get_command_translation(
+            "system.commands.cleanup.dry_run_header",
+            &[
+                &items.len().to_string(),
+                &crate::core::helpers::format_bytes(total),
+            ],
+        )];
+        lines.extend(items.iter().map(|(item, bytes)| match item {
+            CleanupItem::Server { id, .. } => crate::i18n::get_translation(
+                "system.commands.cleanup.dry_run_server",
+                &[&item.label(), id],
+            ),
+            CleanupItem::Www(path) | CleanupItem::Log(path) => crate::i18n::get_translation(
+                "system.commands.cleanup.dry_run_path",
+                &[
+                    &path.display().to_string(),
+                    &crate::core::helpers::format_bytes(*bytes),
+                ],
+            ),
+        }));
+        lines.join("\n")
+    }
+
+    fn remove_item(ctx: &ServerContext, item: &CleanupItem) {
+        match item {
+            CleanupItem::Server { id, .. } => Self::remove_servers(ctx, vec![id.clone()]),
+            CleanupItem::Www(path) | CleanupItem::Log(path) => {
+                let result = if path.is_dir() {
+                    std::fs::remove_dir_all(path)
+                } else {
+                    std::fs::remove_file(path)
+                };
+                match result {
+                    Ok(()) => log::info!("Deleted: {}", path.display()),
+                    Err(e) => log::error!("Failed to delete {}: {}", path.display(), e),
+                }
+            }
+        }
+    }
+
+    /// Drops servers from the live context and, in the background, the registry
+    fn remove_servers(ctx: &ServerContext, ids: Vec<String>) {
+        if ids.is_empty() {
+            return;
+        }
+        if let Ok(mut servers) = ctx.servers.write() {
+            for id in &ids {
+                servers.remove(id);
+            }
+        }
+        let registry = crate::server::shared::get_persistent_registry();
+        tokio::spawn(async move {
+            match registry.remove_servers(&ids).await {
+                Ok(removed) => log::info!("Removed {} servers from persistent registry", removed),
+                Err(e) => log::error!("Failed to update persistent registry: {}", e),
+            }
+        });
+    }
+
+    /// Removes the servers `collect_servers` finds for `cleanup_type`
+    fn cleanup_servers(
+        &self,
+        ctx: &ServerContext,
+        cleanup_type: CleanupType,
+        filter: &AgeFilter,
+    ) -> usize {
+        let ids: Vec<String> = Self::collect_servers(ctx, &[cleanup_type], filter)
+            .into_iter()
+            .filter_map(|item| match item {
+                CleanupItem::Server { id, .. } => Some(id),
+                _ => None,
+            })
+            .collect();
+        let removed_count = ids.len();
+        Self::remove_servers(ctx, ids);
+        removed_count
+    }
+
+    fn cleanup_stopped_servers(&self, ctx: &ServerContext, filter: &AgeFilter) -> String {
+        let removed_count = self.cleanup_servers(ctx, CleanupType::Stopped, filter);
+        filter.annotate(if removed_count > 0 {
+            crate::i18n::get_command_translation(
+                "system.commands.cleanup.stopped_success",
+                &[&removed_count.to_string()],
+            )
+        } else {
+            crate::i18n::get_command_translation("system.commands.cleanup.no_stopped", &[])
+        })
+    }
+
+    fn cleanup_failed_servers(&self, ctx: &ServerContext, filter: &AgeFilter) -> String {
+        let removed_count = self.cleanup_servers(ctx, CleanupType::Failed, filter);
+        filter.annotate(if removed_count > 0 {
             crate::i18n::get_command_translation(
                 "system.commands.cleanup.failed_success",
                 &[&removed_count.to_string()],
             )
         } else {
             crate::i18n::get_command_translation("system.commands.cleanup.no_failed", &[])
-        }
+        })
     }
 
-    pub async fn cleanup_all_server_logs() -> Result<String> {
-        let exe_path = std::env::current_exe().map_err(AppError::Io)?;
-        let base_dir = exe_path.parent().ok_or_else(|| {
-            AppError::Validation("Cannot determine executable directory".to_string())
-        })?;
-
-        let servers_dir = base_dir.join(".rss").join("servers");
+    pub async fn cleanup_all_server_logs(older_than: Option<Duration>) -> Result<String> {
+        let filter = AgeFilter {
+            max_age: older_than,
+            label: String::new(),
+        };
+        let base_dir = crate::core::paths::base_dir()?;
 
-        if !servers_dir.exists() {
+        if !base_dir.join(".rss").join("servers").exists() {
             return Ok(crate::i18n::get_command_translation(
                 "system.commands.cleanup.no_logs_dir",
                 &[],
@@ -281,27 +794,12 @@ impl CleanupCommand {
         let mut deleted_files = 0;
         let mut total_size = 0u64;
 
-        let mut entries = tokio::fs::read_dir(&servers_dir)
-            .await
-            .map_err(AppError::Io)?;
-
-        while let Some(entry) = entries.next_entry().await.map_err(AppError::Io)? {
-            let path = entry.path();
-
-            if path.is_file() {
-                if let Some(extension) = path.extension() {
-                    if extension == "log" || extension == "gz" {
-                        if let Ok(metadata) = tokio::fs::metadata(&path).await {
-                            total_size += metadata.len();
-                        }
-
-                        tokio::fs::remove_file(&path).await.map_err(AppError::Io)?;
-                        deleted_files += 1;
-
-                        log::info!("Deleted log file: {}", path.display());
-                    }
-                }
-            }
+        for (item, bytes) in Self::collect_files(FileScope::Logs, &filter).await? {
+            let Some(path) = item.path() else { continue };
+            tokio::fs::remove_file(path).await.map_err(AppError::Io)?;
+            deleted_files += 1;
+            total_size += bytes;
+            log::info!("Deleted log file: {}", path.display());
         }
 
         let size_mb = total_size / (1024 * 1024);
@@ -312,15 +810,14 @@ impl CleanupCommand {
         ))
     }
 
-    pub async fn cleanup_www_directory() -> Result<String> {
-        let exe_path = std::env::current_exe().map_err(AppError::Io)?;
-        let base_dir = exe_path.parent().ok_or_else(|| {
-            AppError::Validation("Cannot determine executable directory".to_string())
-        })?;
-
-        let www_dir = base_dir.join("www");
+    pub async fn cleanup_www_directory(older_than: Option<Duration>) -> Result<String> {
+        let filter = AgeFilter {
+            max_age: older_than,
+            label: String::new(),
+        };
+        let base_dir = crate::core::paths::base_dir()?;
 
-        if !www_dir.exists() {
+        if !base_dir.join("www").exists() {
             return Ok(crate::i18n::get_command_translation(
                 "system.commands.cleanup.no_www_dir",
                 &[],
@@ -331,32 +828,20 @@ impl CleanupCommand {
         let mut deleted_files = 0;
         let mut total_size = 0u64;
 
-        let mut entries = tokio::fs::read_dir(&www_dir).await.map_err(AppError::Io)?;
-
-        while let Some(entry) = entries.next_entry().await.map_err(AppError::Io)? {
-            let path = entry.path();
-            let metadata = tokio::fs::metadata(&path).await.map_err(AppError::Io)?;
-
-            // Skip system files (starting with .)
-            if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
-                if name.starts_with('.') {
-                    continue;
-                }
-            }
-
-            if metadata.is_dir() {
-                total_size += Self::calculate_directory_size(&path).await.unwrap_or(0);
-                tokio::fs::remove_dir_all(&path)
+        for (item, bytes) in Self::collect_files(FileScope::Www, &filter).await? {
+            let Some(path) = item.path() else { continue };
+            if path.is_dir() {
+                tokio::fs::remove_dir_all(path)
                     .await
                     .map_err(AppError::Io)?;
                 deleted_dirs += 1;
                 log::info!("Deleted directory: {}", path.display());
-            } else if metadata.is_file() {
-                total_size += metadata.len();
-                tokio::fs::remove_file(&path).await.map_err(AppError::Io)?;
+            } else {
+                tokio::fs::remove_file(path).await.map_err(AppError::Io)?;
                 deleted_files += 1;
                 log::info!("Deleted file: {}", path.display());
             }
+            total_size += bytes;
         }
 
         let size_mb = total_size / (1024 * 1024);
@@ -371,15 +856,17 @@ impl CleanupCommand {
         ))
     }
 
-    pub async fn cleanup_www_by_name(server_name: &str) -> Result<String> {
-        let exe_path = std::env::current_exe().map_err(AppError::Io)?;
-        let base_dir = exe_path.parent().ok_or_else(|| {
-            AppError::Validation("Cannot determine executable directory".to_string())
-        })?;
-
-        let www_dir = base_dir.join("www");
+    pub async fn cleanup_www_by_name(
+        server_name: &str,
+        older_than: Option<Duration>,
+    ) -> Result<String> {
+        let filter = AgeFilter {
+            max_age: older_than,
+            label: String::new(),
+        };
+        let base_dir = crate::core::paths::base_dir()?;
 
-        if !www_dir.exists() {
+        if !base_dir.join("www").exists() {
             return Ok(crate::i18n::get_command_translation(
                 "system.commands.cleanup.no_www_for_server",
                 &[server_name],
@@ -389,24 +876,15 @@ impl CleanupCommand {
         let mut deleted_dirs = 0;
         let mut total_size = 0u64;
 
-        let mut entries = tokio::fs::read_dir(&www_dir).await.map_err(AppError::Io)?;
-
-        while let Some(entry) = entries.next_entry().await.map_err(AppError::Io)? {
-            let path = entry.path();
-            let metadata = tokio::fs::metadata(&path).await.map_err(AppError::Io)?;
-
-            if metadata.is_dir() {
-                if let Some(dir_name) = path.file_name().and_then(|n| n.to_str()) {
-                    if Self::matches_server_name(dir_name, server_name) {
-                        total_size += Self::calculate_directory_size(&path).await.unwrap_or(0);
-                        tokio::fs::remove_dir_all(&path)
-                            .await
-                            .map_err(AppError::Io)?;
-                        deleted_dirs += 1;
-                        log::info!("Deleted server directory: {}", path.display());
-                    }
-                }
-            }
+        for (item, bytes) in Self::collect_files(FileScope::WwwServer(server_name), &filter).await?
+        {
+            let Some(path) = item.path() else { continue };
+            tokio::fs::remove_dir_all(path)
+                .await
+                .map_err(AppError::Io)?;
+            deleted_dirs += 1;
+            total_size += bytes;
+            log::info!("Deleted server directory: {}", path.display());
         }
 
         let size_mb = total_size / (1024 * 1024);
@@ -447,6 +925,33 @@ impl CleanupCommand {
         Ok(total_size)
     }
 
+    /// Newest modification time of a directory and everything below it
+    async fn latest_modification(dir: &std::path::Path) -> Result<SystemTime> {
+        let mut latest = tokio::fs::metadata(dir)
+            .await
+            .and_then(|m| m.modified())
+            .map_err(AppError::Io)?;
+        let mut stack = vec![dir.to_path_buf()];
+
+        while let Some(current_dir) = stack.pop() {
+            let mut entries = tokio::fs::read_dir(&current_dir)
+                .await
+                .map_err(AppError::Io)?;
+
+            while let Some(entry) = entries.next_entry().await.map_err(AppError::Io)? {
+                let metadata = entry.metadata().await.map_err(AppError::Io)?;
+                if let Ok(modified) = metadata.modified() {
+                    latest = latest.max(modified);
+                }
+                if metadata.is_dir() {
+                    stack.push(entry.path());
+                }
+            }
+        }
+
+        Ok(latest)
+    }
+
     fn matches_server_name(dir_name: &str, server_name: &str) -> bool {
         if dir_name == server_name {
             return true;
@@ -467,3 +972,94 @@ impl CleanupCommand {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_age_filter() {
+        let (args, filter) =
+            CleanupCommand::extract_age_filter(&["www", "--older-than", "7d", "blog"]).unwrap();
+        assert_eq!(args, vec!["www", "blog"]);
+        assert_eq!(filter.max_age, Some(Duration::from_secs(7 * 24 * 60 * 60)));
+        assert_eq!(filter.flag(), " --older-than 7d");
+
+        let (args, filter) = CleanupCommand::extract_age_filter(&["stopped"]).unwrap();
+        assert_eq!(args, vec!["stopped"]);
+        assert!(filter.max_age.is_none());
+        assert!(filter.flag().is_empty());
+
+        assert!(CleanupCommand::extract_age_filter(&["--older-than"]).is_err());
+        assert!(CleanupCommand::extract_age_filter(&["--older-than", "soon"]).is_err());
+    }
+
+    #[test]
+    fn test_age_filter_allows_only_old_items() {
+        let filter = AgeFilter {
+            max_age: Some(Duration::from_secs(3600)),
+            label: "1h".to_string(),
+        };
+        let now = SystemTime::now();
+        assert!(filter.allows(now - Duration::from_secs(7200)));
+        assert!(!filter.allows(now - Duration::from_secs(60)));
+        assert!(AgeFilter::default().allows(now));
+    }
+
+    #[test]
+    fn test_interactive_session_answers() {
+        let items: Vec<CleanupItem> = ["a", "b", "c", "d"]
+            .iter()
+            .map(|name| CleanupItem::Www(std::path::PathBuf::from(name)))
+            .collect();
+
+        let mut session = InteractiveSession::new(items.clone());
+        assert_eq!(session.answer("y"), vec![items[0].clone()]);
+        assert!(session.answer("n").is_empty());
+        assert_eq!(session.answer("a"), items[2..].to_vec());
+        assert!(session.is_finished());
+        assert_eq!((session.removed, session.kept), (3, 1));
+
+        let mut session = InteractiveSession::new(items);
+        session.answer("y");
+        assert!(session.answer("q").is_empty());
+        assert!(session.is_finished());
+        assert_eq!((session.removed, session.kept), (1, 3));
+        assert!(session.prompt().is_none());
+    }
+
+    #[test]
+    fn test_render_dry_run_lists_every_item() {
+        assert_eq!(CleanupCommand::render_dry_run(&[]).lines().count(), 1);
+
+        let items = vec![
+            (
+                CleanupItem::Server {
+                    id: "0123456789abcdef".to_string(),
+                    name: "blog".to_string(),
+                    status: ServerStatus::Stopped,
+                },
+                0,
+            ),
+            (
+                CleanupItem::Www(std::path::PathBuf::from("www/blog-[8080]")),
+                4096,
+            ),
+            (
+                CleanupItem::Log(std::path::PathBuf::from("blog-[8080].log")),
+                512,
+            ),
+        ];
+        let output = CleanupCommand::render_dry_run(&items);
+        assert_eq!(output.lines().count(), items.len() + 1);
+    }
+
+    #[test]
+    fn test_cleanup_type_matches_status() {
+        assert!(CleanupType::Stopped.matches(ServerStatus::Stopped));
+        assert!(!CleanupType::Stopped.matches(ServerStatus::Failed));
+        assert!(CleanupType::Failed.matches(ServerStatus::Failed));
+        assert!(CleanupType::All.matches(ServerStatus::Stopped));
+        assert!(!CleanupType::All.matches(ServerStatus::Running));
+    }
+}
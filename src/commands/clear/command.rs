@@ -14,14 +14,44 @@ impl Command for ClearCommand {
     }
 
     fn matches(&self, command: &str) -> bool {
-        crate::matches_exact!(command, "clear" | "cls")
+        command
+            .split_whitespace()
+            .next()
+            .is_some_and(|first| crate::matches_exact!(first, "clear" | "cls"))
     }
 
-    fn execute_sync(&self, _args: &[&str]) -> Result<String> {
-        Ok(crate::core::constants::SIG_CLEAR.to_string())
+    fn execute_sync(&self, args: &[&str]) -> Result<String> {
+        use crate::core::constants::{SIG_CLEAR, SIG_CLEAR_ALL, SIG_CLEAR_HISTORY};
+        match args.first().copied() {
+            None | Some("--output") => Ok(SIG_CLEAR.to_string()),
+            Some("--history") => Ok(SIG_CLEAR_HISTORY.to_string()),
+            Some("--all") => Ok(SIG_CLEAR_ALL.to_string()),
+            Some(other) => Err(AppError::Validation(format!(
+                "Unknown clear option '{}'. Use clear [--output|--history|--all]",
+                other
+            ))),
+        }
     }
 
     fn priority(&self) -> u8 {
         80 // High priority for system command
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::constants::{SIG_CLEAR, SIG_CLEAR_ALL, SIG_CLEAR_HISTORY};
+
+    #[test]
+    fn test_clear_targets() {
+        let cmd = ClearCommand;
+        assert!(cmd.matches("clear --history"));
+        assert!(!cmd.matches("clearall"));
+        assert_eq!(cmd.execute_sync(&[]).unwrap(), SIG_CLEAR);
+        assert_eq!(cmd.execute_sync(&["--output"]).unwrap(), SIG_CLEAR);
+        assert_eq!(cmd.execute_sync(&["--history"]).unwrap(), SIG_CLEAR_HISTORY);
+        assert_eq!(cmd.execute_sync(&["--all"]).unwrap(), SIG_CLEAR_ALL);
+        assert!(cmd.execute_sync(&["--bogus"]).is_err());
+    }
+}
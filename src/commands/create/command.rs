@@ -2,9 +2,27 @@
 use crate::commands::command::Command;
 use crate::core::prelude::*;
 use crate::server::types::{ServerContext, ServerInfo, ServerStatus};
-use crate::server::utils::validation::validate_server_name;
+use crate::server::ServerManager;
+use std::collections::BTreeMap;
 use uuid::Uuid;
 
+/// Inclusive bounds from `--port-range`
+type PortRange = (u16, u16);
+
+/// Flags that apply to every server of one `create` call
+#[derive(Debug, Default)]
+struct CreateOptions {
+    port_range: Option<PortRange>,
+    /// `--env KEY=VALUE` pairs, stored on each created server
+    env: BTreeMap<String, String>,
+    /// `--workers N`, stored on each created server
+    workers: Option<usize>,
+    /// `--header "Name: value"` pairs, stored on each created server
+    headers: BTreeMap<String, String>,
+    /// `--on-stop "cmd"`, stored on each created server
+    on_stop: Option<String>,
+}
+
 #[derive(Debug, Default)]
 pub struct CreateCommand;
 
@@ -29,19 +47,46 @@ impl Command for CreateCommand {
         let config = get_config()?;
         let ctx = crate::server::shared::get_shared_context();
 
+        let (args, port_range) = self.extract_port_range(args, &config)?;
+        let (args, env) = self.extract_env(&args)?;
+        let (args, headers) = self.extract_headers(&args)?;
+        let (args, on_stop) = self.extract_on_stop(&args)?;
+        let (args, workers) = self.extract_workers(&args)?;
+        let options = CreateOptions {
+            port_range,
+            env,
+            workers,
+            headers,
+            on_stop,
+        };
+
         // Parse arguments for different creation modes
-        match self.parse_creation_args(args) {
+        match self.parse_creation_args(&args) {
+            CreationMode::Single { port: Some(_), .. } | CreationMode::BulkWithBase { .. }
+                if options.port_range.is_some() =>
+            {
+                Err(AppError::Validation(
+                    "--port-range cannot be combined with an explicit port".to_string(),
+                ))
+            }
             CreationMode::Single { name, port } => {
-                self.create_single_server(&config, ctx, name, port)
+                self.create_single_server(&config, ctx, name, port, &options)
             }
             CreationMode::BulkAuto { count } => {
-                self.create_bulk_servers(&config, ctx, count, None, None)
+                self.create_bulk_servers(&config, ctx, count, None, None, &options)
             }
             CreationMode::BulkWithBase {
                 base_name,
                 base_port,
                 count,
-            } => self.create_bulk_servers(&config, ctx, count, Some(base_name), Some(base_port)),
+            } => self.create_bulk_servers(
+                &config,
+                ctx,
+                count,
+                Some(base_name),
+                Some(base_port),
+                &options,
+            ),
             CreationMode::Invalid(error) => Err(AppError::Validation(error)),
         }
     }
@@ -69,8 +114,174 @@ enum CreationMode {
 }
 
 impl CreateCommand {
+    /// Strips `--port-range <start-end>`; the range must lie inside the configured one
+    fn extract_port_range<'a>(
+        &self,
+        args: &[&'a str],
+        config: &Config,
+    ) -> Result<(Vec<&'a str>, Option<PortRange>)> {
+        let Some(pos) = args.iter().position(|a| *a == "--port-range") else {
+            return Ok((args.to_vec(), None));
+        };
+
+        let (start, end) = args
+            .get(pos + 1)
+            .and_then(|value| crate::commands::parsing::parse_port_range(value))
+            .ok_or_else(|| {
+                AppError::Validation(
+                    "Usage: create --port-range <start-end> [name|count]".to_string(),
+                )
+            })?;
+
+        let min_port = config.server.port_range_start.max(1024);
+        if start < min_port || end > config.server.port_range_end {
+            return Err(AppError::Validation(format!(
+                "Port range {}-{} must lie within the configured range {}-{}",
+                start, end, min_port, config.server.port_range_end
+            )));
+        }
+
+        let rest = args
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != pos && *i != pos + 1)
+            .map(|(_, a)| *a)
+            .collect();
+        Ok((rest, Some((start, end))))
+    }
+
+    /// Strips all `--env KEY=VALUE` pairs; later pairs override earlier ones
+    fn extract_env<'a>(
+        &self,
+        args: &[&'a str],
+    ) -> Result<(Vec<&'a str>, BTreeMap<String, String>)> {
+        let mut rest = Vec::with_capacity(args.len());
+        let mut env = BTreeMap::new();
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            if *arg != "--env" {
+                rest.push(*arg);
+                continue;
+            }
+            let (key, value) = iter
+                .next()
+                .and_then(|pair| crate::commands::parsing::parse_env_pair(pair))
+                .ok_or_else(|| {
+                    AppError::Validation(
+                        "Usage: create [name] [port] --env KEY=VALUE (key: letters, digits, _)"
+                            .to_string(),
+                    )
+                })?;
+            env.insert(key, value);
+        }
+        Ok((rest, env))
+    }
+
+    /// Strips all `--header "Name: value"` specs. Input is split on
+    /// whitespace, so a quoted spec is joined back from its words.
+    fn extract_headers<'a>(
+        &self,
+        args: &[&'a str],
+    ) -> Result<(Vec<&'a str>, BTreeMap<String, String>)> {
+        let usage = || {
+            AppError::Validation(
+                "Usage: create [name] [port] --header \"Name: value\" (valid header name and value)"
+                    .to_string(),
+            )
+        };
+        let mut rest = Vec::with_capacity(args.len());
+        let mut headers = BTreeMap::new();
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            if *arg != "--header" {
+                rest.push(*arg);
+                continue;
+            }
+            let first = iter.next().ok_or_else(usage)?;
+            let spec = crate::commands::parsing::join_quoted(first, &mut iter).ok_or_else(usage)?;
+            let (name, value) =
+                crate::commands::parsing::parse_header_spec(&spec).ok_or_else(usage)?;
+            headers.insert(name, value);
+        }
+        Ok((rest, headers))
+    }
+
+    /// Strips `--on-stop "cmd"`, the shell command run after the server stops
+    fn extract_on_stop<'a>(&self, args: &[&'a str]) -> Result<(Vec<&'a str>, Option<String>)> {
+        let usage = || {
+            AppError::Validation("Usage: create [name] [port] --on-stop \"command\"".to_string())
+        };
+        let mut rest = Vec::with_capacity(args.len());
+        let mut on_stop = None;
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            if *arg != "--on-stop" {
+                rest.push(*arg);
+                continue;
+            }
+            let first = iter.next().ok_or_else(usage)?;
+            let command =
+                crate::commands::parsing::join_quoted(first, &mut iter).ok_or_else(usage)?;
+            if command.trim().is_empty() {
+                return Err(usage());
+            }
+            on_stop = Some(command);
+        }
+        Ok((rest, on_stop))
+    }
+
+    /// Strips `--workers N` / `-w N` (1-16)
+    fn extract_workers<'a>(&self, args: &[&'a str]) -> Result<(Vec<&'a str>, Option<usize>)> {
+        let Some(pos) = args.iter().position(|a| *a == "--workers" || *a == "-w") else {
+            return Ok((args.to_vec(), None));
+        };
+
+        let workers = args
+            .get(pos + 1)
+            .and_then(|value| value.parse::<usize>().ok())
+            .filter(|w| (1..=16).contains(w))
+            .ok_or_else(|| {
+                AppError::Validation("Usage: create [name] [port] --workers N (1-16)".to_string())
+            })?;
+
+        let rest = args
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != pos && *i != pos + 1)
+            .map(|(_, a)| *a)
+            .collect();
+        Ok((rest, Some(workers)))
+    }
+
     // Argument parsing logic
     fn parse_creation_args(&self, args: &[&str]) -> CreationMode {
+        // "create --name blog [port]" reserves an explicit name
+        if let Some(pos) = args.iter().position(|a| *a == "--name") {
+            let Some(name) = args.get(pos + 1).filter(|n| !n.starts_with("--")) else {
+                return CreationMode::Invalid("Usage: create --name <name> [port]".to_string());
+            };
+            let rest: Vec<&str> = args
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != pos && *i != pos + 1)
+                .map(|(_, a)| *a)
+                .collect();
+            return match rest.as_slice() {
+                [] => CreationMode::Single {
+                    name: Some(name.to_string()),
+                    port: None,
+                },
+                [port] => match port.parse::<u16>() {
+                    Ok(port) => CreationMode::Single {
+                        name: Some(name.to_string()),
+                        port: Some(port),
+                    },
+                    Err(_) => CreationMode::Invalid("Invalid port number".to_string()),
+                },
+                _ => CreationMode::Invalid("Usage: create --name <name> [port]".to_string()),
+            };
+        }
+
         match args.len() {
             0 => CreationMode::Single {
                 name: None,
@@ -147,8 +358,9 @@ impl CreateCommand {
         ctx: &ServerContext,
         custom_name: Option<String>,
         custom_port: Option<u16>,
+        options: &CreateOptions,
     ) -> Result<String> {
-        let result = self.create_server_internal(config, ctx, custom_name, custom_port)?;
+        let result = self.create_server_internal(config, ctx, custom_name, custom_port, options)?;
         Ok(format!("Server created: {}", result.summary))
     }
 
@@ -160,6 +372,7 @@ impl CreateCommand {
         count: u32,
         base_name: Option<String>,
         base_port: Option<u16>,
+        options: &CreateOptions,
     ) -> Result<String> {
         let initial_server_count = read_lock(&ctx.servers, "servers")?.len();
 
@@ -186,7 +399,7 @@ impl CreateCommand {
                     (None, None)
                 };
 
-            match self.create_server_internal(config, ctx, name, port) {
+            match self.create_server_internal(config, ctx, name, port, options) {
                 Ok(result) => {
                     created_servers.push(result);
                 }
@@ -238,18 +451,12 @@ impl CreateCommand {
         ctx: &ServerContext,
         custom_name: Option<String>,
         custom_port: Option<u16>,
+        options: &CreateOptions,
     ) -> Result<ServerCreationResult> {
         let id = Uuid::new_v4().to_string();
 
         let name = if let Some(custom_name) = custom_name {
-            validate_server_name(&custom_name)?;
-            let servers = read_lock(&ctx.servers, "servers")?;
-            if servers.values().any(|s| s.name == custom_name) {
-                return Err(AppError::Validation(get_translation(
-                    "server.error.name_taken",
-                    &[&custom_name],
-                )));
-            }
+            ServerManager::with_context(ctx.clone()).ensure_name_available(&custom_name)?;
             custom_name
         } else {
             let server_number = self.find_next_server_number(ctx);
@@ -291,7 +498,14 @@ impl CreateCommand {
 
             custom_port
         } else {
-            self.find_next_available_port(config)?
+            let (start, end) = options
+                .port_range
+                .unwrap_or((config.server.port_range_start, config.server.port_range_end));
+            ServerManager::with_context(ctx.clone()).find_free_port(
+                start,
+                Some(end),
+                &config.server.bind_address,
+            )?
         };
 
         let timestamp = std::time::SystemTime::now()
@@ -306,19 +520,13 @@ impl CreateCommand {
             status: ServerStatus::Stopped,
             created_at: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
             created_timestamp: timestamp,
+            env: options.env.clone(),
+            workers: options.workers,
+            headers: options.headers.clone(),
+            on_stop: options.on_stop.clone(),
         };
 
-        // Create server directory and files
-        if let Err(e) = crate::server::handlers::web::create_server_directory_and_files(&name, port)
-        {
-            return Err(AppError::Validation(format!(
-                "Failed to create server directory: {}",
-                e
-            )));
-        }
-
-        // Add to runtime context
-        write_lock(&ctx.servers, "servers")?.insert(id.clone(), server_info.clone());
+        ServerManager::with_context(ctx.clone()).create_server(server_info.clone())?;
 
         // Persist to file (async)
         let registry = crate::server::shared::get_persistent_registry();
@@ -331,62 +539,33 @@ impl CreateCommand {
             }
         });
 
+        let workers = options
+            .workers
+            .map(|w| format!(", {} worker{}", w, if w == 1 { "" } else { "s" }))
+            .unwrap_or_default();
+        let headers = match options.headers.len() {
+            0 => String::new(),
+            1 => ", 1 header".to_string(),
+            n => format!(", {} headers", n),
+        };
+        let on_stop = if options.on_stop.is_some() {
+            ", on-stop hook"
+        } else {
+            ""
+        };
         let summary = format!(
-            "'{}' (ID: {}) on port {} [PERSISTENT]",
+            "'{}' (ID: {}) on port {}{}{}{} [PERSISTENT]",
             name,
             &id[0..8],
-            port
+            port,
+            workers,
+            headers,
+            on_stop
         );
 
         Ok(ServerCreationResult { name, summary })
     }
 
-    // Existing helper methods (unchanged)
-    fn find_next_available_port(&self, config: &Config) -> Result<u16> {
-        let ctx = crate::server::shared::get_shared_context();
-        let used_ports: std::collections::HashSet<u16> = {
-            let servers = ctx
-                .servers
-                .read()
-                .map_err(|_| AppError::Validation("Server-Context lock poisoned".to_string()))?;
-            servers.values().map(|s| s.port).collect()
-        };
-
-        let start_port = config.server.port_range_start;
-        let end_port = config.server.port_range_end;
-
-        if start_port >= end_port {
-            return Err(AppError::Validation(format!(
-                "Invalid port range: {} >= {}. Check config.",
-                start_port, end_port
-            )));
-        }
-
-        let max_attempts = ((end_port - start_port + 1) as usize).min(1000);
-
-        for i in 0..max_attempts {
-            let candidate_port = start_port + (i as u16);
-
-            if candidate_port > end_port {
-                break;
-            }
-
-            if !used_ports.contains(&candidate_port)
-                && crate::server::utils::port::is_port_available(
-                    candidate_port,
-                    &config.server.bind_address,
-                )
-            {
-                return Ok(candidate_port);
-            }
-        }
-
-        Err(AppError::Validation(format!(
-            "No available ports in range {}-{} after {} attempts",
-            start_port, end_port, max_attempts
-        )))
-    }
-
     fn find_next_server_number(&self, ctx: &ServerContext) -> u32 {
         let servers = match ctx.servers.read() {
             Ok(s) => s,
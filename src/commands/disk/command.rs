@@ -0,0 +1,185 @@
+use crate::commands::command::Command;
+use crate::core::helpers::{collect_files, dir_size, format_bytes};
+use crate::core::prelude::*;
+use std::path::Path;
+
+const LARGEST_FILES: usize = 5;
+
+#[derive(Debug, Default)]
+pub struct DiskCommand;
+
+impl DiskCommand {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Command for DiskCommand {
+    fn name(&self) -> &'static str {
+        "disk"
+    }
+
+    fn description(&self) -> &'static str {
+        "Show disk usage of www/ and .rss/ (read-only)"
+    }
+
+    fn matches(&self, command: &str) -> bool {
+        command.trim().to_lowercase() == "disk"
+    }
+
+    fn execute_sync(&self, args: &[&str]) -> Result<String> {
+        if let Some(&arg) = args.first() {
+            return Err(AppError::Validation(format!(
+                "Unknown argument '{}'. Usage: disk",
+                arg
+            )));
+        }
+
+        let base_dir = crate::core::paths::base_dir()?;
+        Ok(DiskReport::scan(&base_dir).render())
+    }
+
+    fn priority(&self) -> u8 {
+        50
+    }
+}
+
+/// Sizes per server directory, per log and of the remaining .rss/ entries
+#[derive(Debug, Default)]
+struct DiskReport {
+    www: Vec<(String, u64)>,
+    logs: Vec<(String, u64)>,
+    other: Vec<(String, u64)>,
+    largest: Vec<(String, u64)>,
+    total: u64,
+}
+
+impl DiskReport {
+    fn scan(base_dir: &Path) -> Self {
+        let www_dir = base_dir.join("www");
+        let rss_dir = base_dir.join(".rss");
+        let logs_dir = rss_dir.join("servers");
+
+        let www = Self::entry_sizes(&www_dir, |_| true);
+        let logs = Self::entry_sizes(&logs_dir, |_| true);
+        let other = Self::entry_sizes(&rss_dir, |name| name != "servers");
+
+        let mut largest: Vec<(String, u64)> = collect_files(&www_dir)
+            .into_iter()
+            .chain(collect_files(&rss_dir))
+            .map(|(path, size)| {
+                let relative = path.strip_prefix(base_dir).unwrap_or(&path);
+                (relative.display().to_string(), size)
+            })
+            .collect();
+        largest.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        largest.truncate(LARGEST_FILES);
+
+        let total = [&www, &logs, &other]
+            .iter()
+            .flat_map(|group| group.iter())
+            .map(|(_, size)| size)
+            .sum();
+
+        Self {
+            www,
+            logs,
+            other,
+            largest,
+            total,
+        }
+    }
+
+    /// Direct children of `dir` with their (recursive) size, largest first
+    fn entry_sizes(dir: &Path, include: impl Fn(&str) -> bool) -> Vec<(String, u64)> {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return Vec::new();
+        };
+
+        let mut sizes: Vec<(String, u64)> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name().to_string_lossy().to_string();
+                if !include(&name) {
+                    return None;
+                }
+                let file_type = entry.file_type().ok()?;
+                let size = if file_type.is_dir() {
+                    dir_size(&entry.path())
+                } else {
+                    entry.metadata().map(|m| m.len()).unwrap_or(0)
+                };
+                Some((name, size))
+            })
+            .collect();
+
+        sizes.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        sizes
+    }
+
+    fn render(&self) -> String {
+        let mut result = String::from("\n  Disk Usage\n");
+
+        Self::render_group(&mut result, "www/", &self.www);
+        Self::render_group(&mut result, ".rss/servers/ (logs)", &self.logs);
+        Self::render_group(&mut result, ".rss/", &self.other);
+
+        if !self.largest.is_empty() {
+            result.push_str("\n  Largest files:\n");
+            for (path, size) in &self.largest {
+                result.push_str(&format!("    {:>9}  {}\n", format_bytes(*size), path));
+            }
+        }
+
+        result.push_str(&format!("\n  Total: {}\n", format_bytes(self.total)));
+        result
+    }
+
+    fn render_group(result: &mut String, title: &str, entries: &[(String, u64)]) {
+        let group_total: u64 = entries.iter().map(|(_, size)| size).sum();
+        result.push_str(&format!("\n  {} {}\n", title, format_bytes(group_total)));
+
+        if entries.is_empty() {
+            result.push_str("    (empty)\n");
+        }
+        for (name, size) in entries {
+            result.push_str(&format!("    {:>9}  {}\n", format_bytes(*size), name));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_groups_and_sorts_by_size() {
+        let base = std::env::temp_dir().join(format!("rush-disk-{}", std::process::id()));
+        let write = |rel: &str, len: usize| {
+            let path = base.join(rel);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, vec![b'x'; len]).unwrap();
+        };
+        write("www/small-[8080]/index.html", 10);
+        write("www/big-[8081]/index.html", 300);
+        write("www/big-[8081]/assets/app.js", 700);
+        write(".rss/servers/big-[8081].log", 50);
+        write(".rss/certs/big.pem", 20);
+
+        let report = DiskReport::scan(&base);
+        std::fs::remove_dir_all(&base).ok();
+
+        assert_eq!(
+            report.www,
+            vec![
+                ("big-[8081]".to_string(), 1000),
+                ("small-[8080]".to_string(), 10)
+            ]
+        );
+        assert_eq!(report.logs, vec![("big-[8081].log".to_string(), 50)]);
+        assert_eq!(report.other, vec![("certs".to_string(), 20)]);
+        assert_eq!(report.total, 1080);
+        assert_eq!(report.largest[0].1, 700);
+        assert!(report.largest[0].0.ends_with("app.js"));
+    }
+}
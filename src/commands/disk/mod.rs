@@ -0,0 +1,3 @@
+// src/commands/disk/mod.rs
+pub mod command;
+pub use command::DiskCommand;
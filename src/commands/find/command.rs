@@ -0,0 +1,134 @@
+use crate::commands::command::Command;
+use crate::core::constants::SIG_OUTPUT_SEARCH;
+use crate::core::prelude::*;
+
+/// What `find` asks the screen to do with the output buffer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FindAction {
+    Query { text: String, case_sensitive: bool },
+    Next,
+    Prev,
+    ToggleCase,
+    Clear,
+}
+
+impl FindAction {
+    pub fn to_signal(&self) -> String {
+        let payload = match self {
+            Self::Query {
+                text,
+                case_sensitive,
+            } => format!("{}:{}", if *case_sensitive { "c" } else { "i" }, text),
+            Self::Next => "next".to_string(),
+            Self::Prev => "prev".to_string(),
+            Self::ToggleCase => "case".to_string(),
+            Self::Clear => "clear".to_string(),
+        };
+        format!("{}{}", SIG_OUTPUT_SEARCH, payload)
+    }
+
+    pub fn from_signal(signal: &str) -> Option<Self> {
+        let payload = signal.strip_prefix(SIG_OUTPUT_SEARCH)?;
+        match payload {
+            "next" => Some(Self::Next),
+            "prev" => Some(Self::Prev),
+            "case" => Some(Self::ToggleCase),
+            "clear" => Some(Self::Clear),
+            _ => {
+                let (mode, text) = payload.split_once(':')?;
+                Some(Self::Query {
+                    text: text.to_string(),
+                    case_sensitive: mode == "c",
+                })
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct FindCommand;
+
+impl Command for FindCommand {
+    fn name(&self) -> &'static str {
+        "find"
+    }
+
+    fn description(&self) -> &'static str {
+        "Search the output and highlight matches"
+    }
+
+    fn matches(&self, command: &str) -> bool {
+        command
+            .split_whitespace()
+            .next()
+            .is_some_and(|first| first.eq_ignore_ascii_case("find"))
+    }
+
+    fn execute_sync(&self, args: &[&str]) -> Result<String> {
+        let action = match args {
+            [] | ["--clear"] => FindAction::Clear,
+            ["--next" | "-n"] => FindAction::Next,
+            ["--prev" | "-p"] => FindAction::Prev,
+            ["--case" | "-c"] => FindAction::ToggleCase,
+            ["--case" | "-c", text @ ..] => FindAction::Query {
+                text: text.join(" "),
+                case_sensitive: true,
+            },
+            [flag, ..] if flag.starts_with("--") => {
+                return Err(AppError::Validation(format!(
+                    "Unknown find option '{}'. Use find [--case] <text>, --next, --prev or --clear",
+                    flag
+                )));
+            }
+            text => FindAction::Query {
+                text: text.join(" "),
+                case_sensitive: false,
+            },
+        };
+        Ok(action.to_signal())
+    }
+
+    fn priority(&self) -> u8 {
+        60
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_actions_round_trip() {
+        let cmd = FindCommand;
+        let signal = cmd.execute_sync(&["server", "started"]).unwrap();
+        assert_eq!(
+            FindAction::from_signal(&signal),
+            Some(FindAction::Query {
+                text: "server started".into(),
+                case_sensitive: false,
+            })
+        );
+
+        let signal = cmd.execute_sync(&["--case", "ERROR"]).unwrap();
+        assert_eq!(
+            FindAction::from_signal(&signal),
+            Some(FindAction::Query {
+                text: "ERROR".into(),
+                case_sensitive: true,
+            })
+        );
+
+        for (args, action) in [
+            (vec![], FindAction::Clear),
+            (vec!["--next"], FindAction::Next),
+            (vec!["-p"], FindAction::Prev),
+            (vec!["--case"], FindAction::ToggleCase),
+        ] {
+            let signal = cmd.execute_sync(&args).unwrap();
+            assert_eq!(FindAction::from_signal(&signal), Some(action));
+        }
+
+        assert!(cmd.execute_sync(&["--bogus"]).is_err());
+        assert_eq!(FindAction::from_signal("find"), None);
+    }
+}
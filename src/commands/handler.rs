@@ -1,8 +1,12 @@
 use super::registry::CommandRegistry;
 use crate::core::prelude::*;
 use crate::i18n;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
+/// Commands run this session; survives restarts like the session start time
+static COMMANDS_EXECUTED: AtomicU64 = AtomicU64::new(0);
+
 #[derive(Debug, Clone)]
 pub struct CommandResult {
     pub message: String,
@@ -64,6 +68,11 @@ impl CommandHandler {
         }
     }
 
+    /// Number of recognized commands executed this session
+    pub fn commands_executed() -> u64 {
+        COMMANDS_EXECUTED.load(Ordering::Relaxed)
+    }
+
     pub fn list_commands(&self) -> Vec<(&str, &str)> {
         self.registry.list_commands()
     }
@@ -99,8 +108,11 @@ impl CommandHandler {
         log::debug!("Processing command: '{}'", parts.command);
 
         match self.registry.execute_sync(parts.command, &parts.args) {
-            Some(result) => self.process_command_result(result),
-            None => self.create_unknown_command_result(input.trim()),
+            Some(result) => {
+                COMMANDS_EXECUTED.fetch_add(1, Ordering::Relaxed);
+                self.process_command_result(result)
+            }
+            None => self.create_unknown_command_result(input.trim(), parts.command),
         }
     }
 
@@ -116,8 +128,11 @@ impl CommandHandler {
             .execute_async(parts.command, &parts.args)
             .await
         {
-            Some(result) => self.process_command_result(result),
-            None => self.create_unknown_command_result(input.trim()),
+            Some(result) => {
+                COMMANDS_EXECUTED.fetch_add(1, Ordering::Relaxed);
+                self.process_command_result(result)
+            }
+            None => self.create_unknown_command_result(input.trim(), parts.command),
         }
     }
 
@@ -141,9 +156,17 @@ impl CommandHandler {
         }
     }
 
-    fn create_unknown_command_result(&self, input: &str) -> CommandResult {
+    fn create_unknown_command_result(&self, input: &str, command: &str) -> CommandResult {
         log::warn!("Unknown command: '{}'", input);
-        CommandResult::error(&UnknownCommandCache::get_message(input))
+        let mut message = UnknownCommandCache::get_message(input);
+        if let Some(suggestion) = self.registry.suggest(command) {
+            message.push_str(" - ");
+            message.push_str(&i18n::get_translation(
+                "system.commands.did_you_mean",
+                &[suggestion],
+            ));
+        }
+        CommandResult::error(&message)
     }
 
     fn log_command_success(&self, msg: &str) {
@@ -48,20 +48,38 @@ impl HelpCommand {
                 "  create                   Create server with auto name/port\n  \
                  create <name>             Create server with custom name\n  \
                  create <name> <port>      Create with name and port\n  \
+                 create --name <n> [port]  Reserve a name (a-z, 0-9, -)\n  \
                  create <count>            Bulk create (1-100 servers)\n  \
-                 create <name> <port> <n>  Bulk with base name, port, count\n\n  \
+                 create <name> <port> <n>  Bulk with base name, port, count\n  \
+                 --port-range <a-b>        Auto ports only from a-b\n  \
+                 --env KEY=VALUE           Replace {{KEY}} in served HTML (repeatable)\n  \
+                 --workers N, -w N         Workers for this server (1-16), overrides\n  \
+                 [server] workers. 1 serializes request handling for debugging\n  \
+                 --header \"Name: value\"    Add a response header (repeatable); headers\n  \
+                 a response already sets are kept\n  \
+                 --on-stop \"cmd\"           Shell command run after the server stops\n  \
+                 (output in its log, killed after 30s)\n\n  \
+                 CORS comes from [server] cors_origins, not from create: unset,\n  \
+                 localhost origins get CORS headers while production_domain is\n  \
+                 \"localhost\" and no origin does otherwise; [\"*\"] allows any\n\n  \
                  Examples:\n    \
                  create                    -> rss-001 on next free port\n    \
                  create mysite             -> mysite on next free port\n    \
                  create mysite 8080        -> mysite on port 8080\n    \
+                 create --name blog        -> blog, served at blog.localhost\n    \
                  create 50                 -> 50 servers (rss-001..rss-050)\n    \
-                 create web 8001 10        -> web-001:8001 .. web-010:8010",
+                 create web 8001 10        -> web-001:8001 .. web-010:8010\n    \
+                 create shop --env API_URL=https://api.example.com\n    \
+                 create dev --workers 1    -> one request at a time\n    \
+                 create app --header \"X-Frame-Options: DENY\"\n    \
+                 create api --on-stop \"redis-cli flushall\"",
             ),
             "start" => Some(
                 "  start <id|name|number>   Start a single server\n  \
                  start <start>-<end>       Start range of servers\n  \
                  start all                 Start all stopped servers\n  \
-                 --workers N, -w N         Set workers per server (1-16)\n\n  \
+                 --workers N, -w N         Set workers per server (1-16), this run only\n  \
+                 --auto-port               Move to the next free port if the port is taken\n\n  \
                  Note: 'start all' and ranges skip browser opening.\n  \
                  Bulk operations show time + memory benchmarks.\n\n  \
                  Examples:\n    \
@@ -70,14 +88,17 @@ impl HelpCommand {
                  start 1-10               -> start servers 1 through 10\n    \
                  start all                 -> start all stopped servers\n    \
                  start rss-001 --workers 3 -> start with 3 workers\n    \
-                 start all -w 2            -> start all with 2 workers each",
+                 start all -w 2            -> start all with 2 workers each\n    \
+                 start rss-001 --auto-port -> move to a free port if needed",
             ),
             "stop" => Some(
                 "  stop <id|name|number>    Stop a single server\n  \
                  stop <start>-<end>        Stop range of servers\n  \
-                 stop all                  Stop all running servers\n\n  \
+                 stop all                  Stop all running servers\n  \
+                 stop <id> --drain <sec>   Finish in-flight requests first\n\n  \
                  Examples:\n    \
                  stop rss-001              -> stop by name\n    \
+                 stop 1 --drain 10         -> wait up to 10s for requests\n    \
                  stop 1                    -> stop server #1\n    \
                  stop 1-5                  -> stop servers 1 through 5\n    \
                  stop all                  -> stop all running servers",
@@ -112,25 +133,48 @@ impl HelpCommand {
                  cleanup www               Clean all www directories\n  \
                  cleanup www <name>        Clean specific server www\n  \
                  cleanup all               Clean everything\n  \
+                 cleanup -i                Confirm each item (y/n/a/q)\n  \
+                 --older-than <duration>   Only items inactive longer (30m, 12h, 7d, 2w)\n  \
+                 --dry-run                 List what would be removed, delete nothing\n  \
                  cleanup --force-stopped   Skip confirmation\n  \
                  cleanup --force-failed    Skip confirmation\n  \
                  cleanup --force-logs      Skip confirmation\n  \
                  cleanup --force-www       Skip confirmation\n  \
                  cleanup --force-all       Skip confirmation",
             ),
+            "server" => Some(
+                "  server export [file]     Write server definitions to JSON\n  \
+                 server import <file>      Re-create servers from an export\n  \
+                 server health             Check /api/health of all running servers\n  \
+                 server start --all        Start every stopped server (same as start all)\n  \
+                 server stop --all         Stop every running server (same as stop all)\n  \
+                 server open <id>          Open the server's www/ dir in the file manager\n  \
+                 server browse <id>        Open the server in the default browser\n  \
+                 (https://<name>.localhost with proxy + HTTPS)\n  \
+                 server reload <id>        Reload every page open on a running server\n\n  \
+                 Import skips existing IDs; taken ports are remapped.",
+            ),
+            "disk" => Some(
+                "  disk                     Size per server www/ dir and log, largest files, total\n  \
+                 Read-only - use 'cleanup' to free space",
+            ),
             "recover" => Some(
                 "  recover                  Auto-fix inconsistent servers\n  \
                  recover all               Fix all servers\n  \
-                 recover <id>              Fix specific server\n\n  \
+                 recover <id>              Fix specific server\n  \
+                 recover orphans           Reset servers persisted as running whose port is unbound\n  \
+                 recover orphans --restart Start those servers again instead\n\n  \
                  Aliases: fix, status-fix",
             ),
             "remote" => Some(
                 "  remote list              List SSH profiles\n  \
-                 remote add <name> <user@host> <path> [port] [key]\n  \
+                 remote add <name> <user@host> <path> [port] [key] [--alias <a>]\n  \
+                 remote alias <name> [a]   Set or clear the short alias\n  \
                  remote show <name>        Show profile details\n  \
                  remote remove <name>      Delete profile\n  \
                  remote test <name>        Test SSH connection\n  \
                  remote help               Show help\n\n  \
+                 <name> also accepts the alias or user@host\n\n  \
                  Aliases: remote ls, remote rm, remote delete",
             ),
             "sync" => Some(
@@ -139,21 +183,42 @@ impl HelpCommand {
                  sync test <remote>        Test connection\n  \
                  sync exec <remote> <cmd>  Run remote command\n  \
                  sync restart <remote>     Restart remote service\n  \
-                 sync git-pull <remote>    Remote git pull\n\n  \
+                 sync git-pull <remote>    Remote git pull\n  \
+                 sync status               Last push/pull and error per remote\n  \
+                 sync disconnect [remote]  Close cached SSH connections\n\n  \
                  Flags:\n    \
                  --delete                  Remove files not in source\n    \
-                 --dry-run, -n             Preview without applying",
+                 --dry-run, -n             List files and sizes, apply nothing",
+            ),
+            "tls" => Some(
+                "  tls status               Show Let's Encrypt state, SANs and renewal\n  \
+                 tls renew [--force]      Renew if due, or immediately with --force\n  \
+                 tls provision <domain>   Provision Let's Encrypt certificate\n  \
+                 --challenge-only          Serve ACME challenges on :80 only, write cert/key, exit\n  \
+                 --staging                 Use Let's Encrypt staging",
             ),
             "theme" => Some(
                 "  theme                    Show current & available themes\n  \
                  theme <name>              Switch theme (live)\n  \
-                 theme preview <name>      Preview theme\n  \
+                 theme change <name>       Same as 'theme <name>'\n  \
+                 theme next                Switch to the next theme (sorted, wraps around)\n  \
+                 theme random              Switch to a random other theme\n  \
+                 theme preview <name>      Preview theme (--live: try it in the UI briefly)\n  \
                  theme debug <name>        Show theme details\n  \
-                 theme -h, --help          Show help",
+                 theme reload              Re-read [theme.*] from rush.toml\n  \
+                 theme migrate             Rename legacy theme keys in rush.toml\n  \
+                 theme create <name> --<key> <value> ... [--force]\n  \
+                                           Write a new [theme.<name>] to rush.toml\n  \
+                 theme export <name> [--file <path>]\n  \
+                                           Print the theme as a TOML block or write it to a file\n  \
+                 theme -h, --help          Show help\n\n  \
+                 Built-in: default, high-contrast, deuteranopia (override with [theme.<name>])",
             ),
             "lang" | "language" => Some(
                 "  lang                     Show current language\n  \
-                 lang <code>               Switch language (en, de, fr...)",
+                 lang <code>               Switch language (en, de, fr...)\n  \
+                 lang reload               Re-read .rss/langs/<code>.json overrides\n  \
+                 lang missing              List keys missing in the current language",
             ),
             "log-level" => Some(
                 "  log-level                Show current level\n  \
@@ -161,19 +226,53 @@ impl HelpCommand {
                  log-level -h, --help      Show help",
             ),
             "history" => Some(
-                "  history                  Show info\n  \
+                "  history                  Show entry count and limit\n  \
+                 history limit <N>         Set the history size (saved to rush.toml)\n  \
                  history -c, --clear       Clear with confirmation\n  \
                  history -fc, --force-clear  Force clear\n  \
+                 history search [text]     Reverse search (also Ctrl+R)\n  \
                  history -h, --help        Show help",
             ),
             "version" => Some(
-                "  version                  Show version info\n\n  \
+                "  version                  Show version info\n  \
+                 version --verbose, -v    Also show commit, build time,\n  \
+                 rustc, target and enabled features\n\n  \
                  Alias: ver",
             ),
+            "uptime" => Some(
+                "  uptime                   Session runtime and number of\n  \
+                 commands executed since start",
+            ),
+            "session" => Some(
+                "  session                  Version, theme, language, servers,\n  \
+                 uptime, data dir and config path\n\n  \
+                 Alias: whoami",
+            ),
             "clear" => Some(
-                "  clear                    Clear screen\n\n  \
+                "  clear                    Clear screen (same as clear --output)\n  \
+                 clear --history          Clear command history, incl. the saved history file\n  \
+                 clear --all              Clear screen and command history\n\n  \
                  Alias: cls",
             ),
+            "find" => Some(
+                "  find <text>              Search the output (case-insensitive)\n  \
+                 find --case <text>        Case-sensitive search\n  \
+                 find --case               Toggle case sensitivity of the active search\n  \
+                 find --next / --prev      Cycle matches (or F3 / Shift+F3)\n  \
+                 find, find --clear        End the search",
+            ),
+            "wrap" => Some(
+                "  wrap                     Toggle line wrapping of the output\n  \
+                 wrap on|off               Wrap long lines / clip them to the width\n\n  \
+                 Unwrapped lines scroll sideways with Shift+Left/Right",
+            ),
+            "archive" => Some(
+                "  archive                  Last 50 messages dropped from the output\n  \
+                 archive <N>               Last N archived messages\n  \
+                 archive path              Show the archive file (.rss/session.log)\n  \
+                 archive clear             Delete the archive\n\n  \
+                 Enable with archive_overflow = true in [general]",
+            ),
             "exit" => Some(
                 "  exit                     Exit with confirmation\n\n  \
                  Alias: q",
@@ -240,14 +339,20 @@ impl HelpCommand {
             for (name, description) in &commands {
                 // Show short usage hint next to description
                 let usage_hint = match *name {
-                    "create" => " (create [name] [port] [count])",
-                    "start" => " (start <id|name|all|1-N> [-w N])",
+                    "create" => " (create [name|--name <name>] [port] [count] [-w N])",
+                    "start" => " (start <id|name|all|1-N> [-w N] [--auto-port])",
                     "stop" => " (stop <id|name|all|1-N>)",
-                    "cleanup" => " (cleanup [stopped|failed|logs|www|all])",
-                    "sync" => " (sync push|pull|test|exec ...)",
-                    "remote" => " (remote list|add|show|remove|test)",
-                    "lang" | "language" => " (lang [code])",
-                    "theme" => " (theme [name|preview|debug])",
+                    "server" => " (server export|import|health|start|stop|open|browse|reload ...)",
+                    "cleanup" => " (cleanup [stopped|failed|logs|www|all|-i])",
+                    "sync" => " (sync push|pull|status|test|exec ...)",
+                    "remote" => " (remote list|add|alias|show|remove|test)",
+                    "tls" => " (tls status|renew [--force]|provision <domain>)",
+                    "lang" | "language" => " (lang [code|reload|missing])",
+                    "find" => " (find [--case] <text>|--next|--prev)",
+                    "wrap" => " (wrap [on|off])",
+                    "archive" => " (archive [N|path|clear])",
+                    "version" => " (version [--verbose])",
+                    "theme" => " (theme [name|next|random|preview|debug|reload|migrate|create|export])",
                     "log-level" => " (log-level [level])",
                     _ => "",
                 };
@@ -274,9 +379,24 @@ impl HelpCommand {
             {
                 "server_control"
             }
-            name if name.starts_with("create") || name.starts_with("list") => "server_management",
-            name if name.starts_with("remote") || name.starts_with("sync") => "deployment",
-            name if name.starts_with("cleanup") || name.starts_with("recover") => "maintenance",
+            name if name.starts_with("create")
+                || name.starts_with("list")
+                || name.starts_with("server") =>
+            {
+                "server_management"
+            }
+            name if name.starts_with("remote")
+                || name.starts_with("sync")
+                || name.starts_with("tls") =>
+            {
+                "deployment"
+            }
+            name if name.starts_with("cleanup")
+                || name.starts_with("recover")
+                || name.starts_with("disk") =>
+            {
+                "maintenance"
+            }
             name if name.starts_with("theme")
                 || name.starts_with("lang")
                 || name.starts_with("log-level") =>
@@ -285,11 +405,20 @@ impl HelpCommand {
             }
             name if name.starts_with("help")
                 || name.starts_with("version")
+                || name.starts_with("session")
+                || name.starts_with("uptime")
                 || name.starts_with("history") =>
             {
                 "information"
             }
-            name if name.starts_with("exit") || name.starts_with("clear") => "system",
+            name if name.starts_with("exit")
+                || name.starts_with("clear")
+                || name.starts_with("find")
+                || name.starts_with("wrap")
+                || name.starts_with("archive") =>
+            {
+                "system"
+            }
             _ => "other",
         }
     }
@@ -30,6 +30,16 @@ impl Command for HistoryCommand {
 
             Some(&"--force-clear" | &"-fc") => Ok(SIG_CLEAR_HISTORY.to_string()),
 
+            Some(&"search" | &"-s") => Ok(format!(
+                "{}{}",
+                crate::core::constants::SIG_HISTORY_SEARCH,
+                args[1..].join(" ")
+            )),
+
+            Some(&"limit") => self.set_limit(args.get(1).copied()),
+
+            None => Ok(crate::core::constants::SIG_HISTORY_STATUS.to_string()),
+
             Some(&"-h" | &"--help") => {
                 Ok(get_command_translation("system.commands.history.help", &[]))
             }
@@ -45,3 +55,34 @@ impl Command for HistoryCommand {
         60
     }
 }
+
+impl HistoryCommand {
+    /// Validates the new cap, writes it to rush.toml and hands it to the input state
+    fn set_limit(&self, value: Option<&str>) -> Result<String> {
+        let limit = value
+            .and_then(|v| v.parse::<usize>().ok())
+            .filter(|n| (1..=MAX_HISTORY_LIMIT).contains(n))
+            .ok_or_else(|| {
+                AppError::Validation(get_translation(
+                    "system.commands.history.invalid_limit",
+                    &[&MAX_HISTORY_LIMIT.to_string()],
+                ))
+            })?;
+
+        tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current().block_on(async {
+                let mut config = Config::load().await?;
+                config.max_history = limit;
+                config.save().await
+            })
+        })?;
+
+        Ok(format!(
+            "{}{}",
+            crate::core::constants::SIG_HISTORY_LIMIT,
+            limit
+        ))
+    }
+}
+
+const MAX_HISTORY_LIMIT: usize = 10_000;
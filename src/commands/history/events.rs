@@ -1,18 +1,31 @@
 #[derive(Debug, Clone, PartialEq)]
 pub enum HistoryEvent {
     Clear,
+    /// History and output buffer (`clear --all`)
+    ClearAll,
     Add(String),
     NavigatePrevious,
     NavigateNext,
     ResetPosition,
+    Search(String),
+    SetLimit(usize),
+    Status,
 }
 
 pub struct HistoryEventHandler;
 
 impl HistoryEventHandler {
     pub fn handle_command_result(result: &str) -> Option<HistoryEvent> {
+        if let Some(query) = result.strip_prefix(crate::core::constants::SIG_HISTORY_SEARCH) {
+            return Some(HistoryEvent::Search(query.to_string()));
+        }
+        if let Some(limit) = result.strip_prefix(crate::core::constants::SIG_HISTORY_LIMIT) {
+            return limit.parse().ok().map(HistoryEvent::SetLimit);
+        }
         match result {
             "__CLEAR_HISTORY__" => Some(HistoryEvent::Clear),
+            "__CLEAR_ALL__" => Some(HistoryEvent::ClearAll),
+            "__HISTORY_STATUS__" => Some(HistoryEvent::Status),
             _ => None,
         }
     }
@@ -25,17 +25,15 @@ impl HistoryManager {
     }
 
     fn get_history_path() -> PathBuf {
-        if let Ok(exe_path) = std::env::current_exe() {
-            if let Some(base_dir) = exe_path.parent() {
-                let history_path = base_dir.join(".rss").join("rush.history");
+        if let Ok(base_dir) = crate::core::paths::base_dir() {
+            let history_path = base_dir.join(".rss").join("rush.history");
 
-                // Create directory if it doesn't exist
-                if let Some(parent) = history_path.parent() {
-                    let _ = std::fs::create_dir_all(parent);
-                }
-
-                return history_path;
+            // Create directory if it doesn't exist
+            if let Some(parent) = history_path.parent() {
+                let _ = std::fs::create_dir_all(parent);
             }
+
+            return history_path;
         }
         PathBuf::from("rush.history") // Fallback
     }
@@ -45,26 +43,47 @@ impl HistoryManager {
             return;
         }
 
-        if let Ok(content) = std::fs::read_to_string(&self.file_path) {
-            for line in content.lines() {
-                let trimmed = line.trim();
-                if !trimmed.is_empty() && !self.entries.contains(&trimmed.to_string()) {
-                    self.entries.push(trimmed.to_string());
-                }
+        match std::fs::read_to_string(&self.file_path) {
+            Ok(content) => {
+                self.entries = Self::parse_entries(&content, self.max_size);
+                log::info!("Loaded {} history entries", self.entries.len());
             }
+            // Binary garbage or unreadable file: start with an empty history
+            Err(e) => log::warn!(
+                "History file {} unreadable, starting empty: {}",
+                self.file_path.display(),
+                e
+            ),
+        }
+    }
 
-            // Trim to max_size
-            if self.entries.len() > self.max_size {
-                self.entries.drain(0..self.entries.len() - self.max_size);
+    /// Newline-delimited entries, deduplicated and capped at `max_size`.
+    /// Lines with control characters (partial/corrupt writes) are skipped.
+    fn parse_entries(content: &str, max_size: usize) -> Vec<String> {
+        let mut entries: Vec<String> = Vec::new();
+        for line in content.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.chars().any(char::is_control) {
+                continue;
             }
+            if !entries.iter().any(|e| e == trimmed) {
+                entries.push(trimmed.to_string());
+            }
+        }
 
-            log::info!("Loaded {} history entries", self.entries.len());
+        if entries.len() > max_size {
+            entries.drain(0..entries.len() - max_size);
         }
+        entries
     }
 
+    /// Writes to a temp file first so a crash never leaves a half-written history
     fn save_to_file(&self) {
         let content = self.entries.join("\n");
-        if let Err(e) = std::fs::write(&self.file_path, content) {
+        let tmp_path = self.file_path.with_extension("history.tmp");
+        let result = std::fs::write(&tmp_path, content)
+            .and_then(|_| std::fs::rename(&tmp_path, &self.file_path));
+        if let Err(e) = result {
             log::error!("Failed to save history: {}", e);
         }
     }
@@ -115,6 +134,17 @@ impl HistoryManager {
         None
     }
 
+    /// Entries containing `query` (case-insensitive), most recent first
+    pub fn search(&self, query: &str) -> Vec<String> {
+        let query = query.to_lowercase();
+        self.entries
+            .iter()
+            .rev()
+            .filter(|entry| entry.to_lowercase().contains(&query))
+            .cloned()
+            .collect()
+    }
+
     pub fn clear(&mut self) {
         self.entries.clear();
         self.position = None;
@@ -123,6 +153,21 @@ impl HistoryManager {
         log::info!("History cleared");
     }
 
+    /// Changes the cap live; drops the oldest entries if it shrinks
+    pub fn set_max_size(&mut self, max_size: usize) {
+        self.max_size = max_size.max(1);
+        if self.entries.len() > self.max_size {
+            let excess = self.entries.len() - self.max_size;
+            self.entries.drain(0..excess);
+            self.position = None;
+            self.save_to_file();
+        }
+    }
+
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
     pub fn reset_position(&mut self) {
         self.position = None;
     }
@@ -149,3 +194,50 @@ impl HistoryManager {
         self.entries.len()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_orders_by_recency() {
+        let manager = HistoryManager {
+            entries: vec![
+                "start 1".to_string(),
+                "theme dark".to_string(),
+                "stop 1".to_string(),
+                "Start all".to_string(),
+            ],
+            position: None,
+            max_size: 10,
+            file_path: PathBuf::new(),
+        };
+
+        assert_eq!(manager.search("start"), vec!["Start all", "start 1"]);
+        assert_eq!(manager.search("1"), vec!["stop 1", "start 1"]);
+        assert!(manager.search("xyz").is_empty());
+    }
+
+    #[test]
+    fn test_set_max_size_trims_oldest() {
+        let mut manager = HistoryManager {
+            entries: vec!["a".into(), "b".into(), "c".into()],
+            position: Some(1),
+            max_size: 10,
+            file_path: std::env::temp_dir().join("rush-history-limit-test"),
+        };
+        manager.set_max_size(2);
+        assert_eq!(manager.get_all_entries(), vec!["b", "c"]);
+        assert_eq!(manager.max_size(), 2);
+        let _ = std::fs::remove_file(&manager.file_path);
+    }
+
+    #[test]
+    fn test_parse_entries_skips_corrupt_lines_and_caps() {
+        let content = "start 1\nstart 1\n\u{0}\u{1}garbage\n  \nstop 1\nlist\nthe";
+        assert_eq!(
+            HistoryManager::parse_entries(content, 3),
+            vec!["stop 1", "list", "the"]
+        );
+    }
+}
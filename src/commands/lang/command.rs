@@ -39,6 +39,8 @@ impl Command for LanguageCommand {
 
         match args.first() {
             None => Ok(service.show_status()),
+            Some(&"reload") => Ok(service.reload()),
+            Some(&"missing") => Ok(service.show_missing()),
             Some(&lang) => match service.switch_language_only(lang) {
                 Ok(()) => {
                     let msg = crate::i18n::get_command_translation(
@@ -54,6 +54,52 @@ impl LanguageService {
         }
     }
 
+    /// `lang reload`: picks up edited `.rss/langs/<lang>.json` overrides
+    pub fn reload(&self) -> String {
+        let lang = get_current_language();
+        if let Err(e) = crate::i18n::reload_language() {
+            return crate::i18n::get_command_translation(
+                "system.commands.language.reload_failed",
+                &[&e.to_string()],
+            );
+        }
+        match crate::i18n::override_path(&lang) {
+            Some(path) => crate::i18n::get_command_translation(
+                "system.commands.language.reloaded_override",
+                &[&lang, &path.display().to_string()],
+            ),
+            None => {
+                crate::i18n::get_command_translation("system.commands.language.reloaded", &[&lang])
+            }
+        }
+    }
+
+    /// `lang missing`: keys the active language lacked so far this session
+    pub fn show_missing(&self) -> String {
+        let lang = get_current_language();
+        let missing = crate::i18n::get_missing_translations();
+        if missing.is_empty() {
+            return crate::i18n::get_command_translation(
+                "system.commands.language.missing_none",
+                &[&lang],
+            );
+        }
+
+        let mut out = crate::i18n::get_command_translation(
+            "system.commands.language.missing_header",
+            &[&missing.len().to_string(), &lang],
+        );
+        for item in &missing {
+            out.push_str(&format!(
+                "\n  {:<50} {:>4}x  -> {}",
+                item.key,
+                item.count,
+                item.fallback.as_deref().unwrap_or("-")
+            ));
+        }
+        out
+    }
+
     pub fn switch_language_only(&self, lang: &str) -> Result<()> {
         set_language(lang)
     }
@@ -109,11 +155,17 @@ impl LanguageService {
 
     fn update_language_in_toml(&self, content: &str, lang: &str) -> Result<String> {
         let updated_content = if content.contains("[language]") {
+            let has_current = content
+                .lines()
+                .any(|line| line.trim_start().starts_with("current ="));
             content
                 .lines()
                 .map(|line| {
                     if line.trim_start().starts_with("current =") {
                         format!("current = \"{}\"", lang)
+                    } else if !has_current && line.trim() == "[language]" {
+                        // Section without a stored language (auto-detected so far)
+                        format!("{}\ncurrent = \"{}\"", line, lang)
                     } else {
                         line.to_string()
                     }
@@ -172,6 +224,10 @@ impl LanguageService {
     ) -> Result<()> {
         let lang = &config.language;
 
+        if let Err(e) = crate::i18n::set_fallback_languages(&config.language_fallback) {
+            log::warn!("i18n fallback not applied: {}", e);
+        }
+
         if let Err(e) = crate::i18n::set_language(lang) {
             log::warn!(
                 "{}",
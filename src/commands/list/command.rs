@@ -77,7 +77,9 @@ impl ListCommand {
                         i += 1;
                         SortMode::PortDesc
                     } else {
-                        if dir.as_deref() == Some("asc") { i += 1; }
+                        if dir.as_deref() == Some("asc") {
+                            i += 1;
+                        }
                         SortMode::PortAsc
                     };
                 }
@@ -87,7 +89,9 @@ impl ListCommand {
                         i += 1;
                         SortMode::NameDesc
                     } else {
-                        if dir.as_deref() == Some("asc") { i += 1; }
+                        if dir.as_deref() == Some("asc") {
+                            i += 1;
+                        }
                         SortMode::NameAsc
                     };
                 }
@@ -96,7 +100,11 @@ impl ListCommand {
             i += 1;
         }
 
-        ListOpts { status_filter, sort_mode, show_memory }
+        ListOpts {
+            status_filter,
+            sort_mode,
+            show_memory,
+        }
     }
 
     fn list_servers(
@@ -138,7 +146,7 @@ impl ListCommand {
         // Sort
         match sort_mode {
             SortMode::PortAsc => server_list.sort_by_key(|s| s.port),
-            SortMode::PortDesc => server_list.sort_by(|a, b| b.port.cmp(&a.port)),
+            SortMode::PortDesc => server_list.sort_by_key(|s| std::cmp::Reverse(s.port)),
             SortMode::NameAsc => server_list.sort_by(|a, b| a.name.cmp(&b.name)),
             SortMode::NameDesc => server_list.sort_by(|a, b| b.name.cmp(&a.name)),
         }
@@ -168,10 +176,7 @@ impl ListCommand {
                 ServerStatus::Failed => "[Failed]",
             };
 
-            let url = format!(
-                "http://{}:{}",
-                config.server.bind_address, server.port
-            );
+            let url = format!("http://{}:{}", config.server.bind_address, server.port);
 
             result.push_str(&format!(
                 "  {:>3}. {:<12} {}  {}\n",
@@ -202,7 +207,7 @@ impl ListCommand {
         let mut server_list: Vec<_> = servers.values().collect();
         server_list.sort_by_key(|s| s.port);
 
-        let base_dir = crate::core::helpers::get_base_dir().ok();
+        let base_dir = crate::core::paths::base_dir().ok();
 
         // Collect sizes
         let mut entries: Vec<(String, u16, String, u64)> = Vec::new();
@@ -210,8 +215,10 @@ impl ListCommand {
 
         for server in &server_list {
             let dir_size = base_dir.as_ref().map_or(0, |base| {
-                let dir = base.join("www").join(format!("{}-[{}]", server.name, server.port));
-                Self::dir_size(&dir)
+                let dir = base
+                    .join("www")
+                    .join(format!("{}-[{}]", server.name, server.port));
+                crate::core::helpers::dir_size(&dir)
             });
             total_disk += dir_size;
 
@@ -221,11 +228,16 @@ impl ListCommand {
                 ServerStatus::Failed => "[Failed]",
             };
 
-            entries.push((server.name.clone(), server.port, status.to_string(), dir_size));
+            entries.push((
+                server.name.clone(),
+                server.port,
+                status.to_string(),
+                dir_size,
+            ));
         }
 
         // Sort by size descending
-        entries.sort_by(|a, b| b.3.cmp(&a.3));
+        entries.sort_by_key(|e| std::cmp::Reverse(e.3));
 
         let process_mem = Self::get_process_memory();
 
@@ -240,14 +252,14 @@ impl ListCommand {
                 i + 1,
                 name,
                 port,
-                Self::format_bytes(*size),
+                crate::core::helpers::format_bytes(*size),
                 status,
             ));
         }
 
         result.push_str(&format!(
             "\n  Total disk: {}",
-            Self::format_bytes(total_disk)
+            crate::core::helpers::format_bytes(total_disk)
         ));
 
         if !process_mem.is_empty() {
@@ -258,110 +270,10 @@ impl ListCommand {
         result
     }
 
-    /// Calculate directory size recursively
-    fn dir_size(path: &std::path::Path) -> u64 {
-        if !path.exists() {
-            return 0;
-        }
-        let mut total = 0u64;
-        if let Ok(entries) = std::fs::read_dir(path) {
-            for entry in entries.flatten() {
-                let meta = match entry.metadata() {
-                    Ok(m) => m,
-                    Err(_) => continue,
-                };
-                if meta.is_dir() {
-                    total += Self::dir_size(&entry.path());
-                } else {
-                    total += meta.len();
-                }
-            }
-        }
-        total
-    }
-
-    fn format_bytes(bytes: u64) -> String {
-        if bytes == 0 {
-            return "0 B".to_string();
-        }
-        let units = ["B", "KB", "MB", "GB"];
-        let mut size = bytes as f64;
-        let mut unit_idx = 0;
-        while size >= 1024.0 && unit_idx < units.len() - 1 {
-            size /= 1024.0;
-            unit_idx += 1;
-        }
-        if unit_idx == 0 {
-            format!("{} B", bytes)
-        } else {
-            format!("{:.1} {}", size, units[unit_idx])
-        }
-    }
-
     /// Get process RSS memory
     fn get_process_memory() -> String {
-        #[cfg(target_os = "macos")]
-        {
-            use std::mem;
-            extern "C" {
-                fn mach_task_self() -> u32;
-                fn task_info(
-                    task: u32,
-                    flavor: u32,
-                    info: *mut libc::c_void,
-                    count: *mut u32,
-                ) -> i32;
-            }
-
-            #[repr(C)]
-            struct MachTaskBasicInfo {
-                virtual_size: u64,
-                resident_size: u64,
-                resident_size_max: u64,
-                user_time: [u32; 2],
-                system_time: [u32; 2],
-                policy: i32,
-                suspend_count: i32,
-            }
-
-            let mut info: MachTaskBasicInfo = unsafe { mem::zeroed() };
-            let mut count = (mem::size_of::<MachTaskBasicInfo>() / mem::size_of::<u32>()) as u32;
-
-            let result = unsafe {
-                task_info(
-                    mach_task_self(),
-                    20, // MACH_TASK_BASIC_INFO
-                    &mut info as *mut _ as *mut libc::c_void,
-                    &mut count,
-                )
-            };
-
-            if result == 0 {
-                let rss_mb = info.resident_size as f64 / (1024.0 * 1024.0);
-                format!("{:.1} MB", rss_mb)
-            } else {
-                String::new()
-            }
-        }
-        #[cfg(target_os = "linux")]
-        {
-            if let Ok(status) = std::fs::read_to_string("/proc/self/status") {
-                for line in status.lines() {
-                    if line.starts_with("VmRSS:") {
-                        let kb: f64 = line
-                            .split_whitespace()
-                            .nth(1)
-                            .and_then(|s| s.parse().ok())
-                            .unwrap_or(0.0);
-                        return format!("{:.1} MB", kb / 1024.0);
-                    }
-                }
-            }
-            String::new()
-        }
-        #[cfg(not(any(target_os = "macos", target_os = "linux")))]
-        {
-            String::new()
-        }
+        crate::core::helpers::process_rss_bytes()
+            .map(|bytes| format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0)))
+            .unwrap_or_default()
     }
 }
@@ -1,10 +1,11 @@
 use crate::core::prelude::*;
 use log::LevelFilter;
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 pub struct LogLevelManager;
 
-static CURRENT_LOG_LEVEL: Mutex<LevelFilter> = Mutex::new(LevelFilter::Info);
+/// Active filter as `LevelFilter as usize`; the debug logger reads it per record
+static CURRENT_LOG_LEVEL: AtomicUsize = AtomicUsize::new(LevelFilter::Info as usize);
 
 impl LogLevelManager {
     pub fn show_status() -> String {
@@ -52,18 +53,52 @@ impl LogLevelManager {
             }
         };
 
+        let name = Self::level_to_name(level_filter);
+        let number = Self::level_to_number(level_filter);
+        let saved = Self::save_to_config(&name.to_lowercase());
+        // After the save: loading the config must not see a half-applied level
         Self::init_with_level(level_filter);
-        Ok(get_command_translation(
-            "system.commands.log_level.changed",
-            &[level_input],
-        ))
+
+        match saved {
+            Ok(()) => Ok(get_command_translation(
+                "system.commands.log_level.changed_persistent",
+                &[&name, &number],
+            )),
+            Err(e) => Ok(get_command_translation(
+                "system.commands.log_level.changed_not_saved",
+                &[&name, &number, &e.to_string()],
+            )),
+        }
+    }
+
+    fn save_to_config(level_name: &str) -> Result<()> {
+        tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current().block_on(async {
+                let mut config = Config::load().await?;
+                config.log_level = level_name.to_string();
+                config.save().await
+            })
+        })
     }
 
     pub fn set_level_runtime(level_filter: LevelFilter) {
-        if let Ok(mut current) = CURRENT_LOG_LEVEL.lock() {
-            *current = level_filter;
+        Self::init_with_level(level_filter);
+    }
+
+    /// Applies `log_level` from rush.toml, keeping the current level if it is invalid
+    pub fn apply_config(config: &Config) {
+        match Self::string_to_level_filter(&config.log_level) {
+            Ok(level) => Self::init_with_level(level),
+            Err(_) => log::warn!(
+                "{}",
+                get_translation("config.validation.invalid_log_level", &[&config.log_level])
+            ),
         }
-        log::set_max_level(level_filter);
+    }
+
+    /// Whether the logger should write a record of `level`
+    pub fn allows(level: log::Level) -> bool {
+        level <= Self::get_current_level()
     }
 
     pub async fn load_from_config() -> LevelFilter {
@@ -86,17 +121,18 @@ impl LogLevelManager {
     }
 
     pub fn get_current_level() -> LevelFilter {
-        if let Ok(current) = CURRENT_LOG_LEVEL.lock() {
-            *current
-        } else {
-            log::max_level()
+        match CURRENT_LOG_LEVEL.load(Ordering::Relaxed) {
+            0 => LevelFilter::Off,
+            1 => LevelFilter::Error,
+            2 => LevelFilter::Warn,
+            3 => LevelFilter::Info,
+            4 => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
         }
     }
 
     pub fn init_with_level(level: LevelFilter) {
-        if let Ok(mut current) = CURRENT_LOG_LEVEL.lock() {
-            *current = level;
-        }
+        CURRENT_LOG_LEVEL.store(level as usize, Ordering::Relaxed);
         log::set_max_level(level);
     }
 
@@ -1,3 +1,8 @@
+// Without the `memory` feature only `mem help` works; the readers below
+// are then unused
+#![cfg_attr(not(feature = "memory"), allow(dead_code))]
+
+#[cfg(feature = "memory")]
 use crate::memory;
 use crate::Result;
 
@@ -10,6 +15,12 @@ impl MemoryCommand {
     }
 }
 
+impl Default for MemoryCommand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[derive(Debug, Clone)]
 enum MemorySubcommand {
     Help,
@@ -18,6 +29,7 @@ enum MemorySubcommand {
         top: Option<usize>,
         all: bool,
     },
+    Registry,
 }
 
 #[async_trait::async_trait]
@@ -49,6 +61,27 @@ impl crate::commands::command::Command for MemoryCommand {
             MemorySubcommand::Help => {
                 return Ok(help_text());
             }
+            #[cfg(not(feature = "memory"))]
+            _ => {
+                return Ok(
+                    "memory feature disabled: rebuild with `--features memory` to use 'mem'"
+                        .to_string(),
+                );
+            }
+            #[cfg(feature = "memory")]
+            MemorySubcommand::Registry => {
+                let items = memory::snapshot();
+                let rows: Vec<(&str, &str, u64)> = items
+                    .iter()
+                    .map(|r| (kind_str(&r.kind), r.id.as_str(), r.bytes))
+                    .collect();
+                return Ok(render_registry(
+                    &rows,
+                    memory::process_rss_bytes(),
+                    memory::process_vms_bytes(),
+                ));
+            }
+            #[cfg(feature = "memory")]
             MemorySubcommand::Info { json, top, all } => {
                 let items = memory::snapshot();
 
@@ -349,10 +382,12 @@ fn parse_from_args(args: &[&str]) -> MemorySubcommand {
     match sub.as_str() {
         "help" | "" => MemorySubcommand::Help,
         "info" => MemorySubcommand::Info { json, top, all },
+        "registry" => MemorySubcommand::Registry,
         _ => MemorySubcommand::Help,
     }
 }
 
+#[cfg(feature = "memory")]
 fn kind_str(k: &crate::memory::ResourceKind) -> &'static str {
     use crate::memory::ResourceKind::*;
     match k {
@@ -376,6 +411,77 @@ fn fmt_bytes(b: u64) -> (String, String) {
     (format!("{b}"), format!("{kb:.2} KB / {mb:.3} MB"))
 }
 
+/// Human-readable size with binary units (B, KiB, MiB, GiB)
+fn human_bytes(b: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = b as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", b)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// Registry rows `(kind, id, bytes)` grouped by kind (largest group first,
+/// largest entry first within a group), with per-kind totals, grand total
+/// and live RSS/VMS.
+fn render_registry(items: &[(&str, &str, u64)], rss: u64, vms: u64) -> String {
+    let mut groups: Vec<(&str, Vec<(&str, u64)>)> = Vec::new();
+    for &(kind, id, bytes) in items {
+        match groups.iter_mut().find(|(k, _)| *k == kind) {
+            Some((_, group)) => group.push((id, bytes)),
+            None => groups.push((kind, vec![(id, bytes)])),
+        }
+    }
+    let group_total = |group: &[(&str, u64)]| group.iter().map(|(_, bytes)| bytes).sum::<u64>();
+    for (_, group) in &mut groups {
+        group.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    }
+    groups.sort_by_key(|(_, group)| std::cmp::Reverse(group_total(group)));
+
+    let mut out = String::new();
+    out.push_str("MEMORY REGISTRY\n");
+    out.push_str("===============\n");
+    if groups.is_empty() {
+        out.push_str("(no registered resources)\n");
+    }
+    for (kind, group) in &groups {
+        out.push_str(&format!(
+            "\n{} ({} entries, {})\n",
+            kind,
+            group.len(),
+            human_bytes(group_total(group))
+        ));
+        for (id, bytes) in group {
+            out.push_str(&format!(
+                "  {:<36}  {:>12}\n",
+                truncate(id, 36),
+                human_bytes(*bytes)
+            ));
+        }
+    }
+
+    let total: u64 = items.iter().map(|(_, _, bytes)| bytes).sum();
+    out.push_str(&format!("{}\n", "-".repeat(52)));
+    out.push_str(&format!("  {:<36}  {:>12}\n", "TOTAL", human_bytes(total)));
+    out.push_str(&format!(
+        "  {:<36}  {:>12}\n",
+        "Process RSS",
+        human_bytes(rss)
+    ));
+    out.push_str(&format!(
+        "  {:<36}  {:>12}\n",
+        "Process VMS",
+        human_bytes(vms)
+    ));
+    out
+}
+
 // --- Help ---
 fn help_text() -> String {
     let mut s = String::new();
@@ -387,6 +493,7 @@ fn help_text() -> String {
     s.push_str("  mem info --json         Ausgabe als JSON\n");
     s.push_str("  mem info --all          Erweiterte Prozessinfos (FDs, /proc/status, limits)\n");
     s.push_str("  mem info --json --all   JSON inkl. erweiterter Prozessinfos\n");
+    s.push_str("  mem registry            Registry nach ResourceKind gruppiert (KiB/MiB)\n");
     s
 }
 
@@ -404,6 +511,7 @@ struct FdSummary {
 }
 
 #[cfg(unix)]
+#[allow(clippy::unnecessary_cast)] // the S_IF* constants are u16 on some platforms
 fn fd_summary() -> FdSummary {
     use std::os::unix::fs::MetadataExt;
     use std::{fs, path::Path};
@@ -529,3 +637,61 @@ fn linux_proc_limits_json() -> Option<String> {
 fn linux_proc_limits_json() -> Option<String> {
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_formatting() {
+        assert_eq!(human_bytes(0), "0 B");
+        assert_eq!(human_bytes(1023), "1023 B");
+        assert_eq!(human_bytes(1536), "1.5 KiB");
+        assert_eq!(human_bytes(5 * 1024 * 1024), "5.0 MiB");
+        assert_eq!(human_bytes(3 << 40), "3072.0 GiB");
+        assert_eq!(
+            fmt_bytes(2048),
+            ("2048".to_string(), "2.00 KB / 0.002 MB".to_string())
+        );
+    }
+
+    #[test]
+    fn test_render_registry_groups_and_sorts() {
+        let rows = [
+            ("Phase", "startup", 100),
+            ("EmbeddedAsset", "logo.svg", 2048),
+            ("Phase", "theme", 300),
+            ("EmbeddedAsset", "font.woff2", 4096),
+            ("Other", "misc", 10),
+        ];
+        let out = render_registry(&rows, 1024 * 1024, 0);
+
+        let pos = |needle: &str| out.find(needle).unwrap();
+        // Largest group first, largest entry first within a group
+        assert!(out.contains("EmbeddedAsset (2 entries, 6.0 KiB)"));
+        assert!(out.contains("Phase (2 entries, 400 B)"));
+        assert!(pos("EmbeddedAsset (") < pos("Phase (") && pos("Phase (") < pos("Other ("));
+        assert!(pos("font.woff2") < pos("logo.svg"));
+        assert!(pos("theme") < pos("startup"));
+        assert!(out.contains(&format!("  {:<36}  {:>12}\n", "TOTAL", "6.4 KiB")));
+        assert!(out.contains(&format!("  {:<36}  {:>12}\n", "Process RSS", "1.0 MiB")));
+
+        assert!(render_registry(&[], 0, 0).contains("(no registered resources)"));
+    }
+
+    #[tokio::test]
+    async fn test_mem_is_registered() {
+        use crate::commands::command::Command;
+
+        let registry = crate::create_default_registry();
+        assert!(registry.find_command("mem info").is_some());
+        let help = MemoryCommand::new().execute(&["mem"]).await.unwrap();
+        assert!(help.starts_with("mem "));
+        #[cfg(not(feature = "memory"))]
+        assert!(MemoryCommand::new()
+            .execute(&["mem", "info"])
+            .await
+            .unwrap()
+            .starts_with("memory feature disabled"));
+    }
+}
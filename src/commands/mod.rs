@@ -1,36 +1,51 @@
+pub mod archive;
 pub mod cleanup;
 pub mod clear;
 pub mod command;
 pub mod create;
+pub mod disk;
 pub mod exit;
+pub mod find;
 pub mod handler;
 pub mod help;
 pub mod history;
 pub mod lang;
 pub mod list;
 pub mod log_level;
-#[cfg(feature = "memory")]
 pub mod memory;
 pub mod parsing;
 pub mod recovery;
 pub mod registry;
 pub mod remote;
 pub mod restart;
+pub mod server;
+pub mod session;
 pub mod start;
 pub mod stop;
 pub mod sync;
 pub mod theme;
+pub mod tls;
+pub mod uptime;
 pub mod version;
+pub mod wrap;
 
+pub use archive::ArchiveCommand;
 pub use cleanup::CleanupCommand;
 pub use command::Command;
 pub use create::CreateCommand;
+pub use disk::DiskCommand;
+pub use find::FindCommand;
 pub use handler::CommandHandler;
 pub use help::HelpCommand;
 pub use list::ListCommand;
 pub use recovery::RecoveryCommand;
 pub use registry::CommandRegistry;
 pub use remote::RemoteCommand;
+pub use server::ServerCommand;
+pub use session::SessionCommand;
 pub use start::StartCommand;
 pub use stop::StopCommand;
 pub use sync::SyncCommand;
+pub use tls::TlsCommand;
+pub use uptime::UptimeCommand;
+pub use wrap::WrapCommand;
@@ -42,3 +42,142 @@ pub fn parse_bulk_args(args: &[&str]) -> BulkMode {
         BulkMode::Single(arg.to_string())
     }
 }
+
+/// Parse a human duration like "90s", "30m", "12h", "7d" or "2w".
+pub fn parse_duration(input: &str) -> Option<std::time::Duration> {
+    let input = input.trim().to_lowercase();
+    let split = input.find(|c: char| !c.is_ascii_digit())?;
+    let (number, unit) = input.split_at(split);
+    let value: u64 = number.parse().ok()?;
+
+    let seconds = match unit {
+        "s" => value,
+        "m" => value.checked_mul(60)?,
+        "h" => value.checked_mul(60 * 60)?,
+        "d" => value.checked_mul(24 * 60 * 60)?,
+        "w" => value.checked_mul(7 * 24 * 60 * 60)?,
+        _ => return None,
+    };
+    Some(std::time::Duration::from_secs(seconds))
+}
+
+/// Parse an inclusive port range like "8080-8099".
+pub fn parse_port_range(input: &str) -> Option<(u16, u16)> {
+    let (start, end) = input.trim().split_once('-')?;
+    let (start, end) = (start.parse::<u16>().ok()?, end.parse::<u16>().ok()?);
+    (start > 0 && start <= end).then_some((start, end))
+}
+
+/// Parse an environment pair like "API_URL=https://example.com". Keys are
+/// letters, digits and `_`; the value may be empty.
+pub fn parse_env_pair(input: &str) -> Option<(String, String)> {
+    let (key, value) = input.split_once('=')?;
+    let valid_key = !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    valid_key.then(|| (key.to_string(), value.to_string()))
+}
+
+/// Parse a response header like "X-Frame-Options: DENY". The name must be a
+/// valid header name (returned lowercase), the value a visible-ASCII header value.
+pub fn parse_header_spec(input: &str) -> Option<(String, String)> {
+    use actix_web::http::header::{HeaderName, HeaderValue};
+    let (name, value) = input.split_once(':')?;
+    let name = HeaderName::from_bytes(name.trim().as_bytes()).ok()?;
+    let value = value.trim();
+    HeaderValue::from_str(value).ok()?;
+    Some((name.as_str().to_string(), value.to_string()))
+}
+
+/// Rejoins a `"quoted value"` that whitespace splitting broke into several
+/// args. `first` is the arg after the flag, further words come from `rest`;
+/// an unquoted `first` is returned as is. `None` if the closing quote is missing.
+pub fn join_quoted<S: AsRef<str>>(
+    first: &str,
+    rest: &mut impl Iterator<Item = S>,
+) -> Option<String> {
+    if !first.starts_with('"') {
+        return Some(first.to_string());
+    }
+    let mut value = first.to_string();
+    while !(value.len() > 1 && value.ends_with('"')) {
+        value.push(' ');
+        value.push_str(rest.next()?.as_ref());
+    }
+    Some(value[1..value.len() - 1].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_duration_units() {
+        assert_eq!(parse_duration("90s"), Some(Duration::from_secs(90)));
+        assert_eq!(parse_duration("30m"), Some(Duration::from_secs(1800)));
+        assert_eq!(parse_duration("12h"), Some(Duration::from_secs(43200)));
+        assert_eq!(parse_duration("7d"), Some(Duration::from_secs(604800)));
+        assert_eq!(parse_duration("2W"), Some(Duration::from_secs(1209600)));
+    }
+
+    #[test]
+    fn test_join_quoted() {
+        let mut rest = ["cache", "flush\"", "--port"].into_iter();
+        assert_eq!(
+            join_quoted("\"redis-cli", &mut rest).as_deref(),
+            Some("redis-cli cache flush")
+        );
+        assert_eq!(rest.next(), Some("--port"));
+        assert_eq!(join_quoted("plain", &mut rest).as_deref(), Some("plain"));
+        assert_eq!(join_quoted("\"\"", &mut rest).as_deref(), Some(""));
+        assert_eq!(join_quoted("\"open", &mut std::iter::empty::<&str>()), None);
+    }
+
+    #[test]
+    fn test_parse_duration_invalid() {
+        for value in ["", "7", "d", "7x", "-1d", "1.5h"] {
+            assert_eq!(parse_duration(value), None, "{}", value);
+        }
+    }
+
+    #[test]
+    fn test_parse_port_range() {
+        assert_eq!(parse_port_range("8080-8099"), Some((8080, 8099)));
+        assert_eq!(parse_port_range("8080-8080"), Some((8080, 8080)));
+        for value in ["8099-8080", "8080", "0-10", "8080-", "a-b", "8080-70000"] {
+            assert_eq!(parse_port_range(value), None, "{}", value);
+        }
+    }
+
+    #[test]
+    fn test_parse_env_pair() {
+        assert_eq!(
+            parse_env_pair("API_URL=https://x.io/?a=b"),
+            Some(("API_URL".to_string(), "https://x.io/?a=b".to_string()))
+        );
+        assert_eq!(
+            parse_env_pair("EMPTY="),
+            Some(("EMPTY".to_string(), String::new()))
+        );
+        for value in ["NOVALUE", "=x", "MY-KEY=x", "{{KEY}}=x"] {
+            assert_eq!(parse_env_pair(value), None, "{}", value);
+        }
+    }
+
+    #[test]
+    fn test_parse_header_spec() {
+        assert_eq!(
+            parse_header_spec("X-Frame-Options: DENY"),
+            Some(("x-frame-options".to_string(), "DENY".to_string()))
+        );
+        assert_eq!(
+            parse_header_spec("Cache-Control:no-store, max-age=0"),
+            Some((
+                "cache-control".to_string(),
+                "no-store, max-age=0".to_string()
+            ))
+        );
+        for spec in ["NoColon", ": value", "Bad Name: x", "X-Foo: line\nbreak"] {
+            assert_eq!(parse_header_spec(spec), None, "{}", spec);
+        }
+    }
+}
@@ -1,7 +1,13 @@
 use crate::commands::command::Command;
 use crate::core::prelude::*;
+use crate::server::persistence::PersistentServerInfo;
 use crate::server::types::{ServerContext, ServerStatus};
 use crate::server::utils::port::is_port_available;
+use std::net::{SocketAddr, TcpStream};
+use std::time::Duration;
+
+/// Connect timeout for the orphan probe; local ports answer immediately
+const PROBE_TIMEOUT: Duration = Duration::from_millis(250);
 
 #[derive(Debug, Default)]
 pub struct RecoveryCommand;
@@ -31,6 +37,7 @@ impl Command for RecoveryCommand {
 
         match args.first() {
             Some(&"all") => Ok(self.recover_all_servers(ctx)),
+            Some(&"orphans") => self.recover_orphans(ctx, args.contains(&"--restart")),
             Some(&server_id) => Ok(self.recover_single_server(ctx, server_id)),
             None => Ok(self.auto_recover(ctx)),
         }
@@ -133,6 +140,62 @@ impl RecoveryCommand {
         }
     }
 
+    /// Persisted `Running` servers whose port no longer answers (left over
+    /// after a hard kill): reset them to Stopped, or start them again with
+    /// `--restart`
+    fn recover_orphans(&self, ctx: &ServerContext, restart: bool) -> Result<String> {
+        let config = get_config()?;
+        let registry = crate::server::shared::get_persistent_registry();
+        let persisted = tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current().block_on(registry.load_servers())
+        })?;
+
+        let host = probe_host(&config.server.bind_address);
+        let orphans = find_orphans(persisted.values(), |port| port_responds(host, port));
+        if orphans.is_empty() {
+            return Ok("No orphaned servers: every running server answers on its port".to_string());
+        }
+
+        let mut actions = Vec::new();
+        for server in &orphans {
+            // A handle whose server no longer listens is dead as well
+            let stale_handle = write_lock(&ctx.handles, "handles")?.remove(&server.id);
+            if let Some(handle) = stale_handle {
+                tokio::spawn(async move {
+                    let _ = handle.stop(false).await;
+                });
+            }
+
+            self.update_server_status(ctx, &server.id, ServerStatus::Stopped);
+            tokio::task::block_in_place(|| {
+                tokio::runtime::Handle::current()
+                    .block_on(registry.update_server_status(&server.id, ServerStatus::Stopped))
+            })?;
+
+            let action = if restart {
+                match crate::commands::StartCommand::new().execute_sync(&[&server.id]) {
+                    Ok(msg) if msg.contains("started successfully") => {
+                        "Running → restarted".to_string()
+                    }
+                    Ok(msg) => format!("Running → Stopped, restart skipped: {}", msg.trim()),
+                    Err(e) => format!("Running → Stopped, restart failed: {}", e),
+                }
+            } else {
+                "Running → Stopped (port not bound)".to_string()
+            };
+            actions.push(format!(
+                "'{}' (port {}): {}",
+                server.name, server.port, action
+            ));
+        }
+
+        Ok(format!(
+            "Orphan recovery: {} server(s) reconciled\n{}",
+            orphans.len(),
+            actions.join("\n")
+        ))
+    }
+
     fn recover_single_server(&self, ctx: &ServerContext, identifier: &str) -> String {
         let servers = match ctx.servers.read() {
             Ok(s) => s,
@@ -316,3 +379,71 @@ impl RecoveryCommand {
         }
     }
 }
+
+/// Servers persisted as `Running` whose port does not answer, ordered by port
+fn find_orphans<'a>(
+    servers: impl IntoIterator<Item = &'a PersistentServerInfo>,
+    responds: impl Fn(u16) -> bool,
+) -> Vec<&'a PersistentServerInfo> {
+    let mut orphans: Vec<_> = servers
+        .into_iter()
+        .filter(|s| s.status == ServerStatus::Running && !responds(s.port))
+        .collect();
+    orphans.sort_by_key(|s| s.port);
+    orphans
+}
+
+/// Wildcard bind addresses are probed on loopback
+fn probe_host(bind_address: &str) -> std::net::IpAddr {
+    match bind_address.parse::<std::net::IpAddr>() {
+        Ok(ip) if !ip.is_unspecified() => ip,
+        _ => std::net::IpAddr::from([127, 0, 0, 1]),
+    }
+}
+
+fn port_responds(host: std::net::IpAddr, port: u16) -> bool {
+    TcpStream::connect_timeout(&SocketAddr::new(host, port), PROBE_TIMEOUT).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::types::ServerInfo;
+
+    fn persisted(name: &str, port: u16, status: ServerStatus) -> PersistentServerInfo {
+        PersistentServerInfo::from(ServerInfo {
+            id: name.to_string(),
+            name: name.to_string(),
+            port,
+            status,
+            ..ServerInfo::default()
+        })
+    }
+
+    #[test]
+    fn test_find_orphans_only_unbound_running() {
+        let servers = [
+            persisted("live", 8001, ServerStatus::Running),
+            persisted("crashed-b", 8003, ServerStatus::Running),
+            persisted("crashed-a", 8002, ServerStatus::Running),
+            persisted("idle", 8004, ServerStatus::Stopped),
+        ];
+        let orphans = find_orphans(&servers, |port| port == 8001);
+        let names: Vec<_> = orphans.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["crashed-a", "crashed-b"]);
+    }
+
+    #[test]
+    fn test_port_responds_probes_listener() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let host = probe_host("0.0.0.0");
+        assert!(port_responds(host, port));
+        drop(listener);
+        assert!(!port_responds(host, port));
+        assert_eq!(
+            probe_host("192.168.1.5"),
+            std::net::IpAddr::from([192, 168, 1, 5])
+        );
+    }
+}
@@ -84,13 +84,13 @@ impl CommandRegistry {
 
     pub fn execute_sync(&self, command: &str, args: &[&str]) -> Option<Result<String>> {
         // Support "command ?" syntax → redirect to help
-        if args == ["?"] || args == ["--help"] || args == ["-h"] {
-            if self.find_command(command).is_some() {
-                // Execute help with the command name as argument
-                return self
-                    .find_command("help")
-                    .map(|help_cmd| help_cmd.execute_sync(&[command]));
-            }
+        if (args == ["?"] || args == ["--help"] || args == ["-h"])
+            && self.find_command(command).is_some()
+        {
+            // Execute help with the command name as argument
+            return self
+                .find_command("help")
+                .map(|help_cmd| help_cmd.execute_sync(&[command]));
         }
 
         self.find_command(command).map(|cmd| cmd.execute_sync(args))
@@ -98,12 +98,12 @@ impl CommandRegistry {
 
     pub async fn execute_async(&self, command: &str, args: &[&str]) -> Option<Result<String>> {
         // Support "command ?" syntax → redirect to help
-        if args == ["?"] || args == ["--help"] || args == ["-h"] {
-            if self.find_command(command).is_some() {
-                return self
-                    .find_command("help")
-                    .map(|help_cmd| help_cmd.execute_sync(&[command]));
-            }
+        if (args == ["?"] || args == ["--help"] || args == ["-h"])
+            && self.find_command(command).is_some()
+        {
+            return self
+                .find_command("help")
+                .map(|help_cmd| help_cmd.execute_sync(&[command]));
         }
 
         match self.find_command(command) {
@@ -134,6 +134,22 @@ impl CommandRegistry {
         }
     }
 
+    /// Closest available command name within edit distance 2, for typos
+    pub fn suggest(&self, input: &str) -> Option<&str> {
+        let input = input.trim().to_lowercase();
+        if input.is_empty() {
+            return None;
+        }
+
+        self.commands
+            .iter()
+            .filter(|cmd| cmd.is_available())
+            .map(|cmd| (levenshtein(&input, &cmd.name().to_lowercase()), cmd.name()))
+            .filter(|&(distance, _)| distance <= 2)
+            .min_by_key(|&(distance, _)| distance)
+            .map(|(_, name)| name)
+    }
+
     pub fn debug_info(&self) -> String {
         format!(
             "CommandRegistry: {} commands registered",
@@ -150,6 +166,25 @@ impl CommandRegistry {
     }
 }
 
+/// Edit distance (insert, delete, substitute) between two strings
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, &cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != cb);
+            current[j + 1] = (previous[j] + cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
 impl Default for CommandRegistry {
     fn default() -> Self {
         Self::new()
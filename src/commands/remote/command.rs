@@ -35,6 +35,7 @@ impl Command for RemoteCommand {
             Some("show") => self.show_profile(&store, args),
             Some("remove" | "rm" | "delete") => self.remove_profile(&store, args),
             Some("test") => self.test_profile(&store, args),
+            Some("alias") => self.set_alias(&store, args),
             Some(sub) => Err(AppError::Validation(format!(
                 "Unknown remote subcommand '{}'. Use 'remote help'.",
                 sub
@@ -49,9 +50,11 @@ impl Command for RemoteCommand {
 
 impl RemoteCommand {
     fn add_profile(&self, store: &RemoteProfileStore, args: &[&str]) -> Result<String> {
+        let (args, alias) = take_alias_flag(args)?;
+        let args = args.as_slice();
         if args.len() < 4 {
             return Err(AppError::Validation(
-                "Usage: remote add <name> <user@host> <remote_path> [port] [identity_file]"
+                "Usage: remote add <name> <user@host> <remote_path> [port] [identity_file] [--alias <alias>]"
                     .to_string(),
             ));
         }
@@ -72,8 +75,16 @@ impl RemoteCommand {
             }
         };
 
-        let profile = RemoteProfile::new(user, host, remote_path, port, identity_file)?;
         let existed = store.exists(name)?;
+        // Re-adding a remote keeps its alias unless --alias sets a new one
+        // (`remote alias` changes or clears it)
+        let alias = match alias {
+            Some(alias) => Some(alias),
+            None if existed => store.get(name)?.alias,
+            None => None,
+        };
+        let profile =
+            RemoteProfile::new(user, host, remote_path, port, identity_file)?.with_alias(alias);
         store.upsert(name, profile)?;
 
         Ok(if existed {
@@ -95,10 +106,15 @@ impl RemoteCommand {
         let mut out = String::from("Configured remotes:\n");
         for (name, profile) in profiles {
             let identity = profile.identity_file.as_deref().unwrap_or("-");
+            let alias = profile
+                .alias
+                .as_deref()
+                .map(|a| format!(" ({})", a))
+                .unwrap_or_default();
 
             out.push_str(&format!(
-                "  {} -> {}@{}:{} {}\n",
-                name, profile.user, profile.host, profile.port, profile.remote_path
+                "  {}{} -> {}@{}:{} {}\n",
+                name, alias, profile.user, profile.host, profile.port, profile.remote_path
             ));
             out.push_str(&format!("     identity: {}\n", identity));
         }
@@ -111,10 +127,11 @@ impl RemoteCommand {
             .get(1)
             .ok_or_else(|| AppError::Validation("Usage: remote show <name>".to_string()))?;
 
-        let profile = store.get(name)?;
+        let (name, profile) = store.resolve(name)?;
         Ok(format!(
-            "Remote '{}'\n  user: {}\n  host: {}\n  port: {}\n  remote_path: {}\n  identity_file: {}",
+            "Remote '{}'\n  alias: {}\n  user: {}\n  host: {}\n  port: {}\n  remote_path: {}\n  identity_file: {}",
             name,
+            profile.alias.as_deref().unwrap_or("-"),
             profile.user,
             profile.host,
             profile.port,
@@ -128,16 +145,31 @@ impl RemoteCommand {
             .get(1)
             .ok_or_else(|| AppError::Validation("Usage: remote remove <name>".to_string()))?;
 
-        store.remove(name)?;
+        let name = store.remove(name)?;
         Ok(format!("Remote '{}' removed", name))
     }
 
+    fn set_alias(&self, store: &RemoteProfileStore, args: &[&str]) -> Result<String> {
+        let name = args.get(1).ok_or_else(|| {
+            AppError::Validation("Usage: remote alias <name> [alias]".to_string())
+        })?;
+
+        let (name, profile) = store.resolve(name)?;
+        let alias = args.get(2).map(|a| (*a).to_string());
+        let message = match &alias {
+            Some(alias) => format!("Remote '{}' can now be used as '{}'", name, alias),
+            None => format!("Alias of remote '{}' removed", name),
+        };
+        store.upsert(&name, profile.with_alias(alias))?;
+        Ok(message)
+    }
+
     fn test_profile(&self, store: &RemoteProfileStore, args: &[&str]) -> Result<String> {
         let name = args
             .get(1)
             .ok_or_else(|| AppError::Validation("Usage: remote test <name>".to_string()))?;
 
-        let profile = store.get(name)?;
+        let (name, profile) = store.resolve(name)?;
         let message = test_connection(&profile)?;
         Ok(format!("{} [{}]", message, name))
     }
@@ -147,15 +179,56 @@ impl RemoteCommand {
             "Remote profile management\n\n\
              Commands:\n\
                remote list\n\
-               remote add <name> <user@host> <remote_path> [port] [identity_file]\n\
+               remote add <name> <user@host> <remote_path> [port] [identity_file] [--alias <alias>]\n\
+               remote alias <name> [alias]   (no alias: remove it)\n\
                remote show <name>\n\
                remote remove <name>\n\
                remote test <name>\n\n\
+             <name> also accepts the alias or user@host.\n\n\
              Example:\n\
-               remote add prod deploy@example.com /opt/rush-sync 22 ~/.ssh/id_ed25519\n\n\
+               remote add prod deploy@example.com /opt/rush-sync 22 ~/.ssh/id_ed25519 --alias p\n\n\
              Storage:\n\
                {}",
             file_path.display()
         )
     }
 }
+
+/// Splits `--alias <alias>` off the argument list
+fn take_alias_flag<'a>(args: &[&'a str]) -> Result<(Vec<&'a str>, Option<String>)> {
+    let mut rest = Vec::with_capacity(args.len());
+    let mut alias = None;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if *arg == "--alias" {
+            let value = iter
+                .next()
+                .ok_or_else(|| AppError::Validation("--alias needs a value".to_string()))?;
+            alias = Some((*value).to_string());
+        } else {
+            rest.push(*arg);
+        }
+    }
+    Ok((rest, alias))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_re_adding_keeps_alias() {
+        let dir = std::env::temp_dir().join(format!("rush-remote-readd-{}", std::process::id()));
+        let store = RemoteProfileStore::with_path(dir.join("remotes.toml"));
+        let add = |args: &[&str]| RemoteCommand::new().add_profile(&store, args);
+
+        add(&["add", "production", "deploy@prod", "/srv", "--alias", "p"]).unwrap();
+        let updated = add(&["add", "production", "deploy@prod", "/opt"]).unwrap();
+        assert_eq!(updated, "Remote 'production' updated");
+
+        let profile = store.get("production").unwrap();
+        assert_eq!(profile.remote_path, "/opt");
+        assert_eq!(profile.alias.as_deref(), Some("p"));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,694 @@
+// src/commands/server/command.rs
+use crate::commands::command::Command;
+use crate::core::prelude::*;
+use crate::server::persistence::{PersistentServerInfo, RegistryExport};
+use crate::server::types::{ServerInfo, ServerStatus};
+use crate::server::ServerManager;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+const DEFAULT_EXPORT_FILE: &str = "servers-export.json";
+
+/// Per-server limit for `server health`, so one hung server can't stall the sweep
+const HEALTH_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Default)]
+pub struct ServerCommand;
+
+impl ServerCommand {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Command for ServerCommand {
+    fn name(&self) -> &'static str {
+        "server"
+    }
+
+    fn description(&self) -> &'static str {
+        "Export, import, health-check, bulk start/stop, open, browse and reload servers"
+    }
+
+    fn matches(&self, command: &str) -> bool {
+        command.trim().to_lowercase().starts_with("server")
+    }
+
+    fn execute_sync(&self, args: &[&str]) -> Result<String> {
+        match args.first().copied() {
+            None | Some("-h" | "--help" | "help") => Ok(self.help_text()),
+            Some("export") => self.export(args.get(1).copied()),
+            Some("health") => self.health(),
+            Some("open") => match args.get(1) {
+                Some(id) => self.open(id),
+                None => Err(AppError::Validation(
+                    "Usage: server open <id|name|number>".to_string(),
+                )),
+            },
+            Some("browse") => match args.get(1) {
+                Some(id) => self.browse(id),
+                None => Err(AppError::Validation(
+                    "Usage: server browse <id|name|number>".to_string(),
+                )),
+            },
+            Some("reload") => match args.get(1) {
+                Some(id) => self.reload(id),
+                None => Err(AppError::Validation(
+                    "Usage: server reload <id|name|number>".to_string(),
+                )),
+            },
+            Some(op @ ("start" | "stop")) => {
+                if args[1..].contains(&"--all") {
+                    self.bulk(op)
+                } else {
+                    Err(AppError::Validation(format!(
+                        "Usage: server {} --all (single servers: {} <id>)",
+                        op, op
+                    )))
+                }
+            }
+            Some("import") => match args.get(1) {
+                Some(file) => self.import(file),
+                None => Err(AppError::Validation(
+                    "Usage: server import <file>".to_string(),
+                )),
+            },
+            Some(sub) => Err(AppError::Validation(format!(
+                "Unknown server subcommand '{}'. Use 'server help'.",
+                sub
+            ))),
+        }
+    }
+
+    fn priority(&self) -> u8 {
+        66
+    }
+}
+
+/// What happened to one entry of an import file
+#[derive(Debug)]
+enum ImportOutcome {
+    Created {
+        name: String,
+        port: u16,
+        remapped_from: Option<u16>,
+        /// `on_stop` command from the file; never imported, since it would
+        /// run arbitrary shell commands from someone else's export
+        dropped_on_stop: Option<String>,
+    },
+    Skipped {
+        name: String,
+        reason: String,
+    },
+    Failed {
+        name: String,
+        error: String,
+    },
+}
+
+/// Result of one `/api/health` probe
+#[derive(Debug)]
+enum HealthResult {
+    Healthy { latency: Duration },
+    Unhealthy { status: u16, latency: Duration },
+    TimedOut,
+    Unreachable { error: String },
+}
+
+impl ServerCommand {
+    fn export(&self, file: Option<&str>) -> Result<String> {
+        let path = match file {
+            Some(file) => PathBuf::from(file),
+            None => crate::core::paths::base_dir()?.join(DEFAULT_EXPORT_FILE),
+        };
+
+        let registry = crate::server::shared::get_persistent_registry();
+        let servers = tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current().block_on(registry.load_servers())
+        })?;
+        if servers.is_empty() {
+            return Ok("No servers to export".to_string());
+        }
+
+        let export = RegistryExport::new(servers.into_values());
+        let content = serde_json::to_string_pretty(&export)
+            .map_err(|e| AppError::Validation(format!("Failed to serialize servers: {}", e)))?;
+        std::fs::write(&path, content).map_err(AppError::Io)?;
+
+        Ok(format!(
+            "Exported {} server(s) to {}",
+            export.servers.len(),
+            path.display()
+        ))
+    }
+
+    fn import(&self, file: &str) -> Result<String> {
+        let content = std::fs::read_to_string(file).map_err(AppError::Io)?;
+        let export = RegistryExport::from_json(&content)?;
+        let config = get_config()?;
+        let manager =
+            ServerManager::with_context(crate::server::shared::get_shared_context().clone());
+
+        let mut outcomes = Vec::new();
+        let mut imported = Vec::new();
+        for entry in export.servers {
+            let outcome = match self.import_entry(&manager, &config, &entry) {
+                Ok(Some((info, remapped_from))) => {
+                    let outcome = ImportOutcome::Created {
+                        name: info.name.clone(),
+                        port: info.port,
+                        remapped_from,
+                        dropped_on_stop: entry.on_stop.clone(),
+                    };
+                    imported.push((info, entry.auto_start));
+                    outcome
+                }
+                Ok(None) => ImportOutcome::Skipped {
+                    name: entry.name,
+                    reason: format!("ID {} already exists", short_id(&entry.id)),
+                },
+                Err(e) => ImportOutcome::Failed {
+                    name: entry.name,
+                    error: e.to_string(),
+                },
+            };
+            outcomes.push(outcome);
+        }
+
+        if !imported.is_empty() {
+            let registry = crate::server::shared::get_persistent_registry();
+            tokio::task::block_in_place(|| {
+                tokio::runtime::Handle::current().block_on(async {
+                    for (info, auto_start) in imported {
+                        let id = info.id.clone();
+                        if let Err(e) = registry.add_server(info).await {
+                            log::error!("Failed to persist imported server {}: {}", id, e);
+                        } else if auto_start {
+                            if let Err(e) = registry.set_auto_start(&id, true).await {
+                                log::warn!("Failed to restore auto-start for {}: {}", id, e);
+                            }
+                        }
+                    }
+                })
+            });
+        }
+
+        Ok(render_import_report(&outcomes))
+    }
+
+    /// Re-creates one exported server. `None` if its id is already known;
+    /// otherwise the created server and its original port if that was taken.
+    fn import_entry(
+        &self,
+        manager: &ServerManager,
+        config: &Config,
+        entry: &PersistentServerInfo,
+    ) -> Result<Option<(ServerInfo, Option<u16>)>> {
+        if read_lock(&manager.get_context().servers, "servers")?.contains_key(&entry.id) {
+            return Ok(None);
+        }
+        manager.ensure_name_available(&entry.name)?;
+
+        let bind_address = &config.server.bind_address;
+        let port_taken = read_lock(&manager.get_context().servers, "servers")?
+            .values()
+            .any(|s| s.port == entry.port)
+            || !crate::server::utils::port::is_port_available(entry.port, bind_address);
+        let port = if port_taken {
+            manager.find_free_port(
+                config.server.port_range_start.max(1024),
+                Some(config.server.port_range_end),
+                bind_address,
+            )?
+        } else {
+            entry.port
+        };
+
+        let info = manager.create_server(ServerInfo {
+            port,
+            on_stop: None,
+            ..ServerInfo::from(entry.clone())
+        })?;
+        Ok(Some((info, port_taken.then_some(entry.port))))
+    }
+
+    /// Probes every running server's `/api/health` concurrently
+    fn health(&self) -> Result<String> {
+        let config = get_config()?;
+        let manager =
+            ServerManager::with_context(crate::server::shared::get_shared_context().clone());
+        let servers = manager.list_servers()?;
+        let (running, stopped): (Vec<_>, Vec<_>) = servers
+            .into_iter()
+            .partition(|s| s.status == ServerStatus::Running);
+
+        if running.is_empty() {
+            return Ok(format!(
+                "No running servers to check ({} stopped)",
+                stopped.len()
+            ));
+        }
+
+        let host = probe_host(&config.server.bind_address);
+        let results = tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current().block_on(async {
+                let client = reqwest::Client::new();
+                let probes = running
+                    .iter()
+                    .map(|server| probe_health(&client, &host, server.port, HEALTH_TIMEOUT));
+                futures_util::future::join_all(probes).await
+            })
+        });
+
+        let checked: Vec<(ServerInfo, HealthResult)> = running.into_iter().zip(results).collect();
+        Ok(render_health_report(&checked, stopped.len()))
+    }
+
+    /// `server start|stop --all`: same as `start all` / `stop all`, which
+    /// report each server as it is done and a summary at the end
+    fn bulk(&self, op: &str) -> Result<String> {
+        if op == "start" {
+            crate::commands::StartCommand::new().execute_sync(&["all"])
+        } else {
+            crate::commands::StopCommand::new().execute_sync(&["all"])
+        }
+    }
+
+    /// Opens the server's `www/{name}-[{port}]` directory in the file manager
+    fn open(&self, identifier: &str) -> Result<String> {
+        let manager =
+            ServerManager::with_context(crate::server::shared::get_shared_context().clone());
+        let server = manager.get_server_info(identifier)?;
+        let dir =
+            crate::server::settings::ServerSettings::get_server_dir(&server.name, server.port)
+                .ok_or_else(|| {
+                    AppError::Validation("Cannot resolve the data directory".to_string())
+                })?;
+
+        if !dir.is_dir() {
+            return Err(AppError::Validation(format!(
+                "Directory {} does not exist yet - start '{}' once to create it",
+                dir.display(),
+                server.name
+            )));
+        }
+
+        crate::core::launcher::open(&dir.to_string_lossy())?;
+        Ok(format!("Opened {} in the file manager", dir.display()))
+    }
+
+    /// Opens the server in the default browser; a stopped server is opened
+    /// anyway (it may be starting) but flagged
+    fn browse(&self, identifier: &str) -> Result<String> {
+        let config = get_config()?;
+        let manager =
+            ServerManager::with_context(crate::server::shared::get_shared_context().clone());
+        let server = manager.get_server_info(identifier)?;
+        let url = browse_url(&server, &config);
+
+        crate::core::launcher::open(&url)?;
+        let mut result = format!("Opened {} in the browser", url);
+        if server.status != ServerStatus::Running {
+            result.push_str(&format!(
+                "\n  Warning: '{}' is {} - run 'start {}' first",
+                server.name,
+                server.status.to_string().to_lowercase(),
+                server.name
+            ));
+        }
+        Ok(result)
+    }
+
+    /// Reloads every page open on a running server through its hot-reload
+    /// WebSocket, e.g. after a backend change no file watch picks up
+    fn reload(&self, identifier: &str) -> Result<String> {
+        let manager =
+            ServerManager::with_context(crate::server::shared::get_shared_context().clone());
+        let server = manager.get_server_info(identifier)?;
+
+        if server.status != ServerStatus::Running {
+            return Err(AppError::Validation(format!(
+                "'{}' is {} - run 'start {}' first",
+                server.name,
+                server.status.to_string().to_lowercase(),
+                server.name
+            )));
+        }
+        if !get_config()?.server.hot_reload {
+            return Err(AppError::Validation(
+                "Hot reload is off ([server] hot_reload = false)".to_string(),
+            ));
+        }
+
+        let watchdog = crate::server::watchdog::get_watchdog_manager();
+        match watchdog.send_reload(&server.name, server.port) {
+            0 => Err(AppError::Validation(format!(
+                "No browser is connected to '{}' - open it with 'server browse {}'",
+                server.name, server.name
+            ))),
+            clients => Ok(format!(
+                "Reload sent to {} connected browser{} of '{}'",
+                clients,
+                if clients == 1 { "" } else { "s" },
+                server.name
+            )),
+        }
+    }
+
+    fn help_text(&self) -> String {
+        format!(
+            "  server export [file]     Write all server definitions to JSON\n  \
+             server import <file>      Re-create servers from an export\n  \
+             server health             Check /api/health of all running servers\n  \
+             server start --all        Start every stopped server (same as start all)\n  \
+             server stop --all         Stop every running server (same as stop all)\n  \
+             server open <id>          Open the server's www/ directory in the file manager\n  \
+             server browse <id>        Open the server in the default browser\n  \
+             server reload <id>        Reload every page open on the server\n\n  \
+             Default export file: .rss base dir/{}\n  \
+             Import skips IDs that already exist and moves servers\n  \
+             whose port is taken to the next free port.",
+            DEFAULT_EXPORT_FILE
+        )
+    }
+}
+
+/// The HTTPS proxy address (`https://{name}.localhost`) when the proxy and
+/// HTTPS are on, otherwise the server's own HTTP port
+fn browse_url(server: &ServerInfo, config: &Config) -> String {
+    if config.proxy.enabled && config.server.enable_https {
+        let https_port = config.proxy.port + config.proxy.https_port_offset;
+        let port_suffix = if https_port == 443 {
+            String::new()
+        } else {
+            format!(":{}", https_port)
+        };
+        return format!("https://{}.localhost{}", server.name, port_suffix);
+    }
+
+    let host = match config.server.bind_address.as_str() {
+        "0.0.0.0" | "::" | "[::]" | "" | "127.0.0.1" => "localhost".to_string(),
+        addr => probe_host(addr),
+    };
+    format!("http://{}:{}", host, server.port)
+}
+
+/// Servers bound to all interfaces are reached over loopback
+fn probe_host(bind_address: &str) -> String {
+    match bind_address {
+        "0.0.0.0" | "::" | "[::]" | "" => "127.0.0.1".to_string(),
+        addr if addr.contains(':') && !addr.starts_with('[') => format!("[{}]", addr),
+        addr => addr.to_string(),
+    }
+}
+
+async fn probe_health(
+    client: &reqwest::Client,
+    host: &str,
+    port: u16,
+    timeout: Duration,
+) -> HealthResult {
+    let url = format!("http://{}:{}/api/health", host, port);
+    let started = Instant::now();
+    match client.get(&url).timeout(timeout).send().await {
+        Ok(response) if response.status().is_success() => HealthResult::Healthy {
+            latency: started.elapsed(),
+        },
+        Ok(response) => HealthResult::Unhealthy {
+            status: response.status().as_u16(),
+            latency: started.elapsed(),
+        },
+        Err(e) if e.is_timeout() => HealthResult::TimedOut,
+        Err(e) => HealthResult::Unreachable {
+            error: e.to_string(),
+        },
+    }
+}
+
+fn render_health_report(checked: &[(ServerInfo, HealthResult)], stopped: usize) -> String {
+    let count = |f: fn(&HealthResult) -> bool| checked.iter().filter(|(_, r)| f(r)).count();
+    let healthy = count(|r| matches!(r, HealthResult::Healthy { .. }));
+    let unhealthy = count(|r| matches!(r, HealthResult::Unhealthy { .. }));
+    let timed_out = count(|r| matches!(r, HealthResult::TimedOut));
+    let unreachable = count(|r| matches!(r, HealthResult::Unreachable { .. }));
+
+    let mut result = format!(
+        "Health check: {} of {} running servers responding",
+        healthy,
+        checked.len()
+    );
+    for (server, health) in checked {
+        let line = match health {
+            HealthResult::Healthy { latency } => format!(
+                "  + {} (port {}) {} ms",
+                server.name,
+                server.port,
+                latency.as_millis()
+            ),
+            HealthResult::Unhealthy { status, latency } => format!(
+                "  ! {} (port {}) HTTP {} after {} ms",
+                server.name,
+                server.port,
+                status,
+                latency.as_millis()
+            ),
+            HealthResult::TimedOut => format!(
+                "  ! {} (port {}) timed out after {} ms",
+                server.name,
+                server.port,
+                HEALTH_TIMEOUT.as_millis()
+            ),
+            HealthResult::Unreachable { error } => {
+                format!(
+                    "  ! {} (port {}) unreachable: {}",
+                    server.name, server.port, error
+                )
+            }
+        };
+        result.push('\n');
+        result.push_str(&line);
+    }
+    result.push_str(&format!(
+        "\n\nHealthy: {}, unhealthy: {}, timed out: {}, unreachable: {}",
+        healthy, unhealthy, timed_out, unreachable
+    ));
+    if stopped > 0 {
+        result.push_str(&format!(" ({} stopped, not checked)", stopped));
+    }
+    result
+}
+
+fn short_id(id: &str) -> &str {
+    id.get(..8).unwrap_or(id)
+}
+
+fn render_import_report(outcomes: &[ImportOutcome]) -> String {
+    let created = outcomes
+        .iter()
+        .filter(|o| matches!(o, ImportOutcome::Created { .. }))
+        .count();
+    let mut result = format!(
+        "Import completed: {} of {} servers created",
+        created,
+        outcomes.len()
+    );
+
+    for outcome in outcomes {
+        let line = match outcome {
+            ImportOutcome::Created {
+                name,
+                port,
+                remapped_from,
+                dropped_on_stop,
+            } => {
+                let mut line = format!("  + {} on port {}", name, port);
+                if let Some(original) = remapped_from {
+                    line.push_str(&format!(" (port {} was taken)", original));
+                }
+                if let Some(command) = dropped_on_stop {
+                    line.push_str(&format!(" (on-stop hook not imported: {})", command));
+                }
+                line
+            }
+            ImportOutcome::Skipped { name, reason } => format!("  = {} skipped: {}", name, reason),
+            ImportOutcome::Failed { name, error } => format!("  ! {} failed: {}", name, error),
+        };
+        result.push('\n');
+        result.push_str(&line);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: &str, name: &str, port: u16, created_timestamp: u64) -> PersistentServerInfo {
+        PersistentServerInfo {
+            id: id.to_string(),
+            name: name.to_string(),
+            port,
+            status: ServerStatus::Running,
+            created_at: String::new(),
+            created_timestamp,
+            auto_start: true,
+            last_started: Some("2026-01-01 10:00:00".to_string()),
+            start_count: 4,
+            env: Default::default(),
+            workers: None,
+            headers: Default::default(),
+            on_stop: None,
+        }
+    }
+
+    #[test]
+    fn test_export_round_trip() {
+        let export = RegistryExport::new(vec![
+            entry("b", "shop", 8001, 20),
+            entry("a", "blog", 8000, 10),
+        ]);
+        let json = serde_json::to_string(&export).unwrap();
+        let parsed = RegistryExport::from_json(&json).unwrap();
+
+        assert_eq!(parsed.version, RegistryExport::VERSION);
+        let names: Vec<&str> = parsed.servers.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["blog", "shop"]);
+        // Runtime state is dropped, settings are kept
+        assert!(parsed
+            .servers
+            .iter()
+            .all(|s| s.status == ServerStatus::Stopped && s.start_count == 0 && s.auto_start));
+
+        // A raw registry file imports too
+        let raw = serde_json::to_string(&vec![entry("a", "blog", 8000, 10)]).unwrap();
+        assert_eq!(RegistryExport::from_json(&raw).unwrap().servers.len(), 1);
+        assert!(RegistryExport::from_json(
+            "{\"version\": 99, \"exported_at\": \"\", \"servers\": []}"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_import_report_lists_remapped_ports() {
+        let report = render_import_report(&[
+            ImportOutcome::Created {
+                name: "blog".to_string(),
+                port: 8002,
+                remapped_from: Some(8000),
+                dropped_on_stop: None,
+            },
+            ImportOutcome::Created {
+                name: "docs".to_string(),
+                port: 8003,
+                remapped_from: None,
+                dropped_on_stop: Some("rm -rf ~".to_string()),
+            },
+            ImportOutcome::Skipped {
+                name: "shop".to_string(),
+                reason: "ID 12345678 already exists".to_string(),
+            },
+        ]);
+        assert!(report.starts_with("Import completed: 2 of 3 servers created"));
+        assert!(report.contains("blog on port 8002 (port 8000 was taken)\n"));
+        assert!(report.contains("docs on port 8003 (on-stop hook not imported: rm -rf ~)"));
+        assert!(report.contains("shop skipped"));
+    }
+
+    #[test]
+    fn test_import_requires_file() {
+        assert!(ServerCommand::new().execute_sync(&["import"]).is_err());
+        assert!(ServerCommand::new().execute_sync(&["bogus"]).is_err());
+    }
+
+    #[test]
+    fn test_open_requires_known_server() {
+        let cmd = ServerCommand::new();
+        assert!(cmd.execute_sync(&["open"]).is_err());
+        assert!(cmd.execute_sync(&["open", "no-such-server"]).is_err());
+        assert!(cmd.execute_sync(&["browse"]).is_err());
+    }
+
+    #[test]
+    fn test_reload_requires_known_server() {
+        let cmd = ServerCommand::new();
+        assert!(cmd.execute_sync(&["reload"]).is_err());
+        assert!(cmd.execute_sync(&["reload", "no-such-server"]).is_err());
+    }
+
+    #[test]
+    fn test_browse_url_prefers_https_proxy() {
+        let mut config = Config::default();
+        config.proxy.enabled = true;
+        config.server.enable_https = true;
+        config.proxy.port = 3000;
+        config.proxy.https_port_offset = 443;
+        let blog = ServerInfo {
+            name: "blog".to_string(),
+            port: 8001,
+            ..ServerInfo::default()
+        };
+        assert_eq!(browse_url(&blog, &config), "https://blog.localhost:3443");
+
+        config.proxy.port = 80;
+        config.proxy.https_port_offset = 363;
+        assert_eq!(browse_url(&blog, &config), "https://blog.localhost");
+
+        config.server.enable_https = false;
+        config.server.bind_address = "0.0.0.0".to_string();
+        assert_eq!(browse_url(&blog, &config), "http://localhost:8001");
+        config.server.bind_address = "192.168.1.5".to_string();
+        assert_eq!(browse_url(&blog, &config), "http://192.168.1.5:8001");
+    }
+
+    #[test]
+    fn test_bulk_requires_all() {
+        assert!(ServerCommand::new().execute_sync(&["stop"]).is_err());
+        assert!(ServerCommand::new()
+            .execute_sync(&["start", "blog"])
+            .is_err());
+    }
+
+    #[test]
+    fn test_health_report_counts() {
+        let server = |name: &str, port: u16| ServerInfo {
+            name: name.to_string(),
+            port,
+            ..ServerInfo::default()
+        };
+        let report = render_health_report(
+            &[
+                (
+                    server("blog", 8001),
+                    HealthResult::Healthy {
+                        latency: Duration::from_millis(4),
+                    },
+                ),
+                (server("api", 8002), HealthResult::TimedOut),
+            ],
+            3,
+        );
+        assert!(report.starts_with("Health check: 1 of 2 running servers responding"));
+        assert!(report.contains("blog (port 8001) 4 ms"));
+        assert!(report.contains("api (port 8002) timed out"));
+        assert!(report.ends_with("timed out: 1, unreachable: 0 (3 stopped, not checked)"));
+        assert_eq!(probe_host("0.0.0.0"), "127.0.0.1");
+        assert_eq!(probe_host("::1"), "[::1]");
+    }
+
+    #[tokio::test]
+    async fn test_probe_health_times_out_on_silent_server() {
+        // Accepts connections but never answers
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let _accept = tokio::spawn(async move {
+            let mut held = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                held.push(socket);
+            }
+        });
+
+        let client = reqwest::Client::new();
+        let result = probe_health(&client, "127.0.0.1", port, Duration::from_millis(200)).await;
+        assert!(matches!(result, HealthResult::TimedOut), "{:?}", result);
+    }
+}
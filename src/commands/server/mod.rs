@@ -0,0 +1,3 @@
+// src/commands/server/mod.rs
+pub mod command;
+pub use command::ServerCommand;
@@ -0,0 +1,163 @@
+// src/commands/session/command.rs
+use crate::commands::command::Command;
+use crate::core::constants::VERSION;
+use crate::core::helpers::format_duration;
+use crate::core::prelude::*;
+use crate::server::types::ServerStatus;
+use std::sync::OnceLock;
+use std::time::Instant;
+
+static SESSION_START: OnceLock<Instant> = OnceLock::new();
+
+/// Marks the start of the TUI session; a restart keeps the first time
+pub fn mark_session_start() {
+    SESSION_START.get_or_init(Instant::now);
+}
+
+/// Time since `mark_session_start`, `None` outside the TUI
+pub fn session_uptime() -> Option<Duration> {
+    SESSION_START.get().map(Instant::elapsed)
+}
+
+#[derive(Debug, Default)]
+pub struct SessionCommand;
+
+impl SessionCommand {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Command for SessionCommand {
+    fn name(&self) -> &'static str {
+        "session"
+    }
+
+    fn description(&self) -> &'static str {
+        "Show runtime facts for support (version, theme, servers, paths)"
+    }
+
+    fn matches(&self, command: &str) -> bool {
+        crate::matches_exact!(command, "session" | "whoami")
+    }
+
+    fn execute_sync(&self, args: &[&str]) -> Result<String> {
+        if let Some(&arg) = args.first() {
+            return Err(AppError::Validation(format!(
+                "Unknown argument '{}'. Usage: session",
+                arg
+            )));
+        }
+        Ok(SessionInfo::gather()?.render())
+    }
+
+    fn priority(&self) -> u8 {
+        41
+    }
+}
+
+/// Current runtime state, collected for one `session` call
+#[derive(Debug)]
+struct SessionInfo {
+    version: &'static str,
+    theme: String,
+    language: String,
+    running_servers: usize,
+    total_servers: usize,
+    uptime: Option<Duration>,
+    data_dir: String,
+    config_path: String,
+}
+
+impl SessionInfo {
+    fn gather() -> Result<Self> {
+        let config = get_config()?;
+        let ctx = crate::server::shared::get_shared_context();
+        let (running_servers, total_servers) = {
+            let servers = read_lock(&ctx.servers, "servers")?;
+            let running = servers
+                .values()
+                .filter(|s| s.status == ServerStatus::Running)
+                .count();
+            (running, servers.len())
+        };
+
+        let data_dir = crate::core::paths::base_dir()
+            .map(|dir| dir.display().to_string())
+            .unwrap_or_else(|e| format!("unavailable ({})", e));
+        let config_path = config
+            .path()
+            .map(|p| p.display().to_string())
+            .unwrap_or_else(|| "none (defaults)".to_string());
+
+        Ok(Self {
+            version: VERSION,
+            theme: config.current_theme_name.clone(),
+            language: crate::i18n::get_current_language(),
+            running_servers,
+            total_servers,
+            uptime: session_uptime(),
+            data_dir,
+            config_path,
+        })
+    }
+
+    fn render(&self) -> String {
+        let uptime = self
+            .uptime
+            .map(format_duration)
+            .unwrap_or_else(|| "-".to_string());
+        let rows = [
+            ("Version", self.version.to_string()),
+            ("Theme", self.theme.clone()),
+            ("Language", self.language.clone()),
+            (
+                "Servers",
+                format!(
+                    "{} running / {} total",
+                    self.running_servers, self.total_servers
+                ),
+            ),
+            ("Uptime", uptime),
+            ("Data dir", self.data_dir.clone()),
+            ("Config", self.config_path.clone()),
+        ];
+
+        let mut result = String::from("Session\n");
+        for (label, value) in rows {
+            result.push_str(&format!("\n  {:<10} {}", label, value));
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_session_info() {
+        let info = SessionInfo {
+            version: "1.2.3",
+            theme: "dark".to_string(),
+            language: "en".to_string(),
+            running_servers: 2,
+            total_servers: 5,
+            uptime: Some(Duration::from_secs(3725)),
+            data_dir: "/srv/rush".to_string(),
+            config_path: "/srv/rush/.rss/rush.toml".to_string(),
+        };
+        let output = info.render();
+        assert!(output.contains("Version    1.2.3"));
+        assert!(output.contains("Servers    2 running / 5 total"));
+        assert!(output.contains("Uptime     1h 02m 05s"));
+        assert!(output.contains("Config     /srv/rush/.rss/rush.toml"));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_secs(45)), "45s");
+        assert_eq!(format_duration(Duration::from_secs(723)), "12m 03s");
+        assert_eq!(format_duration(Duration::from_secs(187_800)), "2d 04h 10m");
+    }
+}
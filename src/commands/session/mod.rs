@@ -0,0 +1,3 @@
+// src/commands/session/mod.rs
+pub mod command;
+pub use command::SessionCommand;
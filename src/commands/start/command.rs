@@ -1,9 +1,11 @@
 use crate::commands::command::Command;
 use crate::commands::parsing::{parse_bulk_args, BulkMode};
 use crate::core::prelude::*;
+use crate::server::handlers::web::StartError;
 use crate::server::types::{ServerContext, ServerStatus};
 use crate::server::utils::port::is_port_available;
 use crate::server::utils::validation::find_server;
+use crate::server::ServerManager;
 use opener;
 
 #[derive(Debug, Default)]
@@ -37,8 +39,14 @@ impl Command for StartCommand {
         let config = get_config()?;
         let ctx = crate::server::shared::get_shared_context();
 
-        // Extract --workers N from args
-        let (filtered_args, workers_override) = Self::extract_workers_flag(args);
+        // Extract --workers N and --auto-port from args
+        let (filtered_args, workers) = Self::extract_workers_flag(args);
+        let auto_port = filtered_args.iter().any(|a| a == "--auto-port");
+        let filtered_args: Vec<String> = filtered_args
+            .into_iter()
+            .filter(|a| a != "--auto-port")
+            .collect();
+        let options = StartOptions { workers, auto_port };
 
         if filtered_args.is_empty() {
             return Err(AppError::Validation(get_translation(
@@ -51,12 +59,12 @@ impl Command for StartCommand {
 
         match parse_bulk_args(&filtered_refs) {
             BulkMode::Single(identifier) => {
-                self.start_server_internal(&config, ctx, &identifier, false, workers_override)
+                self.start_server_internal(&config, ctx, &identifier, false, options)
             }
             BulkMode::Range(start, end) => {
-                self.start_range_servers(&config, ctx, start, end, workers_override)
+                self.start_range_servers(&config, ctx, start, end, options)
             }
-            BulkMode::All => self.start_all_servers(&config, ctx, workers_override),
+            BulkMode::All => self.start_all_servers(&config, ctx, options),
             BulkMode::Invalid(error) => Err(AppError::Validation(error)),
         }
     }
@@ -82,7 +90,7 @@ impl StartCommand {
             if *arg == "--workers" || *arg == "-w" {
                 if let Some(next) = args.get(i + 1) {
                     if let Ok(w) = next.parse::<usize>() {
-                        if w >= 1 && w <= 16 {
+                        if (1..=16).contains(&w) {
                             workers = Some(w);
                             skip_next = true;
                             continue;
@@ -106,7 +114,7 @@ impl StartCommand {
         ctx: &ServerContext,
         identifier: &str,
         skip_browser: bool,
-        workers_override: Option<usize>,
+        options: StartOptions,
     ) -> Result<String> {
         let (server_info, existing_handle) =
             {
@@ -164,10 +172,7 @@ impl StartCommand {
                 ));
             }
             PortValidationResult::OccupiedByOther => {
-                return Ok(get_translation(
-                    "server.error.port_used_by_other",
-                    &[&server_info.port.to_string(), &server_info.name],
-                ));
+                return self.handle_port_in_use(config, ctx, server_info, skip_browser, options);
             }
         }
 
@@ -186,7 +191,7 @@ impl StartCommand {
             server_info,
             running_count,
             skip_browser,
-            workers_override,
+            options,
         )
     }
 
@@ -202,7 +207,7 @@ impl StartCommand {
         _ctx: &ServerContext,
         start: u32,
         end: u32,
-        workers_override: Option<usize>,
+        options: StartOptions,
     ) -> Result<String> {
         let total = (end - start + 1) as usize;
         let config = config.clone();
@@ -213,23 +218,28 @@ impl StartCommand {
             let ctx = crate::server::shared::get_shared_context();
             let timer = std::time::Instant::now();
 
-            let identifiers: Vec<_> = (start..=end)
-                .map(|i| (format!("{}", i), i))
-                .collect();
+            let identifiers: Vec<_> = (start..=end).map(|i| (format!("{}", i), i)).collect();
 
-            let (started, failed) = Self::start_batch_parallel(
-                &config, ctx, &identifiers, total, workers_override, &rt_handle,
-            );
+            let (started, failed) =
+                Self::start_batch_parallel(&config, ctx, &identifiers, total, options, &rt_handle);
 
             let elapsed = timer.elapsed();
             let mem_info = Self::get_memory_info();
             crate::input::send_progress(format!(
                 "\n  Range {}-{}: {} [Started], {} [Failed]\n  Time: {:.2}s{}\n",
-                start, end, started, failed, elapsed.as_secs_f64(), mem_info,
+                start,
+                end,
+                started,
+                failed,
+                elapsed.as_secs_f64(),
+                mem_info,
             ));
         });
 
-        Ok(format!("  Starting {} servers (range {}-{})...", total, start, end))
+        Ok(format!(
+            "  Starting {} servers (range {}-{})...",
+            total, start, end
+        ))
     }
 
     // Start all stopped servers — NON-BLOCKING, PARALLEL with progress
@@ -237,7 +247,7 @@ impl StartCommand {
         &self,
         config: &Config,
         ctx: &ServerContext,
-        workers_override: Option<usize>,
+        options: StartOptions,
     ) -> Result<String> {
         let mut stopped_servers: Vec<_> = {
             let servers = read_lock(&ctx.servers, "servers")?;
@@ -282,18 +292,32 @@ impl StartCommand {
                 .collect();
 
             let (started, failed) = Self::start_batch_parallel_with_names(
-                &config, ctx, &identifiers, &port_map, total, workers_override, &rt_handle,
+                &config,
+                ctx,
+                &identifiers,
+                &port_map,
+                total,
+                options,
+                &rt_handle,
             );
 
             let elapsed = timer.elapsed();
             let mem_info = Self::get_memory_info();
             crate::input::send_progress(format!(
                 "\n  Done: {} [Started], {} [Failed] (of {})\n  Time: {:.2}s{}\n",
-                started, failed, total, elapsed.as_secs_f64(), mem_info,
+                started,
+                failed,
+                total,
+                elapsed.as_secs_f64(),
+                mem_info,
             ));
         });
 
-        Ok(format!("  Starting {} servers ({} parallel)...", total, Self::PARALLEL_BATCH_SIZE))
+        Ok(format!(
+            "  Starting {} servers ({} parallel)...",
+            total,
+            Self::PARALLEL_BATCH_SIZE
+        ))
     }
 
     /// Start servers in parallel batches (for range operations)
@@ -302,7 +326,7 @@ impl StartCommand {
         ctx: &ServerContext,
         identifiers: &[(String, u32)],
         total: usize,
-        workers_override: Option<usize>,
+        options: StartOptions,
         rt_handle: &tokio::runtime::Handle,
     ) -> (usize, usize) {
         use std::sync::atomic::{AtomicUsize, Ordering};
@@ -327,7 +351,7 @@ impl StartCommand {
                     s.spawn(move || {
                         let _g = rt.enter();
                         let cmd = StartCommand::new();
-                        match cmd.start_server_internal(config, ctx, identifier, true, workers_override) {
+                        match cmd.start_server_internal(config, ctx, identifier, true, options) {
                             Ok(message) => {
                                 if message.contains("started successfully") {
                                     started.fetch_add(1, Ordering::Relaxed);
@@ -342,7 +366,10 @@ impl StartCommand {
                                         name, url_str
                                     ));
                                 } else {
-                                    crate::input::send_progress(format!("  Server {}: {}", num, message));
+                                    crate::input::send_progress(format!(
+                                        "  Server {}: {}",
+                                        num, message
+                                    ));
                                 }
                             }
                             Err(e) => {
@@ -358,7 +385,10 @@ impl StartCommand {
             });
         }
 
-        (started.load(Ordering::Relaxed), failed.load(Ordering::Relaxed))
+        (
+            started.load(Ordering::Relaxed),
+            failed.load(Ordering::Relaxed),
+        )
     }
 
     /// Start servers in parallel batches (for "all" operations with name/port info)
@@ -368,7 +398,7 @@ impl StartCommand {
         identifiers: &[(String, u32)],
         port_map: &std::collections::HashMap<String, (String, u16)>,
         total: usize,
-        workers_override: Option<usize>,
+        options: StartOptions,
         rt_handle: &tokio::runtime::Handle,
     ) -> (usize, usize) {
         use std::sync::atomic::{AtomicUsize, Ordering};
@@ -398,7 +428,7 @@ impl StartCommand {
                     s.spawn(move || {
                         let _g = rt.enter();
                         let cmd = StartCommand::new();
-                        match cmd.start_server_internal(config, ctx, server_id, true, workers_override) {
+                        match cmd.start_server_internal(config, ctx, server_id, true, options) {
                             Ok(message) => {
                                 if message.contains("started successfully") {
                                     started.fetch_add(1, Ordering::Relaxed);
@@ -423,7 +453,10 @@ impl StartCommand {
             });
         }
 
-        (started.load(Ordering::Relaxed), failed.load(Ordering::Relaxed))
+        (
+            started.load(Ordering::Relaxed),
+            failed.load(Ordering::Relaxed),
+        )
     }
 
     // Actually start the server
@@ -434,9 +467,9 @@ impl StartCommand {
         server_info: crate::server::types::ServerInfo,
         current_running_count: usize,
         skip_browser: bool,
-        workers_override: Option<usize>,
+        options: StartOptions,
     ) -> Result<String> {
-        match self.spawn_server(config, ctx, server_info.clone(), workers_override) {
+        match self.spawn_server(config, ctx, server_info.clone(), options.workers) {
             Ok(handle) => {
                 {
                     let mut handles = write_lock(&ctx.handles, "handles")?;
@@ -455,7 +488,11 @@ impl StartCommand {
                     format!("http://{}:{}", config.server.bind_address, server_info.port);
                 let proxy_http_port = config.proxy.port;
                 let proxy_https_port = config.proxy.port + config.proxy.https_port_offset;
-                let actual_workers = workers_override.unwrap_or(config.server.workers);
+                let actual_workers = crate::server::handlers::web::effective_workers(
+                    &server_info,
+                    config,
+                    options.workers,
+                );
 
                 let open_browser = !skip_browser && config.server.auto_open_browser;
                 if open_browser {
@@ -473,10 +510,13 @@ impl StartCommand {
                      Running {}/{}{}\n",
                     server_info.name,
                     server_url,
-                    server_info.name, proxy_http_port,
-                    server_info.name, proxy_https_port,
+                    server_info.name,
+                    proxy_http_port,
+                    server_info.name,
+                    proxy_https_port,
                     server_url,
-                    server_info.name, server_info.port,
+                    server_info.name,
+                    server_info.port,
                     actual_workers,
                     current_running_count + 1,
                     config.server.max_concurrent,
@@ -487,6 +527,10 @@ impl StartCommand {
                     }
                 ))
             }
+            // Lost the race for the port after validation
+            Err(StartError::AddrInUse(port)) if port == server_info.port => {
+                self.handle_port_in_use(config, ctx, server_info, skip_browser, options)
+            }
             Err(e) => {
                 self.update_server_status(ctx, &server_info.id, ServerStatus::Failed);
 
@@ -501,71 +545,82 @@ impl StartCommand {
         }
     }
 
-    /// Get process memory usage for benchmarking
-    fn get_memory_info() -> String {
-        #[cfg(target_os = "macos")]
-        {
-            use std::mem;
-            extern "C" {
-                fn mach_task_self() -> u32;
-                fn task_info(
-                    task: u32,
-                    flavor: u32,
-                    info: *mut libc::c_void,
-                    count: *mut u32,
-                ) -> i32;
-            }
+    /// Another process holds the server's port: suggest the next free port,
+    /// or move the server there and start it with `--auto-port`
+    fn handle_port_in_use(
+        &self,
+        config: &Config,
+        ctx: &ServerContext,
+        server_info: crate::server::types::ServerInfo,
+        skip_browser: bool,
+        options: StartOptions,
+    ) -> Result<String> {
+        let manager = ServerManager::with_context(ctx.clone());
+        let next_port = manager.find_free_port(
+            config.server.port_range_start.max(1024),
+            Some(config.server.port_range_end),
+            &config.server.bind_address,
+        )?;
+
+        if !options.auto_port {
+            return Ok(get_translation(
+                "server.error.port_used_by_other_suggest",
+                &[
+                    &server_info.port.to_string(),
+                    &server_info.name,
+                    &next_port.to_string(),
+                ],
+            ));
+        }
 
-            #[repr(C)]
-            struct MachTaskBasicInfo {
-                virtual_size: u64,
-                resident_size: u64,
-                resident_size_max: u64,
-                user_time: [u32; 2],
-                system_time: [u32; 2],
-                policy: i32,
-                suspend_count: i32,
+        let old_port = server_info.port;
+        let moved = manager.move_to_port(&server_info.id, next_port)?;
+        let server_id = moved.id.clone();
+        tokio::spawn(async move {
+            let registry = crate::server::shared::get_persistent_registry();
+            if let Err(e) = registry.update_server_port(&server_id, next_port).await {
+                log::error!("Failed to persist new port for {}: {}", server_id, e);
             }
+        });
 
-            let mut info: MachTaskBasicInfo = unsafe { mem::zeroed() };
-            let mut count = (mem::size_of::<MachTaskBasicInfo>() / mem::size_of::<u32>()) as u32;
+        let running_count = self.count_running_servers(ctx);
+        if running_count >= config.server.max_concurrent {
+            return Err(AppError::Validation(format!(
+                "Cannot start server: Running servers limit reached ({}/{})",
+                running_count, config.server.max_concurrent
+            )));
+        }
 
-            let result = unsafe {
-                task_info(
-                    mach_task_self(),
-                    20, // MACH_TASK_BASIC_INFO
-                    &mut info as *mut _ as *mut libc::c_void,
-                    &mut count,
-                )
-            };
+        let started = self.actually_start_server(
+            config,
+            ctx,
+            moved,
+            running_count,
+            skip_browser,
+            StartOptions {
+                auto_port: false,
+                ..options
+            },
+        )?;
+        Ok(format!(
+            "{}\n{}",
+            get_translation(
+                "server.info.port_moved",
+                &[
+                    &old_port.to_string(),
+                    &server_info.name,
+                    &next_port.to_string()
+                ],
+            ),
+            started
+        ))
+    }
 
-            if result == 0 {
-                let rss_mb = info.resident_size as f64 / (1024.0 * 1024.0);
-                format!("  |  Memory: {:.1} MB", rss_mb)
-            } else {
-                String::new()
-            }
-        }
-        #[cfg(target_os = "linux")]
-        {
-            if let Ok(status) = std::fs::read_to_string("/proc/self/status") {
-                for line in status.lines() {
-                    if line.starts_with("VmRSS:") {
-                        let kb: f64 = line
-                            .split_whitespace()
-                            .nth(1)
-                            .and_then(|s| s.parse().ok())
-                            .unwrap_or(0.0);
-                        return format!("  |  Memory: {:.1} MB", kb / 1024.0);
-                    }
-                }
-            }
-            String::new()
-        }
-        #[cfg(not(any(target_os = "macos", target_os = "linux")))]
-        {
-            String::new()
-        }
+    /// Get process memory usage for benchmarking
+    fn get_memory_info() -> String {
+        crate::core::helpers::process_rss_bytes()
+            .map(|bytes| format!("  |  Memory: {:.1} MB", bytes as f64 / (1024.0 * 1024.0)))
+            .unwrap_or_default()
     }
 
     // Helper methods
@@ -611,7 +666,7 @@ impl StartCommand {
         ctx: &ServerContext,
         server_info: crate::server::types::ServerInfo,
         workers_override: Option<usize>,
-    ) -> std::result::Result<actix_web::dev::ServerHandle, String> {
+    ) -> std::result::Result<actix_web::dev::ServerHandle, StartError> {
         crate::server::handlers::web::create_web_server_with_workers(
             ctx,
             server_info,
@@ -640,13 +695,11 @@ impl StartCommand {
 
     /// Extract port from a success message like "http://127.0.0.1:8001"
     fn extract_port_from_message(message: &str) -> Option<u16> {
-        message
-            .find("127.0.0.1:")
-            .and_then(|pos| {
-                let after = &message[pos + 10..];
-                let port_str: String = after.chars().take_while(|c| c.is_ascii_digit()).collect();
-                port_str.parse().ok()
-            })
+        message.find("127.0.0.1:").and_then(|pos| {
+            let after = &message[pos + 10..];
+            let port_str: String = after.chars().take_while(|c| c.is_ascii_digit()).collect();
+            port_str.parse().ok()
+        })
     }
 
     /// Extract server name from a success message like "Server 'rss-001' started"
@@ -657,6 +710,15 @@ impl StartCommand {
     }
 }
 
+/// Flags shared by single and bulk starts
+#[derive(Debug, Clone, Copy, Default)]
+struct StartOptions {
+    /// `--workers N`
+    workers: Option<usize>,
+    /// `--auto-port`: move to the next free port instead of failing
+    auto_port: bool,
+}
+
 #[derive(Debug)]
 enum PortValidationResult {
     Available,
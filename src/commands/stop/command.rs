@@ -34,6 +34,10 @@ impl Command for StopCommand {
             )));
         }
 
+        if let Some(pos) = args.iter().position(|a| *a == "--drain") {
+            return self.stop_with_drain(args, pos);
+        }
+
         let config = get_config()?;
         let ctx = crate::server::shared::get_shared_context();
 
@@ -54,6 +58,75 @@ impl StopCommand {
     /// Parallel batch size for bulk stop operations
     const PARALLEL_BATCH_SIZE: usize = 4;
 
+    /// Upper bound for `--drain <seconds>`
+    const MAX_DRAIN_SECS: u64 = 600;
+
+    /// `stop <id> --drain <seconds>`: drain in the background, report via progress
+    fn stop_with_drain(&self, args: &[&str], pos: usize) -> Result<String> {
+        let seconds = args
+            .get(pos + 1)
+            .and_then(|s| s.parse::<u64>().ok())
+            .filter(|s| *s <= Self::MAX_DRAIN_SECS)
+            .ok_or_else(|| {
+                AppError::Validation(format!(
+                    "--drain expects seconds (0-{})",
+                    Self::MAX_DRAIN_SECS
+                ))
+            })?;
+
+        let rest: Vec<&str> = args
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != pos && *i != pos + 1)
+            .map(|(_, a)| *a)
+            .collect();
+        let identifier = match parse_bulk_args(&rest) {
+            BulkMode::Single(identifier) => identifier,
+            BulkMode::Invalid(error) => return Err(AppError::Validation(error)),
+            _ => {
+                return Err(AppError::Validation(
+                    "--drain works with a single server".to_string(),
+                ))
+            }
+        };
+
+        let ctx = crate::server::shared::get_shared_context().clone();
+        let manager = crate::server::ServerManager::with_context(ctx);
+        let server = manager.get_server_info(&identifier)?;
+        if server.status != ServerStatus::Running {
+            return Ok(format!(
+                "Server '{}' is not active (Status: {})",
+                server.name, server.status
+            ));
+        }
+
+        self.notify_browser_shutdown(&server);
+
+        tokio::spawn(async move {
+            let max_wait = std::time::Duration::from_secs(seconds);
+            match manager.stop_server_graceful(&identifier, max_wait).await {
+                Ok(report) if report.drained => crate::input::send_progress(format!(
+                    "Server '{}' drained and stopped after {:.1}s [PERSISTENT]",
+                    report.server.name,
+                    report.waited.as_secs_f64()
+                )),
+                Ok(report) => crate::input::send_progress(format!(
+                    "Server '{}' force-stopped after {}s drain ({} request(s) cut off) [PERSISTENT]",
+                    report.server.name, seconds, report.remaining
+                )),
+                Err(e) => crate::input::send_progress(format!(
+                    "Drain of '{}' failed: {}",
+                    identifier, e
+                )),
+            }
+        });
+
+        Ok(format!(
+            "Draining server '{}' (up to {}s, no new connections)...",
+            server.name, seconds
+        ))
+    }
+
     // Stop single server
     // `bulk_mode`: when true, skip the blocking sleep (for parallel bulk ops)
     fn stop_single_server(
@@ -63,7 +136,7 @@ impl StopCommand {
         identifier: &str,
         bulk_mode: bool,
     ) -> Result<String> {
-        let (server_info, handle) = {
+        let server_info = {
             let servers_guard = ctx
                 .servers
                 .read()
@@ -77,16 +150,7 @@ impl StopCommand {
                     server_info.name, server_info.status
                 ));
             }
-
-            // Atomically remove the handle
-            let handle = {
-                let mut handles_guard = ctx.handles.write().map_err(|_| {
-                    AppError::Validation("Handle-Context lock poisoned".to_string())
-                })?;
-                handles_guard.remove(&server_info.id)
-            };
-
-            (server_info, handle)
+            server_info
         };
 
         log::info!(
@@ -95,8 +159,8 @@ impl StopCommand {
             server_info.port
         );
 
-        // Set status to Stopped immediately
-        self.update_server_status(ctx, &server_info.id, ServerStatus::Stopped);
+        // Removes the handle and sets the status to Stopped immediately
+        let handle = ctx.begin_stop(&server_info.id);
 
         // Notify browser to close (skip in bulk mode for speed)
         if !bulk_mode {
@@ -358,69 +422,9 @@ impl StopCommand {
 
     /// Get process memory usage for benchmarking
     fn get_memory_info() -> String {
-        #[cfg(target_os = "macos")]
-        {
-            use std::mem;
-            extern "C" {
-                fn mach_task_self() -> u32;
-                fn task_info(
-                    task: u32,
-                    flavor: u32,
-                    info: *mut libc::c_void,
-                    count: *mut u32,
-                ) -> i32;
-            }
-
-            #[repr(C)]
-            struct MachTaskBasicInfo {
-                virtual_size: u64,
-                resident_size: u64,
-                resident_size_max: u64,
-                user_time: [u32; 2],
-                system_time: [u32; 2],
-                policy: i32,
-                suspend_count: i32,
-            }
-
-            let mut info: MachTaskBasicInfo = unsafe { mem::zeroed() };
-            let mut count = (mem::size_of::<MachTaskBasicInfo>() / mem::size_of::<u32>()) as u32;
-
-            let result = unsafe {
-                task_info(
-                    mach_task_self(),
-                    20, // MACH_TASK_BASIC_INFO
-                    &mut info as *mut _ as *mut libc::c_void,
-                    &mut count,
-                )
-            };
-
-            if result == 0 {
-                let rss_mb = info.resident_size as f64 / (1024.0 * 1024.0);
-                format!("  |  Memory: {:.1} MB", rss_mb)
-            } else {
-                String::new()
-            }
-        }
-        #[cfg(target_os = "linux")]
-        {
-            if let Ok(status) = std::fs::read_to_string("/proc/self/status") {
-                for line in status.lines() {
-                    if line.starts_with("VmRSS:") {
-                        let kb: f64 = line
-                            .split_whitespace()
-                            .nth(1)
-                            .and_then(|s| s.parse().ok())
-                            .unwrap_or(0.0);
-                        return format!("  |  Memory: {:.1} MB", kb / 1024.0);
-                    }
-                }
-            }
-            String::new()
-        }
-        #[cfg(not(any(target_os = "macos", target_os = "linux")))]
-        {
-            String::new()
-        }
+        crate::core::helpers::process_rss_bytes()
+            .map(|bytes| format!("  |  Memory: {:.1} MB", bytes as f64 / (1024.0 * 1024.0)))
+            .unwrap_or_default()
     }
 
     // Browser notification
@@ -474,13 +478,4 @@ impl StopCommand {
             }
         });
     }
-
-    // Status update helper
-    fn update_server_status(&self, ctx: &ServerContext, server_id: &str, status: ServerStatus) {
-        if let Ok(mut servers) = ctx.servers.write() {
-            if let Some(server) = servers.get_mut(server_id) {
-                server.status = status;
-            }
-        }
-    }
 }
@@ -1,8 +1,9 @@
 use crate::commands::command::Command;
 use crate::core::prelude::*;
-use crate::sync::profiles::RemoteProfileStore;
+use crate::sync::profiles::{RemoteProfileStore, SyncDirection};
 use crate::sync::transport::{
-    git_pull, restart_service, run_remote_command, sync_pull, sync_push, test_connection,
+    disconnect, git_pull, restart_service, run_remote_command, sync_pull, sync_push,
+    test_connection, SSH_CONTROL_PERSIST_SECS,
 };
 use std::path::PathBuf;
 
@@ -39,6 +40,8 @@ impl Command for SyncCommand {
             Some("exec") => self.exec(&store, args),
             Some("restart") => self.restart(&store, args),
             Some("git-pull") => self.git_pull(&store, args),
+            Some("disconnect") => self.disconnect(&store, args),
+            Some("status") => self.status(&store),
             Some(sub) => Err(AppError::Validation(format!(
                 "Unknown sync subcommand '{}'. Use 'sync help'.",
                 sub
@@ -60,13 +63,17 @@ impl SyncCommand {
             )
         })?;
 
-        let profile = store.get(remote_name)?;
+        let (remote_name, profile) = store.resolve(remote_name)?;
         let local_path = positionals
             .get(2)
             .map(PathBuf::from)
             .unwrap_or_else(|| PathBuf::from("www"));
 
-        let result = sync_push(&profile, &local_path, delete, dry_run)?;
+        let result = sync_push(&profile, &local_path, delete, dry_run);
+        if !dry_run {
+            record_outcome(store, &remote_name, SyncDirection::Push, &result);
+        }
+        let result = result?;
         Ok(format!(
             "{}PUSH {} -> {} [{}]\n{}",
             if dry_run { "[DRY-RUN] " } else { "" },
@@ -85,13 +92,17 @@ impl SyncCommand {
             )
         })?;
 
-        let profile = store.get(remote_name)?;
+        let (remote_name, profile) = store.resolve(remote_name)?;
         let local_path = positionals
             .get(2)
             .map(PathBuf::from)
             .unwrap_or_else(|| PathBuf::from(format!("download-{}", remote_name)));
 
-        let result = sync_pull(&profile, &local_path, delete, dry_run)?;
+        let result = sync_pull(&profile, &local_path, delete, dry_run);
+        if !dry_run {
+            record_outcome(store, &remote_name, SyncDirection::Pull, &result);
+        }
+        let result = result?;
         Ok(format!(
             "{}PULL {} <- {} [{}]\n{}",
             if dry_run { "[DRY-RUN] " } else { "" },
@@ -155,23 +166,100 @@ impl SyncCommand {
         ))
     }
 
+    fn status(&self, store: &RemoteProfileStore) -> Result<String> {
+        let profiles = store.list()?;
+        if profiles.is_empty() {
+            return Ok("No remotes configured. Add one with 'remote add'.".to_string());
+        }
+
+        let mut out = format!("Sync status ({} remotes)\n", profiles.len());
+        for (name, profile) in profiles {
+            let history = store.history(&name)?;
+            let label = match &profile.alias {
+                Some(alias) => format!("{} ({})", name, alias),
+                None => name.clone(),
+            };
+            out.push_str(&format!(
+                "\n  {:<12} {}:{}\n    last sync  {}",
+                label,
+                profile.ssh_target(),
+                profile.port,
+                history.last_success().unwrap_or("never")
+            ));
+            for (label, at) in [("push", &history.last_push), ("pull", &history.last_pull)] {
+                if let Some(at) = at {
+                    out.push_str(&format!("\n    {:<10} {}", label, at));
+                }
+            }
+            if let Some(error) = &history.last_error {
+                out.push_str(&format!(
+                    "\n    error      {} {}: {}",
+                    error.at, error.direction, error.message
+                ));
+            }
+        }
+        Ok(out)
+    }
+
+    fn disconnect(&self, store: &RemoteProfileStore, args: &[&str]) -> Result<String> {
+        let closed = match args.get(1) {
+            Some(remote_name) => disconnect(Some(&store.get(remote_name)?)),
+            None => disconnect(None),
+        };
+
+        if closed.is_empty() {
+            Ok("No cached SSH connections to close".to_string())
+        } else {
+            Ok(format!(
+                "Closed {} SSH connection{}: {}",
+                closed.len(),
+                if closed.len() == 1 { "" } else { "s" },
+                closed.join(", ")
+            ))
+        }
+    }
+
     fn help_text(&self) -> String {
-        "Sync and remote actions\n\n\
+        format!(
+            "Sync and remote actions\n\n\
          Commands:\n\
            sync push <remote> [local_path] [--delete] [--dry-run]\n\
            sync pull <remote> [local_path] [--delete] [--dry-run]\n\
            sync test <remote>\n\
            sync exec <remote> <command...>\n\
            sync restart <remote> [service]\n\
-           sync git-pull <remote> [branch]\n\n\
+           sync git-pull <remote> [branch]\n\
+           sync status\n\
+           sync disconnect [remote]\n\n\
          Flags:\n\
            --delete    Remove files on destination not present in source\n\
-           --dry-run   Show what would be transferred without making changes (rsync only)\n\n\
+           --dry-run   List files to transfer/delete with sizes, change nothing\n\
+             (pull previews need rsync)\n\n\
          Notes:\n\
            - Uses rsync over SSH when available.\n\
            - Falls back to scp when rsync is not installed.\n\
-           - Configure remotes via the 'remote' command."
-            .to_string()
+           - Consecutive calls to one remote share an SSH connection;\n\
+             it closes after {}s idle or with 'sync disconnect'.\n\
+           - Configure remotes via the 'remote' command.",
+            SSH_CONTROL_PERSIST_SECS
+        )
+    }
+}
+
+/// Keeps the sync history in `remotes.toml`; a failed write only logs
+fn record_outcome(
+    store: &RemoteProfileStore,
+    remote_name: &str,
+    direction: SyncDirection,
+    result: &Result<String>,
+) {
+    let error = result.as_ref().err().map(|e| e.to_string());
+    let outcome = match &error {
+        Some(message) => Err(message.as_str()),
+        None => Ok(()),
+    };
+    if let Err(e) = store.record_sync(remote_name, direction, outcome) {
+        log::warn!("Could not record sync history for '{}': {}", remote_name, e);
     }
 }
 
@@ -50,9 +50,8 @@ impl Command for ThemeCommand {
             Err(e) => {
                 log::error!("ThemeSystem load failed: {}", e);
                 return Ok(format!(
-                    "{} {}\n\n{}",
+                    "{}\n\n{}",
                     get_command_translation("system.commands.theme.load_failed", &[&e.to_string()]),
-                    get_command_translation("system.commands.theme.no_themes_hint", &[]),
                     get_command_translation("system.commands.theme.add_sections_hint", &[])
                 ));
             }
@@ -83,7 +82,50 @@ impl Command for ThemeCommand {
                     &[],
                 )),
             },
+            Some(&"create") => match args.get(1) {
+                Some(&name) if !name.starts_with("--") => {
+                    let (fields, force) = ThemeSystem::parse_create_flags(&args[2..])?;
+                    if fields.is_empty() {
+                        Ok(Self::create_usage_i18n())
+                    } else {
+                        theme_system.create_theme_i18n(name, &fields, force)
+                    }
+                }
+                _ => Ok(Self::create_usage_i18n()),
+            },
+            Some(&"export") => match args.get(1) {
+                Some(&name) if !name.starts_with("--") => {
+                    let file = match args.iter().position(|a| *a == "--file") {
+                        Some(pos) => Some(*args.get(pos + 1).ok_or_else(|| {
+                            AppError::Validation("--file needs a path".to_string())
+                        })?),
+                        None => None,
+                    };
+                    theme_system.export_theme_i18n(name, file)
+                }
+                _ => Ok(get_command_translation(
+                    "system.commands.theme.export.missing_name",
+                    &[],
+                )),
+            },
+            Some(&"next") => {
+                let name = theme_system.next_name();
+                theme_system.change_theme_i18n(&name)
+            }
+            Some(&"random") => {
+                let name = theme_system.random_name(random_seed());
+                theme_system.change_theme_i18n(&name)
+            }
+            Some(&"reload") => theme_system.reload_i18n(),
+            Some(&"migrate") => theme_system.migrate_i18n(),
+            Some(&"change") => match args.get(1) {
+                Some(&theme_name) => theme_system.change_theme_i18n(theme_name),
+                None => Ok(Self::create_help_text_i18n(theme_system)),
+            },
             Some(&"preview") => match args.get(1) {
+                Some(&theme_name) if args.contains(&"--live") => {
+                    theme_system.preview_theme_live_i18n(theme_name)
+                }
                 Some(&theme_name) => theme_system.preview_theme_i18n(theme_name),
                 None => Ok(get_command_translation(
                     "system.commands.theme.preview_missing_name",
@@ -103,25 +145,33 @@ impl Command for ThemeCommand {
 }
 
 impl ThemeCommand {
+    /// Field list for `theme create` without flags
+    fn create_usage_i18n() -> String {
+        get_command_translation(
+            "system.commands.theme.create.usage",
+            &[
+                "input_text, input_bg, output_text, output_bg",
+                "input_cursor_color, output_cursor_color, input_cursor, output_cursor, \
+                 input_cursor_prefix, typewriter_delay_ms",
+            ],
+        )
+    }
+
     fn create_help_text_i18n(theme_system: &ThemeSystem) -> String {
         let available_themes = theme_system.get_available_names();
-
-        if available_themes.is_empty() {
-            return format!(
-                "{}\n\n{}",
-                get_command_translation("system.commands.theme.no_themes_available", &[]),
-                get_command_translation("system.commands.theme.how_to_add_themes", &[])
-            );
-        }
-
         let themes_list = available_themes.join(", ");
 
         format!(
-            "{}\n{}\n{}\n{}\n{}\n\n{}\n{}\n{}\n{}\n\n{}",
+            "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n\n{}\n{}\n{}\n{}\n\n{}",
             get_command_translation("system.commands.theme.help.header", &[]),
             get_command_translation("system.commands.theme.help.show_themes", &[]),
             get_command_translation("system.commands.theme.help.select_theme", &[&themes_list]),
+            get_command_translation("system.commands.theme.help.cycle_theme", &[]),
             get_command_translation("system.commands.theme.help.preview_theme", &[]),
+            get_command_translation("system.commands.theme.help.reload_themes", &[]),
+            get_command_translation("system.commands.theme.help.migrate_themes", &[]),
+            get_command_translation("system.commands.theme.help.create_theme", &[]),
+            get_command_translation("system.commands.theme.help.export_theme", &[]),
             get_command_translation("system.commands.theme.help.show_help", &[]),
             get_command_translation("system.commands.theme.help.live_loaded", &[]),
             get_command_translation("system.commands.theme.help.cursor_config", &[]),
@@ -140,30 +190,158 @@ impl Default for ThemeCommand {
 
 impl ThemeSystem {
     pub fn show_status_i18n(&self) -> String {
-        if self.themes.is_empty() {
-            return get_command_translation("system.commands.theme.no_themes_found", &[]);
-        }
+        let summary = self.parse_problems_i18n();
 
         let themes_list = self.themes.keys().cloned().collect::<Vec<_>>().join(", ");
-        get_command_translation(
+        let status = get_command_translation(
             "system.commands.theme.current_status",
             &[&self.current_name.to_uppercase(), &themes_list],
-        )
+        );
+        match summary {
+            Some(summary) => format!("{}\n{}", status, summary),
+            None => status,
+        }
+    }
+
+    /// Writes a validated `[theme.<name>]` block to rush.toml and loads it;
+    /// an existing theme is only replaced with `force`
+    pub fn create_theme_i18n(
+        &mut self,
+        name: &str,
+        fields: &std::collections::HashMap<String, String>,
+        force: bool,
+    ) -> Result<String> {
+        let name = name.to_lowercase();
+        let section = Self::render_theme_section(&name, fields)?;
+        if self.theme_exists(&name) && !force {
+            return Ok(get_command_translation(
+                "system.commands.theme.create.exists",
+                &[&name],
+            ));
+        }
+
+        let Some(path) = self.config_paths.iter().find(|path| path.exists()).cloned() else {
+            return Ok(get_command_translation(
+                "system.commands.theme.create.no_config",
+                &[],
+            ));
+        };
+        let content = std::fs::read_to_string(&path).map_err(AppError::Io)?;
+        let Some(updated) = Self::upsert_theme_section(&content, &name, &section, force) else {
+            return Ok(get_command_translation(
+                "system.commands.theme.create.exists",
+                &[&name],
+            ));
+        };
+        std::fs::write(&path, updated).map_err(AppError::Io)?;
+        self.reload()?;
+
+        Ok(format!(
+            "{}\n{}",
+            get_command_translation(
+                "system.commands.theme.create.done",
+                &[&name, &path.display().to_string()],
+            ),
+            section
+        ))
+    }
+
+    /// Prints a theme as a `[theme.<name>]` block, or writes it to `file`
+    pub fn export_theme_i18n(&self, name: &str, file: Option<&str>) -> Result<String> {
+        let name = name.to_lowercase();
+        let Some(theme) = self.get_theme(&name) else {
+            return Ok(get_command_translation(
+                "system.commands.theme.not_found",
+                &[&name, &self.get_available_names().join(", ")],
+            ));
+        };
+        let section = theme.to_toml_section(&name);
+
+        match file {
+            Some(file) => {
+                std::fs::write(file, format!("{}\n", section)).map_err(AppError::Io)?;
+                Ok(get_command_translation(
+                    "system.commands.theme.export.written",
+                    &[&name, file],
+                ))
+            }
+            None => Ok(section),
+        }
+    }
+
+    pub fn reload_i18n(&mut self) -> Result<String> {
+        let reload = self.reload()?;
+
+        let mut result = get_command_translation(
+            "system.commands.theme.reloaded",
+            &[
+                &reload.total.to_string(),
+                &reload.added.len().to_string(),
+                &reload.removed.len().to_string(),
+            ],
+        );
+        if !reload.added.is_empty() {
+            result.push_str(&format!("\n  + {}", reload.added.join(", ")));
+        }
+        if !reload.removed.is_empty() {
+            result.push_str(&format!("\n  - {}", reload.removed.join(", ")));
+        }
+        if let Some(problems) = self.parse_problems_i18n() {
+            result.push('\n');
+            result.push_str(&problems);
+        }
+
+        // Re-apply the active theme so edits to it show up immediately
+        match reload.kept_current {
+            Some(current) => {
+                result.push('\n');
+                result.push_str(&get_command_translation(
+                    "system.commands.theme.reload_kept_current",
+                    &[&current],
+                ));
+                Ok(result)
+            }
+            None => Ok(format!(
+                "__LIVE_THEME_UPDATE__{}__MESSAGE__{}",
+                self.current_name,
+                result
+            )),
+        }
+    }
+
+    fn parse_problems_i18n(&self) -> Option<String> {
+        let problems = self.parse_problems();
+        (!problems.is_empty()).then(|| {
+            get_command_translation(
+                "system.commands.theme.parse_problems",
+                &[&problems.join("\n  ")],
+            )
+        })
+    }
+
+    /// Error message for a theme that exists in TOML but lacks required keys
+    fn incomplete_theme_i18n(&self, theme_name: &str) -> Option<String> {
+        self.get_missing_keys(theme_name).map(|missing| {
+            get_command_translation(
+                "system.commands.theme.incomplete",
+                &[&theme_name.to_lowercase(), &missing.join(", ")],
+            )
+        })
     }
 
     pub fn change_theme_i18n(&mut self, theme_name: &str) -> Result<String> {
         let theme_name_lower = theme_name.to_lowercase();
 
+        if let Some(msg) = self.incomplete_theme_i18n(theme_name) {
+            return Ok(msg);
+        }
+
         if !self.themes.contains_key(&theme_name_lower) {
-            return Ok(if self.themes.is_empty() {
-                get_command_translation("system.commands.theme.no_themes_found", &[])
-            } else {
-                let available = self.themes.keys().cloned().collect::<Vec<_>>().join(", ");
-                get_command_translation(
-                    "system.commands.theme.not_found",
-                    &[theme_name, &available],
-                )
-            });
+            let available = self.themes.keys().cloned().collect::<Vec<_>>().join(", ");
+            return Ok(get_command_translation(
+                "system.commands.theme.not_found",
+                &[theme_name, &available],
+            ));
         }
 
         self.current_name = theme_name_lower.clone();
@@ -203,6 +381,10 @@ impl ThemeSystem {
     pub fn preview_theme_i18n(&self, theme_name: &str) -> Result<String> {
         let theme_name_lower = theme_name.to_lowercase();
 
+        if let Some(msg) = self.incomplete_theme_i18n(theme_name) {
+            return Ok(msg);
+        }
+
         if let Some(theme_def) = self.themes.get(&theme_name_lower) {
             Ok(get_command_translation(
                 "system.commands.theme.preview_details",
@@ -229,7 +411,86 @@ impl ThemeSystem {
         }
     }
 
+    /// Rewrites legacy theme keys in the first existing rush.toml
+    pub fn migrate_i18n(&self) -> Result<String> {
+        let Some(path) = self.config_paths.iter().find(|path| path.exists()) else {
+            return Ok(get_command_translation(
+                "system.commands.theme.migrate.no_config",
+                &[],
+            ));
+        };
+        let file = path.display().to_string();
+
+        let content = std::fs::read_to_string(path).map_err(AppError::Io)?;
+        let (updated, migrated) = Self::migrate_legacy_keys(&content);
+        if migrated.is_empty() {
+            return Ok(get_command_translation(
+                "system.commands.theme.migrate.up_to_date",
+                &[&file],
+            ));
+        }
+        std::fs::write(path, updated).map_err(AppError::Io)?;
+
+        let details = migrated
+            .iter()
+            .map(|key| {
+                let action = if key.dropped {
+                    get_command_translation(
+                        "system.commands.theme.migrate.dropped",
+                        &[&key.from, &key.to],
+                    )
+                } else {
+                    format!("{} -> {}", key.from, key.to)
+                };
+                format!("  [theme.{}] {}", key.theme, action)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        Ok(format!(
+            "{}\n{}",
+            get_command_translation(
+                "system.commands.theme.migrate.done",
+                &[&migrated.len().to_string(), &file],
+            ),
+            details
+        ))
+    }
+
+    /// Signals the screen to show the theme briefly; nothing is saved
+    pub fn preview_theme_live_i18n(&self, theme_name: &str) -> Result<String> {
+        use crate::core::constants::{SIG_THEME_MSG_SEP, SIG_THEME_PREVIEW, THEME_PREVIEW_SECS};
+        let theme_name_lower = theme_name.to_lowercase();
+
+        if let Some(msg) = self.incomplete_theme_i18n(theme_name) {
+            return Ok(msg);
+        }
+
+        if !self.themes.contains_key(&theme_name_lower) {
+            let available = self.themes.keys().cloned().collect::<Vec<_>>().join(", ");
+            return Ok(get_command_translation(
+                "system.commands.theme.not_found",
+                &[theme_name, &available],
+            ));
+        }
+
+        let message = get_command_translation(
+            "system.commands.theme.preview_live",
+            &[
+                &theme_name_lower.to_uppercase(),
+                &THEME_PREVIEW_SECS.to_string(),
+            ],
+        );
+        Ok(format!(
+            "{}{}{}{}",
+            SIG_THEME_PREVIEW, theme_name_lower, SIG_THEME_MSG_SEP, message
+        ))
+    }
+
     pub fn debug_theme_details_i18n(&self, theme_name: &str) -> String {
+        if let Some(msg) = self.incomplete_theme_i18n(theme_name) {
+            return msg;
+        }
+
         if let Some(theme_def) = self.themes.get(&theme_name.to_lowercase()) {
             get_command_translation(
                 "system.commands.theme.debug_details",
@@ -251,3 +512,16 @@ impl ThemeSystem {
         }
     }
 }
+
+/// Per-call seed for `theme random`; std's hasher keys are randomized
+fn random_seed() -> u64 {
+    use std::hash::{BuildHasher, Hasher};
+    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos(),
+    );
+    hasher.finish()
+}
@@ -4,7 +4,7 @@ use std::collections::HashMap;
 pub mod command;
 pub use command::ThemeCommand;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ThemeDefinition {
     pub input_text: String,
     pub input_bg: String,
@@ -15,11 +15,145 @@ pub struct ThemeDefinition {
     pub input_cursor: String,
     pub output_cursor: String,
     pub output_cursor_color: String,
+    pub typewriter_delay_ms: Option<u64>,
+}
+
+impl ThemeDefinition {
+    /// The `[theme.<name>]` block for this theme with every key written out,
+    /// so it loads the same everywhere (`theme export`)
+    pub fn to_toml_section(&self, name: &str) -> String {
+        // Quoted and escaped by toml, so quotes and backslashes survive
+        let string = |value: &str| toml::Value::String(value.to_string()).to_string();
+        let mut lines = vec![
+            format!("[theme.{}]", name),
+            format!("input_text = {}", string(&self.input_text)),
+            format!("input_bg = {}", string(&self.input_bg)),
+            format!("output_text = {}", string(&self.output_text)),
+            format!("output_bg = {}", string(&self.output_bg)),
+            format!(
+                "input_cursor_prefix = {}",
+                string(&self.input_cursor_prefix)
+            ),
+            format!("input_cursor_color = {}", string(&self.input_cursor_color)),
+            format!("input_cursor = {}", string(&self.input_cursor)),
+            format!("output_cursor = {}", string(&self.output_cursor)),
+            format!(
+                "output_cursor_color = {}",
+                string(&self.output_cursor_color)
+            ),
+        ];
+        if let Some(delay) = self.typewriter_delay_ms {
+            lines.push(format!("typewriter_delay_ms = {}", delay));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Themes available without any `[theme.*]` section: the `default` look and
+/// two accessibility themes. A TOML section with the same name replaces the
+/// built-in.
+pub fn builtin_themes() -> HashMap<String, ThemeDefinition> {
+    // colors: input text/bg, output text/bg, input/output cursor
+    let theme = |colors: [&str; 6], cursor: &str| ThemeDefinition {
+        input_text: colors[0].to_string(),
+        input_bg: colors[1].to_string(),
+        output_text: colors[2].to_string(),
+        output_bg: colors[3].to_string(),
+        input_cursor_prefix: "/// ".to_string(),
+        input_cursor_color: colors[4].to_string(),
+        input_cursor: cursor.to_string(),
+        output_cursor: cursor.to_string(),
+        output_cursor_color: colors[5].to_string(),
+        typewriter_delay_ms: None,
+    };
+
+    HashMap::from([
+        // Same colors as `Theme::default()`, so theming works on a bare rush.toml
+        (
+            "default".to_string(),
+            theme(
+                ["White", "Black", "White", "Black", "LightBlue", "White"],
+                "PIPE",
+            ),
+        ),
+        // Okabe-Ito blue/orange/sky blue, no red-green distinctions
+        (
+            "deuteranopia".to_string(),
+            theme(
+                [
+                    "#FFFFFF", "#0072B2", "#56B4E9", "#000000", "#E69F00", "#E69F00",
+                ],
+                "PIPE",
+            ),
+        ),
+        // Pure black and white with block cursors
+        (
+            "high-contrast".to_string(),
+            theme(
+                ["Black", "White", "White", "Black", "Black", "White"],
+                "BLOCK",
+            ),
+        ),
+    ])
+}
+
+/// Keys every `[theme.xyz]` section must define
+const REQUIRED_THEME_KEYS: [&str; 4] = ["input_text", "input_bg", "output_text", "output_bg"];
+
+/// Keys a `[theme.xyz]` section may define besides the required ones
+const OPTIONAL_THEME_KEYS: [&str; 6] = [
+    "input_cursor_prefix",
+    "input_cursor_color",
+    "input_cursor",
+    "output_cursor",
+    "output_cursor_color",
+    "typewriter_delay_ms",
+];
+
+/// Old key names still read as fallbacks, with their current equivalents
+const LEGACY_THEME_KEYS: [(&str, &str); 4] = [
+    ("prompt_text", "input_cursor_prefix"),
+    ("prompt_color", "input_cursor_color"),
+    ("prompt_cursor", "input_cursor"),
+    ("output_color", "output_cursor_color"),
+];
+
+#[derive(Debug, Default)]
+struct ParsedThemes {
+    themes: HashMap<String, ThemeDefinition>,
+    // theme name -> missing required keys
+    incomplete: HashMap<String, Vec<String>>,
+    // "theme.key" for every legacy key in use
+    legacy: Vec<String>,
+    // "line N: ..." for every line that could not be used as written
+    problems: Vec<String>,
+}
+
+/// One legacy key handled by `ThemeSystem::migrate_legacy_keys`
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigratedKey {
+    pub theme: String,
+    pub from: String,
+    pub to: String,
+    /// The current key was already set, so the legacy line was dropped
+    pub dropped: bool,
+}
+
+/// Result of re-reading the theme sections from rush.toml
+#[derive(Debug, Default)]
+pub struct ThemeReload {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub total: usize,
+    /// Set if the active theme vanished from the file and was kept in memory
+    pub kept_current: Option<String>,
 }
 
 #[derive(Debug)]
 pub struct ThemeSystem {
     themes: HashMap<String, ThemeDefinition>,
+    incomplete: HashMap<String, Vec<String>>,
+    problems: Vec<String>,
     current_name: String,
     config_paths: Vec<std::path::PathBuf>,
 }
@@ -27,32 +161,90 @@ pub struct ThemeSystem {
 impl ThemeSystem {
     pub fn load() -> Result<Self> {
         let config_paths = crate::setup::setup_toml::get_config_paths();
-        let themes = Self::load_themes_from_paths(&config_paths)?;
-        let current_name = Self::load_current_theme_name(&config_paths).unwrap_or_else(|| {
-            themes
-                .keys()
-                .next()
-                .cloned()
-                .unwrap_or_else(|| "default".to_string())
-        });
+        let ParsedThemes {
+            themes,
+            incomplete,
+            legacy,
+            problems,
+        } = Self::load_themes_from_paths(&config_paths)?;
+        let current_name =
+            Self::resolve_current_name(&themes, Self::load_current_theme_name(&config_paths));
 
         log::info!(
             "{} themes loaded: {}",
             themes.len(),
             themes.keys().cloned().collect::<Vec<_>>().join(", ")
         );
+        for problem in &problems {
+            log::warn!("rush.toml theme {}", problem);
+        }
+        if !legacy.is_empty() {
+            log::warn!(
+                "Legacy theme keys in use ({}), run 'theme migrate' to rename them",
+                legacy.join(", ")
+            );
+        }
 
         Ok(Self {
             themes,
+            incomplete,
+            problems,
             current_name,
             config_paths,
         })
     }
 
-    pub fn show_status(&self) -> String {
-        if self.themes.is_empty() {
-            return "No themes available! Add [theme.xyz] sections to rush.toml.".to_string();
+    /// Re-reads all `[theme.*]` sections and swaps the in-memory registry.
+    /// The active theme is kept (with its old definition) if it was removed.
+    pub fn reload(&mut self) -> Result<ThemeReload> {
+        let ParsedThemes {
+            mut themes,
+            incomplete,
+            problems,
+            ..
+        } = Self::load_themes_from_paths(&self.config_paths)?;
+
+        let mut added: Vec<String> = themes
+            .keys()
+            .filter(|name| !self.themes.contains_key(*name))
+            .cloned()
+            .collect();
+        let mut removed: Vec<String> = self
+            .themes
+            .keys()
+            .filter(|name| !themes.contains_key(*name))
+            .cloned()
+            .collect();
+        added.sort();
+        removed.sort();
+
+        let mut kept_current = None;
+        if !themes.contains_key(&self.current_name) {
+            if let Some(current) = self.themes.get(&self.current_name) {
+                log::warn!(
+                    "Active theme '{}' no longer in rush.toml, keeping it in memory",
+                    self.current_name
+                );
+                themes.insert(self.current_name.clone(), current.clone());
+                removed.retain(|name| name != &self.current_name);
+                kept_current = Some(self.current_name.clone());
+            }
         }
+
+        let total = themes.len();
+        self.themes = themes;
+        self.incomplete = incomplete;
+        self.problems = problems;
+
+        Ok(ThemeReload {
+            added,
+            removed,
+            total,
+            kept_current,
+        })
+    }
+
+    pub fn show_status(&self) -> String {
         format!(
             "Current theme: {} (from TOML)\nAvailable: {}",
             self.current_name.to_uppercase(),
@@ -64,15 +256,11 @@ impl ThemeSystem {
         let theme_name_lower = theme_name.to_lowercase();
 
         if !self.themes.contains_key(&theme_name_lower) {
-            return Ok(if self.themes.is_empty() {
-                "No themes available! Add [theme.xyz] sections to rush.toml.".to_string()
-            } else {
-                format!(
-                    "Theme '{}' not found. Available: {}",
-                    theme_name,
-                    self.themes.keys().cloned().collect::<Vec<_>>().join(", ")
-                )
-            });
+            return Ok(format!(
+                "Theme '{}' not found. Available: {}",
+                theme_name,
+                self.themes.keys().cloned().collect::<Vec<_>>().join(", ")
+            ));
         }
 
         self.current_name = theme_name_lower.clone();
@@ -151,46 +339,110 @@ impl ThemeSystem {
         &self.current_name
     }
 
-    fn load_themes_from_paths(
-        config_paths: &[std::path::PathBuf],
-    ) -> Result<HashMap<String, ThemeDefinition>> {
-        for path in config_paths {
-            if path.exists() {
-                if let Ok(content) = std::fs::read_to_string(path) {
-                    if let Ok(themes) = Self::parse_themes_from_toml(&content) {
-                        return Ok(themes);
-                    }
-                }
-            }
+    /// The theme after the current one in sorted order, wrapping around
+    pub fn next_name(&self) -> String {
+        let names = self.get_available_names();
+        let next = names
+            .iter()
+            .position(|name| *name == self.current_name)
+            .map_or(0, |pos| (pos + 1) % names.len());
+        names[next].clone()
+    }
+
+    /// A theme other than the current one picked by `seed`; the current one
+    /// only if it is the only theme
+    pub fn random_name(&self, seed: u64) -> String {
+        let others: Vec<String> = self
+            .get_available_names()
+            .into_iter()
+            .filter(|name| *name != self.current_name)
+            .collect();
+        if others.is_empty() {
+            return self.current_name.clone();
         }
-        Ok(HashMap::new())
+        others[(seed % others.len() as u64) as usize].clone()
     }
 
-    fn parse_themes_from_toml(content: &str) -> Result<HashMap<String, ThemeDefinition>> {
-        let mut themes = HashMap::new();
-        let mut current_theme: Option<String> = None;
+    /// Missing required keys of a theme that was skipped while loading
+    pub fn get_missing_keys(&self, theme_name: &str) -> Option<&[String]> {
+        self.incomplete
+            .get(&theme_name.to_lowercase())
+            .map(|keys| keys.as_slice())
+    }
+
+    /// Line-level problems found in the `[theme.*]` sections, e.g.
+    /// `line 12: unterminated quote in input_bg`
+    pub fn parse_problems(&self) -> &[String] {
+        &self.problems
+    }
+
+    /// Built-in themes, overlaid by the `[theme.*]` sections of the first readable config
+    fn load_themes_from_paths(config_paths: &[std::path::PathBuf]) -> Result<ParsedThemes> {
+        let parsed = config_paths
+            .iter()
+            .filter(|path| path.exists())
+            .filter_map(|path| std::fs::read_to_string(path).ok())
+            .find_map(|content| Self::parse_themes_from_toml(&content).ok())
+            .unwrap_or_default();
+
+        let mut themes = builtin_themes();
+        themes.extend(parsed.themes);
+        Ok(ParsedThemes {
+            themes,
+            incomplete: parsed.incomplete,
+            legacy: parsed.legacy,
+            problems: parsed.problems,
+        })
+    }
+
+    fn parse_themes_from_toml(content: &str) -> Result<ParsedThemes> {
+        let mut themes = ParsedThemes::default();
+        // (name, header line) of the section being read
+        let mut current_theme: Option<(String, usize)> = None;
         let mut current_data = HashMap::new();
 
-        for line in content.lines() {
+        for (index, line) in content.lines().enumerate() {
+            let line_no = index + 1;
             let trimmed = line.trim();
             if trimmed.is_empty() || trimmed.starts_with('#') {
                 continue;
             }
 
-            if let Some(theme_name) = trimmed
-                .strip_prefix("[theme.")
-                .and_then(|s| s.strip_suffix(']'))
-            {
+            if trimmed.starts_with("[theme.") {
                 Self::finalize_theme(&mut themes, current_theme.take(), &mut current_data);
-                current_theme = Some(theme_name.to_lowercase());
-            } else if trimmed.starts_with('[') && !trimmed.starts_with("[theme.") {
+                match trimmed
+                    .strip_prefix("[theme.")
+                    .and_then(|s| s.strip_suffix(']'))
+                {
+                    Some(name) if !name.trim().is_empty() => {
+                        current_theme = Some((name.trim().to_lowercase(), line_no));
+                    }
+                    Some(_) => themes
+                        .problems
+                        .push(format!("line {}: theme section without a name", line_no)),
+                    None => themes.problems.push(format!(
+                        "line {}: unterminated section header '{}'",
+                        line_no, trimmed
+                    )),
+                }
+            } else if trimmed.starts_with('[') {
                 Self::finalize_theme(&mut themes, current_theme.take(), &mut current_data);
-            } else if current_theme.is_some() && trimmed.contains('=') {
-                if let Some((key, value)) = trimmed.split_once('=') {
-                    let clean_value = value.trim().trim_matches('"').trim_matches('\'');
-                    if !clean_value.is_empty() {
-                        current_data.insert(key.trim().to_string(), clean_value.to_string());
+            } else if current_theme.is_some() {
+                match Self::parse_theme_line(trimmed) {
+                    Ok((key, raw)) => {
+                        if let Some(problem) = Self::check_theme_value(&key, &raw) {
+                            themes
+                                .problems
+                                .push(format!("line {}: {}", line_no, problem));
+                        }
+                        let value = raw.trim_matches('"').trim_matches('\'');
+                        if !value.is_empty() {
+                            current_data.insert(key, value.to_string());
+                        }
                     }
+                    Err(problem) => themes
+                        .problems
+                        .push(format!("line {}: {}", line_no, problem)),
                 }
             }
         }
@@ -198,25 +450,111 @@ impl ThemeSystem {
         Ok(themes)
     }
 
+    /// Splits `key = value` into the key and the still-quoted value
+    fn parse_theme_line(line: &str) -> std::result::Result<(String, String), String> {
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("expected 'key = value', found '{}'", line))?;
+        let key = key.trim();
+        if key.is_empty() {
+            return Err("missing key before '='".to_string());
+        }
+        Ok((
+            key.to_string(),
+            Self::strip_comment(value).trim().to_string(),
+        ))
+    }
+
+    /// `value` without a trailing `# comment`; a `#` inside quotes is kept
+    fn strip_comment(value: &str) -> &str {
+        let mut quote = None;
+        for (i, c) in value.char_indices() {
+            match (quote, c) {
+                (None, '"' | '\'') => quote = Some(c),
+                (Some(open), _) if c == open => quote = None,
+                (None, '#') => return &value[..i],
+                _ => {}
+            }
+        }
+        value
+    }
+
+    /// What is wrong with a raw `key = value` pair, if anything.
+    /// The value is still used, so a stray quote does not drop the theme.
+    fn check_theme_value(key: &str, raw: &str) -> Option<String> {
+        let unterminated = ['"', '\''].iter().any(|quote| {
+            let starts = raw.starts_with(*quote);
+            let ends = raw.len() > 1 && raw.ends_with(*quote);
+            starts != ends
+        });
+        let known = REQUIRED_THEME_KEYS.contains(&key)
+            || OPTIONAL_THEME_KEYS.contains(&key)
+            || LEGACY_THEME_KEYS.iter().any(|(legacy, _)| *legacy == key);
+        let value = raw.trim_matches('"').trim_matches('\'');
+
+        if unterminated {
+            Some(format!("unterminated quote in {}", key))
+        } else if !known {
+            Some(format!("unknown key '{}'", key))
+        } else if value.is_empty() {
+            Some(format!("empty value for {}", key))
+        } else if key == "typewriter_delay_ms" && value.parse::<u64>().is_err() {
+            Some(format!(
+                "typewriter_delay_ms must be a number, found '{}'",
+                value
+            ))
+        } else {
+            None
+        }
+    }
+
     fn finalize_theme(
-        themes: &mut HashMap<String, ThemeDefinition>,
-        theme_name: Option<String>,
+        themes: &mut ParsedThemes,
+        theme: Option<(String, usize)>,
         data: &mut HashMap<String, String>,
     ) {
-        if let Some(name) = theme_name {
-            if let Some(theme_def) = Self::build_theme_from_data(data) {
-                themes.insert(name, theme_def);
+        if let Some((name, line_no)) = theme {
+            for (legacy, _) in LEGACY_THEME_KEYS {
+                if data.contains_key(legacy) {
+                    themes.legacy.push(format!("{}.{}", name, legacy));
+                }
+            }
+            match Self::build_theme_from_data(data) {
+                Ok(theme_def) => {
+                    themes.themes.insert(name, theme_def);
+                }
+                Err(missing) => {
+                    themes.problems.push(format!(
+                        "line {}: theme '{}' skipped, missing {}",
+                        line_no,
+                        name,
+                        missing.join(", ")
+                    ));
+                    themes.incomplete.insert(name, missing);
+                }
             }
             data.clear();
         }
     }
 
-    fn build_theme_from_data(data: &HashMap<String, String>) -> Option<ThemeDefinition> {
-        Some(ThemeDefinition {
-            input_text: data.get("input_text")?.clone(),
-            input_bg: data.get("input_bg")?.clone(),
-            output_text: data.get("output_text")?.clone(),
-            output_bg: data.get("output_bg")?.clone(),
+    /// Builds a theme, or returns the names of the missing required keys
+    fn build_theme_from_data(
+        data: &HashMap<String, String>,
+    ) -> std::result::Result<ThemeDefinition, Vec<String>> {
+        let missing: Vec<String> = REQUIRED_THEME_KEYS
+            .iter()
+            .filter(|key| !data.contains_key(**key))
+            .map(|key| key.to_string())
+            .collect();
+        if !missing.is_empty() {
+            return Err(missing);
+        }
+
+        Ok(ThemeDefinition {
+            input_text: data["input_text"].clone(),
+            input_bg: data["input_bg"].clone(),
+            output_text: data["output_text"].clone(),
+            output_bg: data["output_bg"].clone(),
             input_cursor_prefix: data
                 .get("input_cursor_prefix")
                 .or(data.get("prompt_text"))
@@ -241,9 +579,45 @@ impl ThemeSystem {
                 .or(data.get("output_color"))
                 .unwrap_or(&"White".to_string())
                 .clone(),
+            typewriter_delay_ms: data
+                .get("typewriter_delay_ms")
+                .and_then(|v| v.parse::<u64>().ok())
+                .map(|ms| ms.min(2000)),
         })
     }
 
+    /// Configured `current_theme` if it names a loaded theme, otherwise "default"
+    fn resolve_current_name(
+        themes: &HashMap<String, ThemeDefinition>,
+        configured: Option<String>,
+    ) -> String {
+        let Some(name) = configured else {
+            return "default".to_string();
+        };
+        let lower = name.to_lowercase();
+        if themes.contains_key(&lower) {
+            return lower;
+        }
+
+        let mut available: Vec<_> = themes.keys().cloned().collect();
+        available.sort();
+        let fallback = if themes.contains_key("default") {
+            "default".to_string()
+        } else {
+            available
+                .first()
+                .cloned()
+                .unwrap_or_else(|| "default".to_string())
+        };
+        log::warn!(
+            "current_theme '{}' does not exist, falling back to '{}' (available: {})",
+            name,
+            fallback,
+            available.join(", ")
+        );
+        fallback
+    }
+
     fn load_current_theme_name(config_paths: &[std::path::PathBuf]) -> Option<String> {
         for path in config_paths {
             if path.exists() {
@@ -298,6 +672,228 @@ impl ThemeSystem {
         Err(AppError::Validation("No config file found".to_string()))
     }
 
+    /// Renames legacy keys in all `[theme.*]` sections, line by line so
+    /// comments and layout survive. A legacy key whose current name is also
+    /// set is dropped (the current one already wins on load).
+    pub fn migrate_legacy_keys(content: &str) -> (String, Vec<MigratedKey>) {
+        let section_of = |trimmed: &str| -> Option<Option<String>> {
+            trimmed.starts_with('[').then(|| {
+                trimmed
+                    .strip_prefix("[theme.")
+                    .and_then(|s| s.strip_suffix(']'))
+                    .map(|name| name.to_string())
+            })
+        };
+        let key_of = |trimmed: &str| -> Option<String> {
+            if trimmed.starts_with('#') {
+                return None;
+            }
+            trimmed
+                .split_once('=')
+                .map(|(key, _)| key.trim().to_string())
+        };
+
+        // Keys present per theme section, to detect legacy/current duplicates
+        let mut present: HashMap<String, Vec<String>> = HashMap::new();
+        let mut theme: Option<String> = None;
+        for line in content.lines() {
+            let trimmed = line.trim();
+            if let Some(section) = section_of(trimmed) {
+                theme = section;
+            } else if let (Some(name), Some(key)) = (&theme, key_of(trimmed)) {
+                present.entry(name.clone()).or_default().push(key);
+            }
+        }
+
+        let mut migrated = Vec::new();
+        let mut lines = Vec::new();
+        theme = None;
+        for line in content.lines() {
+            let trimmed = line.trim();
+            if let Some(section) = section_of(trimmed) {
+                theme = section;
+            } else if let (Some(name), Some(key)) = (&theme, key_of(trimmed)) {
+                if let Some((from, to)) = LEGACY_THEME_KEYS.iter().find(|(from, _)| *from == key) {
+                    let dropped = present[name].iter().any(|k| k == to);
+                    migrated.push(MigratedKey {
+                        theme: name.clone(),
+                        from: from.to_string(),
+                        to: to.to_string(),
+                        dropped,
+                    });
+                    if !dropped {
+                        let start = line.find(from).unwrap_or(0);
+                        lines.push(format!(
+                            "{}{}{}",
+                            &line[..start],
+                            to,
+                            &line[start + from.len()..]
+                        ));
+                    }
+                    continue;
+                }
+            }
+            lines.push(line.to_string());
+        }
+
+        let mut updated = lines.join("\n");
+        if content.ends_with('\n') {
+            updated.push('\n');
+        }
+        (updated, migrated)
+    }
+
+    /// Reads `theme create` flags: `--<key> <value>` for every theme key
+    /// (`-` and `_` both work) and `--force`
+    pub fn parse_create_flags(args: &[&str]) -> Result<(HashMap<String, String>, bool)> {
+        let mut fields = HashMap::new();
+        let mut force = false;
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            if *arg == "--force" {
+                force = true;
+                continue;
+            }
+            let key = arg
+                .strip_prefix("--")
+                .ok_or_else(|| {
+                    AppError::Validation(format!("expected '--<key> <value>', found '{}'", arg))
+                })?
+                .replace('-', "_");
+            let value = iter
+                .next()
+                .ok_or_else(|| AppError::Validation(format!("--{} needs a value", key)))?;
+            fields.insert(key, value.to_string());
+        }
+        Ok((fields, force))
+    }
+
+    /// Validates the fields of `theme create` and renders the
+    /// `[theme.<name>]` block, keys in the documented order
+    pub fn render_theme_section(name: &str, fields: &HashMap<String, String>) -> Result<String> {
+        let valid_name = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+        if !valid_name {
+            return Err(AppError::Validation(format!(
+                "Invalid theme name '{}': use a-z, 0-9, '-' and '_'",
+                name
+            )));
+        }
+
+        let known: Vec<&str> = REQUIRED_THEME_KEYS
+            .iter()
+            .chain(OPTIONAL_THEME_KEYS.iter())
+            .copied()
+            .collect();
+        if let Some(unknown) = fields.keys().find(|key| !known.contains(&key.as_str())) {
+            return Err(AppError::Validation(format!(
+                "Unknown theme key '{}'. Keys: {}",
+                unknown,
+                known.join(", ")
+            )));
+        }
+        let missing: Vec<&str> = REQUIRED_THEME_KEYS
+            .iter()
+            .filter(|key| !fields.contains_key(**key))
+            .copied()
+            .collect();
+        if !missing.is_empty() {
+            return Err(AppError::Validation(format!(
+                "Missing required theme keys: {}",
+                missing.join(", ")
+            )));
+        }
+
+        let mut lines = vec![format!("[theme.{}]", name)];
+        for key in known {
+            let Some(value) = fields.get(key) else {
+                continue;
+            };
+            match key {
+                "typewriter_delay_ms" => {
+                    let delay = value.parse::<u64>().map_err(|_| {
+                        AppError::Validation(format!(
+                            "typewriter_delay_ms must be a number, found '{}'",
+                            value
+                        ))
+                    })?;
+                    lines.push(format!("{} = {}", key, delay));
+                    continue;
+                }
+                "input_cursor" | "output_cursor" => {
+                    if !["BLOCK", "PIPE", "UNDERSCORE"].contains(&value.to_uppercase().as_str()) {
+                        return Err(AppError::Validation(format!(
+                            "{} must be BLOCK, PIPE or UNDERSCORE, found '{}'",
+                            key, value
+                        )));
+                    }
+                }
+                "input_cursor_prefix" => {}
+                _ => {
+                    crate::ui::color::AppColor::from_string(value).map_err(|_| {
+                        AppError::Validation(format!("{}: invalid color '{}'", key, value))
+                    })?;
+                }
+            }
+            let quoted = toml::Value::String(value.to_string()).to_string();
+            lines.push(format!("{} = {}", key, quoted));
+        }
+        let section = lines.join("\n");
+
+        // rush.toml is loaded as a whole, so a block it can't parse must not be written
+        toml::from_str::<toml::Table>(&section).map_err(|e| {
+            AppError::Validation(format!("theme '{}' renders invalid TOML: {}", name, e))
+        })?;
+        Ok(section)
+    }
+
+    /// Puts a `[theme.<name>]` block into `content`: appended at the end, or
+    /// in place of the existing block when `replace` is set. `None` if the
+    /// block exists and `replace` is not set.
+    pub fn upsert_theme_section(
+        content: &str,
+        name: &str,
+        section: &str,
+        replace: bool,
+    ) -> Option<String> {
+        let header = format!("[theme.{}]", name);
+        let lines: Vec<&str> = content.lines().collect();
+        let Some(start) = lines
+            .iter()
+            .position(|line| line.trim().eq_ignore_ascii_case(&header))
+        else {
+            let mut updated = content.trim_end().to_string();
+            if !updated.is_empty() {
+                updated.push_str("\n\n");
+            }
+            updated.push_str(section);
+            updated.push('\n');
+            return Some(updated);
+        };
+        if !replace {
+            return None;
+        }
+
+        // The old block ends at the next section; blank lines before it stay
+        let end = lines[start + 1..]
+            .iter()
+            .position(|line| line.trim().starts_with('['))
+            .map_or(lines.len(), |offset| start + 1 + offset);
+        let mut body_end = end;
+        while body_end > start + 1 && lines[body_end - 1].trim().is_empty() {
+            body_end -= 1;
+        }
+
+        let mut updated: Vec<&str> = lines[..start].to_vec();
+        updated.extend(section.lines());
+        updated.extend(&lines[body_end..]);
+        let mut updated = updated.join("\n");
+        updated.push('\n');
+        Some(updated)
+    }
+
     fn update_current_theme_in_toml(content: &str, theme_name: &str) -> Result<String> {
         let mut lines: Vec<String> = content.lines().map(|l| l.to_string()).collect();
         let mut in_general = false;
@@ -330,3 +926,370 @@ impl ThemeSystem {
         Ok(lines.join("\n"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THEMES: &str = r#"
+[theme.fast]
+input_text = "Black"
+input_bg = "White"
+output_text = "White"
+output_bg = "Black"
+typewriter_delay_ms = 1
+
+[theme.plain]
+input_text = "Black"
+input_bg = "White"
+output_text = "White"
+output_bg = "Black"
+"#;
+
+    #[test]
+    fn test_render_theme_section_validates_fields() {
+        let (fields, force) = ThemeSystem::parse_create_flags(&[
+            "--input-text",
+            "Black",
+            "--input_bg",
+            "#FFEEDD",
+            "--output_text",
+            "White",
+            "--output_bg",
+            "Black",
+            "--input_cursor",
+            "block",
+            "--typewriter_delay_ms",
+            "5",
+            "--force",
+        ])
+        .unwrap();
+        assert!(force);
+
+        let section = ThemeSystem::render_theme_section("ocean", &fields).unwrap();
+        assert_eq!(
+            section,
+            "[theme.ocean]\ninput_text = \"Black\"\ninput_bg = \"#FFEEDD\"\n\
+             output_text = \"White\"\noutput_bg = \"Black\"\n\
+             input_cursor = \"block\"\ntypewriter_delay_ms = 5"
+        );
+        let parsed = ThemeSystem::parse_themes_from_toml(&section).unwrap();
+        assert!(parsed.problems.is_empty());
+        assert!(parsed.themes.contains_key("ocean"));
+
+        let mut bad = fields.clone();
+        bad.insert("output_bg".into(), "Blurple".into());
+        assert!(ThemeSystem::render_theme_section("ocean", &bad).is_err());
+        bad.remove("output_bg");
+        assert!(ThemeSystem::render_theme_section("ocean", &bad).is_err());
+        assert!(ThemeSystem::render_theme_section("Ocean Blue", &fields).is_err());
+        assert!(ThemeSystem::parse_create_flags(&["--input_text"]).is_err());
+    }
+
+    #[test]
+    fn test_render_theme_section_escapes_prefix() {
+        let (fields, _) = ThemeSystem::parse_create_flags(&[
+            "--input_text",
+            "Black",
+            "--input_bg",
+            "White",
+            "--output_text",
+            "White",
+            "--output_bg",
+            "Black",
+            "--input_cursor_prefix",
+            "C:\\> \"",
+        ])
+        .unwrap();
+
+        let section = ThemeSystem::render_theme_section("dos", &fields).unwrap();
+        let table: toml::Table = toml::from_str(&section).unwrap();
+        assert_eq!(
+            table["theme"]["dos"]["input_cursor_prefix"].as_str(),
+            Some("C:\\> \"")
+        );
+        let parsed = ThemeSystem::parse_themes_from_toml(&section).unwrap();
+        assert!(parsed.problems.is_empty());
+        assert_eq!(parsed.themes["dos"].input_cursor_prefix, "C:\\> \"");
+    }
+
+    #[test]
+    fn test_export_round_trips() {
+        let themes = ThemeSystem::parse_themes_from_toml(THEMES).unwrap().themes;
+        for name in ["fast", "plain"] {
+            let section = themes[name].to_toml_section(name);
+            let parsed = ThemeSystem::parse_themes_from_toml(&section).unwrap();
+            assert!(parsed.problems.is_empty());
+            assert_eq!(parsed.themes[name], themes[name]);
+        }
+
+        let builtin = &builtin_themes()["deuteranopia"];
+        let section = builtin.to_toml_section("deuteranopia");
+        assert!(section.contains("input_cursor_prefix = \"/// \""));
+        let parsed = ThemeSystem::parse_themes_from_toml(&section).unwrap();
+        assert_eq!(&parsed.themes["deuteranopia"], builtin);
+
+        // Quotes and backslashes in a prompt prefix are escaped
+        let quoted = ThemeDefinition {
+            input_cursor_prefix: "C:\\> \"".to_string(),
+            ..builtin.clone()
+        };
+        let section = quoted.to_toml_section("quoted");
+        let parsed = ThemeSystem::parse_themes_from_toml(&section).unwrap();
+        assert!(parsed.problems.is_empty());
+        assert_eq!(parsed.themes["quoted"], quoted);
+    }
+
+    #[test]
+    fn test_next_and_random_name() {
+        let mut system = ThemeSystem {
+            themes: builtin_themes(),
+            incomplete: HashMap::new(),
+            problems: Vec::new(),
+            current_name: "default".to_string(),
+            config_paths: Vec::new(),
+        };
+        // default, deuteranopia, high-contrast
+        assert_eq!(system.next_name(), "deuteranopia");
+        system.current_name = "high-contrast".to_string();
+        assert_eq!(system.next_name(), "default");
+
+        for seed in 0..6 {
+            assert_ne!(system.random_name(seed), "high-contrast");
+        }
+        assert_ne!(system.random_name(0), system.random_name(1));
+
+        system.themes.retain(|name, _| name == "high-contrast");
+        assert_eq!(system.random_name(3), "high-contrast");
+    }
+
+    #[test]
+    fn test_upsert_theme_section() {
+        let section = "[theme.fast]\ninput_text = \"Red\"";
+        assert_eq!(
+            ThemeSystem::upsert_theme_section(THEMES, "fast", section, false),
+            None
+        );
+
+        let replaced = ThemeSystem::upsert_theme_section(THEMES, "fast", section, true).unwrap();
+        assert!(replaced.contains("[theme.fast]\ninput_text = \"Red\"\n\n[theme.plain]"));
+        assert!(!replaced.contains("typewriter_delay_ms"));
+
+        let added = ThemeSystem::upsert_theme_section(THEMES, "new", "[theme.new]", false).unwrap();
+        assert!(added.ends_with("output_bg = \"Black\"\n\n[theme.new]\n"));
+    }
+
+    #[test]
+    fn test_typewriter_delay_is_optional_per_theme() {
+        let themes = ThemeSystem::parse_themes_from_toml(THEMES).unwrap().themes;
+        assert_eq!(themes["fast"].typewriter_delay_ms, Some(1));
+        assert_eq!(themes["plain"].typewriter_delay_ms, None);
+    }
+
+    #[test]
+    fn test_typewriter_delay_is_clamped() {
+        let toml = THEMES.replace("typewriter_delay_ms = 1", "typewriter_delay_ms = 99999");
+        let themes = ThemeSystem::parse_themes_from_toml(&toml).unwrap().themes;
+        assert_eq!(themes["fast"].typewriter_delay_ms, Some(2000));
+    }
+
+    #[test]
+    fn test_incomplete_theme_reports_missing_keys() {
+        let toml = format!(
+            "{}\n[theme.broken]\ninput_text = \"Black\"\noutput_bg = \"Black\"\n",
+            THEMES
+        );
+        let parsed = ThemeSystem::parse_themes_from_toml(&toml).unwrap();
+
+        assert!(!parsed.themes.contains_key("broken"));
+        assert_eq!(parsed.incomplete["broken"], vec!["input_bg", "output_text"]);
+        assert_eq!(parsed.themes.len(), 2);
+    }
+
+    #[test]
+    fn test_reload_keeps_removed_active_theme() {
+        let dir = std::env::temp_dir().join(format!("rush-theme-reload-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("rush.toml");
+        std::fs::write(&path, THEMES).unwrap();
+
+        let parsed = ThemeSystem::load_themes_from_paths(std::slice::from_ref(&path)).unwrap();
+        let mut system = ThemeSystem {
+            themes: parsed.themes,
+            incomplete: parsed.incomplete,
+            problems: parsed.problems,
+            current_name: "fast".to_string(),
+            config_paths: vec![path.clone()],
+        };
+
+        let toml = THEMES.replace("[theme.fast]", "[theme.fresh]");
+        std::fs::write(&path, toml).unwrap();
+        let reload = system.reload().unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(reload.added, vec!["fresh"]);
+        assert!(reload.removed.is_empty());
+        assert_eq!(reload.kept_current.as_deref(), Some("fast"));
+        assert_eq!(reload.total, 3 + builtin_themes().len());
+        assert!(system.theme_exists("fast"));
+    }
+
+    #[test]
+    fn test_builtin_themes_without_toml() {
+        let parsed = ThemeSystem::load_themes_from_paths(&[]).unwrap();
+        assert!(parsed.themes.contains_key("default"));
+        assert!(parsed.themes.contains_key("high-contrast"));
+        assert!(parsed.themes.contains_key("deuteranopia"));
+
+        let default =
+            crate::core::config::Theme::from_definition(&parsed.themes["default"]).unwrap();
+        let fallback = crate::core::config::Theme::default();
+        assert_eq!(default.input_cursor_color, fallback.input_cursor_color);
+        assert_eq!(default.output_cursor_color, fallback.output_cursor_color);
+        assert_eq!(default.output_bg, fallback.output_bg);
+
+        for (name, def) in builtin_themes() {
+            assert!(
+                crate::core::config::Theme::from_definition(&def).is_ok(),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_toml_theme_overrides_builtin() {
+        let dir = std::env::temp_dir().join(format!("rush-theme-builtin-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("rush.toml");
+        std::fs::write(
+            &path,
+            THEMES.replace("[theme.plain]", "[theme.high-contrast]"),
+        )
+        .unwrap();
+
+        let parsed = ThemeSystem::load_themes_from_paths(std::slice::from_ref(&path)).unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(parsed.themes["high-contrast"].input_cursor, "PIPE");
+        assert_eq!(parsed.themes["deuteranopia"].input_bg, "#0072B2");
+    }
+
+    #[test]
+    fn test_parse_problems_have_line_numbers() {
+        let toml = "[general]\ncurrent_theme = \"a\"\n\n[theme.a]\ninput_text = \"White\"\n\
+                    input_bg = \"Black\nouput_text = \"White\"\noutput_bg\n\n[theme.b\n";
+        let parsed = ThemeSystem::parse_themes_from_toml(toml).unwrap();
+
+        assert_eq!(
+            parsed.problems,
+            vec![
+                "line 6: unterminated quote in input_bg",
+                "line 7: unknown key 'ouput_text'",
+                "line 8: expected 'key = value', found 'output_bg'",
+                "line 4: theme 'a' skipped, missing output_text, output_bg",
+                "line 10: unterminated section header '[theme.b'",
+            ]
+        );
+        assert!(parsed.incomplete.contains_key("a"));
+        assert!(ThemeSystem::parse_themes_from_toml(THEMES)
+            .unwrap()
+            .problems
+            .is_empty());
+    }
+
+    #[test]
+    fn test_invalid_current_theme_falls_back() {
+        let themes = builtin_themes();
+        assert_eq!(
+            ThemeSystem::resolve_current_name(&themes, Some("High-Contrast".into())),
+            "high-contrast"
+        );
+        assert_eq!(
+            ThemeSystem::resolve_current_name(&themes, Some("hihg-contrast".into())),
+            "default"
+        );
+        assert_eq!(ThemeSystem::resolve_current_name(&themes, None), "default");
+    }
+
+    #[test]
+    fn test_live_preview_signal_does_not_change_current() {
+        let system = ThemeSystem {
+            themes: builtin_themes(),
+            incomplete: HashMap::new(),
+            problems: Vec::new(),
+            current_name: "default".to_string(),
+            config_paths: Vec::new(),
+        };
+
+        let signal = system.preview_theme_live_i18n("High-Contrast").unwrap();
+        assert!(signal.starts_with("__THEME_PREVIEW__high-contrast__MESSAGE__"));
+        assert_eq!(system.current_name, "default");
+        assert!(!system
+            .preview_theme_live_i18n("missing")
+            .unwrap()
+            .starts_with("__THEME_PREVIEW__"));
+    }
+
+    #[test]
+    fn test_quoted_value_with_comment() {
+        let toml = format!(
+            "{}\n[theme.commented]\ninput_text = \"Black\"  # ink\ninput_bg = \"#FFFFFF\"\n\
+             output_text = 'White' # paper\noutput_bg = \"Black\"\n\
+             input_cursor_prefix = \">> \"   # arrow\n",
+            THEMES
+        );
+        let parsed = ThemeSystem::parse_themes_from_toml(&toml).unwrap();
+
+        assert!(parsed.problems.is_empty(), "{:?}", parsed.problems);
+        let theme = &parsed.themes["commented"];
+        assert_eq!(theme.input_text, "Black");
+        assert_eq!(theme.input_bg, "#FFFFFF");
+        assert_eq!(theme.output_text, "White");
+        assert_eq!(theme.input_cursor_prefix, ">> ");
+    }
+
+    #[test]
+    fn test_migrate_legacy_keys() {
+        let toml = r#"# my themes
+[general]
+prompt_text = "kept outside themes"
+
+[theme.old]
+input_text = "Black"
+  prompt_text = ">> "   # arrow
+prompt_color = "Cyan"
+input_cursor_color = "Red"
+output_color = "Green"
+
+[theme.fast]
+input_text = "Black"
+"#;
+        let (updated, migrated) = ThemeSystem::migrate_legacy_keys(toml);
+
+        assert!(updated.starts_with("# my themes\n[general]\nprompt_text = \"kept"));
+        assert!(updated.contains("  input_cursor_prefix = \">> \"   # arrow\n"));
+        assert!(!updated.contains("prompt_color"));
+        assert!(updated.contains("output_cursor_color = \"Green\""));
+        assert!(updated.ends_with("[theme.fast]\ninput_text = \"Black\"\n"));
+
+        let summary: Vec<(&str, bool)> = migrated
+            .iter()
+            .map(|m| (m.from.as_str(), m.dropped))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("prompt_text", false),
+                ("prompt_color", true),
+                ("output_color", false)
+            ]
+        );
+        assert!(migrated.iter().all(|m| m.theme == "old"));
+
+        // Already up to date: nothing changes
+        let (again, none) = ThemeSystem::migrate_legacy_keys(&updated);
+        assert!(none.is_empty());
+        assert_eq!(again, updated);
+    }
+}
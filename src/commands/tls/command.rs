@@ -0,0 +1,313 @@
+use crate::commands::command::Command;
+use crate::core::prelude::*;
+
+#[derive(Debug, Default)]
+pub struct TlsCommand;
+
+impl TlsCommand {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Command for TlsCommand {
+    fn name(&self) -> &'static str {
+        "tls"
+    }
+
+    fn description(&self) -> &'static str {
+        "Manage TLS certificates (Let's Encrypt provisioning)"
+    }
+
+    fn matches(&self, command: &str) -> bool {
+        command.trim().to_lowercase().starts_with("tls")
+    }
+
+    fn execute_sync(&self, args: &[&str]) -> Result<String> {
+        match args.first().copied() {
+            None | Some("-h" | "--help" | "help") => Ok(self.help_text()),
+            Some("provision") => self.provision(args),
+            Some("renew") => self.renew(args),
+            Some("status") => Ok(render_acme_status(&crate::server::acme::get_acme_status())),
+            Some(sub) => Err(AppError::Validation(format!(
+                "Unknown tls subcommand '{}'. Use 'tls help'.",
+                sub
+            ))),
+        }
+    }
+
+    fn priority(&self) -> u8 {
+        74
+    }
+}
+
+impl TlsCommand {
+    fn provision(&self, args: &[&str]) -> Result<String> {
+        let challenge_only = args.contains(&"--challenge-only");
+        let staging = args.contains(&"--staging");
+        let domain = args
+            .iter()
+            .skip(1)
+            .find(|a| !a.starts_with("--"))
+            .map(|d| d.trim().to_lowercase())
+            .ok_or_else(|| {
+                AppError::Validation(
+                    "Usage: tls provision <domain> [--challenge-only] [--staging]".to_string(),
+                )
+            })?;
+
+        if domain == "localhost" || !domain.contains('.') {
+            return Err(AppError::Validation(format!(
+                "'{}' is not a public domain name",
+                domain
+            )));
+        }
+
+        let config = get_config()?;
+        let cert_dir = crate::core::paths::base_dir()
+            .map(|b| b.join(&config.server.cert_dir))
+            .unwrap_or_else(|_| std::path::PathBuf::from(&config.server.cert_dir));
+        let email = config.server.acme_email.clone();
+        let mode = crate::server::acme::ChallengeMode::from_config(
+            &config.server.acme_challenge,
+            &config.server.acme_dns_hook,
+        );
+        let via = match (&mode, challenge_only) {
+            (crate::server::acme::ChallengeMode::Dns01 { .. }, _) => " (dns-01)",
+            (_, true) => " (challenge-only on port 80)",
+            _ => "",
+        };
+
+        let task_domain = domain.clone();
+        tokio::spawn(async move {
+            let result = if challenge_only {
+                crate::server::acme::provision_challenge_only(
+                    &task_domain,
+                    &cert_dir,
+                    &email,
+                    staging,
+                    &mode,
+                )
+                .await
+                .map(|(cert, key)| {
+                    format!(
+                        "  Certificate for {} written:\n    cert: {}\n    key:  {}",
+                        task_domain,
+                        cert.display(),
+                        key.display()
+                    )
+                })
+            } else {
+                crate::server::acme::provision_certificate(
+                    &task_domain,
+                    &cert_dir,
+                    &email,
+                    staging,
+                    &[],
+                    &mode,
+                )
+                .await
+                .map(|_| {
+                    crate::proxy::handler::reload_proxy_tls(&task_domain);
+                    format!("  Certificate for {} provisioned and loaded", task_domain)
+                })
+            };
+
+            match result {
+                Ok(msg) => {
+                    crate::input::send_progress(msg);
+                }
+                Err(e) => {
+                    log::error!("TLS provisioning for {} failed: {}", task_domain, e);
+                    crate::input::send_progress(format!(
+                        "  Certificate for {} failed: {}",
+                        task_domain, e
+                    ));
+                }
+            }
+        });
+
+        Ok(format!(
+            "  Provisioning certificate for {}{}...",
+            domain, via
+        ))
+    }
+
+    fn renew(&self, args: &[&str]) -> Result<String> {
+        let force = args.contains(&"--force");
+        let staging = args.contains(&"--staging");
+
+        let config = get_config()?;
+        if !config.server.use_lets_encrypt || config.server.production_domain == "localhost" {
+            return Err(AppError::Validation(
+                "ACME is not configured (set server.use_lets_encrypt and server.production_domain)"
+                    .to_string(),
+            ));
+        }
+
+        let domain = config.server.production_domain.clone();
+        let cert_dir = crate::core::paths::base_dir()
+            .map(|b| b.join(&config.server.cert_dir))
+            .unwrap_or_else(|_| std::path::PathBuf::from(&config.server.cert_dir));
+        let email = config.server.acme_email.clone();
+        let mode = crate::server::acme::ChallengeMode::from_config(
+            &config.server.acme_challenge,
+            &config.server.acme_dns_hook,
+        );
+
+        let task_domain = domain.clone();
+        tokio::spawn(async move {
+            let result = crate::server::acme::renew_now(
+                &task_domain,
+                &cert_dir,
+                &email,
+                staging,
+                &mode,
+                force,
+            )
+            .await;
+
+            crate::input::send_progress(match result {
+                Ok(true) => format!("  Certificate for {} renewed and loaded", task_domain),
+                Ok(false) => format!(
+                    "  Certificate for {} is not due for renewal (use --force)",
+                    task_domain
+                ),
+                Err(e) => {
+                    log::error!("TLS renewal for {} failed: {}", task_domain, e);
+                    format!("  Renewal for {} failed: {}", task_domain, e)
+                }
+            });
+        });
+
+        Ok(if force {
+            format!("  Forcing certificate renewal for {}...", domain)
+        } else {
+            format!("  Checking certificate renewal for {}...", domain)
+        })
+    }
+
+    fn help_text(&self) -> String {
+        "  tls status\n  \
+         tls renew [--force] [--staging]\n  \
+         tls provision <domain> [--staging]\n  \
+         tls provision <domain> --challenge-only\n\n  \
+         Flags:\n    \
+         --challenge-only          Serve only ACME challenges on :80, write cert/key, stop\n    \
+         --force                   Renew now, regardless of certificate age\n    \
+         --staging                 Use the Let's Encrypt staging environment\n\n  \
+         Challenge type: server.acme_challenge (http-01 | dns-01), dns-01 uses server.acme_dns_hook"
+            .to_string()
+    }
+}
+
+/// Readable summary of `get_acme_status()`. The state is emitted as a
+/// `[MARKER]` so the output widget colors it (green/yellow/red).
+fn render_acme_status(status: &serde_json::Value) -> String {
+    let text = |value: &serde_json::Value| value.as_str().unwrap_or("-").to_string();
+    let list = |value: &serde_json::Value| {
+        value
+            .as_array()
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|item| item.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .unwrap_or_default()
+    };
+
+    let state = text(&status["status"]);
+    if state == "not_configured" {
+        return "  ACME/Let's Encrypt is not configured \
+                (set server.use_lets_encrypt and server.production_domain)"
+            .to_string();
+    }
+
+    let cert = &status["certificate"];
+    let certificate = match (cert["exists"].as_bool(), cert["age_days"].as_u64()) {
+        (Some(true), Some(age)) => format!(
+            "{} days old, renewal in {} days",
+            age,
+            cert["days_until_renewal"].as_i64().unwrap_or(0)
+        ),
+        (Some(true), None) => "present (age unknown)".to_string(),
+        _ => "not provisioned yet".to_string(),
+    };
+    let sans = match list(&cert["sans"]) {
+        sans if sans.is_empty() => format!("(requested) {}", list(&status["subdomains"])),
+        sans => sans,
+    };
+
+    format!(
+        "  [{}] Let's Encrypt for {}\n    \
+         SANs:          {}\n    \
+         Certificate:   {}\n    \
+         Last attempt:  {} ({} total)\n    \
+         Last success:  {}\n    \
+         Last error:    {}\n    \
+         Next check:    {}",
+        state.to_uppercase(),
+        text(&status["domain"]),
+        sans,
+        certificate,
+        text(&status["last_attempt"]),
+        status["attempt_count"].as_u64().unwrap_or(0),
+        text(&status["last_success"]),
+        status["last_error"].as_str().unwrap_or("none"),
+        text(&status["next_renewal_check"]),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provision_requires_domain() {
+        let cmd = TlsCommand::new();
+        assert!(cmd.execute_sync(&["provision"]).is_err());
+        assert!(cmd
+            .execute_sync(&["provision", "--challenge-only"])
+            .is_err());
+    }
+
+    #[test]
+    fn test_provision_rejects_non_public_domain() {
+        let cmd = TlsCommand::new();
+        assert!(cmd
+            .execute_sync(&["provision", "localhost", "--challenge-only"])
+            .is_err());
+        assert!(cmd.execute_sync(&["provision", "intranet"]).is_err());
+    }
+
+    #[test]
+    fn test_render_acme_status() {
+        let status = serde_json::json!({
+            "status": "success",
+            "domain": "example.com",
+            "subdomains": ["blog"],
+            "certificate": {
+                "exists": true,
+                "age_days": 10,
+                "days_until_renewal": 50,
+                "sans": ["example.com", "www.example.com"],
+            },
+            "last_attempt": "2026-01-01T00:00:00Z",
+            "last_success": "2026-01-01T00:00:00Z",
+            "last_error": null,
+            "attempt_count": 2,
+            "next_renewal_check": "2026-01-02T00:00:00Z",
+        });
+        let out = render_acme_status(&status);
+        assert!(out.starts_with("  [SUCCESS] Let's Encrypt for example.com"));
+        assert!(out.contains("example.com, www.example.com"));
+        assert!(out.contains("10 days old, renewal in 50 days"));
+        assert!(out.contains("Last error:    none"));
+        assert!(out.contains("Next check:    2026-01-02T00:00:00Z"));
+
+        let missing = render_acme_status(&serde_json::json!({"status": "not_configured"}));
+        assert!(missing.contains("not configured"));
+    }
+}
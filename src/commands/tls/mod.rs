@@ -0,0 +1,3 @@
+// src/commands/tls/mod.rs
+pub mod command;
+pub use command::TlsCommand;
@@ -0,0 +1,45 @@
+// src/commands/uptime/command.rs
+use crate::commands::command::Command;
+use crate::commands::handler::CommandHandler;
+use crate::commands::session::command::session_uptime;
+use crate::core::helpers::format_duration;
+use crate::core::prelude::*;
+use crate::i18n::get_command_translation;
+
+#[derive(Debug)]
+pub struct UptimeCommand;
+
+impl Command for UptimeCommand {
+    fn name(&self) -> &'static str {
+        "uptime"
+    }
+
+    fn description(&self) -> &'static str {
+        "Show how long this session has been running"
+    }
+
+    fn matches(&self, command: &str) -> bool {
+        crate::matches_exact!(command, "uptime")
+    }
+
+    fn execute_sync(&self, args: &[&str]) -> Result<String> {
+        if let Some(&arg) = args.first() {
+            return Err(AppError::Validation(format!(
+                "Unknown argument '{}'. Usage: uptime",
+                arg
+            )));
+        }
+
+        let uptime = session_uptime()
+            .map(format_duration)
+            .unwrap_or_else(|| "-".to_string());
+        Ok(get_command_translation(
+            "system.commands.uptime",
+            &[&uptime, &CommandHandler::commands_executed().to_string()],
+        ))
+    }
+
+    fn priority(&self) -> u8 {
+        41
+    }
+}
@@ -0,0 +1,3 @@
+// src/commands/uptime/mod.rs
+pub mod command;
+pub use command::UptimeCommand;
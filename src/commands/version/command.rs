@@ -1,11 +1,32 @@
 use crate::commands::command::Command;
-use crate::core::constants::VERSION;
+use crate::core::constants::{
+    BUILD_FEATURES, BUILD_TARGET, BUILD_TIMESTAMP, GIT_HASH, RUSTC_VERSION, VERSION,
+};
 use crate::core::prelude::*;
-use crate::i18n::get_command_translation;
+use crate::i18n::{get_command_translation, get_translation};
 
 #[derive(Debug)]
 pub struct VersionCommand;
 
+impl VersionCommand {
+    /// Build metadata block, one `label: value` line per field
+    fn verbose_report(&self) -> String {
+        let rows = [
+            ("system.commands.version.verbose.commit", GIT_HASH),
+            ("system.commands.version.verbose.built", BUILD_TIMESTAMP),
+            ("system.commands.version.verbose.rustc", RUSTC_VERSION),
+            ("system.commands.version.verbose.target", BUILD_TARGET),
+            ("system.commands.version.verbose.features", BUILD_FEATURES),
+        ];
+
+        let mut out = get_command_translation("system.commands.version", &[VERSION]);
+        for (key, value) in rows {
+            out.push_str(&format!("\n  {:<10} {}", get_translation(key, &[]), value));
+        }
+        out
+    }
+}
+
 impl Command for VersionCommand {
     fn name(&self) -> &'static str {
         "version"
@@ -16,17 +37,46 @@ impl Command for VersionCommand {
     }
 
     fn matches(&self, command: &str) -> bool {
-        crate::matches_exact!(command, "version" | "ver")
+        command
+            .split_whitespace()
+            .next()
+            .is_some_and(|first| matches!(first.to_lowercase().as_str(), "version" | "ver"))
     }
 
-    fn execute_sync(&self, _args: &[&str]) -> Result<String> {
-        Ok(get_command_translation(
-            "system.commands.version",
-            &[VERSION],
-        ))
+    fn execute_sync(&self, args: &[&str]) -> Result<String> {
+        match args.first() {
+            None => Ok(get_command_translation(
+                "system.commands.version",
+                &[VERSION],
+            )),
+            Some(&"--verbose") | Some(&"-v") => Ok(self.verbose_report()),
+            Some(other) => Err(AppError::Validation(format!(
+                "Unknown version option '{}'. Use version [--verbose]",
+                other
+            ))),
+        }
     }
 
     fn priority(&self) -> u8 {
         40
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_verbose_reports_build_metadata() {
+        let cmd = VersionCommand;
+        assert!(cmd.matches("version --verbose"));
+        assert!(cmd.matches("ver"));
+        assert!(!cmd.matches("versions"));
+
+        let out = cmd.execute_sync(&["--verbose"]).unwrap();
+        assert!(out.contains(GIT_HASH));
+        assert!(out.contains(BUILD_TARGET));
+        assert!(out.contains(RUSTC_VERSION));
+        assert!(!BUILD_TIMESTAMP.is_empty());
+    }
+}
@@ -0,0 +1,61 @@
+use crate::commands::command::Command;
+use crate::core::constants::SIG_WRAP;
+use crate::core::prelude::*;
+
+#[derive(Debug)]
+pub struct WrapCommand;
+
+impl Command for WrapCommand {
+    fn name(&self) -> &'static str {
+        "wrap"
+    }
+
+    fn description(&self) -> &'static str {
+        "Toggle line wrapping of the output"
+    }
+
+    fn matches(&self, command: &str) -> bool {
+        command
+            .split_whitespace()
+            .next()
+            .is_some_and(|first| first.eq_ignore_ascii_case("wrap"))
+    }
+
+    fn execute_sync(&self, args: &[&str]) -> Result<String> {
+        let mode = match args.first().map(|a| a.to_lowercase()).as_deref() {
+            None => "toggle",
+            Some("on") => "on",
+            Some("off") => "off",
+            Some(other) => {
+                return Err(AppError::Validation(format!(
+                    "Unknown wrap mode '{}'. Use wrap [on|off]",
+                    other
+                )));
+            }
+        };
+        Ok(format!("{}{}", SIG_WRAP, mode))
+    }
+
+    fn priority(&self) -> u8 {
+        60
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_modes() {
+        let cmd = WrapCommand;
+        assert_eq!(
+            cmd.execute_sync(&[]).unwrap(),
+            format!("{}toggle", SIG_WRAP)
+        );
+        assert_eq!(
+            cmd.execute_sync(&["OFF"]).unwrap(),
+            format!("{}off", SIG_WRAP)
+        );
+        assert!(cmd.execute_sync(&["sideways"]).is_err());
+    }
+}
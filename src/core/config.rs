@@ -1,11 +1,14 @@
 // src/core/config.rs - Cleaned and simplified
 use crate::core::api_key::ApiKey;
-use crate::core::constants::{DEFAULT_BUFFER_SIZE, DEFAULT_POLL_RATE};
+use crate::core::constants::{
+    DEFAULT_BUFFER_SIZE, DEFAULT_INPUT_POLL_MS, DEFAULT_KEY_INTERVAL_MS, DEFAULT_POLL_RATE,
+    DEFAULT_RESIZE_THROTTLE_MS,
+};
 use crate::core::prelude::*;
 use crate::proxy::types::{ProxyConfig, ProxyConfigToml};
 use crate::ui::color::AppColor;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
 // TOML Configuration Structure
@@ -17,9 +20,14 @@ struct ConfigFile {
     #[serde(default)]
     logging: Option<LoggingConfigToml>,
     #[serde(default)]
+    typewriter: Option<TypewriterConfigToml>,
+    #[serde(default)]
     theme: Option<HashMap<String, ThemeDefinitionConfig>>,
+    #[serde(default)]
     language: LanguageConfig,
     proxy: Option<ProxyConfigToml>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    keybindings: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -32,11 +40,35 @@ struct GeneralConfig {
     log_level: String,
     #[serde(default = "default_theme")]
     current_theme: String,
+    #[serde(default = "default_show_banner")]
+    show_banner: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    banner: Option<String>,
+    #[serde(default = "default_input_poll_ms")]
+    input_poll_ms: u64,
+    #[serde(default = "default_key_interval_ms")]
+    key_interval_ms: u64,
+    #[serde(default = "default_resize_throttle_ms")]
+    resize_throttle_ms: u64,
+    #[serde(default = "default_mouse_capture")]
+    mouse_capture: bool,
+    #[serde(default = "default_wrap_lines")]
+    wrap_lines: bool,
+    #[serde(default)]
+    show_timestamps: bool,
+    #[serde(default)]
+    archive_overflow: bool,
+    #[serde(default)]
+    ansi_colors: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 struct LanguageConfig {
+    /// Empty when rush.toml has no stored language
+    #[serde(default)]
     current: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    fallback: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -77,23 +109,49 @@ struct ServerConfigToml {
     production_domain: String,
     #[serde(default)]
     acme_email: String,
+    #[serde(default = "default_acme_challenge")]
+    acme_challenge: String,
+    #[serde(default)]
+    acme_dns_hook: String,
 
     // Security
     #[serde(default)]
     api_key: String,
+    #[serde(default)]
+    cors_origins: Vec<String>,
+    #[serde(default)]
+    dashboard_user: String,
+    #[serde(default)]
+    dashboard_password: String,
 
     // Rate Limiting
     #[serde(default = "default_rate_limit_rps")]
     rate_limit_rps: u32,
     #[serde(default = "default_rate_limit_enabled")]
     rate_limit_enabled: bool,
+    #[serde(default)]
+    rate_limit_per_min: u32,
+
+    // Static files
+    #[serde(default = "default_index_files")]
+    index_files: Vec<String>,
+    #[serde(default = "default_compression")]
+    compression: String,
+    #[serde(default = "default_hot_reload")]
+    hot_reload: bool,
+    #[serde(default = "default_hot_reload_debounce_ms")]
+    hot_reload_debounce_ms: u64,
+    #[serde(default = "default_hot_reload_include")]
+    hot_reload_include: Vec<String>,
+    #[serde(default = "default_hot_reload_exclude")]
+    hot_reload_exclude: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct LoggingConfigToml {
     #[serde(default = "default_max_file_size")]
     max_file_size_mb: u64,
-    #[serde(default = "default_max_archive_files")]
+    #[serde(default = "default_max_archive_files", alias = "max_files")]
     max_archive_files: u8,
     #[serde(default = "default_compress_archives")]
     compress_archives: bool,
@@ -103,6 +161,20 @@ struct LoggingConfigToml {
     log_security_alerts: bool,
     #[serde(default = "default_log_performance")]
     log_performance: bool,
+    #[serde(default = "default_log_format")]
+    format: String,
+    #[serde(default = "default_sample_rate")]
+    sample_rate: u32,
+    #[serde(default = "default_slow_request_ms")]
+    slow_request_ms: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct TypewriterConfigToml {
+    #[serde(default = "default_force_instant_lines")]
+    force_instant_lines: usize,
+    #[serde(default = "default_force_instant_bytes")]
+    force_instant_bytes: usize,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -121,12 +193,32 @@ struct ThemeDefinitionConfig {
     output_cursor: String,
     #[serde(default = "default_output_color")]
     output_cursor_color: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    typewriter_delay_ms: Option<u64>,
 }
 
 // Default Functions
 fn default_theme() -> String {
     "dark".into()
 }
+fn default_show_banner() -> bool {
+    true
+}
+fn default_mouse_capture() -> bool {
+    true
+}
+fn default_wrap_lines() -> bool {
+    true
+}
+fn default_input_poll_ms() -> u64 {
+    DEFAULT_INPUT_POLL_MS
+}
+fn default_key_interval_ms() -> u64 {
+    DEFAULT_KEY_INTERVAL_MS
+}
+fn default_resize_throttle_ms() -> u64 {
+    DEFAULT_RESIZE_THROTTLE_MS
+}
 fn default_prefix() -> String {
     "/// ".into()
 }
@@ -194,6 +286,26 @@ fn default_rate_limit_rps() -> u32 {
 fn default_rate_limit_enabled() -> bool {
     true
 }
+fn default_index_files() -> Vec<String> {
+    vec!["index.html".into(), "index.htm".into()]
+}
+fn default_hot_reload() -> bool {
+    true
+}
+fn default_hot_reload_debounce_ms() -> u64 {
+    300
+}
+fn default_hot_reload_include() -> Vec<String> {
+    vec![
+        "*.html".into(),
+        "*.htm".into(),
+        "*.css".into(),
+        "*.js".into(),
+    ]
+}
+fn default_hot_reload_exclude() -> Vec<String> {
+    vec![".*".into(), "*~".into(), "*.tmp".into(), "*.swp".into()]
+}
 
 // Logging Defaults
 fn default_max_file_size() -> u64 {
@@ -214,6 +326,27 @@ fn default_log_security() -> bool {
 fn default_log_performance() -> bool {
     true
 }
+fn default_log_format() -> String {
+    "native".into()
+}
+fn default_sample_rate() -> u32 {
+    1
+}
+fn default_slow_request_ms() -> u64 {
+    1000
+}
+fn default_compression() -> String {
+    "auto".into()
+}
+fn default_acme_challenge() -> String {
+    "http-01".into()
+}
+fn default_force_instant_lines() -> usize {
+    5
+}
+fn default_force_instant_bytes() -> usize {
+    200
+}
 
 // Main Configuration Structures
 #[derive(Clone)]
@@ -224,14 +357,40 @@ pub struct Config {
     pub input_max_length: usize,
     pub max_history: usize,
     pub poll_rate: Duration,
+    /// How long the input loop waits for a terminal event per iteration
+    pub input_poll: Duration,
+    /// Key events closer together than this are dropped
+    pub key_interval: Duration,
+    /// Resize events closer together than this are dropped
+    pub resize_throttle: Duration,
+    /// Capture the mouse for wheel scrolling (off = native text selection)
+    pub mouse_capture: bool,
+    /// Wrap long output lines (off = clip to the width, scroll with Shift+Left/Right)
+    pub wrap_lines: bool,
+    /// Prefix output messages with their local HH:MM:SS arrival time
+    pub show_timestamps: bool,
+    /// Append messages dropped over `max_messages` to `.rss/session.log`
+    pub archive_overflow: bool,
+    /// Render ANSI SGR colors in messages instead of stripping them
+    pub ansi_colors: bool,
     pub log_level: String,
     pub theme: Theme,
     pub current_theme_name: String,
+    /// Startup banner in the output pane; `--no-banner` turns it off per run
+    pub show_banner: bool,
+    /// Custom banner text, supports {{VERSION}}, {{THEME}}, {{LANGUAGE}},
+    /// {{SERVERS}} and {{MEMORY}}
+    pub banner: Option<String>,
     pub language: String,
+    /// Ordered fallback languages for keys missing in `language`
+    pub language_fallback: Vec<String>,
     pub debug_info: Option<String>,
     pub server: ServerConfig,
     pub logging: LoggingConfig,
+    pub typewriter: TypewriterConfig,
     pub proxy: ProxyConfig,
+    /// `[keybindings]`: key spec (`ctrl+k`) -> action name (`ClearLine`)
+    pub keybindings: BTreeMap<String, String>,
 }
 
 #[derive(Clone)]
@@ -254,13 +413,41 @@ pub struct ServerConfig {
     pub use_lets_encrypt: bool,
     pub production_domain: String,
     pub acme_email: String,
+    /// ACME challenge type: "http-01" (default) or "dns-01"
+    pub acme_challenge: String,
+    /// Command run to publish/remove the DNS-01 TXT record (empty = print for manual entry)
+    pub acme_dns_hook: String,
 
     // Security
     pub api_key: ApiKey,
+    /// Origins answered with CORS headers, "*" for any. Empty: localhost
+    /// origins while production_domain is "localhost", none otherwise
+    pub cors_origins: Vec<String>,
+    /// Basic Auth user for /.rss/ and /api/ (empty = no login)
+    pub dashboard_user: String,
+    /// Basic Auth password, plaintext or `$hmac-sha256$` hash like api_key
+    pub dashboard_password: ApiKey,
 
     // Rate Limiting
     pub rate_limit_rps: u32,
     pub rate_limit_enabled: bool,
+    /// Requests per minute per IP across all paths (0 = off)
+    pub rate_limit_per_min: u32,
+
+    /// Directory index names, tried in order
+    pub index_files: Vec<String>,
+    /// Response compression: "auto" (negotiate br/gzip/zstd), "brotli" or
+    /// "gzip" (only that encoding), "off" (no Compress middleware at all)
+    pub compression: String,
+    /// File watching, `/ws/hot-reload` and the `/rss.js` injection into HTML
+    pub hot_reload: bool,
+    /// Changes within this many ms of each other trigger one reload (0 = each)
+    pub hot_reload_debounce_ms: u64,
+    /// Globs (relative to the server directory) whose changes trigger a
+    /// reload; empty = every file
+    pub hot_reload_include: Vec<String>,
+    /// Globs never triggering a reload, checked before `hot_reload_include`
+    pub hot_reload_exclude: Vec<String>,
 }
 
 #[derive(Clone)]
@@ -271,9 +458,31 @@ pub struct LoggingConfig {
     pub log_requests: bool,
     pub log_security_alerts: bool,
     pub log_performance: bool,
+    /// "native" (full entry, read by the log viewer) or "json" (flat JSON Lines)
+    pub format: String,
+    /// Write 1 in N successful requests to the log; errors are always written
+    pub sample_rate: u32,
+    /// Requests slower than this are logged at WARN and always written; 0 = off
+    pub slow_request_ms: u64,
 }
 
-#[derive(Clone)]
+/// When a message skips the typewriter effect and is shown at once
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypewriterConfig {
+    /// Messages with more lines than this are shown instantly
+    pub force_instant_lines: usize,
+    /// Messages longer than this (in bytes) are shown instantly
+    pub force_instant_bytes: usize,
+}
+
+impl TypewriterConfig {
+    pub fn forces_instant(&self, content: &str) -> bool {
+        content.lines().count() > self.force_instant_lines
+            || content.len() > self.force_instant_bytes
+    }
+}
+
+#[derive(Clone, PartialEq)]
 pub struct Theme {
     pub input_text: AppColor,
     pub input_bg: AppColor,
@@ -321,9 +530,21 @@ impl Default for ServerConfig {
             use_lets_encrypt: false,
             production_domain: "localhost".to_string(),
             acme_email: String::new(),
+            acme_challenge: default_acme_challenge(),
+            acme_dns_hook: String::new(),
             api_key: ApiKey::empty(),
+            cors_origins: Vec::new(),
+            dashboard_user: String::new(),
+            dashboard_password: ApiKey::empty(),
             rate_limit_rps: 100,
             rate_limit_enabled: true,
+            rate_limit_per_min: 0,
+            index_files: default_index_files(),
+            compression: default_compression(),
+            hot_reload: true,
+            hot_reload_debounce_ms: default_hot_reload_debounce_ms(),
+            hot_reload_include: default_hot_reload_include(),
+            hot_reload_exclude: default_hot_reload_exclude(),
         }
     }
 }
@@ -337,6 +558,18 @@ impl Default for LoggingConfig {
             log_requests: true,
             log_security_alerts: true,
             log_performance: true,
+            format: default_log_format(),
+            sample_rate: default_sample_rate(),
+            slow_request_ms: default_slow_request_ms(),
+        }
+    }
+}
+
+impl Default for TypewriterConfig {
+    fn default() -> Self {
+        Self {
+            force_instant_lines: default_force_instant_lines(),
+            force_instant_bytes: default_force_instant_bytes(),
         }
     }
 }
@@ -373,6 +606,11 @@ impl Config {
         Ok(config)
     }
 
+    /// The rush.toml this config was loaded from
+    pub fn path(&self) -> Option<&Path> {
+        self.config_path.as_deref().map(Path::new)
+    }
+
     pub async fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
         let content = tokio::fs::read_to_string(&path)
             .await
@@ -416,9 +654,21 @@ impl Config {
                     use_lets_encrypt: s.use_lets_encrypt,
                     production_domain: s.production_domain,
                     acme_email: s.acme_email,
+                    acme_challenge: Self::acme_challenge_type(s.acme_challenge),
+                    acme_dns_hook: s.acme_dns_hook,
                     api_key,
+                    cors_origins: s.cors_origins,
+                    dashboard_user: s.dashboard_user,
+                    dashboard_password: ApiKey::from_toml(&s.dashboard_password),
                     rate_limit_rps: s.rate_limit_rps,
                     rate_limit_enabled: s.rate_limit_enabled,
+                    rate_limit_per_min: s.rate_limit_per_min,
+                    index_files: s.index_files,
+                    compression: Self::compression_mode(s.compression),
+                    hot_reload: s.hot_reload,
+                    hot_reload_debounce_ms: s.hot_reload_debounce_ms,
+                    hot_reload_include: s.hot_reload_include,
+                    hot_reload_exclude: s.hot_reload_exclude,
                 }
             });
 
@@ -432,8 +682,18 @@ impl Config {
                 log_requests: l.log_requests,
                 log_security_alerts: l.log_security_alerts,
                 log_performance: l.log_performance,
+                format: l.format,
+                sample_rate: l.sample_rate.max(1),
+                slow_request_ms: l.slow_request_ms,
             });
 
+        let typewriter_config =
+            file.typewriter
+                .map_or_else(TypewriterConfig::default, |t| TypewriterConfig {
+                    force_instant_lines: t.force_instant_lines,
+                    force_instant_bytes: t.force_instant_bytes,
+                });
+
         let config = Self {
             config_path: Some(path.as_ref().to_string_lossy().into_owned()),
             max_messages: file.general.max_messages,
@@ -441,10 +701,41 @@ impl Config {
             input_max_length: file.general.input_max_length,
             max_history: file.general.max_history,
             poll_rate: Duration::from_millis(poll_rate),
+            input_poll: Duration::from_millis(Self::clamp(
+                file.general.input_poll_ms,
+                1,
+                1000,
+                DEFAULT_INPUT_POLL_MS,
+            )),
+            key_interval: Duration::from_millis(Self::clamp(
+                file.general.key_interval_ms,
+                0,
+                500,
+                DEFAULT_KEY_INTERVAL_MS,
+            )),
+            resize_throttle: Duration::from_millis(Self::clamp(
+                file.general.resize_throttle_ms,
+                0,
+                1000,
+                DEFAULT_RESIZE_THROTTLE_MS,
+            )),
+            mouse_capture: file.general.mouse_capture,
+            wrap_lines: file.general.wrap_lines,
+            show_timestamps: file.general.show_timestamps,
+            archive_overflow: file.general.archive_overflow,
+            ansi_colors: file.general.ansi_colors,
             log_level: file.general.log_level,
             theme,
             current_theme_name: file.general.current_theme,
-            language: file.language.current,
+            show_banner: file.general.show_banner,
+            banner: file.general.banner,
+            language: if file.language.current.trim().is_empty() {
+                crate::i18n::detect_system_language()
+                    .unwrap_or_else(|| crate::i18n::DEFAULT_LANGUAGE.into())
+            } else {
+                file.language.current
+            },
+            language_fallback: file.language.fallback,
             debug_info: None,
             proxy: {
                 let mut proxy = file.proxy.map(ProxyConfig::from).unwrap_or_default();
@@ -455,6 +746,8 @@ impl Config {
             },
             server,
             logging,
+            typewriter: typewriter_config,
+            keybindings: file.keybindings,
         };
 
         // Auto-save corrected values
@@ -480,6 +773,16 @@ impl Config {
                 poll_rate: self.poll_rate.as_millis() as u64,
                 log_level: self.log_level.clone(),
                 current_theme: self.current_theme_name.clone(),
+                show_banner: self.show_banner,
+                banner: self.banner.clone(),
+                input_poll_ms: self.input_poll.as_millis() as u64,
+                key_interval_ms: self.key_interval.as_millis() as u64,
+                resize_throttle_ms: self.resize_throttle.as_millis() as u64,
+                mouse_capture: self.mouse_capture,
+                wrap_lines: self.wrap_lines,
+                show_timestamps: self.show_timestamps,
+                archive_overflow: self.archive_overflow,
+                ansi_colors: self.ansi_colors,
             },
             server: Some(ServerConfigToml {
                 port_range_start: self.server.port_range_start,
@@ -498,9 +801,21 @@ impl Config {
                 use_lets_encrypt: self.server.use_lets_encrypt,
                 production_domain: self.server.production_domain.clone(),
                 acme_email: self.server.acme_email.clone(),
+                acme_challenge: self.server.acme_challenge.clone(),
+                acme_dns_hook: self.server.acme_dns_hook.clone(),
                 api_key: self.server.api_key.to_toml_value(),
+                cors_origins: self.server.cors_origins.clone(),
+                dashboard_user: self.server.dashboard_user.clone(),
+                dashboard_password: self.server.dashboard_password.to_toml_value(),
                 rate_limit_rps: self.server.rate_limit_rps,
                 rate_limit_enabled: self.server.rate_limit_enabled,
+                rate_limit_per_min: self.server.rate_limit_per_min,
+                index_files: self.server.index_files.clone(),
+                compression: self.server.compression.clone(),
+                hot_reload: self.server.hot_reload,
+                hot_reload_debounce_ms: self.server.hot_reload_debounce_ms,
+                hot_reload_include: self.server.hot_reload_include.clone(),
+                hot_reload_exclude: self.server.hot_reload_exclude.clone(),
             }),
             logging: Some(LoggingConfigToml {
                 max_file_size_mb: self.logging.max_file_size_mb,
@@ -509,6 +824,13 @@ impl Config {
                 log_requests: self.logging.log_requests,
                 log_security_alerts: self.logging.log_security_alerts,
                 log_performance: self.logging.log_performance,
+                format: self.logging.format.clone(),
+                sample_rate: self.logging.sample_rate,
+                slow_request_ms: self.logging.slow_request_ms,
+            }),
+            typewriter: Some(TypewriterConfigToml {
+                force_instant_lines: self.typewriter.force_instant_lines,
+                force_instant_bytes: self.typewriter.force_instant_bytes,
             }),
             theme: if themes.is_empty() {
                 None
@@ -517,8 +839,10 @@ impl Config {
             },
             language: LanguageConfig {
                 current: self.language.clone(),
+                fallback: self.language_fallback.clone(),
             },
             proxy: Some(self.proxy.clone().into()),
+            keybindings: self.keybindings.clone(),
         };
 
         let content = toml::to_string_pretty(&file)
@@ -567,10 +891,42 @@ impl Config {
         }
     }
 
+    fn compression_mode(value: String) -> String {
+        let value = value.trim().to_lowercase();
+        match value.as_str() {
+            "auto" | "off" | "brotli" | "gzip" => value,
+            _ => {
+                log::warn!(
+                    "Unknown server.compression '{}', using \"auto\" (auto|off|brotli|gzip)",
+                    value
+                );
+                default_compression()
+            }
+        }
+    }
+
+    fn acme_challenge_type(value: String) -> String {
+        let value = value.trim().to_lowercase();
+        match value.as_str() {
+            "http-01" | "dns-01" => value,
+            _ => {
+                log::warn!(
+                    "Unknown server.acme_challenge '{}', using \"http-01\" (http-01|dns-01)",
+                    value
+                );
+                default_acme_challenge()
+            }
+        }
+    }
+
     fn load_theme(file: &ConfigFile) -> Option<Theme> {
-        let themes = file.theme.as_ref()?;
-        let def = themes.get(&file.general.current_theme)?;
-        Theme::from_config(def).ok()
+        let name = &file.general.current_theme;
+        match file.theme.as_ref().and_then(|themes| themes.get(name)) {
+            Some(def) => Theme::from_config(def).ok(),
+            None => crate::commands::theme::builtin_themes()
+                .get(&name.to_lowercase())
+                .and_then(|def| Theme::from_definition(def).ok()),
+        }
     }
 
     async fn load_existing_themes() -> Result<HashMap<String, ThemeDefinitionConfig>> {
@@ -611,6 +967,20 @@ impl Config {
 }
 
 impl Theme {
+    pub fn from_definition(def: &crate::commands::theme::ThemeDefinition) -> Result<Self> {
+        Ok(Self {
+            input_text: AppColor::from_string(&def.input_text)?,
+            input_bg: AppColor::from_string(&def.input_bg)?,
+            output_text: AppColor::from_string(&def.output_text)?,
+            output_bg: AppColor::from_string(&def.output_bg)?,
+            input_cursor_prefix: def.input_cursor_prefix.clone(),
+            input_cursor_color: AppColor::from_string(&def.input_cursor_color)?,
+            input_cursor: def.input_cursor.clone(),
+            output_cursor: def.output_cursor.clone(),
+            output_cursor_color: AppColor::from_string(&def.output_cursor_color)?,
+        })
+    }
+
     fn from_config(def: &ThemeDefinitionConfig) -> Result<Self> {
         Ok(Self {
             input_text: AppColor::from_string(&def.input_text)?,
@@ -635,14 +1005,27 @@ impl Default for Config {
             input_max_length: DEFAULT_BUFFER_SIZE,
             max_history: 30,
             poll_rate: Duration::from_millis(DEFAULT_POLL_RATE),
+            input_poll: Duration::from_millis(DEFAULT_INPUT_POLL_MS),
+            key_interval: Duration::from_millis(DEFAULT_KEY_INTERVAL_MS),
+            resize_throttle: Duration::from_millis(DEFAULT_RESIZE_THROTTLE_MS),
+            mouse_capture: true,
+            wrap_lines: true,
+            show_timestamps: false,
+            archive_overflow: false,
+            ansi_colors: false,
             log_level: "info".into(),
             theme: Theme::default(),
             current_theme_name: "dark".into(),
+            show_banner: true,
+            banner: None,
             language: crate::i18n::DEFAULT_LANGUAGE.into(),
+            language_fallback: Vec::new(),
             debug_info: None,
             server: ServerConfig::default(),
             logging: LoggingConfig::default(),
+            typewriter: TypewriterConfig::default(),
             proxy: ProxyConfig::default(),
+            keybindings: BTreeMap::new(),
         }
     }
 }
@@ -4,8 +4,27 @@ pub const DEFAULT_POLL_RATE: u64 = 16;
 pub const MIN_POLL_RATE: u64 = 16;
 pub const MAX_POLL_RATE: u64 = 1000;
 pub const DOUBLE_ESC_THRESHOLD: u64 = 250;
+/// Maximum gap between the keys of a chord binding (`ctrl+x ctrl+s`)
+pub const CHORD_TIMEOUT_MS: u64 = 1000;
+/// Terminal event poll timeout of the input loop
+pub const DEFAULT_INPUT_POLL_MS: u64 = 99;
+/// Minimum gap between two forwarded key events
+pub const DEFAULT_KEY_INTERVAL_MS: u64 = 16;
+/// Minimum gap between two forwarded resize events
+pub const DEFAULT_RESIZE_THROTTLE_MS: u64 = 50;
+/// How long `theme preview <name> --live` shows a theme before reverting
+pub const THEME_PREVIEW_SECS: u64 = 5;
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+// Build metadata captured by build.rs (shown by `version --verbose`).
+// BUILD_TIMESTAMP is when the sources, manifest or git HEAD last changed and
+// triggered a build, not when the binary was last linked.
+pub const GIT_HASH: &str = env!("RSS_GIT_HASH");
+pub const BUILD_TIMESTAMP: &str = env!("RSS_BUILD_TIMESTAMP");
+pub const RUSTC_VERSION: &str = env!("RSS_RUSTC_VERSION");
+pub const BUILD_TARGET: &str = env!("RSS_TARGET");
+pub const BUILD_FEATURES: &str = env!("RSS_FEATURES");
+
 // System command signals
 pub const SIG_CLEAR: &str = "__CLEAR__";
 pub const SIG_EXIT: &str = "__EXIT__";
@@ -15,10 +34,18 @@ pub const SIG_RESTART_FORCE: &str = "__RESTART_FORCE__";
 pub const SIG_RESTART_WITH_MSG: &str = "__RESTART_WITH_MSG__";
 pub const SIG_CONFIRM_RESTART: &str = "__CONFIRM_RESTART__";
 pub const SIG_CLEAR_HISTORY: &str = "__CLEAR_HISTORY__";
+pub const SIG_CLEAR_ALL: &str = "__CLEAR_ALL__";
+pub const SIG_HISTORY_SEARCH: &str = "__HISTORY_SEARCH__";
+pub const SIG_HISTORY_LIMIT: &str = "__HISTORY_LIMIT__";
+pub const SIG_HISTORY_STATUS: &str = "__HISTORY_STATUS__";
 pub const SIG_CONFIRM_CLEANUP: &str = "__CLEANUP__";
+pub const SIG_CONFIRM_CLEANUP_ITEM: &str = "__CLEANUP_ITEM__";
 pub const SIG_CONFIRM_PREFIX: &str = "__CONFIRM:";
 pub const SIG_LIVE_THEME_UPDATE: &str = "__LIVE_THEME_UPDATE__";
 pub const SIG_THEME_MSG_SEP: &str = "__MESSAGE__";
+pub const SIG_THEME_PREVIEW: &str = "__THEME_PREVIEW__";
+pub const SIG_OUTPUT_SEARCH: &str = "__OUTPUT_SEARCH__";
+pub const SIG_WRAP: &str = "__WRAP__";
 
 /// Register constants in the memory manager
 #[cfg(feature = "memory")]
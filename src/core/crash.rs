@@ -0,0 +1,102 @@
+// src/core/crash.rs - Crash file written by the panic hook
+use crate::core::prelude::*;
+use std::backtrace::Backtrace;
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+/// Config summary from the last load; the panic hook cannot load config itself
+static CONFIG_SUMMARY: RwLock<Option<String>> = RwLock::new(None);
+
+/// Remembers the active config for crash files. Secrets (API key, ACME
+/// email, DNS hook) are left out so the file can be attached to a bug report.
+pub fn set_config_summary(config: &Config) {
+    let summary = format!(
+        "config file:     {}\n\
+         theme:           {}\n\
+         language:        {}\n\
+         log level:       {}\n\
+         ports:           {}-{} (max {} servers)\n\
+         https:           {}\n\
+         proxy:           {} (port {})\n\
+         wrap lines:      {}\n\
+         mouse capture:   {}",
+        config
+            .path()
+            .map(|p| p.display().to_string())
+            .unwrap_or_else(|| "-".into()),
+        config.current_theme_name,
+        config.language,
+        config.log_level,
+        config.server.port_range_start,
+        config.server.port_range_end,
+        config.server.max_concurrent,
+        config.server.enable_https,
+        config.proxy.enabled,
+        config.proxy.port,
+        config.wrap_lines,
+        config.mouse_capture,
+    );
+    if let Ok(mut current) = CONFIG_SUMMARY.write() {
+        *current = Some(summary);
+    }
+}
+
+/// Full crash report text: panic message, version, config summary and backtrace
+pub fn crash_report(message: &str, backtrace: &Backtrace) -> String {
+    let summary = CONFIG_SUMMARY
+        .read()
+        .ok()
+        .and_then(|s| s.clone())
+        .unwrap_or_else(|| "(no config loaded yet)".into());
+
+    format!(
+        "Rush Sync Server crash report\n\
+         =============================\n\
+         time:            {}\n\
+         version:         {}\n\
+         os:              {} {}\n\n\
+         Panic\n-----\n{}\n\n\
+         Config\n------\n{}\n\n\
+         Backtrace\n---------\n{}\n",
+        chrono::Local::now().format("%Y-%m-%d %H:%M:%S %z"),
+        crate::core::constants::VERSION,
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        message,
+        summary,
+        backtrace,
+    )
+}
+
+/// Writes `.rss/crash-<timestamp>.txt` with a forced backtrace capture
+/// (independent of `RUST_BACKTRACE`) and returns its path.
+pub fn write_crash_file(message: &str) -> Option<PathBuf> {
+    let dir = crate::core::paths::base_dir().ok()?.join(".rss");
+    std::fs::create_dir_all(&dir).ok()?;
+
+    let path = dir.join(format!(
+        "crash-{}.txt",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    let report = crash_report(message, &Backtrace::force_capture());
+    std::fs::write(&path, report).ok()?;
+    Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crash_report_contents() {
+        let mut config = Config::default();
+        config.current_theme_name = "neon".into();
+        set_config_summary(&config);
+
+        let report = crash_report("boom at src/x.rs:1", &Backtrace::disabled());
+        assert!(report.contains("boom at src/x.rs:1"));
+        assert!(report.contains(crate::core::constants::VERSION));
+        assert!(report.contains("neon"));
+        assert!(report.contains("Backtrace"));
+    }
+}
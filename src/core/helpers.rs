@@ -1,6 +1,6 @@
 use crate::core::prelude::*;
 use std::path::PathBuf;
-use std::sync::{OnceLock, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 /// Runtime-safe config loader
 pub fn get_config() -> Result<Config> {
@@ -15,20 +15,6 @@ pub fn read_lock<'a, T>(lock: &'a RwLock<T>, context: &str) -> Result<RwLockRead
     })
 }
 
-static BASE_DIR: OnceLock<PathBuf> = OnceLock::new();
-
-/// Get the base directory (parent of the executable), cached via OnceLock
-pub fn get_base_dir() -> Result<PathBuf> {
-    Ok(BASE_DIR
-        .get_or_init(|| {
-            std::env::current_exe()
-                .ok()
-                .and_then(|p| p.parent().map(|p| p.to_path_buf()))
-                .unwrap_or_else(|| PathBuf::from("."))
-        })
-        .clone())
-}
-
 /// Safe write lock acquisition with context for error messages
 pub fn write_lock<'a, T>(lock: &'a RwLock<T>, context: &str) -> Result<RwLockWriteGuard<'a, T>> {
     lock.write().map_err(|e| {
@@ -37,6 +23,135 @@ pub fn write_lock<'a, T>(lock: &'a RwLock<T>, context: &str) -> Result<RwLockWri
     })
 }
 
+/// Calculate directory size recursively
+pub fn dir_size(path: &std::path::Path) -> u64 {
+    if !path.exists() {
+        return 0;
+    }
+    let mut total = 0u64;
+    if let Ok(entries) = std::fs::read_dir(path) {
+        for entry in entries.flatten() {
+            let meta = match entry.metadata() {
+                Ok(m) => m,
+                Err(_) => continue,
+            };
+            if meta.is_dir() {
+                total += dir_size(&entry.path());
+            } else {
+                total += meta.len();
+            }
+        }
+    }
+    total
+}
+
+/// All files below `path` with their sizes (symlinks are not followed)
+pub fn collect_files(path: &std::path::Path) -> Vec<(PathBuf, u64)> {
+    let mut files = Vec::new();
+    let mut stack = vec![path.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_dir() {
+                stack.push(entry.path());
+            } else if file_type.is_file() {
+                let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+                files.push((entry.path(), size));
+            }
+        }
+    }
+    files
+}
+
+/// Human-readable byte count (B, KB, MB, GB)
+pub fn format_bytes(bytes: u64) -> String {
+    if bytes == 0 {
+        return "0 B".to_string();
+    }
+    let units = ["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit_idx = 0;
+    while size >= 1024.0 && unit_idx < units.len() - 1 {
+        size /= 1024.0;
+        unit_idx += 1;
+    }
+    if unit_idx == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", size, units[unit_idx])
+    }
+}
+
+/// Resident memory of this process in bytes; `None` where it can't be read.
+/// Works without the `memory` feature.
+pub fn process_rss_bytes() -> Option<u64> {
+    #[cfg(target_os = "macos")]
+    {
+        use std::mem;
+        extern "C" {
+            fn mach_task_self() -> u32;
+            fn task_info(task: u32, flavor: u32, info: *mut libc::c_void, count: *mut u32) -> i32;
+        }
+
+        #[repr(C)]
+        struct MachTaskBasicInfo {
+            virtual_size: u64,
+            resident_size: u64,
+            resident_size_max: u64,
+            user_time: [u32; 2],
+            system_time: [u32; 2],
+            policy: i32,
+            suspend_count: i32,
+        }
+
+        let mut info: MachTaskBasicInfo = unsafe { mem::zeroed() };
+        let mut count = (mem::size_of::<MachTaskBasicInfo>() / mem::size_of::<u32>()) as u32;
+
+        let result = unsafe {
+            task_info(
+                mach_task_self(),
+                20, // MACH_TASK_BASIC_INFO
+                &mut info as *mut _ as *mut libc::c_void,
+                &mut count,
+            )
+        };
+        (result == 0).then_some(info.resident_size)
+    }
+    #[cfg(target_os = "linux")]
+    {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        let kb: u64 = status
+            .lines()
+            .find(|line| line.starts_with("VmRSS:"))?
+            .split_whitespace()
+            .nth(1)?
+            .parse()
+            .ok()?;
+        Some(kb * 1024)
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+    {
+        None
+    }
+}
+
+/// Compact duration like "45s", "12m 03s" or "2d 04h 10m"
+pub fn format_duration(duration: std::time::Duration) -> String {
+    let secs = duration.as_secs();
+    let (days, hours, mins, secs) = (secs / 86_400, secs / 3600 % 24, secs / 60 % 60, secs % 60);
+    match (days, hours, mins) {
+        (0, 0, 0) => format!("{}s", secs),
+        (0, 0, _) => format!("{}m {:02}s", mins, secs),
+        (0, _, _) => format!("{}h {:02}m {:02}s", hours, mins, secs),
+        _ => format!("{}d {:02}h {:02}m", days, hours, mins),
+    }
+}
+
 /// Escape HTML special characters to prevent XSS
 pub fn html_escape(input: &str) -> String {
     input
@@ -0,0 +1,39 @@
+// src/core/launcher.rs - Hands directories and URLs to the OS default handler
+
+use crate::core::prelude::*;
+use std::process::{Command, Stdio};
+
+/// Platform opener: `open` on macOS, `explorer` on Windows, `xdg-open` elsewhere
+pub fn opener() -> &'static str {
+    if cfg!(target_os = "macos") {
+        "open"
+    } else if cfg!(target_os = "windows") {
+        "explorer"
+    } else {
+        "xdg-open"
+    }
+}
+
+/// Opens a directory in the file manager or a URL in the default browser.
+/// Returns once the opener is spawned; it is reaped on a background thread.
+pub fn open(target: &str) -> Result<()> {
+    let mut child = Command::new(opener())
+        .arg(target)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| {
+            AppError::Validation(format!(
+                "Could not run '{}' to open {}: {}",
+                opener(),
+                target,
+                e
+            ))
+        })?;
+
+    std::thread::spawn(move || {
+        let _ = child.wait();
+    });
+    Ok(())
+}
@@ -2,6 +2,9 @@
 pub mod api_key;
 pub mod config;
 pub mod constants;
+pub mod crash;
 pub mod error;
 pub mod helpers;
+pub mod launcher;
+pub mod paths;
 pub mod prelude;
@@ -0,0 +1,60 @@
+// src/core/paths.rs - Single place that decides where .rss/ and www/ live
+use crate::core::prelude::*;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+/// `None` once resolution failed, so every caller gets the same error
+static BASE_DIR: OnceLock<Option<PathBuf>> = OnceLock::new();
+
+/// Relocates `.rss/` and `www/` (from `--data-dir`). Must run before the first
+/// `base_dir()` call; returns false if the base dir is already fixed.
+pub fn set_data_dir(path: PathBuf) -> bool {
+    BASE_DIR.set(Some(absolutize(path))).is_ok()
+}
+
+/// Root for `.rss/` and `www/`, resolved once and cached.
+/// Order: `--data-dir`, then `RUSH_DATA_DIR`, then the parent of the executable.
+/// Errors (instead of guessing the working directory) if the executable path
+/// cannot be resolved, e.g. in some sandboxes.
+pub fn base_dir() -> Result<PathBuf> {
+    BASE_DIR
+        .get_or_init(resolve_base_dir)
+        .clone()
+        .ok_or_else(|| AppError::Validation(get_translation("system.config.dir_error", &[])))
+}
+
+fn resolve_base_dir() -> Option<PathBuf> {
+    if let Some(dir) = std::env::var_os("RUSH_DATA_DIR").filter(|v| !v.is_empty()) {
+        return Some(absolutize(PathBuf::from(dir)));
+    }
+    match std::env::current_exe() {
+        Ok(exe) => exe.parent().map(|p| p.to_path_buf()),
+        Err(e) => {
+            log::error!("Cannot resolve executable path: {}", e);
+            None
+        }
+    }
+}
+
+/// Resolves a relative CLI path against the working directory
+pub fn absolutize(path: PathBuf) -> PathBuf {
+    if path.is_absolute() {
+        path
+    } else {
+        std::env::current_dir()
+            .map(|dir| dir.join(&path))
+            .unwrap_or(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_absolutize() {
+        let abs = std::env::temp_dir();
+        assert_eq!(absolutize(abs.clone()), abs);
+        assert!(absolutize(PathBuf::from("data")).is_absolute());
+    }
+}
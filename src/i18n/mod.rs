@@ -47,11 +47,25 @@ impl Entry {
     }
 }
 
+/// A key the active language had no entry for during this session
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingTranslation {
+    pub key: String,
+    pub count: usize,
+    /// Fallback language that answered instead, `None` if no language had it
+    pub fallback: Option<String>,
+}
+
 struct I18nService {
     language: String,
     entries: HashMap<String, Entry>,
-    fallback: HashMap<String, Entry>,
+    /// Configured fallback languages, as written in `[language] fallback`
+    fallback_config: Vec<String>,
+    /// Resolved fallback chain, tried in order for keys missing in `entries`
+    fallback: Vec<(String, HashMap<String, Entry>)>,
     cache: RwLock<HashMap<String, String>>,
+    /// Keys missing in the active language, with lookup counts
+    missing: RwLock<HashMap<String, MissingTranslation>>,
 }
 
 impl I18nService {
@@ -59,16 +73,16 @@ impl I18nService {
         Self {
             language: DEFAULT_LANGUAGE.into(),
             entries: HashMap::new(),
-            fallback: HashMap::new(),
+            fallback_config: Vec::new(),
+            fallback: Vec::new(),
             cache: RwLock::new(HashMap::new()),
+            missing: RwLock::new(HashMap::new()),
         }
     }
 
-    fn load_language(&mut self, lang: &str) -> Result<()> {
-        if !Self::available_languages()
-            .iter()
-            .any(|l| l.eq_ignore_ascii_case(lang))
-        {
+    fn load_language(&mut self, lang: &str, fallback: &[String]) -> Result<()> {
+        let available = Self::available_languages();
+        if !available.iter().any(|l| l.eq_ignore_ascii_case(lang)) {
             return Err(AppError::Translation(TranslationError::InvalidLanguage(
                 lang.into(),
             )));
@@ -76,25 +90,64 @@ impl I18nService {
 
         self.entries = Self::load_entries(lang)?;
 
-        // Load fallback from other languages
         self.fallback.clear();
-        for available_lang in Self::available_languages() {
-            if available_lang.to_lowercase() != lang.to_lowercase() {
-                if let Ok(other_entries) = Self::load_entries(&available_lang.to_lowercase()) {
-                    for (key, entry) in other_entries {
-                        self.fallback.entry(key).or_insert(entry);
-                    }
-                }
+        for fallback_lang in fallback_chain(lang, fallback) {
+            if !available
+                .iter()
+                .any(|l| l.eq_ignore_ascii_case(&fallback_lang))
+            {
+                log::warn!("i18n: fallback language '{}' not available", fallback_lang);
+                continue;
+            }
+            match Self::load_entries(&fallback_lang) {
+                Ok(entries) => self.fallback.push((fallback_lang, entries)),
+                Err(e) => log::warn!("i18n: fallback '{}' not loaded: {}", fallback_lang, e),
             }
         }
 
         if let Ok(mut cache) = self.cache.write() {
             cache.clear();
         }
+        if let Ok(mut missing) = self.missing.write() {
+            missing.clear();
+        }
         self.language = lang.into();
+        self.fallback_config = fallback.to_vec();
         Ok(())
     }
 
+    fn lookup(&self, key: &str) -> Option<&Entry> {
+        self.resolve(key).map(|(entry, _)| entry)
+    }
+
+    /// Entry for `key` plus the fallback language it came from, if any
+    fn resolve(&self, key: &str) -> Option<(&Entry, Option<&str>)> {
+        if let Some(entry) = self.entries.get(key) {
+            return Some((entry, None));
+        }
+        self.fallback
+            .iter()
+            .find_map(|(lang, entries)| entries.get(key).map(|entry| (entry, Some(lang.as_str()))))
+    }
+
+    /// Like `resolve`, but counts keys the active language lacks
+    fn resolve_recorded(&self, key: &str) -> Option<&Entry> {
+        let resolved = self.resolve(key);
+        if !matches!(resolved, Some((_, None))) {
+            if let Ok(mut missing) = self.missing.write() {
+                missing
+                    .entry(key.to_string())
+                    .or_insert_with(|| MissingTranslation {
+                        key: key.to_string(),
+                        count: 0,
+                        fallback: resolved.and_then(|(_, lang)| lang.map(str::to_uppercase)),
+                    })
+                    .count += 1;
+            }
+        }
+        resolved.map(|(entry, _)| entry)
+    }
+
     fn load_entries(lang: &str) -> Result<HashMap<String, Entry>> {
         let lang_lower = lang.to_lowercase();
         let mut merged_raw: HashMap<String, String> = HashMap::new();
@@ -125,20 +178,32 @@ impl I18nService {
         }
 
         // Fallback: single-file format
+        let filename = format!("{}.json", lang_lower);
+        let overrides = Self::load_override(&lang_lower)?;
         if !found_modular {
-            let filename = format!("{}.json", lang_lower);
-            let content = Langs::get(&filename).ok_or_else(|| {
-                AppError::Translation(TranslationError::LoadError(format!(
-                    "File not found: {}",
-                    filename
-                )))
-            })?;
-
-            let content_str = std::str::from_utf8(content.data.as_ref())
-                .map_err(|e| AppError::Translation(TranslationError::LoadError(e.to_string())))?;
-
-            merged_raw = serde_json::from_str(content_str)
-                .map_err(|e| AppError::Translation(TranslationError::LoadError(e.to_string())))?;
+            match Langs::get(&filename) {
+                Some(content) => {
+                    let content_str = std::str::from_utf8(content.data.as_ref()).map_err(|e| {
+                        AppError::Translation(TranslationError::LoadError(e.to_string()))
+                    })?;
+
+                    merged_raw = serde_json::from_str(content_str).map_err(|e| {
+                        AppError::Translation(TranslationError::LoadError(e.to_string()))
+                    })?;
+                }
+                None if overrides.is_none() => {
+                    return Err(AppError::Translation(TranslationError::LoadError(format!(
+                        "File not found: {}",
+                        filename
+                    ))));
+                }
+                None => {}
+            }
+        }
+
+        // Translator overrides win key by key over the embedded baseline
+        if let Some(overrides) = overrides {
+            merged_raw.extend(overrides);
         }
 
         Ok(merged_raw
@@ -167,6 +232,21 @@ impl I18nService {
             .collect())
     }
 
+    /// `.rss/langs/<lang>.json` on disk, if a translator put one there
+    fn load_override(lang_lower: &str) -> Result<Option<HashMap<String, String>>> {
+        let Some(path) = override_path(lang_lower) else {
+            return Ok(None);
+        };
+        let content = std::fs::read_to_string(&path).map_err(AppError::Io)?;
+        serde_json::from_str(&content).map(Some).map_err(|e| {
+            AppError::Translation(TranslationError::LoadError(format!(
+                "{}: {}",
+                path.display(),
+                e
+            )))
+        })
+    }
+
     // Now takes &self - cache has its own lock
     fn get_translation(&self, key: &str, params: &[&str]) -> String {
         let cache_key = if params.is_empty() {
@@ -183,11 +263,16 @@ impl I18nService {
         }
 
         // Slow path: compute and write to cache
-        let text = match self.entries.get(key).or_else(|| self.fallback.get(key)) {
+        let text = match self.resolve_recorded(key) {
             Some(entry) => entry.format(params),
             None => format!("Missing: {}", key),
         };
 
+        // Gaps stay uncached so `lang missing` counts every lookup
+        if !self.entries.contains_key(key) {
+            return text;
+        }
+
         if let Ok(mut cache) = self.cache.write() {
             if cache.len() >= 1000 {
                 cache.clear();
@@ -198,7 +283,7 @@ impl I18nService {
     }
 
     fn get_command_translation(&self, key: &str, params: &[&str]) -> String {
-        match self.entries.get(key).or_else(|| self.fallback.get(key)) {
+        match self.resolve_recorded(key) {
             Some(entry) => format!("[{}] {}", entry.display, entry.format(params)),
             None => format!("[WARNING] Missing: {}", key),
         }
@@ -233,24 +318,164 @@ impl I18nService {
             }
         }
 
+        if let Some(dir) = override_dir() {
+            if let Ok(files) = std::fs::read_dir(dir) {
+                for file in files.flatten() {
+                    let path = file.path();
+                    if path.extension().is_some_and(|ext| ext == "json") {
+                        if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+                            languages.insert(stem.to_uppercase());
+                        }
+                    }
+                }
+            }
+        }
+
         languages.into_iter().collect()
     }
 }
 
+/// Directory for translation overrides: `.rss/langs/` next to rush.toml
+fn override_dir() -> Option<std::path::PathBuf> {
+    crate::core::paths::base_dir()
+        .ok()
+        .map(|dir| dir.join(".rss").join("langs"))
+}
+
+/// Override file for `lang`, only when it exists on disk
+pub fn override_path(lang: &str) -> Option<std::path::PathBuf> {
+    override_dir()
+        .map(|dir| dir.join(format!("{}.json", lang.to_lowercase())))
+        .filter(|path| path.is_file())
+}
+
+/// Ordered languages tried after `lang`: a region code first falls back to
+/// its base language (`pt-BR` -> `pt`), then the configured list, and
+/// `DEFAULT_LANGUAGE` always closes the chain. Duplicates and `lang` itself
+/// are dropped, so the same key always resolves the same way.
+pub fn fallback_chain(lang: &str, configured: &[String]) -> Vec<String> {
+    let lang = lang.to_lowercase();
+    let base = lang
+        .split(['-', '_'])
+        .next()
+        .filter(|base| *base != lang)
+        .map(str::to_string);
+
+    let mut chain: Vec<String> = Vec::new();
+    for candidate in base
+        .into_iter()
+        .chain(configured.iter().map(|l| l.trim().to_lowercase()))
+        .chain(std::iter::once(DEFAULT_LANGUAGE.to_string()))
+    {
+        if !candidate.is_empty() && candidate != lang && !chain.contains(&candidate) {
+            chain.push(candidate);
+        }
+    }
+    chain
+}
+
 static SERVICE: std::sync::LazyLock<RwLock<I18nService>> =
     std::sync::LazyLock::new(|| RwLock::new(I18nService::new()));
 
+/// Uses the system locale on first launch (no rush.toml yet), `en` otherwise;
+/// a stored language is applied later when the config loads.
 pub async fn init() -> Result<()> {
+    if crate::setup::wizard::is_first_run() {
+        if let Some(lang) = detect_system_language() {
+            return set_language(&lang);
+        }
+    }
     set_language(DEFAULT_LANGUAGE)
 }
 
+/// Picks an available language from `LC_ALL`, `LC_MESSAGES` or `LANG` (first
+/// non-empty wins, like POSIX) and logs the choice.
+pub fn detect_system_language() -> Option<String> {
+    let (var, locale) = ["LC_ALL", "LC_MESSAGES", "LANG"].iter().find_map(|var| {
+        std::env::var(var)
+            .ok()
+            .filter(|v| !v.trim().is_empty())
+            .map(|v| (*var, v))
+    })?;
+
+    let lang = language_from_locale(&locale, &I18nService::available_languages())?;
+    log::info!(
+        "Language auto-selected from {}={}: {}",
+        var,
+        locale,
+        lang.to_uppercase()
+    );
+    Some(lang)
+}
+
+/// Maps a locale like `de_DE.UTF-8` or `pt-BR` onto an available language,
+/// preferring the full region code over the base language. `C`/`POSIX` match nothing.
+pub fn language_from_locale(locale: &str, available: &[String]) -> Option<String> {
+    let tag = locale
+        .split(['.', '@'])
+        .next()
+        .unwrap_or("")
+        .trim()
+        .replace('_', "-")
+        .to_lowercase();
+    if tag.is_empty() || tag == "c" || tag == "posix" {
+        return None;
+    }
+
+    let base = tag.split('-').next().unwrap_or(&tag).to_string();
+    [tag, base].into_iter().find(|candidate| {
+        available
+            .iter()
+            .any(|lang| lang.eq_ignore_ascii_case(candidate))
+    })
+}
+
 pub fn set_language(lang: &str) -> Result<()> {
     match SERVICE.write() {
-        Ok(mut service) => service.load_language(lang),
+        Ok(mut service) => {
+            let fallback = service.fallback_config.clone();
+            service.load_language(lang, &fallback)
+        }
+        Err(e) => Err(AppError::Validation(format!("i18n lock poisoned: {}", e))),
+    }
+}
+
+/// Re-reads the active language (and its fallbacks) including any
+/// `.rss/langs/` overrides, and clears the translation cache.
+pub fn reload_language() -> Result<()> {
+    match SERVICE.write() {
+        Ok(mut service) => {
+            let lang = service.language.clone();
+            let fallback = service.fallback_config.clone();
+            service.load_language(&lang, &fallback)
+        }
         Err(e) => Err(AppError::Validation(format!("i18n lock poisoned: {}", e))),
     }
 }
 
+/// Sets the ordered fallback languages and reloads the active language with them.
+pub fn set_fallback_languages(fallback: &[String]) -> Result<()> {
+    match SERVICE.write() {
+        Ok(mut service) => {
+            let lang = service.language.clone();
+            service.load_language(&lang, fallback)
+        }
+        Err(e) => Err(AppError::Validation(format!("i18n lock poisoned: {}", e))),
+    }
+}
+
+/// Fallback languages currently tried after the active one, in order.
+pub fn get_fallback_languages() -> Vec<String> {
+    match SERVICE.read() {
+        Ok(service) => service
+            .fallback
+            .iter()
+            .map(|(lang, _)| lang.to_uppercase())
+            .collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
 pub fn get_translation(key: &str, params: &[&str]) -> String {
     match SERVICE.read() {
         Ok(service) => service.get_translation(key, params),
@@ -293,11 +518,24 @@ pub fn get_available_languages() -> Vec<String> {
 
 pub fn has_translation(key: &str) -> bool {
     match SERVICE.read() {
-        Ok(service) => service.entries.contains_key(key) || service.fallback.contains_key(key),
+        Ok(service) => service.lookup(key).is_some(),
         Err(_) => false,
     }
 }
 
+/// Keys the active language lacked this session, sorted by key
+pub fn get_missing_translations() -> Vec<MissingTranslation> {
+    let Ok(service) = SERVICE.read() else {
+        return Vec::new();
+    };
+    let mut missing: Vec<MissingTranslation> = match service.missing.read() {
+        Ok(missing) => missing.values().cloned().collect(),
+        Err(_) => Vec::new(),
+    };
+    missing.sort_by(|a, b| a.key.cmp(&b.key));
+    missing
+}
+
 pub fn clear_translation_cache() {
     if let Ok(service) = SERVICE.read() {
         if let Ok(mut cache) = service.cache.write() {
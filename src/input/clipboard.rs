@@ -0,0 +1,183 @@
+// src/input/clipboard.rs
+//
+// System clipboard access via external tools, with an in-process buffer
+// when no tool is available (headless Linux, missing xclip/wl-clipboard).
+
+use std::process::{Command, Stdio};
+use std::sync::{Mutex, OnceLock};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipboardBackend {
+    /// macOS pbcopy/pbpaste
+    Pasteboard,
+    /// Wayland wl-copy/wl-paste
+    Wayland,
+    /// X11 xclip
+    Xclip,
+    /// Windows PowerShell Get-Clipboard / clip.exe
+    PowerShell,
+    /// In-process buffer, only shared within this TUI session
+    Internal,
+}
+
+static BACKEND: OnceLock<ClipboardBackend> = OnceLock::new();
+static INTERNAL_BUFFER: Mutex<String> = Mutex::new(String::new());
+
+/// Detected backend; probed once per process by looking up tools on PATH
+pub fn backend() -> ClipboardBackend {
+    *BACKEND.get_or_init(|| {
+        let backend = detect_backend();
+        log::debug!("Clipboard backend: {:?}", backend);
+        backend
+    })
+}
+
+fn detect_backend() -> ClipboardBackend {
+    if cfg!(target_os = "macos") {
+        return ClipboardBackend::Pasteboard;
+    }
+    if cfg!(target_os = "windows") {
+        return ClipboardBackend::PowerShell;
+    }
+
+    let has_env = |name: &str| std::env::var_os(name).is_some_and(|v| !v.is_empty());
+
+    if has_env("WAYLAND_DISPLAY") && in_path("wl-copy") && in_path("wl-paste") {
+        ClipboardBackend::Wayland
+    } else if has_env("DISPLAY") && in_path("xclip") {
+        ClipboardBackend::Xclip
+    } else {
+        ClipboardBackend::Internal
+    }
+}
+
+/// True if an executable named `program` exists in one of the PATH directories
+fn in_path(program: &str) -> bool {
+    std::env::var_os("PATH")
+        .is_some_and(|paths| std::env::split_paths(&paths).any(|dir| dir.join(program).is_file()))
+}
+
+pub fn read() -> Option<String> {
+    let external = command(backend(), "read").and_then(|mut cmd| {
+        let output = cmd.stderr(Stdio::null()).output().ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    });
+
+    let text = match external {
+        Some(text) => text,
+        None => read_internal(),
+    };
+
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
+    }
+}
+
+/// Writes to the system clipboard; the internal buffer always keeps a copy
+/// so paste keeps working if the external tool fails.
+pub fn write(text: &str) -> bool {
+    if text.is_empty() {
+        return false;
+    }
+
+    write_internal(text);
+
+    if let Some(mut cmd) = command(backend(), "write") {
+        let spawned = cmd
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+        if let Ok(mut child) = spawned {
+            if let Some(stdin) = child.stdin.as_mut() {
+                use std::io::Write;
+                let _ = stdin.write_all(text.as_bytes());
+            }
+            // Close stdin so the tool sees EOF before we wait
+            drop(child.stdin.take());
+            if child.wait().is_ok_and(|status| status.success()) {
+                return true;
+            }
+        }
+        log::debug!("Clipboard tool failed, using internal buffer");
+    }
+
+    true
+}
+
+fn read_internal() -> String {
+    INTERNAL_BUFFER
+        .lock()
+        .map(|buffer| buffer.clone())
+        .unwrap_or_default()
+}
+
+fn write_internal(text: &str) {
+    if let Ok(mut buffer) = INTERNAL_BUFFER.lock() {
+        *buffer = text.to_string();
+    }
+}
+
+fn command(backend: ClipboardBackend, op: &str) -> Option<Command> {
+    let read = op == "read";
+    let cmd = match backend {
+        ClipboardBackend::Pasteboard => Command::new(if read { "pbpaste" } else { "pbcopy" }),
+        ClipboardBackend::Wayland => {
+            if read {
+                let mut cmd = Command::new("wl-paste");
+                cmd.arg("--no-newline");
+                cmd
+            } else {
+                Command::new("wl-copy")
+            }
+        }
+        ClipboardBackend::Xclip => {
+            let mut cmd = Command::new("xclip");
+            if read {
+                cmd.args(["-selection", "clipboard", "-o"]);
+            } else {
+                cmd.args(["-selection", "clipboard"]);
+            }
+            cmd
+        }
+        ClipboardBackend::PowerShell => {
+            if read {
+                let mut cmd = Command::new("powershell");
+                cmd.args(["-Command", "Get-Clipboard"]);
+                cmd
+            } else {
+                Command::new("clip")
+            }
+        }
+        ClipboardBackend::Internal => return None,
+    };
+    Some(cmd)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_internal_backend_has_no_command() {
+        assert!(command(ClipboardBackend::Internal, "read").is_none());
+        assert!(command(ClipboardBackend::Internal, "write").is_none());
+        assert!(command(ClipboardBackend::Wayland, "write").is_some());
+    }
+
+    #[test]
+    fn test_internal_buffer_roundtrip() {
+        write_internal("rush clipboard");
+        assert_eq!(read_internal(), "rush clipboard");
+    }
+
+    #[test]
+    fn test_in_path() {
+        assert!(!in_path("rush-sync-no-such-tool"));
+    }
+}
@@ -1,6 +1,7 @@
-use crate::core::constants::DOUBLE_ESC_THRESHOLD;
+use crate::core::constants::{CHORD_TIMEOUT_MS, DOUBLE_ESC_THRESHOLD};
 use crate::core::prelude::*;
 use crossterm::event::KeyModifiers;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{LazyLock, Mutex};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -18,13 +19,24 @@ pub enum KeyAction {
     ClearLine,
     CopySelection,
     PasteBuffer,
+    HistorySearch,
     NoAction,
     ScrollUp,
     ScrollDown,
     PageUp,
     PageDown,
+    ScrollToTop,
+    ScrollToBottom,
+    SearchNext,
+    SearchPrev,
+    ScrollLeft,
+    ScrollRight,
+    /// First key(s) of a chord binding; waiting for the rest
+    ChordPending,
 }
 
+type KeyCombo = (KeyCode, KeyModifiers);
+
 static LAST_ESC_PRESS: LazyLock<Mutex<Option<Instant>>> = LazyLock::new(|| Mutex::new(None));
 static ESCAPE_SEQUENCE_BUFFER: LazyLock<Mutex<Vec<char>>> =
     LazyLock::new(|| Mutex::new(Vec::new()));
@@ -33,6 +45,14 @@ pub struct KeyboardManager {
     double_press_threshold: Duration,
     sequence_timeout: Duration,
     last_key_time: Instant,
+    /// User bindings from `[keybindings]`, checked before the defaults
+    overrides: HashMap<KeyCombo, KeyAction>,
+    /// Multi-key bindings (`"ctrl+x ctrl+s" = "Submit"`)
+    chords: HashMap<Vec<KeyCombo>, KeyAction>,
+    pending: Vec<KeyCombo>,
+    pending_since: Instant,
+    chord_timeout: Duration,
+    chord_completed: bool,
 }
 
 impl KeyboardManager {
@@ -41,7 +61,98 @@ impl KeyboardManager {
             double_press_threshold: Duration::from_millis(DOUBLE_ESC_THRESHOLD),
             sequence_timeout: Duration::from_millis(100),
             last_key_time: Instant::now(),
+            overrides: HashMap::new(),
+            chords: HashMap::new(),
+            pending: Vec::new(),
+            pending_since: Instant::now(),
+            chord_timeout: Duration::from_millis(CHORD_TIMEOUT_MS),
+            chord_completed: false,
+        }
+    }
+
+    pub fn from_config(config: &Config) -> Self {
+        let mut manager = Self::new();
+        manager.set_bindings(&config.keybindings);
+        manager
+    }
+
+    /// Replaces the user bindings (`"ctrl+k" = "ClearLine"`, chords as
+    /// space-separated keys). Entries with an unknown key or action are
+    /// logged and skipped; the default stays.
+    pub fn set_bindings(&mut self, bindings: &BTreeMap<String, String>) {
+        self.overrides.clear();
+        self.chords.clear();
+        self.pending.clear();
+        for (spec, action_name) in bindings {
+            let Some(keys) = spec
+                .split_whitespace()
+                .map(parse_key_spec)
+                .collect::<Option<Vec<_>>>()
+                .filter(|keys| !keys.is_empty())
+            else {
+                log::warn!("[keybindings] unknown key '{}', ignored", spec);
+                continue;
+            };
+            let Some(action) = parse_action_name(action_name) else {
+                log::warn!(
+                    "[keybindings] unknown action '{}' for '{}', ignored",
+                    action_name,
+                    spec
+                );
+                continue;
+            };
+
+            if keys.len() == 1 {
+                self.overrides.insert(keys[0], action);
+            } else if is_plain_char(keys[0]) {
+                // Typing that letter would otherwise start the chord
+                log::warn!(
+                    "[keybindings] chord '{}' must start with a modifier or special key, ignored",
+                    spec
+                );
+            } else {
+                self.chords.insert(keys, action);
+            }
+        }
+    }
+
+    /// Whether the last `get_action` result completed a chord, i.e. the
+    /// action belongs to the whole sequence rather than the key itself
+    pub fn chord_completed(&self) -> bool {
+        self.chord_completed
+    }
+
+    fn resolve_chord(&mut self, combo: KeyCombo) -> Option<KeyAction> {
+        if self.chords.is_empty() {
+            return None;
+        }
+        if !self.pending.is_empty() && self.pending_since.elapsed() > self.chord_timeout {
+            self.pending.clear();
+        }
+
+        let mut sequence = std::mem::take(&mut self.pending);
+        let had_prefix = !sequence.is_empty();
+        sequence.push(combo);
+
+        if let Some(action) = self.chords.get(&sequence) {
+            self.chord_completed = true;
+            return Some(action.clone());
+        }
+        if self
+            .chords
+            .keys()
+            .any(|chord| chord.len() > sequence.len() && chord.starts_with(&sequence))
+        {
+            self.pending = sequence;
+            self.pending_since = Instant::now();
+            return Some(KeyAction::ChordPending);
         }
+
+        // Unbound continuation: the prefix is dropped, the key counts on its own
+        if had_prefix {
+            return self.resolve_chord(combo);
+        }
+        None
     }
 
     // Consolidated security filtering
@@ -97,6 +208,15 @@ impl KeyboardManager {
     }
 
     pub fn get_action(&mut self, key: &KeyEvent) -> KeyAction {
+        self.chord_completed = false;
+        let combo = normalize_key(key.code, key.modifiers);
+        if let Some(action) = self.resolve_chord(combo) {
+            return action;
+        }
+        if let Some(action) = self.overrides.get(&combo) {
+            return action.clone();
+        }
+
         // Handle ESC double-press
         if key.code == KeyCode::Esc {
             return self.handle_escape();
@@ -114,6 +234,11 @@ impl KeyboardManager {
             match key.code {
                 KeyCode::Up => return KeyAction::ScrollUp,
                 KeyCode::Down => return KeyAction::ScrollDown,
+                KeyCode::Home => return KeyAction::ScrollToTop,
+                KeyCode::End => return KeyAction::ScrollToBottom,
+                KeyCode::F(3) => return KeyAction::SearchPrev,
+                KeyCode::Left => return KeyAction::ScrollLeft,
+                KeyCode::Right => return KeyAction::ScrollRight,
                 _ => {}
             }
         }
@@ -130,6 +255,9 @@ impl KeyboardManager {
             // Scrolling
             (KeyCode::PageUp, KeyModifiers::NONE) => KeyAction::PageUp,
             (KeyCode::PageDown, KeyModifiers::NONE) => KeyAction::PageDown,
+            (KeyCode::Home, KeyModifiers::CONTROL) => KeyAction::ScrollToTop,
+            (KeyCode::End, KeyModifiers::CONTROL) => KeyAction::ScrollToBottom,
+            (KeyCode::F(3), KeyModifiers::NONE) => KeyAction::SearchNext,
 
             // Text editing
             (KeyCode::Backspace, KeyModifiers::NONE) => KeyAction::Backspace,
@@ -182,6 +310,7 @@ impl KeyboardManager {
             'a' if self.is_select_modifier(mods) => KeyAction::MoveToStart,
             'e' if self.is_end_modifier(mods) => KeyAction::MoveToEnd,
             'u' if self.is_clear_modifier(mods) => KeyAction::ClearLine,
+            'r' if self.is_end_modifier(mods) => KeyAction::HistorySearch,
             _ => KeyAction::NoAction,
         }
     }
@@ -213,6 +342,103 @@ impl KeyboardManager {
     }
 }
 
+fn is_plain_char((code, mods): KeyCombo) -> bool {
+    matches!(code, KeyCode::Char(_)) && (mods - KeyModifiers::SHIFT).is_empty()
+}
+
+/// Letters are matched case-insensitively; an upper-case letter implies Shift
+fn normalize_key(code: KeyCode, mods: KeyModifiers) -> (KeyCode, KeyModifiers) {
+    match code {
+        KeyCode::Char(c) if c.is_ascii_uppercase() => (
+            KeyCode::Char(c.to_ascii_lowercase()),
+            mods | KeyModifiers::SHIFT,
+        ),
+        _ => (code, mods),
+    }
+}
+
+/// Parses `ctrl+shift+k`, `alt+left`, `f5`, `pageup` ... (case-insensitive)
+pub fn parse_key_spec(spec: &str) -> Option<(KeyCode, KeyModifiers)> {
+    let spec = spec.trim().to_lowercase();
+    let mut parts: Vec<&str> = spec.split('+').map(str::trim).collect();
+    // `ctrl++` binds the plus key itself
+    if spec.ends_with("++") {
+        parts.truncate(parts.len().saturating_sub(2));
+        parts.push("+");
+    }
+    let key = parts.pop().filter(|k| !k.is_empty())?;
+
+    let mut mods = KeyModifiers::NONE;
+    for modifier in parts {
+        mods |= match modifier {
+            "ctrl" | "control" => KeyModifiers::CONTROL,
+            "alt" | "meta" | "option" => KeyModifiers::ALT,
+            "shift" => KeyModifiers::SHIFT,
+            "super" | "cmd" | "command" => KeyModifiers::SUPER,
+            _ => return None,
+        };
+    }
+
+    let code = match key {
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" | "pgup" => KeyCode::PageUp,
+        "pagedown" | "pgdn" => KeyCode::PageDown,
+        "enter" | "return" => KeyCode::Enter,
+        "backspace" => KeyCode::Backspace,
+        "delete" | "del" => KeyCode::Delete,
+        "tab" => KeyCode::Tab,
+        "esc" | "escape" => KeyCode::Esc,
+        "space" => KeyCode::Char(' '),
+        f if f.len() > 1 && f.starts_with('f') => KeyCode::F(f[1..].parse().ok()?),
+        c if c.chars().count() == 1 => KeyCode::Char(c.chars().next()?),
+        _ => return None,
+    };
+    Some(normalize_key(code, mods))
+}
+
+/// Maps an action name (`ScrollUp`, `scroll_up`, `scroll-up`) to its action.
+/// `InsertChar` cannot be bound; `NoAction` unbinds a default key.
+pub fn parse_action_name(name: &str) -> Option<KeyAction> {
+    let normalized: String = name
+        .chars()
+        .filter(|c| !matches!(c, '_' | '-' | ' '))
+        .collect::<String>()
+        .to_lowercase();
+
+    Some(match normalized.as_str() {
+        "moveleft" => KeyAction::MoveLeft,
+        "moveright" => KeyAction::MoveRight,
+        "movetostart" => KeyAction::MoveToStart,
+        "movetoend" => KeyAction::MoveToEnd,
+        "backspace" => KeyAction::Backspace,
+        "delete" => KeyAction::Delete,
+        "submit" => KeyAction::Submit,
+        "cancel" => KeyAction::Cancel,
+        "quit" => KeyAction::Quit,
+        "clearline" => KeyAction::ClearLine,
+        "copyselection" => KeyAction::CopySelection,
+        "pastebuffer" => KeyAction::PasteBuffer,
+        "historysearch" => KeyAction::HistorySearch,
+        "noaction" | "none" => KeyAction::NoAction,
+        "scrollup" => KeyAction::ScrollUp,
+        "scrolldown" => KeyAction::ScrollDown,
+        "pageup" => KeyAction::PageUp,
+        "pagedown" => KeyAction::PageDown,
+        "scrolltotop" => KeyAction::ScrollToTop,
+        "scrolltobottom" => KeyAction::ScrollToBottom,
+        "searchnext" => KeyAction::SearchNext,
+        "searchprev" => KeyAction::SearchPrev,
+        "scrollleft" => KeyAction::ScrollLeft,
+        "scrollright" => KeyAction::ScrollRight,
+        _ => return None,
+    })
+}
+
 impl Default for KeyboardManager {
     fn default() -> Self {
         Self::new()
@@ -252,6 +478,10 @@ mod tests {
         // Test ALT shortcuts (fallback)
         let alt_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::ALT);
         assert_eq!(manager.get_action(&alt_c), KeyAction::CopySelection);
+
+        // Reverse history search
+        let ctrl_r = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL);
+        assert_eq!(manager.get_action(&ctrl_r), KeyAction::HistorySearch);
     }
 
     #[test]
@@ -263,6 +493,100 @@ mod tests {
 
         let shift_down = KeyEvent::new(KeyCode::Down, KeyModifiers::SHIFT);
         assert_eq!(manager.get_action(&shift_down), KeyAction::ScrollDown);
+
+        // Plain Home/End stay with the input cursor
+        let home = KeyEvent::new(KeyCode::Home, KeyModifiers::NONE);
+        assert_eq!(manager.get_action(&home), KeyAction::MoveToStart);
+
+        let ctrl_home = KeyEvent::new(KeyCode::Home, KeyModifiers::CONTROL);
+        assert_eq!(manager.get_action(&ctrl_home), KeyAction::ScrollToTop);
+
+        let shift_end = KeyEvent::new(KeyCode::End, KeyModifiers::SHIFT);
+        assert_eq!(manager.get_action(&shift_end), KeyAction::ScrollToBottom);
+
+        let f3 = KeyEvent::new(KeyCode::F(3), KeyModifiers::NONE);
+        assert_eq!(manager.get_action(&f3), KeyAction::SearchNext);
+
+        let shift_f3 = KeyEvent::new(KeyCode::F(3), KeyModifiers::SHIFT);
+        assert_eq!(manager.get_action(&shift_f3), KeyAction::SearchPrev);
+
+        let shift_right = KeyEvent::new(KeyCode::Right, KeyModifiers::SHIFT);
+        assert_eq!(manager.get_action(&shift_right), KeyAction::ScrollRight);
+    }
+
+    #[test]
+    fn test_keybinding_overrides() {
+        let mut bindings = BTreeMap::new();
+        bindings.insert("ctrl+k".to_string(), "ClearLine".to_string());
+        bindings.insert("Ctrl+Shift+J".to_string(), "scroll_down".to_string());
+        bindings.insert("alt+p".to_string(), "Teleport".to_string());
+        bindings.insert("hyper+x".to_string(), "Quit".to_string());
+        bindings.insert("f3".to_string(), "NoAction".to_string());
+
+        let mut manager = KeyboardManager::new();
+        manager.set_bindings(&bindings);
+
+        let ctrl_k = KeyEvent::new(KeyCode::Char('k'), KeyModifiers::CONTROL);
+        assert_eq!(manager.get_action(&ctrl_k), KeyAction::ClearLine);
+
+        let ctrl_shift_j = KeyEvent::new(
+            KeyCode::Char('J'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        );
+        assert_eq!(manager.get_action(&ctrl_shift_j), KeyAction::ScrollDown);
+
+        // Unbound default and untouched defaults
+        let f3 = KeyEvent::new(KeyCode::F(3), KeyModifiers::NONE);
+        assert_eq!(manager.get_action(&f3), KeyAction::NoAction);
+        let ctrl_r = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL);
+        assert_eq!(manager.get_action(&ctrl_r), KeyAction::HistorySearch);
+
+        // Unknown action/key entries are skipped
+        assert_eq!(manager.overrides.len(), 3);
+        assert_eq!(
+            parse_key_spec("ctrl++"),
+            Some((KeyCode::Char('+'), KeyModifiers::CONTROL))
+        );
+        assert_eq!(
+            parse_key_spec("f12"),
+            Some((KeyCode::F(12), KeyModifiers::NONE))
+        );
+    }
+
+    #[test]
+    fn test_chord_bindings() {
+        let mut bindings = BTreeMap::new();
+        bindings.insert("ctrl+x ctrl+s".to_string(), "Submit".to_string());
+        bindings.insert("ctrl+x k".to_string(), "ClearLine".to_string());
+        bindings.insert("g g".to_string(), "ScrollToTop".to_string());
+
+        let mut manager = KeyboardManager::new();
+        manager.set_bindings(&bindings);
+        // Chords starting with a plain character would swallow typing
+        assert_eq!(manager.chords.len(), 2);
+
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+        let plain = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+
+        assert_eq!(manager.get_action(&ctrl('x')), KeyAction::ChordPending);
+        assert!(!manager.chord_completed());
+        assert_eq!(manager.get_action(&ctrl('s')), KeyAction::Submit);
+        assert!(manager.chord_completed());
+
+        assert_eq!(manager.get_action(&ctrl('x')), KeyAction::ChordPending);
+        assert_eq!(manager.get_action(&plain('k')), KeyAction::ClearLine);
+
+        // Plain typing is untouched, also right after an unbound continuation
+        assert_eq!(manager.get_action(&plain('g')), KeyAction::InsertChar('g'));
+        assert_eq!(manager.get_action(&ctrl('x')), KeyAction::ChordPending);
+        assert_eq!(manager.get_action(&plain('a')), KeyAction::InsertChar('a'));
+        assert!(!manager.chord_completed());
+
+        // An expired prefix is forgotten
+        manager.chord_timeout = Duration::ZERO;
+        assert_eq!(manager.get_action(&ctrl('x')), KeyAction::ChordPending);
+        std::thread::sleep(Duration::from_millis(5));
+        assert_eq!(manager.get_action(&plain('k')), KeyAction::InsertChar('k'));
     }
 
     #[test]
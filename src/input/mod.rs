@@ -1,3 +1,4 @@
+pub mod clipboard;
 pub mod keyboard;
 pub mod state;
 
@@ -40,40 +41,74 @@ pub fn send_progress(message: String) -> bool {
     }
 }
 
+/// Timing of the input and tick loops, taken from `[general]` in rush.toml
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventHandlerConfig {
+    pub tick_rate: Duration,
+    pub poll_interval: Duration,
+    pub key_interval: Duration,
+    pub resize_interval: Duration,
+}
+
+impl Default for EventHandlerConfig {
+    fn default() -> Self {
+        Self::from(&crate::core::config::Config::default())
+    }
+}
+
+impl From<&crate::core::config::Config> for EventHandlerConfig {
+    fn from(config: &crate::core::config::Config) -> Self {
+        Self {
+            tick_rate: config.poll_rate,
+            poll_interval: config.input_poll,
+            key_interval: config.key_interval,
+            resize_interval: config.resize_throttle,
+        }
+    }
+}
+
 pub struct EventHandler {
     rx: mpsc::Receiver<AppEvent>,
     shutdown_tx: Vec<Sender<()>>,
 }
 
 impl EventHandler {
-    pub fn new(tick_rate: Duration) -> Self {
+    pub fn new(config: EventHandlerConfig) -> Self {
         let (tx, rx) = mpsc::channel(100);
         let mut shutdown_tx = Vec::new();
 
         // Input event handler
         let (input_shutdown_tx, input_shutdown_rx) = mpsc::channel(1);
         shutdown_tx.push(input_shutdown_tx);
-        Self::spawn_input_handler(tx.clone(), input_shutdown_rx);
+        Self::spawn_input_handler(tx.clone(), config, input_shutdown_rx);
 
         // Tick handler
         let (tick_shutdown_tx, tick_shutdown_rx) = mpsc::channel(1);
         shutdown_tx.push(tick_shutdown_tx);
-        Self::spawn_tick_handler(tx, tick_rate, tick_shutdown_rx);
+        Self::spawn_tick_handler(tx, config.tick_rate, tick_shutdown_rx);
 
         EventHandler { rx, shutdown_tx }
     }
 
-    fn spawn_input_handler(tx: mpsc::Sender<AppEvent>, mut shutdown_rx: mpsc::Receiver<()>) {
+    fn spawn_input_handler(
+        tx: mpsc::Sender<AppEvent>,
+        config: EventHandlerConfig,
+        mut shutdown_rx: mpsc::Receiver<()>,
+    ) {
         tokio::spawn(async move {
             let (mut last_key_time, mut last_resize_time) = (Instant::now(), Instant::now());
-            let (key_interval, resize_interval) =
-                (Duration::from_millis(16), Duration::from_millis(50));
+            let EventHandlerConfig {
+                poll_interval,
+                key_interval,
+                resize_interval,
+                ..
+            } = config;
 
             loop {
                 tokio::select! {
                     _ = shutdown_rx.recv() => break,
                     _ = async {
-                        if crossterm_event::poll(Duration::from_millis(99)).unwrap_or(false) {
+                        if crossterm_event::poll(poll_interval).unwrap_or(false) {
                             if let Ok(event) = crossterm_event::read() {
                                 let now = Instant::now();
                                 match event {
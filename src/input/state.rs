@@ -31,6 +31,7 @@ enum SystemAction {
     Restart,
     ClearHistory,
     CleanupExecute(String),
+    CleanupItem,
 }
 
 impl SystemCommandProcessor {
@@ -72,6 +73,7 @@ impl SystemCommandProcessor {
         let confirm_restart = format!("{}{}", SIG_CONFIRM_PREFIX, SIG_RESTART);
         let confirm_history = format!("{}{}", SIG_CONFIRM_PREFIX, SIG_CLEAR_HISTORY);
         let confirm_cleanup = format!("{}{}", SIG_CONFIRM_PREFIX, SIG_CONFIRM_CLEANUP);
+        let confirm_cleanup_item = format!("{}{}", SIG_CONFIRM_PREFIX, SIG_CONFIRM_CLEANUP_ITEM);
 
         if let Some(prompt) = input.strip_prefix(&confirm_exit) {
             self.pending_confirmation = Some(PendingConfirmation {
@@ -94,6 +96,13 @@ impl SystemCommandProcessor {
             return Some(SystemCommandResult::ShowPrompt(prompt.to_string()));
         }
 
+        if let Some(prompt) = input.strip_prefix(&confirm_cleanup_item) {
+            self.pending_confirmation = Some(PendingConfirmation {
+                action: SystemAction::CleanupItem,
+            });
+            return Some(SystemCommandResult::ShowPrompt(prompt.to_string()));
+        }
+
         if let Some(rest) = input.strip_prefix(&confirm_cleanup) {
             if let Some((force_command, prompt)) = rest.split_once("__") {
                 self.pending_confirmation = Some(PendingConfirmation {
@@ -106,7 +115,7 @@ impl SystemCommandProcessor {
         None
     }
 
-    /// Handle user confirmation input (y/n).
+    /// Handle user confirmation input (y/n, or y/n/a/q for cleanup items).
     fn handle_user_confirmation(&mut self, input: &str) -> SystemCommandResult {
         let confirm_key = t!("system.input.confirm.short").to_lowercase();
         let user_input = input.trim().to_lowercase();
@@ -115,32 +124,56 @@ impl SystemCommandProcessor {
             return SystemCommandResult::NotSystemCommand;
         };
 
-        let result = if user_input == confirm_key {
-            match &pending.action {
-                SystemAction::Exit => SystemCommandResult::Exit,
-                SystemAction::Restart => SystemCommandResult::Restart,
-                SystemAction::ClearHistory => SystemCommandResult::ClearHistory,
-                SystemAction::CleanupExecute(force_command) => {
-                    SystemCommandResult::CleanupExecute(force_command.clone())
-                }
+        let result = match &pending.action {
+            // Per-item cleanup: hand y/n/a/q back to the cleanup command
+            SystemAction::CleanupItem => SystemCommandResult::CleanupExecute(format!(
+                "cleanup --answer {}",
+                Self::cleanup_item_answer(&user_input)
+            )),
+            _ if user_input != confirm_key => {
+                SystemCommandResult::Message(get_translation("system.input.cancelled", &[]))
+            }
+            SystemAction::Exit => SystemCommandResult::Exit,
+            SystemAction::Restart => SystemCommandResult::Restart,
+            SystemAction::ClearHistory => SystemCommandResult::ClearHistory,
+            SystemAction::CleanupExecute(force_command) => {
+                SystemCommandResult::CleanupExecute(force_command.clone())
             }
-        } else {
-            SystemCommandResult::Message(get_translation("system.input.cancelled", &[]))
         };
 
         self.pending_confirmation = None;
         result
     }
 
+    /// Maps localized y/n/a/q input to the fixed answers of `cleanup --answer`.
+    /// Anything unrecognized quits, like a cancelled confirmation.
+    fn cleanup_item_answer(user_input: &str) -> &'static str {
+        if user_input == t!("system.input.confirm.short").to_lowercase() {
+            "y"
+        } else if user_input == t!("system.input.cancel.short").to_lowercase() {
+            "n"
+        } else if user_input == t!("system.input.all.short").to_lowercase() {
+            "a"
+        } else {
+            "q"
+        }
+    }
+
     pub fn is_valid_confirmation_char(&self, c: char) -> bool {
-        if self.pending_confirmation.is_none() {
+        let Some(pending) = self.pending_confirmation.as_ref() else {
             return false;
-        }
+        };
 
         let confirm_char = t!("system.input.confirm.short").to_lowercase();
         let cancel_char = t!("system.input.cancel.short").to_lowercase();
         let char_str = c.to_lowercase().to_string();
 
+        if pending.action == SystemAction::CleanupItem {
+            let all_char = t!("system.input.all.short").to_lowercase();
+            let quit_char = t!("system.input.quit.short").to_lowercase();
+            return [confirm_char, cancel_char, all_char, quit_char].contains(&char_str);
+        }
+
         [confirm_char, cancel_char].contains(&char_str)
     }
 
@@ -165,6 +198,15 @@ pub enum SystemCommandResult {
     Message(String),
 }
 
+/// Ctrl+R reverse search; `saved` is the input to restore on Esc
+#[derive(Debug, Default)]
+struct HistorySearch {
+    saved: String,
+    query: String,
+    matches: Vec<String>,
+    index: usize,
+}
+
 pub struct InputState {
     content: String,
     cursor: UiCursor,
@@ -174,6 +216,7 @@ pub struct InputState {
     command_handler: CommandHandler,
     keyboard_manager: KeyboardManager,
     system_processor: SystemCommandProcessor,
+    history_search: Option<HistorySearch>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -193,14 +236,18 @@ impl InputState {
             history_manager: HistoryManager::new(history_config.max_entries),
             config: config.clone(),
             command_handler: CommandHandler::new(),
-            keyboard_manager: KeyboardManager::new(),
+            keyboard_manager: KeyboardManager::from_config(config),
             system_processor: SystemCommandProcessor::default(),
+            history_search: None,
         }
     }
 
     pub fn update_from_config(&mut self, config: &Config) {
         self.cursor.update_from_config(config);
         self.prompt = config.theme.input_cursor_prefix.clone();
+        if config.keybindings != self.config.keybindings {
+            self.keyboard_manager.set_bindings(&config.keybindings);
+        }
         self.config = config.clone();
     }
 
@@ -214,6 +261,13 @@ impl InputState {
     }
 
     pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<String> {
+        // Reverse search mode consumes most keys
+        if self.history_search.is_some() {
+            if let Some(result) = self.handle_history_search_key(&key) {
+                return result;
+            }
+        }
+
         // History navigation
         if let Some(action) = HistoryKeyboardHandler::get_history_action(&key) {
             return self.handle_history(action);
@@ -224,7 +278,11 @@ impl InputState {
         }
 
         let action = self.keyboard_manager.get_action(&key);
+        self.handle_action(action)
+    }
 
+    /// Applies an already resolved action, e.g. a completed chord
+    pub fn handle_action(&mut self, action: KeyAction) -> Option<String> {
         // Confirmation mode: only allow confirmation characters
         if self.system_processor.is_waiting_for_confirmation() {
             return self.handle_confirmation_input(action);
@@ -236,6 +294,10 @@ impl InputState {
             KeyAction::PasteBuffer => self.handle_paste(),
             KeyAction::CopySelection => self.handle_copy(),
             KeyAction::ClearLine => self.handle_clear_line(),
+            KeyAction::HistorySearch => {
+                self.start_history_search();
+                None
+            }
             KeyAction::InsertChar(c) => {
                 self.insert_char(c);
                 None
@@ -354,7 +416,11 @@ impl InputState {
             }
             SystemCommandResult::CleanupExecute(force_command) => {
                 let result = self.command_handler.handle_input(&force_command);
-                Some(result.message)
+                // Interactive cleanup answers may come back with the next item prompt
+                match self.system_processor.process_command(&result.message) {
+                    SystemCommandResult::NotSystemCommand => Some(result.message),
+                    next => self.convert_system_result(next),
+                }
             }
             SystemCommandResult::ShowPrompt(prompt) => Some(prompt),
             SystemCommandResult::Message(msg) => Some(msg),
@@ -375,16 +441,157 @@ impl InputState {
         None
     }
 
+    fn start_history_search(&mut self) {
+        self.history_search = Some(HistorySearch {
+            saved: self.content.clone(),
+            ..Default::default()
+        });
+        self.refresh_history_search();
+    }
+
+    /// Returns `Some(result)` if the key was handled by search mode.
+    /// Keys that search does not use accept the current match and fall through.
+    fn handle_history_search_key(&mut self, key: &KeyEvent) -> Option<Option<String>> {
+        match key.code {
+            KeyCode::Esc => {
+                if let Some(search) = self.history_search.take() {
+                    self.set_content(search.saved);
+                }
+                return Some(None);
+            }
+            KeyCode::Up => {
+                self.step_history_search(1);
+                return Some(None);
+            }
+            KeyCode::Down => {
+                self.step_history_search(-1);
+                return Some(None);
+            }
+            _ => {}
+        }
+
+        match self.keyboard_manager.get_action(key) {
+            KeyAction::HistorySearch => self.step_history_search(1),
+            KeyAction::InsertChar(c) => {
+                if let Some(search) = self.history_search.as_mut() {
+                    search.query.push(c);
+                }
+                self.refresh_history_search();
+            }
+            KeyAction::Backspace => {
+                if let Some(search) = self.history_search.as_mut() {
+                    search.query.pop();
+                }
+                self.refresh_history_search();
+            }
+            KeyAction::Submit => {
+                self.history_search = None;
+                return Some(self.handle_submit());
+            }
+            _ => {
+                self.history_search = None;
+                return None;
+            }
+        }
+        Some(None)
+    }
+
+    fn refresh_history_search(&mut self) {
+        let Some(search) = self.history_search.as_mut() else {
+            return;
+        };
+        search.matches = if search.query.is_empty() {
+            Vec::new()
+        } else {
+            let mut matches = self.history_manager.search(&search.query);
+            // Don't offer the `history search ...` command that opened the search
+            matches.retain(|entry| {
+                !entry.starts_with("history search") && !entry.starts_with("history -s")
+            });
+            matches
+        };
+        search.index = 0;
+        let current = search.matches.first().cloned().unwrap_or_default();
+        self.set_content(current);
+    }
+
+    /// Moves to an older (+1) or newer (-1) match
+    fn step_history_search(&mut self, step: isize) {
+        let Some(search) = self.history_search.as_mut() else {
+            return;
+        };
+        if search.matches.is_empty() {
+            return;
+        }
+        search.index = search
+            .index
+            .saturating_add_signed(step)
+            .min(search.matches.len() - 1);
+        let current = search.matches[search.index].clone();
+        self.set_content(current);
+    }
+
+    fn set_content(&mut self, content: String) {
+        self.content = content;
+        self.cursor.update_text_length(&self.content);
+        self.cursor.move_to_end();
+    }
+
+    /// Prompt in front of the input; shows the query while searching
+    fn display_prompt(&self) -> String {
+        match &self.history_search {
+            Some(search) if !search.query.is_empty() && search.matches.is_empty() => format!(
+                "{} ",
+                get_translation("system.input.history_search_none", &[&search.query])
+            ),
+            Some(search) => format!(
+                "{} ",
+                get_translation("system.input.history_search", &[&search.query])
+            ),
+            None => self.prompt.clone(),
+        }
+    }
+
     fn handle_history_event(&mut self, event: HistoryEvent) -> String {
         match event {
             HistoryEvent::Clear => {
                 self.clear_history();
                 HistoryEventHandler::create_clear_response()
             }
+            HistoryEvent::ClearAll => {
+                self.clear_history();
+                crate::core::constants::SIG_CLEAR.to_string()
+            }
             HistoryEvent::Add(entry) => {
                 self.history_manager.add_entry(entry);
                 String::new()
             }
+            HistoryEvent::Search(query) => {
+                self.start_history_search();
+                if let Some(search) = self.history_search.as_mut() {
+                    search.query = query;
+                }
+                self.refresh_history_search();
+                get_translation("system.input.history_search_hint", &[])
+            }
+            HistoryEvent::SetLimit(limit) => {
+                self.history_manager.set_max_size(limit);
+                self.config.max_history = limit;
+                get_translation(
+                    "system.input.history_limit_set",
+                    &[
+                        &limit.to_string(),
+                        &self.history_manager.entry_count().to_string(),
+                    ],
+                )
+            }
+            HistoryEvent::Status => get_translation(
+                "system.input.history_status",
+                &[
+                    &self.history_manager.entry_count().to_string(),
+                    &self.history_manager.max_size().to_string(),
+                ],
+            ),
             _ => String::new(),
         }
     }
@@ -470,66 +677,11 @@ impl InputState {
     }
 
     fn read_clipboard(&self) -> Option<String> {
-        let output = self.get_clipboard_cmd("read")?.output().ok()?;
-        let text = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        if text.is_empty() {
-            None
-        } else {
-            Some(text)
-        }
+        crate::input::clipboard::read()
     }
 
     fn write_clipboard(&self, text: &str) -> bool {
-        if text.is_empty() {
-            return false;
-        }
-
-        if let Some(mut cmd) = self.get_clipboard_cmd("write") {
-            if let Ok(mut child) = cmd.stdin(std::process::Stdio::piped()).spawn() {
-                if let Some(stdin) = child.stdin.as_mut() {
-                    use std::io::Write;
-                    let _ = stdin.write_all(text.as_bytes());
-                }
-                return child.wait().is_ok();
-            }
-        }
-        false
-    }
-
-    fn get_clipboard_cmd(&self, op: &str) -> Option<std::process::Command> {
-        #[cfg(target_os = "macos")]
-        {
-            Some(std::process::Command::new(if op == "read" {
-                "pbpaste"
-            } else {
-                "pbcopy"
-            }))
-        }
-
-        #[cfg(target_os = "linux")]
-        {
-            let mut cmd = std::process::Command::new("xclip");
-            if op == "read" {
-                cmd.args(["-selection", "clipboard", "-o"]);
-            } else {
-                cmd.args(["-selection", "clipboard"]);
-            }
-            Some(cmd)
-        }
-
-        #[cfg(target_os = "windows")]
-        {
-            if op == "read" {
-                let mut cmd = std::process::Command::new("powershell");
-                cmd.args(["-Command", "Get-Clipboard"]);
-                Some(cmd)
-            } else {
-                None
-            }
-        }
-
-        #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
-        None
+        crate::input::clipboard::write(text)
     }
 
     fn insert_char(&mut self, c: char) {
@@ -608,7 +760,8 @@ impl CursorWidget for InputState {
     fn render_with_cursor(&self) -> (Paragraph<'_>, Option<(u16, u16)>) {
         let graphemes: Vec<&str> = self.content.graphemes(true).collect();
         let cursor_pos = self.cursor.get_position();
-        let prompt_width = self.prompt.width();
+        let prompt = self.display_prompt();
+        let prompt_width = prompt.width();
         let available_width = self
             .config
             .input_max_length
@@ -623,7 +776,7 @@ impl CursorWidget for InputState {
 
         // Create spans
         let mut spans = vec![Span::styled(
-            &self.prompt,
+            prompt,
             Style::default().fg(self.config.theme.input_cursor_color.into()),
         )];
 
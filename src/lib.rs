@@ -44,11 +44,14 @@ pub fn create_default_registry() -> CommandRegistry {
 
 fn build_registry() -> CommandRegistry {
     use commands::{
-        cleanup::CleanupCommand, clear::ClearCommand, create::CreateCommand, exit::ExitCommand,
+        archive::ArchiveCommand, cleanup::CleanupCommand, clear::ClearCommand,
+        create::CreateCommand, disk::DiskCommand, exit::ExitCommand, find::FindCommand,
         help::HelpCommand, history::HistoryCommand, lang::LanguageCommand, list::ListCommand,
-        log_level::LogLevelCommand, recovery::RecoveryCommand, remote::RemoteCommand,
-        restart::RestartCommand, start::StartCommand, stop::StopCommand, sync::SyncCommand,
-        theme::ThemeCommand, version::VersionCommand,
+        log_level::LogLevelCommand, memory::command::MemoryCommand, recovery::RecoveryCommand,
+        remote::RemoteCommand, restart::RestartCommand, server::ServerCommand,
+        session::SessionCommand, start::StartCommand, stop::StopCommand, sync::SyncCommand,
+        theme::ThemeCommand, tls::TlsCommand, uptime::UptimeCommand, version::VersionCommand,
+        wrap::WrapCommand,
     };
 
     let mut registry = CommandRegistry::new();
@@ -56,7 +59,12 @@ fn build_registry() -> CommandRegistry {
     registry
         .register(HelpCommand::new())
         .register(VersionCommand)
+        .register(SessionCommand::new())
+        .register(UptimeCommand)
         .register(ClearCommand)
+        .register(FindCommand)
+        .register(WrapCommand)
+        .register(ArchiveCommand)
         .register(ExitCommand)
         .register(RestartCommand)
         .register(LogLevelCommand)
@@ -66,14 +74,15 @@ fn build_registry() -> CommandRegistry {
         .register(RecoveryCommand::new())
         .register(RemoteCommand::new())
         .register(SyncCommand::new())
+        .register(TlsCommand::new())
         .register(CleanupCommand::new())
+        .register(DiskCommand::new())
         .register(CreateCommand::new())
+        .register(ServerCommand::new())
         .register(ListCommand::new())
         .register(StartCommand::new())
-        .register(StopCommand::new());
-
-    #[cfg(feature = "memory")]
-    registry.register(commands::memory::command::MemoryCommand::new());
+        .register(StopCommand::new())
+        .register(MemoryCommand::new());
 
     registry
 }
@@ -1,4 +1,6 @@
+use rush_sync_server::commands::log_level::LogLevelManager;
 use rush_sync_server::core::config::Config;
+use rush_sync_server::core::crash;
 use rush_sync_server::ui::screen::ScreenManager;
 use rush_sync_server::{i18n, Result};
 use std::io::Write;
@@ -26,6 +28,42 @@ async fn main() -> Result<()> {
         }
     }
 
+    // --config <path>: use exactly this rush.toml instead of discovery
+    {
+        let args: Vec<String> = std::env::args().collect();
+        if let Some(pos) = args.iter().position(|a| a == "--config") {
+            match args.get(pos + 1).filter(|p| !p.starts_with("--")) {
+                Some(path) => {
+                    rush_sync_server::setup::setup_toml::set_config_override(PathBuf::from(path));
+                }
+                None => {
+                    eprintln!("Usage: rush-sync --config <path/to/rush.toml>");
+                    std::process::exit(1);
+                }
+            }
+        }
+    }
+
+    // --data-dir <path>: relocate .rss/ and www/ (RUSH_DATA_DIR works too)
+    {
+        let args: Vec<String> = std::env::args().collect();
+        if let Some(pos) = args.iter().position(|a| a == "--data-dir") {
+            match args.get(pos + 1).filter(|p| !p.starts_with("--")) {
+                Some(path) => {
+                    rush_sync_server::core::paths::set_data_dir(PathBuf::from(path));
+                }
+                None => {
+                    eprintln!("Usage: rush-sync --data-dir <directory>");
+                    std::process::exit(1);
+                }
+            }
+        }
+    }
+
+    // Before i18n and the server system: loading the config writes a default
+    // rush.toml, after which a fresh install no longer looks like one
+    let first_run = rush_sync_server::setup::wizard::is_first_run();
+
     // 0) Bootstrap
     #[cfg(feature = "memory")]
     {
@@ -53,12 +91,22 @@ async fn main() -> Result<()> {
     if headless {
         run_headless().await
     } else {
-        run_tui().await
+        run_tui(first_run).await
     }
 }
 
-async fn run_tui() -> Result<()> {
-    let config = Config::load_with_messages(false).await?;
+async fn run_tui(first_run: bool) -> Result<()> {
+    // Fresh install: ask for language/theme/proxy before the TUI takes over
+    if let Err(e) = rush_sync_server::setup::wizard::run_if_first_run(first_run).await {
+        log::warn!("First-run wizard failed, using defaults: {e}");
+    }
+
+    let mut config = Config::load_with_messages(false).await?;
+    LogLevelManager::apply_config(&config);
+    crash::set_config_summary(&config);
+    if std::env::args().any(|a| a == "--no-banner") {
+        config.show_banner = false;
+    }
     let mut screen = ScreenManager::new(&config).await?;
 
     log::info!("Starting application...");
@@ -73,6 +121,10 @@ async fn run_tui() -> Result<()> {
 }
 
 async fn run_headless() -> Result<()> {
+    if let Ok(config) = Config::load_with_messages(false).await {
+        LogLevelManager::apply_config(&config);
+        crash::set_config_summary(&config);
+    }
     log::info!("Rush Sync Server starting in headless mode...");
 
     // Auto-start servers that were previously running
@@ -121,6 +173,9 @@ fn setup_panic_handler(headless: bool) {
 
         write_debug_log("PANIC", &format!("{}", panic_info));
         eprintln!("PANIC: {}", panic_info);
+        if let Some(path) = crash::write_crash_file(&panic_info.to_string()) {
+            eprintln!("Crash report written to {}", path.display());
+        }
     }));
 }
 
@@ -129,7 +184,7 @@ fn setup_logger() {
 
     impl log::Log for DebugLogger {
         fn enabled(&self, metadata: &log::Metadata) -> bool {
-            metadata.level() <= log::Level::Debug
+            LogLevelManager::allows(metadata.level())
         }
 
         fn log(&self, record: &log::Record) {
@@ -142,7 +197,7 @@ fn setup_logger() {
     }
 
     if log::set_boxed_logger(Box::new(DebugLogger)).is_ok() {
-        log::set_max_level(log::LevelFilter::Debug);
+        log::set_max_level(LogLevelManager::get_current_level());
     }
 }
 
@@ -159,10 +214,9 @@ fn write_debug_log(level: &str, message: &str) {
 }
 
 fn get_debug_log_path() -> PathBuf {
-    std::env::current_exe()
-        .ok()
-        .and_then(|exe| exe.parent().map(|p| p.join(".rss").join("rush.debug")))
-        .unwrap_or_else(|| PathBuf::from("rush.debug"))
+    rush_sync_server::core::paths::base_dir()
+        .map(|base| base.join(".rss").join("rush.debug"))
+        .unwrap_or_else(|_| PathBuf::from("rush.debug"))
 }
 
 /// Raise the file descriptor soft limit to the hard limit.
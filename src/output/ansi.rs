@@ -0,0 +1,160 @@
+// src/output/ansi.rs - ANSI SGR colors for output messages (`ansi_colors`)
+use ratatui::style::{Color, Modifier, Style};
+
+/// Removes escape sequences from `text` and returns the plain text with the
+/// SGR style of every char. Other CSI/OSC sequences are dropped like
+/// `strip_ansi_escapes` does.
+pub fn parse_ansi(text: &str) -> (String, Vec<Style>) {
+    let mut plain = String::with_capacity(text.len());
+    let mut styles = Vec::with_capacity(text.len());
+    let mut style = Style::default();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            plain.push(c);
+            styles.push(style);
+            continue;
+        }
+
+        match chars.next() {
+            // CSI: parameters up to a final byte in @..~
+            Some('[') => {
+                let mut params = String::new();
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        if c == 'm' {
+                            style = apply_sgr(style, &params);
+                        }
+                        break;
+                    }
+                    params.push(c);
+                }
+            }
+            // OSC: ends with BEL or ESC \
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\x07' {
+                        break;
+                    }
+                    if c == '\x1b' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    (plain, styles)
+}
+
+fn apply_sgr(mut style: Style, params: &str) -> Style {
+    let codes: Vec<u16> = if params.is_empty() {
+        vec![0]
+    } else {
+        params
+            .split([';', ':'])
+            .map(|p| p.parse().unwrap_or(0))
+            .collect()
+    };
+
+    let mut iter = codes.into_iter();
+    while let Some(code) = iter.next() {
+        style = match code {
+            0 => Style::default(),
+            1 => style.add_modifier(Modifier::BOLD),
+            2 => style.add_modifier(Modifier::DIM),
+            3 => style.add_modifier(Modifier::ITALIC),
+            4 => style.add_modifier(Modifier::UNDERLINED),
+            5 => style.add_modifier(Modifier::SLOW_BLINK),
+            7 => style.add_modifier(Modifier::REVERSED),
+            9 => style.add_modifier(Modifier::CROSSED_OUT),
+            22 => style.remove_modifier(Modifier::BOLD | Modifier::DIM),
+            23 => style.remove_modifier(Modifier::ITALIC),
+            24 => style.remove_modifier(Modifier::UNDERLINED),
+            25 => style.remove_modifier(Modifier::SLOW_BLINK),
+            27 => style.remove_modifier(Modifier::REVERSED),
+            29 => style.remove_modifier(Modifier::CROSSED_OUT),
+            30..=37 => style.fg(basic_color(code - 30)),
+            39 => Style { fg: None, ..style },
+            40..=47 => style.bg(basic_color(code - 40)),
+            49 => Style { bg: None, ..style },
+            90..=97 => style.fg(bright_color(code - 90)),
+            100..=107 => style.bg(bright_color(code - 100)),
+            38 => match extended_color(&mut iter) {
+                Some(color) => style.fg(color),
+                None => style,
+            },
+            48 => match extended_color(&mut iter) {
+                Some(color) => style.bg(color),
+                None => style,
+            },
+            _ => style,
+        };
+    }
+    style
+}
+
+/// `5;n` (256 colors) or `2;r;g;b` after a 38/48
+fn extended_color(iter: &mut impl Iterator<Item = u16>) -> Option<Color> {
+    match iter.next()? {
+        5 => Some(Color::Indexed(iter.next()?.min(255) as u8)),
+        2 => {
+            let mut channel = || iter.next().map(|v| v.min(255) as u8);
+            Some(Color::Rgb(channel()?, channel()?, channel()?))
+        }
+        _ => None,
+    }
+}
+
+fn basic_color(index: u16) -> Color {
+    match index {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        _ => Color::Gray,
+    }
+}
+
+fn bright_color(index: u16) -> Color {
+    match index {
+        0 => Color::DarkGray,
+        1 => Color::LightRed,
+        2 => Color::LightGreen,
+        3 => Color::LightYellow,
+        4 => Color::LightBlue,
+        5 => Color::LightMagenta,
+        6 => Color::LightCyan,
+        _ => Color::White,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ansi_styles() {
+        let (plain, styles) = parse_ansi("\x1b[1;31mERR\x1b[0m ok \x1b[38;5;208mX\x1b[39m");
+        assert_eq!(plain, "ERR ok X");
+        assert_eq!(styles.len(), plain.chars().count());
+        assert_eq!(
+            styles[0],
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+        );
+        assert_eq!(styles[3], Style::default());
+        assert_eq!(styles[7], Style::default().fg(Color::Indexed(208)));
+    }
+
+    #[test]
+    fn test_parse_ansi_drops_other_sequences() {
+        let (plain, styles) = parse_ansi("\x1b]0;title\x07a\x1b[2Kb\x1b[48;2;1;2;3mc");
+        assert_eq!(plain, "abc");
+        assert_eq!(styles[2].bg, Some(Color::Rgb(1, 2, 3)));
+    }
+}
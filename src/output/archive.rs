@@ -0,0 +1,137 @@
+// src/output/archive.rs - Messages dropped from the output buffer (`archive_overflow`)
+use crate::core::prelude::*;
+use crate::output::display::{clean_ansi_codes, Message};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// The first write of a session moves the previous archive to `session.log.1`
+static SESSION_STARTED: AtomicBool = AtomicBool::new(false);
+
+pub fn archive_path() -> Result<PathBuf> {
+    Ok(crate::core::paths::base_dir()?
+        .join(".rss")
+        .join("session.log"))
+}
+
+/// Appends dropped messages to the archive; failures are only logged so the
+/// output keeps working when the data dir is read-only
+pub fn append(messages: &[Message]) {
+    let lines: String = messages
+        .iter()
+        .filter(|m| !m.content.starts_with("__") && !m.content.trim().is_empty())
+        .map(format_entry)
+        .collect();
+    if lines.is_empty() {
+        return;
+    }
+
+    if let Err(e) = archive_path().and_then(|path| write_entries(&path, &lines)) {
+        log::warn!("Output archive not written: {}", e);
+    }
+}
+
+fn format_entry(message: &Message) -> String {
+    let at = chrono::DateTime::<chrono::Local>::from(message.created_at);
+    format!(
+        "[{}] {}\n",
+        at.format("%Y-%m-%d %H:%M:%S"),
+        clean_ansi_codes(&message.content)
+    )
+}
+
+fn write_entries(path: &std::path::Path, lines: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    if !SESSION_STARTED.swap(true, Ordering::SeqCst) && path.exists() {
+        std::fs::rename(path, path.with_extension("log.1"))?;
+    }
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    file.write_all(lines.as_bytes())?;
+    Ok(())
+}
+
+/// Last `limit` archived messages and the total message count. A message
+/// spans several lines when its content does.
+pub fn tail(limit: usize) -> Result<(Vec<String>, usize)> {
+    let content = match std::fs::read_to_string(archive_path()?) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
+    };
+    let entries = split_entries(&content);
+    let total = entries.len();
+    Ok((entries[total.saturating_sub(limit)..].to_vec(), total))
+}
+
+/// Groups archive lines into messages: each starts with the
+/// `[YYYY-MM-DD HH:MM:SS] ` stamp of `format_entry`
+fn split_entries(content: &str) -> Vec<String> {
+    let mut entries: Vec<String> = Vec::new();
+    for line in content.lines() {
+        match entries.last_mut() {
+            Some(entry) if !is_entry_start(line) => {
+                entry.push('\n');
+                entry.push_str(line);
+            }
+            _ => entries.push(line.to_string()),
+        }
+    }
+    entries
+}
+
+fn is_entry_start(line: &str) -> bool {
+    line.starts_with('[')
+        && line.get(20..22) == Some("] ")
+        && line.get(1..20).is_some_and(|stamp| {
+            chrono::NaiveDateTime::parse_from_str(stamp, "%Y-%m-%d %H:%M:%S").is_ok()
+        })
+}
+
+/// Removes the archive; false if there was none
+pub fn clear() -> Result<bool> {
+    match std::fs::remove_file(archive_path()?) {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn test_format_entry_strips_ansi() {
+        let mut message = Message::new("\x1b[31mred\x1b[0m alert".to_string(), Duration::ZERO);
+        message.created_at = SystemTime::UNIX_EPOCH;
+        let entry = format_entry(&message);
+        assert!(entry.ends_with("] red alert\n"));
+        assert!(entry.starts_with('['));
+    }
+
+    #[test]
+    fn test_split_entries_keeps_multiline_messages() {
+        let mut first = Message::new(
+            "status\n  line two\n  line three".to_string(),
+            Duration::ZERO,
+        );
+        first.created_at = SystemTime::UNIX_EPOCH;
+        let mut second = Message::new("[not a stamp] plain".to_string(), Duration::ZERO);
+        second.created_at = SystemTime::UNIX_EPOCH;
+        let content = format!("{}{}", format_entry(&first), format_entry(&second));
+
+        let entries = split_entries(&content);
+        assert_eq!(entries.len(), 2);
+        assert!(entries[0].ends_with("] status\n  line two\n  line three"));
+        assert!(entries[1].ends_with("] [not a stamp] plain"));
+        assert!(split_entries("").is_empty());
+    }
+}
@@ -3,10 +3,11 @@ use crate::ui::color::AppColor;
 use crate::ui::cursor::{CursorKind, UiCursor};
 use crate::ui::viewport::{ScrollDirection, Viewport, ViewportEvent};
 use ratatui::{
-    style::Style,
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Wrap},
 };
+use std::time::SystemTime;
 use strip_ansi_escapes::strip;
 use unicode_segmentation::UnicodeSegmentation;
 
@@ -15,6 +16,8 @@ pub struct Message {
     pub content: String,
     pub current_length: usize,
     pub timestamp: Instant,
+    /// Wall-clock arrival time, shown with `show_timestamps`
+    pub created_at: SystemTime,
     pub line_count: usize,
     pub typewriter_cursor: Option<UiCursor>,
 }
@@ -25,15 +28,52 @@ struct CachedLine {
     message_index: usize,
     is_partial: bool,
     visible_chars: usize,
+    /// `HH:MM:SS ` on a message's first line, blank padding on the rest
+    timestamp: Option<String>,
+    /// ANSI style per grapheme of `content`; empty unless `ansi_colors`
+    styles: Vec<Style>,
 }
 
+/// Width of the `HH:MM:SS ` prefix
+const TIMESTAMP_WIDTH: usize = 9;
+
+/// Content, visible chars, typing, typewriter cursor, persistent cursor, timestamp prefix,
+/// ANSI style per grapheme (empty without `ansi_colors`)
+pub type OutputLine = (String, usize, bool, bool, bool, Option<String>, Vec<Style>);
+
 type RenderData<'a> = (
-    Vec<(String, usize, bool, bool, bool)>,
+    Vec<OutputLine>,
     Config,
     crate::ui::viewport::LayoutArea,
     &'a UiCursor,
+    Option<OutputSearch>,
 );
 
+/// Active search over the output buffer. Matches are wrapped lines in the
+/// line cache, so a hit split across a wrap boundary is not found.
+#[derive(Debug, Clone, Default)]
+pub struct OutputSearch {
+    pub query: String,
+    pub case_sensitive: bool,
+    matches: Vec<usize>,
+    current: usize,
+}
+
+impl OutputSearch {
+    pub fn match_count(&self) -> usize {
+        self.matches.len()
+    }
+
+    /// 1-based position of the current match, 0 without matches
+    pub fn current_position(&self) -> usize {
+        if self.matches.is_empty() {
+            0
+        } else {
+            self.current + 1
+        }
+    }
+}
+
 impl Message {
     pub fn new(content: String, typewriter_delay: Duration) -> Self {
         let (initial_length, typewriter_cursor) = if typewriter_delay.as_millis() == 0 {
@@ -46,6 +86,7 @@ impl Message {
             content,
             current_length: initial_length,
             timestamp: Instant::now(),
+            created_at: SystemTime::now(),
             line_count: 1,
             typewriter_cursor,
         }
@@ -58,9 +99,7 @@ impl Message {
             return;
         }
 
-        let effective_width = (viewport.output_area().width as usize)
-            .saturating_sub(2)
-            .max(10);
+        let effective_width = viewport.text_width();
         let raw_lines: Vec<&str> = clean_content.lines().collect();
 
         let lines_to_process = if clean_content.ends_with('\n') {
@@ -104,26 +143,40 @@ pub struct MessageDisplay {
     config: Config,
     viewport: Viewport,
     persistent_cursor: UiCursor,
+    search: Option<OutputSearch>,
 }
 
 impl MessageDisplay {
     pub fn new(config: &Config, terminal_width: u16, terminal_height: u16) -> Self {
         Self::log_startup();
+        let mut viewport = Viewport::new(terminal_width, terminal_height);
+        viewport.set_wrap_lines(config.wrap_lines);
+        viewport.set_gutter_width(Self::gutter_width(config));
         Self {
             messages: Vec::with_capacity(config.max_messages),
             line_cache: Vec::new(),
             cache_dirty: true,
             config: config.clone(),
-            viewport: Viewport::new(terminal_width, terminal_height),
+            viewport,
             persistent_cursor: UiCursor::from_config(config, CursorKind::Output),
+            search: None,
+        }
+    }
+
+    fn gutter_width(config: &Config) -> usize {
+        if config.show_timestamps {
+            TIMESTAMP_WIDTH
+        } else {
+            0
         }
     }
 
     fn rebuild_line_cache(&mut self) {
         self.line_cache.clear();
-        let effective_width = (self.viewport.output_area().width as usize)
-            .saturating_sub(2)
-            .max(10);
+        // Unwrapped lines stay whole and are clipped when rendered
+        let effective_width = self.viewport.text_width();
+        let show_timestamps = self.config.show_timestamps;
+        let ansi_colors = self.config.ansi_colors;
 
         for (msg_idx, message) in self.messages.iter().enumerate() {
             let visible_content = if message.is_typing() {
@@ -137,7 +190,13 @@ impl MessageDisplay {
                 message.content.clone()
             };
 
-            let clean_content = clean_message_for_display(&visible_content);
+            let (clean_content, line_styles) = if ansi_colors {
+                let (clean, char_styles) = clean_styled_message(&visible_content);
+                let line_styles = grapheme_styles_per_line(&clean, &char_styles);
+                (clean, line_styles)
+            } else {
+                (clean_message_for_display(&visible_content), Vec::new())
+            };
             let lines: Vec<String> = if clean_content.is_empty() {
                 vec![String::new()]
             } else {
@@ -151,6 +210,21 @@ impl MessageDisplay {
                 lines
             };
 
+            let stamp = show_timestamps.then(|| {
+                chrono::DateTime::<chrono::Local>::from(message.created_at)
+                    .format("%H:%M:%S ")
+                    .to_string()
+            });
+            let padding = show_timestamps.then(|| " ".repeat(TIMESTAMP_WIDTH));
+            let mut first_chunk = true;
+            let mut next_prefix = || {
+                if std::mem::take(&mut first_chunk) {
+                    stamp.clone()
+                } else {
+                    padding.clone()
+                }
+            };
+
             for (line_idx, raw_line) in lines.iter().enumerate() {
                 if raw_line.is_empty() {
                     self.line_cache.push(CachedLine {
@@ -158,12 +232,14 @@ impl MessageDisplay {
                         message_index: msg_idx,
                         is_partial: false,
                         visible_chars: 0,
+                        timestamp: next_prefix(),
+                        styles: Vec::new(),
                     });
                 } else {
                     let graphemes: Vec<&str> = raw_line.graphemes(true).collect();
                     let mut start = 0;
                     while start < graphemes.len() {
-                        let end = (start + effective_width).min(graphemes.len());
+                        let end = start.saturating_add(effective_width).min(graphemes.len());
                         let wrapped_line = graphemes[start..end].join("");
                         let is_last_chunk = end == graphemes.len();
                         let is_last_line = line_idx == lines.len() - 1;
@@ -173,6 +249,12 @@ impl MessageDisplay {
                             message_index: msg_idx,
                             is_partial: message.is_typing() && is_last_line && is_last_chunk,
                             visible_chars: wrapped_line.graphemes(true).count(),
+                            timestamp: next_prefix(),
+                            styles: line_styles
+                                .get(line_idx)
+                                .and_then(|styles| styles.get(start..end))
+                                .map(<[Style]>::to_vec)
+                                .unwrap_or_default(),
                         });
                         start = end;
                     }
@@ -187,6 +269,8 @@ impl MessageDisplay {
                     message_index: self.messages.len(),
                     is_partial: false,
                     visible_chars: 0,
+                    timestamp: None,
+                    styles: Vec::new(),
                 });
             }
         }
@@ -194,9 +278,98 @@ impl MessageDisplay {
         self.cache_dirty = false;
         self.viewport
             .update_content_height_silent(self.line_cache.len());
+        self.viewport.update_content_width(
+            self.line_cache
+                .iter()
+                .map(|line| line.visible_chars)
+                .max()
+                .unwrap_or(0),
+        );
+        self.refresh_search_matches();
+    }
+
+    /// Starts a search, jumps to the first match and returns the match count
+    pub fn search(&mut self, query: &str, case_sensitive: bool) -> usize {
+        if self.cache_dirty {
+            self.rebuild_line_cache();
+        }
+        self.search = Some(OutputSearch {
+            query: query.to_string(),
+            case_sensitive,
+            ..Default::default()
+        });
+        self.refresh_search_matches();
+        self.scroll_to_search_match();
+        self.search.as_ref().map_or(0, |s| s.match_count())
+    }
+
+    /// Moves to the next (`forward`) or previous match, wrapping around
+    pub fn search_step(&mut self, forward: bool) -> Option<&OutputSearch> {
+        let search = self.search.as_mut()?;
+        let count = search.matches.len();
+        if count > 0 {
+            search.current = if forward {
+                (search.current + 1) % count
+            } else {
+                (search.current + count - 1) % count
+            };
+        }
+        self.scroll_to_search_match();
+        self.search.as_ref()
+    }
+
+    /// Re-runs the active search with case sensitivity flipped
+    pub fn toggle_search_case(&mut self) -> Option<&OutputSearch> {
+        let search = self.search.as_ref()?;
+        let (query, case_sensitive) = (search.query.clone(), !search.case_sensitive);
+        self.search(&query, case_sensitive);
+        self.search.as_ref()
+    }
+
+    pub fn clear_search(&mut self) {
+        self.search = None;
+    }
+
+    pub fn active_search(&self) -> Option<&OutputSearch> {
+        self.search.as_ref()
+    }
+
+    fn refresh_search_matches(&mut self) {
+        let Some(search) = self.search.as_mut() else {
+            return;
+        };
+        let current_line = search.matches.get(search.current).copied();
+        search.matches = self
+            .line_cache
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| {
+                !find_matches(&line.content, &search.query, search.case_sensitive).is_empty()
+            })
+            .map(|(idx, _)| idx)
+            .collect();
+        // Stay on the same line when new output arrives
+        search.current = current_line
+            .and_then(|line| search.matches.iter().position(|&m| m >= line))
+            .unwrap_or(0);
+    }
+
+    /// Scrolls so the current match is visible and stops following new output
+    pub fn scroll_to_search_match(&mut self) {
+        let Some(line) = self
+            .search
+            .as_ref()
+            .and_then(|s| s.matches.get(s.current).copied())
+        else {
+            return;
+        };
+        // Park the match a third down the window
+        let offset = line.saturating_sub(self.viewport.window_height() / 3);
+        self.viewport.set_scroll_offset_direct(offset);
+        self.viewport.disable_auto_scroll();
     }
 
-    pub fn get_visible_messages(&mut self) -> Vec<(String, usize, bool, bool, bool)> {
+    pub fn get_visible_messages(&mut self) -> Vec<OutputLine> {
         if self.cache_dirty {
             self.rebuild_line_cache();
         }
@@ -216,6 +389,8 @@ impl MessageDisplay {
                 false,
                 false,
                 self.persistent_cursor.is_visible(),
+                None,
+                Vec::new(),
             ));
             return result;
         }
@@ -244,12 +419,14 @@ impl MessageDisplay {
                     is_typing,
                     cursor_visible,
                     persistent_cursor,
+                    cached_line.timestamp.clone(),
+                    cached_line.styles.clone(),
                 ));
             }
         }
 
         while result.len() < window_height {
-            result.push((String::new(), 0, false, false, false));
+            result.push((String::new(), 0, false, false, false, None, Vec::new()));
         }
         result
     }
@@ -262,14 +439,12 @@ impl MessageDisplay {
     }
 
     fn add_message_with_typewriter(&mut self, content: String, use_typewriter: bool) {
-        let line_count = content.lines().count();
-        let force_instant = line_count > 5 || content.len() > 200;
+        let force_instant = self.config.typewriter.forces_instant(&content);
 
         Self::log_to_file(&content);
 
         if self.messages.len() >= self.config.max_messages {
-            self.messages.remove(0);
-            self.cache_dirty = true;
+            self.drop_oldest(1);
         }
 
         let typewriter_delay = if use_typewriter && !force_instant {
@@ -361,6 +536,8 @@ impl MessageDisplay {
             ScrollDirection::PageDown => self.viewport.page_down(),
             ScrollDirection::ToTop => self.viewport.scroll_to_top(),
             ScrollDirection::ToBottom => self.viewport.scroll_to_bottom(),
+            ScrollDirection::Left => self.viewport.scroll_left(amount.max(1)),
+            ScrollDirection::Right => self.viewport.scroll_right(amount.max(1)),
         }
     }
 
@@ -377,6 +554,7 @@ impl MessageDisplay {
     }
 
     pub fn clear_messages(&mut self) {
+        self.search = None;
         self.messages.clear();
         self.line_cache.clear();
         self.cache_dirty = false;
@@ -391,20 +569,39 @@ impl MessageDisplay {
             self.config.clone(),
             self.viewport.output_area(),
             &self.persistent_cursor,
+            self.search.clone(),
         )
     }
 
     pub fn update_config(&mut self, new_config: &Config) {
+        let gutter = Self::gutter_width(new_config);
+        if new_config.wrap_lines != self.viewport.wraps_lines()
+            || gutter != self.viewport.gutter_width()
+        {
+            self.viewport.set_wrap_lines(new_config.wrap_lines);
+            self.viewport.set_gutter_width(gutter);
+            for message in &mut self.messages {
+                message.calculate_wrapped_line_count(&self.viewport);
+            }
+        }
         self.config = new_config.clone();
         self.persistent_cursor = UiCursor::from_config(new_config, CursorKind::Output);
         self.cache_dirty = true;
         if self.messages.len() > self.config.max_messages {
             let excess = self.messages.len() - self.config.max_messages;
-            self.messages.drain(0..excess);
-            self.cache_dirty = true;
+            self.drop_oldest(excess);
         }
     }
 
+    /// Drops messages over the cap, archiving them with `archive_overflow`
+    fn drop_oldest(&mut self, count: usize) {
+        let dropped: Vec<Message> = self.messages.drain(0..count).collect();
+        if self.config.archive_overflow {
+            crate::output::archive::append(&dropped);
+        }
+        self.cache_dirty = true;
+    }
+
     pub fn viewport(&self) -> &Viewport {
         &self.viewport
     }
@@ -459,50 +656,46 @@ impl MessageDisplay {
         if content.starts_with("__") || content.trim().is_empty() {
             return;
         }
-        if let Ok(exe_path) = std::env::current_exe() {
-            if let Some(base_dir) = exe_path.parent() {
-                let log_path = base_dir.join(".rss").join("rush.logs");
-                let _ = std::fs::create_dir_all(log_path.parent().unwrap());
-                let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
-                let log_line = format!("[{}] {}\n", timestamp, content);
-                let _ = std::fs::OpenOptions::new()
-                    .create(true)
-                    .append(true)
-                    .open(&log_path)
-                    .and_then(|mut file| {
-                        use std::io::Write;
-                        file.write_all(log_line.as_bytes())
-                    });
-            }
+        if let Ok(base_dir) = crate::core::paths::base_dir() {
+            let log_path = base_dir.join(".rss").join("rush.logs");
+            let _ = std::fs::create_dir_all(base_dir.join(".rss"));
+            let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+            let log_line = format!("[{}] {}\n", timestamp, content);
+            let _ = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&log_path)
+                .and_then(|mut file| {
+                    use std::io::Write;
+                    file.write_all(log_line.as_bytes())
+                });
         }
     }
 
     fn log_startup() {
-        if let Ok(exe_path) = std::env::current_exe() {
-            if let Some(base_dir) = exe_path.parent() {
-                let log_path = base_dir.join(".rss").join("rush.logs");
-                let _ = std::fs::create_dir_all(log_path.parent().unwrap());
-                let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
-                let version = crate::core::constants::VERSION;
-                let startup_line = format!(
-                    "[{}] === Rush Sync Server v{} Started ===\n",
-                    timestamp, version
-                );
-                let _ = std::fs::OpenOptions::new()
-                    .create(true)
-                    .append(true)
-                    .open(&log_path)
-                    .and_then(|mut file| {
-                        use std::io::Write;
-                        file.write_all(startup_line.as_bytes())
-                    });
-            }
+        if let Ok(base_dir) = crate::core::paths::base_dir() {
+            let log_path = base_dir.join(".rss").join("rush.logs");
+            let _ = std::fs::create_dir_all(base_dir.join(".rss"));
+            let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+            let version = crate::core::constants::VERSION;
+            let startup_line = format!(
+                "[{}] === Rush Sync Server v{} Started ===\n",
+                timestamp, version
+            );
+            let _ = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&log_path)
+                .and_then(|mut file| {
+                    use std::io::Write;
+                    file.write_all(startup_line.as_bytes())
+                });
         }
     }
 }
 
 // Utility functions
-fn clean_ansi_codes(message: &str) -> String {
+pub(crate) fn clean_ansi_codes(message: &str) -> String {
     String::from_utf8_lossy(&strip(message.as_bytes()).unwrap_or_default()).into_owned()
 }
 
@@ -515,6 +708,40 @@ fn clean_message_for_display(message: &str) -> String {
         .to_string()
 }
 
+/// `clean_message_for_display` that keeps the ANSI style of every char
+fn clean_styled_message(message: &str) -> (String, Vec<Style>) {
+    use crate::core::constants::*;
+    let message = message.replace(SIG_CONFIRM_EXIT, "").replace(SIG_CLEAR, "");
+    let (plain, styles) = crate::output::ansi::parse_ansi(&message);
+
+    let trimmed = plain.trim();
+    let lead = plain[..plain.len() - plain.trim_start().len()]
+        .chars()
+        .count();
+    let len = trimmed.chars().count();
+    (trimmed.to_string(), styles[lead..lead + len].to_vec())
+}
+
+/// Char styles regrouped per `\n`-separated line and grapheme, matching how
+/// the line cache splits and wraps the text
+fn grapheme_styles_per_line(text: &str, char_styles: &[Style]) -> Vec<Vec<Style>> {
+    let mut offset = 0;
+    text.split('\n')
+        .map(|line| {
+            let styles = line
+                .graphemes(true)
+                .map(|g| {
+                    let style = char_styles.get(offset).copied().unwrap_or_default();
+                    offset += g.chars().count();
+                    style
+                })
+                .collect();
+            offset += 1;
+            styles
+        })
+        .collect()
+}
+
 fn parse_message_parts(message: &str) -> Vec<(String, bool)> {
     let mut parts = Vec::new();
     let mut chars = message.char_indices().peekable();
@@ -560,16 +787,137 @@ fn parse_message_parts(message: &str) -> Vec<(String, bool)> {
     parts
 }
 
+/// Byte ranges of non-overlapping `needle` hits in `haystack`, compared char
+/// by char so case folding never shifts offsets
+pub fn find_matches(haystack: &str, needle: &str, case_sensitive: bool) -> Vec<(usize, usize)> {
+    let needle: Vec<char> = needle.chars().collect();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    let chars_eq = |a: char, b: char| {
+        if case_sensitive {
+            a == b
+        } else {
+            a == b || a.to_lowercase().eq(b.to_lowercase())
+        }
+    };
+
+    let mut matches = Vec::new();
+    let mut skip_until = 0;
+    for (start, _) in haystack.char_indices() {
+        if start < skip_until {
+            continue;
+        }
+        let mut chars = haystack[start..].char_indices();
+        let hit = needle
+            .iter()
+            .all(|&n| chars.next().is_some_and(|(_, c)| chars_eq(c, n)));
+        if hit {
+            let end = chars
+                .next()
+                .map_or(haystack.len(), |(offset, _)| start + offset);
+            matches.push((start, end));
+            skip_until = end;
+        }
+    }
+    matches
+}
+
+/// Splits `text` into spans, reversing the colors of search hits
+fn push_highlighted<'a>(
+    spans: &mut Vec<Span<'a>>,
+    text: String,
+    style: Style,
+    search: Option<&OutputSearch>,
+) {
+    let hits = search
+        .map(|s| find_matches(&text, &s.query, s.case_sensitive))
+        .unwrap_or_default();
+    if hits.is_empty() {
+        spans.push(Span::styled(text, style));
+        return;
+    }
+
+    let mut last = 0;
+    for (start, end) in hits {
+        if start > last {
+            spans.push(Span::styled(text[last..start].to_string(), style));
+        }
+        spans.push(Span::styled(
+            text[start..end].to_string(),
+            style.add_modifier(Modifier::REVERSED),
+        ));
+        last = end;
+    }
+    if last < text.len() {
+        spans.push(Span::styled(text[last..].to_string(), style));
+    }
+}
+
+/// `push_highlighted` in runs of equal ANSI style (per grapheme) on top of `base`
+fn push_ansi_runs<'a>(
+    spans: &mut Vec<Span<'a>>,
+    text: String,
+    base: Style,
+    styles: &[Style],
+    search: Option<&OutputSearch>,
+) {
+    if styles.is_empty() {
+        push_highlighted(spans, text, base, search);
+        return;
+    }
+
+    let mut run = String::new();
+    let mut run_style = styles[0];
+    for (i, grapheme) in text.graphemes(true).enumerate() {
+        let style = styles.get(i).copied().unwrap_or_default();
+        if style != run_style && !run.is_empty() {
+            let done = std::mem::take(&mut run);
+            push_highlighted(spans, done, base.patch(run_style), search);
+        }
+        run_style = style;
+        run.push_str(grapheme);
+    }
+    if !run.is_empty() {
+        push_highlighted(spans, run, base.patch(run_style), search);
+    }
+}
+
+/// Drops the first `count` graphemes across `spans`, keeping their styles.
+/// Runs on parsed spans so markers keep their color when scrolled into.
+fn skip_graphemes(spans: Vec<Span<'_>>, mut count: usize) -> Vec<Span<'_>> {
+    let mut kept = Vec::with_capacity(spans.len());
+    for span in spans {
+        if count == 0 {
+            kept.push(span);
+            continue;
+        }
+        let len = span.content.graphemes(true).count();
+        if len <= count {
+            count -= len;
+            continue;
+        }
+        let rest: String = span.content.graphemes(true).skip(count).collect();
+        count = 0;
+        kept.push(Span::styled(rest, span.style));
+    }
+    kept
+}
+
 fn get_marker_color(marker: &str) -> AppColor {
     let display_text = marker.trim_start_matches('[').trim_end_matches(']');
     AppColor::from_display_text(display_text)
 }
 
+/// `horizontal_offset` graphemes are scrolled off the left of every line
+/// (unwrapped mode); timestamps stay in place.
 pub fn create_output_widget<'a>(
-    messages: &'a [(String, usize, bool, bool, bool)],
+    messages: &'a [OutputLine],
     layout_area: crate::ui::viewport::LayoutArea,
     config: &'a Config,
     cursor_state: &'a UiCursor,
+    search: Option<&OutputSearch>,
+    horizontal_offset: usize,
 ) -> Paragraph<'a> {
     let max_lines = layout_area.height as usize;
     if max_lines == 0 || layout_area.width == 0 {
@@ -597,24 +945,50 @@ pub fn create_output_widget<'a>(
             .wrap(Wrap { trim: true });
     }
 
+    // Timestamps are dimmed and sit outside the typewriter progression
+    let timestamp_style = Style::default()
+        .fg(config.theme.output_text.into())
+        .add_modifier(Modifier::DIM);
+
     for (
         message_idx,
-        (message, current_length, is_typing, msg_cursor_visible, persistent_cursor_visible),
+        (
+            message,
+            current_length,
+            is_typing,
+            msg_cursor_visible,
+            persistent_cursor_visible,
+            stamp,
+            ansi_styles,
+        ),
     ) in messages.iter().enumerate()
     {
         let is_last_message = message_idx == messages.len() - 1;
+        let stamp_span = || {
+            stamp
+                .as_ref()
+                .map(|stamp| Span::styled(stamp.clone(), timestamp_style))
+        };
 
         if message.is_empty() {
+            let mut spans: Vec<Span> = stamp_span().into_iter().collect();
             if *persistent_cursor_visible {
-                lines.push(Line::from(vec![cursor_state.create_cursor_span(config)]));
+                spans.push(cursor_state.create_cursor_span(config));
             } else {
-                lines.push(Line::from(vec![Span::raw("")]));
+                spans.push(Span::raw(""));
             }
+            lines.push(Line::from(spans));
             continue;
         }
 
         let clean_message = clean_message_for_display(message);
         let message_lines: Vec<&str> = clean_message.lines().collect();
+        // Graphemes trimmed off the front, to line the ANSI styles up again
+        let ansi_lead = if ansi_styles.is_empty() {
+            0
+        } else {
+            message.graphemes(true).count() - message.trim_start().graphemes(true).count()
+        };
 
         if message_lines.is_empty() {
             lines.push(Line::from(vec![Span::raw("")]));
@@ -640,7 +1014,12 @@ pub fn create_output_widget<'a>(
 
                 let message_parts = parse_message_parts(line_content);
                 let mut spans = Vec::new();
+                if line_idx == 0 {
+                    spans.extend(stamp_span());
+                }
+                let content_start = spans.len();
                 let mut chars_used = 0;
+                let mut part_search_from = 0;
 
                 for (part_text, is_marker) in message_parts {
                     let part_chars = part_text.graphemes(true).count();
@@ -653,24 +1032,45 @@ pub fn create_output_widget<'a>(
                     if chars_used >= visible_chars {
                         break;
                     }
+
+                    // Markers keep their color; plain text takes the ANSI styles
+                    let part_styles = if is_marker || ansi_styles.is_empty() || line_idx > 0 {
+                        &[][..]
+                    } else {
+                        let byte = line_content[part_search_from..]
+                            .find(&part_text)
+                            .map_or(part_search_from, |i| part_search_from + i);
+                        part_search_from = byte + part_text.len();
+                        let start = ansi_lead + line_content[..byte].graphemes(true).count();
+                        ansi_styles.get(start..).unwrap_or_default()
+                    };
+
                     let chars_needed = visible_chars - chars_used;
                     if chars_needed >= part_chars {
-                        spans.push(Span::styled(part_text, part_style));
+                        push_ansi_runs(&mut spans, part_text, part_style, part_styles, search);
                         chars_used += part_chars;
                     } else {
                         let graphemes: Vec<&str> = part_text.graphemes(true).collect();
-                        spans.push(Span::styled(
+                        push_ansi_runs(
+                            &mut spans,
                             graphemes
                                 .iter()
                                 .take(chars_needed)
                                 .copied()
                                 .collect::<String>(),
                             part_style,
-                        ));
+                            part_styles,
+                            search,
+                        );
                         break;
                     }
                 }
 
+                if horizontal_offset > 0 {
+                    let content = spans.split_off(content_start);
+                    spans.extend(skip_graphemes(content, horizontal_offset));
+                }
+
                 if is_last_message
                     && is_last_line
                     && ((*is_typing && *msg_cursor_visible)
@@ -700,11 +1100,15 @@ pub fn create_output_widget<'a>(
         ))]));
     }
 
-    Paragraph::new(lines)
-        .block(
-            Block::default()
-                .borders(Borders::NONE)
-                .style(Style::default().bg(config.theme.output_bg.into())),
-        )
-        .wrap(Wrap { trim: true })
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::NONE)
+            .style(Style::default().bg(config.theme.output_bg.into())),
+    );
+    // Unwrapped lines are clipped at the right edge
+    if config.wrap_lines {
+        paragraph.wrap(Wrap { trim: true })
+    } else {
+        paragraph
+    }
 }
@@ -1,3 +1,5 @@
+pub mod ansi;
+pub mod archive;
 pub mod display;
 
 // Legacy re-exports for compatibility
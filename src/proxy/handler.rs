@@ -1,8 +1,11 @@
+use crate::proxy::types::{ProxyConfig, ProxyRoute, RouteKind};
 use crate::proxy::ProxyManager;
+use hyper::client::HttpConnector;
 use hyper::service::{make_service_fn, service_fn};
-use hyper::{Body, Client, Request, Response, Server, Uri};
+use hyper::{Body, Client, Method, Request, Response, Server, Uri};
 use std::convert::Infallible;
 use std::sync::{Arc, OnceLock, RwLock};
+use std::time::Duration;
 
 // Global TLS acceptor that ACME can hot-reload after provisioning a new certificate
 static PROXY_TLS_ACCEPTOR: OnceLock<RwLock<tokio_rustls::TlsAcceptor>> = OnceLock::new();
@@ -74,7 +77,7 @@ impl ProxyServer {
 
         let make_svc = make_service_fn(move |conn: &hyper::server::conn::AddrStream| {
             let manager = Arc::clone(&manager);
-            let client = Client::new();
+            let client = backend_client(manager.get_config());
             let remote_addr = conn.remote_addr();
 
             async move {
@@ -224,7 +227,7 @@ impl ProxyServer {
                             }
                         };
 
-                        let client = hyper::Client::new();
+                        let client = backend_client(manager.get_config());
                         let service = hyper::service::service_fn(move |req| {
                             let manager = Arc::clone(&manager);
                             let client = client.clone();
@@ -256,7 +259,7 @@ use crate::core::helpers::html_escape;
 pub async fn handle_proxy_request(
     req: Request<Body>,
     manager: Arc<ProxyManager>,
-    client: Client<hyper::client::HttpConnector>,
+    client: Client<HttpConnector>,
     remote_addr: std::net::SocketAddr,
 ) -> Result<Response<Body>, hyper::Error> {
     let config = manager.get_config();
@@ -315,6 +318,13 @@ pub async fn handle_proxy_request(
         path_and_query
     );
 
+    // Path-prefix routes only apply on the bare host (localhost/name/...)
+    let path_route = if subdomain.is_empty() {
+        manager.resolve_path_route(&path_and_query).await
+    } else {
+        None
+    };
+
     // Analytics tracking — prefer forwarding headers (set by upstream reverse proxy),
     // fall back to the actual TCP peer address so unique visitors are counted correctly
     // even when the server is accessed directly.
@@ -333,7 +343,9 @@ pub async fn handle_proxy_request(
         .unwrap_or("")
         .to_string();
     crate::server::analytics::track_request(
-        &subdomain,
+        path_route
+            .as_ref()
+            .map_or(&subdomain, |(route, _)| &route.subdomain),
         &path_and_query,
         &client_ip,
         &proxy_user_agent,
@@ -354,6 +366,36 @@ pub async fn handle_proxy_request(
         }
     }
 
+    if let Some((route, rest)) = path_route {
+        let prefix = route.path_prefix();
+        // Without the trailing slash relative links would resolve outside the prefix
+        if rest.is_empty() {
+            return Ok(Response::builder()
+                .status(301)
+                .header("location", format!("{}/", prefix))
+                .body(Body::empty())
+                .expect("redirect response"));
+        }
+        let forward_path = if rest.starts_with('/') {
+            rest
+        } else {
+            format!("/{}", rest)
+        };
+        let (mut parts, body) = req.into_parts();
+        if let Ok(value) = prefix.parse() {
+            parts.headers.insert("x-forwarded-prefix", value);
+        }
+        return Ok(forward_to_backend(
+            Request::from_parts(parts, body),
+            &client,
+            config,
+            route.target_port,
+            &forward_path,
+            &format!("{}{}", host_no_port, prefix),
+        )
+        .await);
+    }
+
     // Handle bare domain — redirect to default subdomain
     if subdomain.is_empty() {
         if manager.get_target_port("default").await.is_some() {
@@ -366,20 +408,9 @@ pub async fn handle_proxy_request(
                 .body(Body::empty())
                 .expect("redirect response"));
         }
-        // No default server — show welcome page
-        let routes = manager.get_routes().await;
-        let route_links = routes
-            .iter()
-            .map(|r| {
-                format!(
-                    r#"<a href="http://{sub}.{dom}{port}/" style="display:inline-block;padding:10px 20px;background:rgba(108,99,255,0.15);border:1px solid rgba(108,99,255,0.3);border-radius:8px;color:#6c63ff;text-decoration:none;font-weight:500;margin:4px;">{sub}.{dom}</a>"#,
-                    sub = r.subdomain,
-                    dom = domain,
-                    port = external_port_suffix
-                )
-            })
-            .collect::<Vec<_>>()
-            .join("\n");
+        // No default server — show the route index
+        let entries = manager.route_status().await;
+        let reachable = entries.iter().filter(|(_, up)| *up).count();
 
         return Ok(Response::builder()
             .status(200)
@@ -388,24 +419,26 @@ pub async fn handle_proxy_request(
                 r#"<!DOCTYPE html>
 <html><head><meta charset="UTF-8"><meta name="viewport" content="width=device-width,initial-scale=1.0">
 <title>Rush Sync Server</title>
-<style>*{{margin:0;padding:0;box-sizing:border-box}}body{{font-family:-apple-system,BlinkMacSystemFont,'Segoe UI',sans-serif;background:#0a0a0f;color:#e4e4ef;display:flex;align-items:center;justify-content:center;min-height:100vh;padding:20px}}.c{{text-align:center;max-width:600px}}h1{{font-size:clamp(32px,5vw,48px);font-weight:800;letter-spacing:-1px;margin-bottom:12px}}h1 span{{color:#6c63ff}}.sub{{color:#8888a0;font-size:16px;margin-bottom:32px}}.routes{{margin:24px 0;display:flex;flex-wrap:wrap;justify-content:center;gap:8px}}.info{{color:#8888a0;font-size:14px;margin-top:24px}}a.gh{{color:#6c63ff;text-decoration:none}}</style>
+<style>*{{margin:0;padding:0;box-sizing:border-box}}body{{font-family:-apple-system,BlinkMacSystemFont,'Segoe UI',sans-serif;background:#0a0a0f;color:#e4e4ef;display:flex;align-items:center;justify-content:center;min-height:100vh;padding:20px}}.c{{text-align:center;max-width:600px}}h1{{font-size:clamp(32px,5vw,48px);font-weight:800;letter-spacing:-1px;margin-bottom:12px}}h1 span{{color:#6c63ff}}.sub{{color:#8888a0;font-size:16px;margin-bottom:32px}}.routes{{margin:24px 0;display:flex;flex-direction:column;align-items:stretch;gap:8px}}.route{{display:flex;align-items:center;gap:12px;padding:10px 20px;background:rgba(108,99,255,0.15);border:1px solid rgba(108,99,255,0.3);border-radius:8px}}.route a{{color:#6c63ff;text-decoration:none;font-weight:500;flex:1;text-align:left}}.port{{color:#8888a0;font-size:13px;font-family:monospace}}.dot{{width:8px;height:8px;border-radius:50%}}.up{{background:#3ddc84}}.down{{background:#ff5c5c}}.info{{color:#8888a0;font-size:14px;margin-top:24px}}a.gh{{color:#6c63ff;text-decoration:none}}</style>
 </head><body><div class="c">
 <h1>RUSH<span>.</span>SYNC<span>.</span>SERVER</h1>
 <p class="sub">{}</p>
 <div class="routes">{}</div>
-<p class="info">Powered by Rush Sync Server v0.3.9</p>
+<p class="info">Powered by Rush Sync Server v{}</p>
 <p class="info" style="margin-top:8px"><a class="gh" href="https://github.com/LEVOGNE/rush.sync.server">GitHub</a></p>
 </div></body></html>"#,
-                if routes.is_empty() {
+                if entries.is_empty() {
                     "No servers are running yet. Create one to get started.".to_string()
                 } else {
-                    format!("{} active server{} on this domain:", routes.len(), if routes.len() == 1 { "" } else { "s" })
+                    format!(
+                        "{} route{} on this domain, {} reachable:",
+                        entries.len(),
+                        if entries.len() == 1 { "" } else { "s" },
+                        reachable
+                    )
                 },
-                if routes.is_empty() {
-                    String::new()
-                } else {
-                    route_links
-                }
+                render_route_index(&entries, &domain, &external_port_suffix),
+                crate::core::constants::VERSION
             )))
             .expect("welcome response"));
     }
@@ -430,48 +463,15 @@ pub async fn handle_proxy_request(
     );
 
     if let Some(target_port) = manager.get_target_port(&subdomain).await {
-        let target_uri = format!("http://127.0.0.1:{}{}", target_port, path_and_query);
-
-        match target_uri.parse::<Uri>() {
-            Ok(uri) => {
-                let (mut parts, body) = req.into_parts();
-                parts.uri = uri;
-                parts.headers.insert(
-                    "host",
-                    format!("127.0.0.1:{}", target_port)
-                        .parse()
-                        .unwrap_or_else(|_| hyper::header::HeaderValue::from_static("localhost")),
-                );
-                let backend_req = Request::from_parts(parts, body);
-
-                match client.request(backend_req).await {
-                    Ok(response) => Ok(response),
-                    Err(e) => {
-                        log::warn!("Backend request failed for {}.{}: {}", subdomain, domain, e);
-                        Ok(Response::builder()
-                            .status(502)
-                            .header("content-type", "text/html")
-                            .body(Body::from(format!(
-                                r#"<!DOCTYPE html>
-<html><head><title>Backend Unavailable</title></head>
-<body>
-<h1>502 Bad Gateway</h1>
-<p>Backend server for <strong>{}.{}</strong> is not responding.</p>
-<p>Target: 127.0.0.1:{}</p>
-</body></html>"#,
-                                html_escape(&subdomain),
-                                html_escape(&domain),
-                                target_port
-                            )))
-                            .expect("static 502 response"))
-                    }
-                }
-            }
-            Err(_) => Ok(Response::builder()
-                .status(400)
-                .body(Body::from("Invalid target URI"))
-                .expect("static 400 response")),
-        }
+        Ok(forward_to_backend(
+            req,
+            &client,
+            config,
+            target_port,
+            &path_and_query,
+            &format!("{}.{}", subdomain, domain),
+        )
+        .await)
     } else {
         let routes_html = if routes.is_empty() {
             r#"<div class="no-routes">No servers are running on this domain yet.</div>"#.to_string()
@@ -498,3 +498,163 @@ pub async fn handle_proxy_request(
             .expect("showroom response"))
     }
 }
+
+/// One row per route for the bare-host index: link, backend port and a
+/// reachable/unreachable marker
+pub fn render_route_index(
+    entries: &[(ProxyRoute, bool)],
+    domain: &str,
+    port_suffix: &str,
+) -> String {
+    entries
+        .iter()
+        .map(|(route, up)| {
+            let (href, label) = match route.kind {
+                RouteKind::Subdomain => (
+                    format!("http://{}.{}{}/", route.subdomain, domain, port_suffix),
+                    format!("{}.{}", route.subdomain, domain),
+                ),
+                RouteKind::PathPrefix => (
+                    format!("http://{}{}{}/", domain, port_suffix, route.path_prefix()),
+                    format!("{}{}/", domain, route.path_prefix()),
+                ),
+            };
+            let (class, title) = if *up {
+                ("up", "reachable")
+            } else {
+                ("down", "not reachable")
+            };
+            format!(
+                r#"<div class="route"><span class="dot {class}" title="{title}"></span><a href="{href}">{label}</a><span class="port">127.0.0.1:{port}</span></div>"#,
+                class = class,
+                title = title,
+                href = html_escape(&href),
+                label = html_escape(&label),
+                port = route.target_port
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// HTTP client for backend requests with the configured connect timeout
+pub fn backend_client(config: &ProxyConfig) -> Client<HttpConnector> {
+    let mut connector = HttpConnector::new();
+    connector.set_connect_timeout(Some(Duration::from_millis(config.connect_timeout_ms)));
+    Client::builder().build(connector)
+}
+
+/// Delay before retry `attempt` (1-based): 100ms, 200ms, 400ms ... capped at 1s
+fn retry_backoff(attempt: u32) -> Duration {
+    Duration::from_millis((100u64 << attempt.saturating_sub(1).min(4)).min(1000))
+}
+
+/// Sends `req` to `127.0.0.1:<target_port><path_and_query>`. GET/HEAD requests
+/// are retried with backoff while the backend refuses connections (e.g. during
+/// a restart); anything else that fails becomes a 502/504 page naming `label`.
+async fn forward_to_backend(
+    req: Request<Body>,
+    client: &Client<HttpConnector>,
+    config: &ProxyConfig,
+    target_port: u16,
+    path_and_query: &str,
+    label: &str,
+) -> Response<Body> {
+    let target_uri = format!("http://127.0.0.1:{}{}", target_port, path_and_query);
+    let Ok(uri) = target_uri.parse::<Uri>() else {
+        return Response::builder()
+            .status(400)
+            .body(Body::from("Invalid target URI"))
+            .expect("static 400 response");
+    };
+
+    let (mut parts, body) = req.into_parts();
+    parts.uri = uri;
+    parts.headers.insert(
+        "host",
+        format!("127.0.0.1:{}", target_port)
+            .parse()
+            .unwrap_or_else(|_| hyper::header::HeaderValue::from_static("localhost")),
+    );
+
+    // Only bodiless, idempotent requests can be replayed
+    let retries = if matches!(parts.method, Method::GET | Method::HEAD) {
+        config.retries
+    } else {
+        0
+    };
+    let read_timeout = Duration::from_millis(config.read_timeout_ms);
+    let mut body = Some(body);
+    let mut attempt = 0;
+
+    loop {
+        let mut backend_req = Request::new(body.take().unwrap_or_else(Body::empty));
+        *backend_req.method_mut() = parts.method.clone();
+        *backend_req.uri_mut() = parts.uri.clone();
+        *backend_req.version_mut() = parts.version;
+        *backend_req.headers_mut() = parts.headers.clone();
+
+        match tokio::time::timeout(read_timeout, client.request(backend_req)).await {
+            Ok(Ok(response)) => return response,
+            Ok(Err(e)) if e.is_connect() && attempt < retries => {
+                attempt += 1;
+                let delay = retry_backoff(attempt);
+                log::debug!(
+                    "Backend {} not reachable ({}), retry {}/{} in {:?}",
+                    label,
+                    e,
+                    attempt,
+                    retries,
+                    delay
+                );
+                tokio::time::sleep(delay).await;
+            }
+            Ok(Err(e)) => {
+                log::warn!("Backend request failed for {}: {}", label, e);
+                return backend_error_page(502, label, target_port, attempt + 1);
+            }
+            Err(_) => {
+                log::warn!(
+                    "Backend {} did not respond within {}ms",
+                    label,
+                    config.read_timeout_ms
+                );
+                return backend_error_page(504, label, target_port, attempt + 1);
+            }
+        }
+    }
+}
+
+/// Error page for a backend that is down (502) or too slow (504). Reloads
+/// itself, since the usual cause is a server that is restarting.
+fn backend_error_page(status: u16, label: &str, target_port: u16, attempts: u32) -> Response<Body> {
+    let (title, detail) = if status == 504 {
+        ("504 Gateway Timeout", "did not answer in time")
+    } else {
+        ("502 Bad Gateway", "is not responding")
+    };
+
+    Response::builder()
+        .status(status)
+        .header("content-type", "text/html; charset=utf-8")
+        .header("retry-after", "5")
+        .body(Body::from(format!(
+            r#"<!DOCTYPE html>
+<html><head><meta charset="UTF-8"><meta http-equiv="refresh" content="5">
+<title>Backend Unavailable</title>
+<style>body{{font-family:-apple-system,BlinkMacSystemFont,'Segoe UI',sans-serif;background:#0a0a0f;color:#e4e4ef;display:flex;align-items:center;justify-content:center;min-height:100vh;margin:0;padding:20px}}.c{{text-align:center;max-width:520px}}h1{{font-size:32px;margin-bottom:12px}}p{{color:#8888a0;margin:8px 0}}code{{color:#6c63ff}}</style>
+</head><body><div class="c">
+<h1>{}</h1>
+<p>Backend server for <strong>{}</strong> {}.</p>
+<p>Target: <code>127.0.0.1:{}</code> &middot; {} attempt{}</p>
+<p>It may be restarting &mdash; this page reloads in a few seconds.</p>
+</div></body></html>"#,
+            title,
+            html_escape(label),
+            detail,
+            target_port,
+            attempts,
+            if attempts == 1 { "" } else { "s" }
+        )))
+        .expect("static backend error response")
+}
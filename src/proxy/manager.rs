@@ -1,13 +1,18 @@
 use crate::core::prelude::*;
 use crate::proxy::handler::ProxyServer;
-use crate::proxy::types::{ProxyConfig, ProxyRoute, ProxyTarget, RouteMap};
+use crate::proxy::types::{ProxyConfig, ProxyRoute, ProxyTarget, RouteKind, RouteMap};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+/// How long the route index waits for a backend to accept a connection
+const PROBE_TIMEOUT: Duration = Duration::from_millis(300);
+
 pub struct ProxyManager {
     config: ProxyConfig,
     routes: Arc<RwLock<RouteMap>>,
+    // Keyed by server name like `routes`; only filled with `path_routing`
+    path_routes: Arc<RwLock<RouteMap>>,
     targets: Arc<RwLock<HashMap<String, ProxyTarget>>>,
 }
 
@@ -16,6 +21,7 @@ impl ProxyManager {
         Self {
             config,
             routes: Arc::new(RwLock::new(HashMap::new())),
+            path_routes: Arc::new(RwLock::new(HashMap::new())),
             targets: Arc::new(RwLock::new(HashMap::new())),
         }
     }
@@ -25,6 +31,7 @@ impl ProxyManager {
             subdomain: server_name.to_string(),
             target_port: port,
             server_id: server_id.to_string(),
+            kind: RouteKind::Subdomain,
         };
 
         let target = ProxyTarget {
@@ -34,6 +41,20 @@ impl ProxyManager {
             last_check: std::time::SystemTime::now(),
         };
 
+        if self.config.path_routing {
+            let path_route = ProxyRoute {
+                kind: RouteKind::PathPrefix,
+                ..route.clone()
+            };
+            log::info!(
+                "Added proxy route: localhost{}/ -> 127.0.0.1:{}",
+                path_route.path_prefix(),
+                port
+            );
+            let mut path_routes = self.path_routes.write().await;
+            path_routes.insert(server_name.to_string(), path_route);
+        }
+
         {
             let mut routes = self.routes.write().await;
             routes.insert(server_name.to_string(), route);
@@ -58,6 +79,11 @@ impl ProxyManager {
             routes.remove(server_name);
         }
 
+        {
+            let mut path_routes = self.path_routes.write().await;
+            path_routes.remove(server_name);
+        }
+
         {
             let mut targets = self.targets.write().await;
             targets.remove(server_name);
@@ -77,6 +103,50 @@ impl ProxyManager {
         routes.get(subdomain).map(|route| route.target_port)
     }
 
+    /// Path-prefix routes (empty unless `path_routing` is enabled)
+    pub async fn get_path_routes(&self) -> Vec<ProxyRoute> {
+        let routes = self.path_routes.read().await;
+        routes.values().cloned().collect()
+    }
+
+    /// Path-prefix route for a bare-host request like `/blog/page?x=1`,
+    /// together with the path to forward (`/page?x=1`). The rest is empty for
+    /// a bare `/blog`.
+    pub async fn resolve_path_route(&self, path_and_query: &str) -> Option<(ProxyRoute, String)> {
+        let (name, rest) = split_path_prefix(path_and_query)?;
+        let routes = self.path_routes.read().await;
+        routes
+            .get(name)
+            .map(|route| (route.clone(), rest.to_string()))
+    }
+
+    /// All subdomain and path routes, each with a live reachability probe of
+    /// its backend. Refreshes the health of the matching targets.
+    pub async fn route_status(&self) -> Vec<(ProxyRoute, bool)> {
+        let mut routes = self.get_routes().await;
+        routes.extend(self.get_path_routes().await);
+        routes.sort_by(|a, b| {
+            a.subdomain
+                .cmp(&b.subdomain)
+                .then((a.kind == RouteKind::PathPrefix).cmp(&(b.kind == RouteKind::PathPrefix)))
+        });
+
+        let probes = routes.iter().map(|r| backend_reachable(r.target_port));
+        let reachable = futures_util::future::join_all(probes).await;
+
+        {
+            let mut targets = self.targets.write().await;
+            for (route, up) in routes.iter().zip(&reachable) {
+                if let Some(target) = targets.get_mut(&route.subdomain) {
+                    target.healthy = *up;
+                    target.last_check = std::time::SystemTime::now();
+                }
+            }
+        }
+
+        routes.into_iter().zip(reachable).collect()
+    }
+
     pub fn get_config(&self) -> &ProxyConfig {
         &self.config
     }
@@ -124,6 +194,34 @@ impl ProxyManager {
                 );
             }
         }
+        for route in self.path_routes.read().await.values() {
+            log::info!(
+                "  {}/ -> 127.0.0.1:{} (server_id: {})",
+                route.path_prefix(),
+                route.target_port,
+                route.server_id
+            );
+        }
         log::info!("=== END ROUTES ===");
     }
 }
+
+/// Splits `/name/rest?query` into `name` and `/rest?query`
+fn split_path_prefix(path_and_query: &str) -> Option<(&str, &str)> {
+    let trimmed = path_and_query.strip_prefix('/')?;
+    let end = trimmed.find(['/', '?']).unwrap_or(trimmed.len());
+    let (name, rest) = trimmed.split_at(end);
+    (!name.is_empty()).then_some((name, rest))
+}
+
+/// True if something accepts TCP connections on `127.0.0.1:<port>`
+async fn backend_reachable(port: u16) -> bool {
+    matches!(
+        tokio::time::timeout(
+            PROBE_TIMEOUT,
+            tokio::net::TcpStream::connect(("127.0.0.1", port))
+        )
+        .await,
+        Ok(Ok(_))
+    )
+}
@@ -3,4 +3,4 @@ pub mod manager;
 pub mod types;
 
 pub use manager::ProxyManager;
-pub use types::{ProxyConfig, ProxyConfigToml, ProxyRoute, ProxyTarget};
+pub use types::{ProxyConfig, ProxyConfigToml, ProxyRoute, ProxyTarget, RouteKind};
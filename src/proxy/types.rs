@@ -8,7 +8,18 @@ pub struct ProxyConfig {
     pub https_port_offset: u16,
     pub bind_address: String,
     pub health_check_interval: u64,
-    pub timeout_ms: u64,
+    /// Also route `localhost/<name>/*` (for setups without wildcard DNS)
+    #[serde(default)]
+    pub path_routing: bool,
+    /// How long to wait for a backend TCP connection
+    #[serde(default = "default_connect_timeout_ms")]
+    pub connect_timeout_ms: u64,
+    /// How long to wait for the backend's response headers
+    #[serde(default = "default_read_timeout_ms")]
+    pub read_timeout_ms: u64,
+    /// Extra attempts for GET/HEAD requests whose backend refused the connection
+    #[serde(default = "default_retries")]
+    pub retries: u32,
     // Populated from [server] config — avoids re-loading config in proxy context
     #[serde(default)]
     pub production_domain: String,
@@ -24,13 +35,28 @@ impl Default for ProxyConfig {
             https_port_offset: 443, // HTTPS port = port + offset (e.g. 3443)
             bind_address: "127.0.0.1".to_string(),
             health_check_interval: 30,
-            timeout_ms: 5000,
+            path_routing: false,
+            connect_timeout_ms: default_connect_timeout_ms(),
+            read_timeout_ms: default_read_timeout_ms(),
+            retries: default_retries(),
             production_domain: "localhost".to_string(),
             use_lets_encrypt: false,
         }
     }
 }
 
+fn default_connect_timeout_ms() -> u64 {
+    2000
+}
+
+fn default_read_timeout_ms() -> u64 {
+    30_000
+}
+
+fn default_retries() -> u32 {
+    3
+}
+
 // TOML-specific struct for serialization
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ProxyConfigToml {
@@ -38,8 +64,19 @@ pub struct ProxyConfigToml {
     pub port: u16,
     pub bind_address: String,
     pub health_check_interval: u64,
-    pub timeout_ms: u64,
+    /// Deprecated and ignored; superseded by `connect_timeout_ms` and
+    /// `read_timeout_ms`. Still accepted so older rush.toml files load.
+    #[serde(default, skip_serializing)]
+    pub timeout_ms: Option<u64>,
     pub https_port_offset: u16,
+    #[serde(default)]
+    pub path_routing: bool,
+    #[serde(default = "default_connect_timeout_ms")]
+    pub connect_timeout_ms: u64,
+    #[serde(default = "default_read_timeout_ms")]
+    pub read_timeout_ms: u64,
+    #[serde(default = "default_retries")]
+    pub retries: u32,
 }
 
 impl Default for ProxyConfigToml {
@@ -50,7 +87,11 @@ impl Default for ProxyConfigToml {
             https_port_offset: 443,
             bind_address: "127.0.0.1".to_string(),
             health_check_interval: 30,
-            timeout_ms: 5000,
+            timeout_ms: None,
+            path_routing: false,
+            connect_timeout_ms: default_connect_timeout_ms(),
+            read_timeout_ms: default_read_timeout_ms(),
+            retries: default_retries(),
         }
     }
 }
@@ -63,20 +104,33 @@ impl From<ProxyConfig> for ProxyConfigToml {
             https_port_offset: config.https_port_offset,
             bind_address: config.bind_address,
             health_check_interval: config.health_check_interval,
-            timeout_ms: config.timeout_ms,
+            timeout_ms: None,
+            path_routing: config.path_routing,
+            connect_timeout_ms: config.connect_timeout_ms,
+            read_timeout_ms: config.read_timeout_ms,
+            retries: config.retries,
         }
     }
 }
 
 impl From<ProxyConfigToml> for ProxyConfig {
     fn from(config: ProxyConfigToml) -> Self {
+        if config.timeout_ms.is_some() {
+            log::warn!(
+                "[proxy] timeout_ms is deprecated and ignored, use connect_timeout_ms and read_timeout_ms"
+            );
+        }
         Self {
             enabled: config.enabled,
             port: config.port,
             https_port_offset: config.https_port_offset,
             bind_address: config.bind_address,
             health_check_interval: config.health_check_interval,
-            timeout_ms: config.timeout_ms,
+            path_routing: config.path_routing,
+            // 0 would fail every backend request at once
+            connect_timeout_ms: config.connect_timeout_ms.max(1),
+            read_timeout_ms: config.read_timeout_ms.max(1),
+            retries: config.retries,
             // These are populated later from [server] config, not from TOML
             production_domain: "localhost".to_string(),
             use_lets_encrypt: false,
@@ -92,11 +146,29 @@ pub struct ProxyTarget {
     pub last_check: std::time::SystemTime,
 }
 
+/// How a request is matched to a route
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RouteKind {
+    /// `name.localhost` / `name.<domain>`
+    #[default]
+    Subdomain,
+    /// `localhost/name/*`; the prefix is stripped before forwarding
+    PathPrefix,
+}
+
 #[derive(Debug, Clone)]
 pub struct ProxyRoute {
     pub subdomain: String,
     pub target_port: u16,
     pub server_id: String,
+    pub kind: RouteKind,
+}
+
+impl ProxyRoute {
+    /// Path prefix this route answers on the bare host, e.g. `/blog`
+    pub fn path_prefix(&self) -> String {
+        format!("/{}", self.subdomain)
+    }
 }
 
 pub type RouteMap = HashMap<String, ProxyRoute>;
@@ -35,6 +35,102 @@ fn remove_challenge(token: &str) {
     }
 }
 
+// =============================================================================
+// Challenge Selection
+// =============================================================================
+
+/// Grace period after printing a DNS-01 record for manual entry.
+const DNS_MANUAL_WAIT_SECS: u64 = 120;
+/// Grace period after the DNS hook ran, so the TXT record can propagate.
+const DNS_HOOK_WAIT_SECS: u64 = 30;
+/// Longest the DNS hook may run before it is killed and the challenge fails.
+const DNS_HOOK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(120);
+
+/// How domain control is proven to Let's Encrypt.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ChallengeMode {
+    /// Serve the key authorization under /.well-known/acme-challenge/ on port 80
+    #[default]
+    Http01,
+    /// Publish a `_acme-challenge` TXT record, via `hook` or by hand
+    Dns01 { hook: Option<String> },
+}
+
+impl ChallengeMode {
+    /// Build from the `server.acme_challenge` and `server.acme_dns_hook` values.
+    pub fn from_config(challenge: &str, dns_hook: &str) -> Self {
+        if challenge.trim().eq_ignore_ascii_case("dns-01") {
+            let hook = dns_hook.trim();
+            Self::Dns01 {
+                hook: (!hook.is_empty()).then(|| hook.to_string()),
+            }
+        } else {
+            Self::Http01
+        }
+    }
+
+    fn acme_type(&self) -> &'static str {
+        match self {
+            Self::Http01 => "http-01",
+            Self::Dns01 { .. } => "dns-01",
+        }
+    }
+}
+
+/// TXT record name and value for a DNS-01 challenge (RFC 8555 §8.4).
+pub fn dns01_record(domain: &str, key_authorization: &str) -> (String, String) {
+    let name = format!("_acme-challenge.{}", domain.trim_start_matches("*."));
+    let hash = digest(&SHA256, key_authorization.as_bytes());
+    (name, URL_SAFE_NO_PAD.encode(hash.as_ref()))
+}
+
+/// Run the DNS hook with `action` ("present" or "cleanup").
+/// The record is passed as RSS_ACME_ACTION, RSS_ACME_DOMAIN,
+/// RSS_ACME_TXT_NAME and RSS_ACME_TXT_VALUE environment variables.
+/// A hook still running after `timeout` is killed.
+async fn run_dns_hook(
+    hook: &str,
+    action: &str,
+    domain: &str,
+    name: &str,
+    value: &str,
+    timeout: std::time::Duration,
+) -> Result<(), String> {
+    let mut command = tokio::process::Command::new("sh");
+    command
+        .arg("-c")
+        .arg(hook)
+        .env("RSS_ACME_ACTION", action)
+        .env("RSS_ACME_DOMAIN", domain)
+        .env("RSS_ACME_TXT_NAME", name)
+        .env("RSS_ACME_TXT_VALUE", value)
+        .stdin(std::process::Stdio::null())
+        .kill_on_drop(true);
+
+    let output = tokio::time::timeout(timeout, command.output())
+        .await
+        .map_err(|_| {
+            format!(
+                "DNS hook '{}' timed out after {}s",
+                action,
+                timeout.as_secs()
+            )
+        })?
+        .map_err(|e| format!("DNS hook failed to start: {}", e))?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(format!(
+            "DNS hook '{}' exited with {}: {}",
+            action,
+            output.status,
+            stderr.trim()
+        ))
+    }
+}
+
 // =============================================================================
 // ACME Status Tracking
 // =============================================================================
@@ -215,9 +311,15 @@ struct AcmeOrder {
     certificate: Option<String>,
 }
 
+#[derive(serde::Deserialize)]
+struct AcmeIdentifier {
+    value: String,
+}
+
 #[derive(serde::Deserialize)]
 struct AcmeAuthorization {
     status: String,
+    identifier: AcmeIdentifier,
     challenges: Vec<AcmeChallenge>,
 }
 
@@ -404,7 +506,12 @@ impl AcmeClient {
         Ok(())
     }
 
-    async fn request_certificate(&mut self, domain: &str, subdomains: &[String]) -> Result<(), String> {
+    async fn request_certificate(
+        &mut self,
+        domain: &str,
+        subdomains: &[String],
+        mode: &ChallengeMode,
+    ) -> Result<(), String> {
         // Build list of domains: bare domain + www + additional subdomains.
         // Every SAN must have a valid DNS A record pointing to this server,
         // otherwise Let's Encrypt HTTP-01 validation fails for the ENTIRE certificate.
@@ -507,17 +614,55 @@ impl AcmeClient {
                 continue;
             }
 
-            // 3. Find HTTP-01 challenge
+            // 3. Find the configured challenge type
             let challenge = auth
                 .challenges
                 .iter()
-                .find(|c| c.challenge_type == "http-01")
-                .ok_or("No HTTP-01 challenge found")?;
+                .find(|c| c.challenge_type == mode.acme_type())
+                .ok_or_else(|| format!("No {} challenge found", mode.acme_type()))?;
 
             // 4. Set up challenge response
             let key_auth = format!("{}.{}", challenge.token, thumbprint);
-            log::info!("ACME challenge: token={}", challenge.token);
-            set_challenge(challenge.token.clone(), key_auth);
+            log::info!(
+                "ACME challenge: type={} token={}",
+                mode.acme_type(),
+                challenge.token
+            );
+            let dns_record = match mode {
+                ChallengeMode::Http01 => {
+                    set_challenge(challenge.token.clone(), key_auth);
+                    None
+                }
+                ChallengeMode::Dns01 { hook } => {
+                    let identifier = &auth.identifier.value;
+                    let (name, value) = dns01_record(identifier, &key_auth);
+                    let wait = match hook {
+                        Some(hook) => {
+                            run_dns_hook(
+                                hook,
+                                "present",
+                                identifier,
+                                &name,
+                                &value,
+                                DNS_HOOK_TIMEOUT,
+                            )
+                            .await?;
+                            log::info!("ACME DNS hook published {} TXT \"{}\"", name, value);
+                            DNS_HOOK_WAIT_SECS
+                        }
+                        None => {
+                            log::warn!("ACME DNS-01: create {} TXT \"{}\"", name, value);
+                            crate::input::send_progress(format!(
+                                "  Create DNS record {} TXT \"{}\" (validation starts in {}s)",
+                                name, value, DNS_MANUAL_WAIT_SECS
+                            ));
+                            DNS_MANUAL_WAIT_SECS
+                        }
+                    };
+                    tokio::time::sleep(tokio::time::Duration::from_secs(wait)).await;
+                    Some((identifier.clone(), name, value))
+                }
+            };
 
             // 5. Tell ACME to verify
             let nonce = self.get_nonce().await?;
@@ -534,6 +679,7 @@ impl AcmeClient {
 
             // 6. Poll authorization until valid
             let mut auth_ok = false;
+            let mut auth_failed = false;
             for attempt in 0..30 {
                 tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
 
@@ -561,8 +707,8 @@ impl AcmeClient {
                         break;
                     }
                     "invalid" => {
-                        remove_challenge(&challenge.token);
-                        return Err("Authorization failed".to_string());
+                        auth_failed = true;
+                        break;
                     }
                     _ => {
                         log::debug!("ACME auth poll attempt {}: status={}", attempt + 1, poll_auth.status);
@@ -571,7 +717,19 @@ impl AcmeClient {
             }
 
             remove_challenge(&challenge.token);
+            if let (ChallengeMode::Dns01 { hook: Some(hook) }, Some((identifier, name, value))) =
+                (mode, &dns_record)
+            {
+                if let Err(e) =
+                    run_dns_hook(hook, "cleanup", identifier, name, value, DNS_HOOK_TIMEOUT).await
+                {
+                    log::warn!("ACME DNS cleanup for {} failed: {}", name, e);
+                }
+            }
 
+            if auth_failed {
+                return Err("Authorization failed".to_string());
+            }
             if !auth_ok {
                 return Err("Authorization timeout".to_string());
             }
@@ -835,29 +993,144 @@ render();setTimeout(function(){location.reload()},15000);
 
 // Public API
 
+/// Verify that something on local port 80 answers HTTP-01 challenges from
+/// this process (redirect server, proxy or challenge-only server).
+/// Uses a throwaway token so Let's Encrypt is never contacted on failure.
+pub async fn check_challenge_listener() -> Result<(), String> {
+    let token = format!("preflight-{}", uuid::Uuid::new_v4().simple());
+    let expected = format!("{}.preflight", token);
+    set_challenge(token.clone(), expected.clone());
+
+    let result = async {
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(3))
+            .build()
+            .map_err(|e| e.to_string())?;
+        let resp = client
+            .get(format!(
+                "http://127.0.0.1:80/.well-known/acme-challenge/{}",
+                token
+            ))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        let body = resp.text().await.map_err(|e| e.to_string())?;
+        if body.trim() == expected {
+            Ok(())
+        } else {
+            Err("port 80 is bound by another service".to_string())
+        }
+    }
+    .await;
+
+    remove_challenge(&token);
+
+    result.map_err(|e| {
+        format!(
+            "ACME pre-flight failed ({}). Start the proxy/redirect server on :80 first, \
+             or use 'tls provision <domain> --challenge-only'",
+            e
+        )
+    })
+}
+
 /// Provision a Let's Encrypt certificate for a domain.
-/// The proxy must be running on port 80 to serve HTTP-01 challenges.
+/// For HTTP-01 the proxy must be running on port 80 to serve challenges;
+/// this is verified before contacting Let's Encrypt. DNS-01 needs no listener.
 pub async fn provision_certificate(
     domain: &str,
     cert_dir: &Path,
     email: &str,
     staging: bool,
     subdomains: &[String],
+    mode: &ChallengeMode,
 ) -> Result<(), String> {
     log::info!(
-        "Starting Let's Encrypt provisioning for {} (staging={})",
+        "Starting Let's Encrypt provisioning for {} (staging={}, challenge={})",
         domain,
-        staging
+        staging,
+        mode.acme_type()
     );
 
+    if *mode == ChallengeMode::Http01 {
+        check_challenge_listener().await?;
+    }
+
     let mut client = AcmeClient::new(cert_dir, staging).await?;
     client.register_account(email).await?;
-    client.request_certificate(domain, subdomains).await?;
+    client.request_certificate(domain, subdomains, mode).await?;
 
     log::info!("Let's Encrypt certificate provisioned for {}", domain);
     Ok(())
 }
 
+/// Provision a certificate for use by an external web server.
+/// Starts a temporary challenge-only listener on port 80 (no managed server or
+/// proxy required), obtains the certificate, then shuts the listener down again.
+/// With DNS-01 no listener is needed and the certificate is requested directly.
+/// Returns the paths of the written certificate chain and private key.
+pub async fn provision_challenge_only(
+    domain: &str,
+    cert_dir: &Path,
+    email: &str,
+    staging: bool,
+    mode: &ChallengeMode,
+) -> Result<(PathBuf, PathBuf), String> {
+    std::fs::create_dir_all(cert_dir)
+        .map_err(|e| format!("Failed to create cert directory: {}", e))?;
+
+    let cert_paths = (
+        cert_dir.join(format!("{}.fullchain.pem", domain)),
+        cert_dir.join(format!("{}.privkey.pem", domain)),
+    );
+
+    if *mode != ChallengeMode::Http01 {
+        provision_certificate(domain, cert_dir, email, staging, &[], mode).await?;
+        return Ok(cert_paths);
+    }
+
+    let (tx, rx) = tokio::sync::oneshot::channel();
+
+    // Own thread + runtime, same as the regular HTTP redirect server
+    std::thread::spawn(move || {
+        let rt = match tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+        {
+            Ok(rt) => rt,
+            Err(e) => {
+                let _ = tx.send(Err(format!("Failed to build runtime: {}", e)));
+                return;
+            }
+        };
+
+        rt.block_on(async move {
+            match crate::server::redirect::HttpRedirectServer::challenge_only(80).bind() {
+                Ok(server) => {
+                    let _ = tx.send(Ok(server.handle()));
+                    if let Err(e) = server.await {
+                        log::error!("ACME challenge server error: {}", e);
+                    }
+                }
+                Err(e) => {
+                    let _ = tx.send(Err(e.to_string()));
+                }
+            }
+        });
+    });
+
+    let handle = rx
+        .await
+        .map_err(|_| "ACME challenge server exited unexpectedly".to_string())??;
+
+    let result = provision_certificate(domain, cert_dir, email, staging, &[], mode).await;
+    handle.stop(true).await;
+    log::info!("ACME challenge server on port 80 stopped");
+    result?;
+
+    Ok(cert_paths)
+}
+
 /// Check if a certificate exists and is valid. Returns true if renewal was performed.
 pub async fn check_and_renew(
     domain: &str,
@@ -866,13 +1139,14 @@ pub async fn check_and_renew(
     staging: bool,
     renew_before_days: u32,
     subdomains: &[String],
+    mode: &ChallengeMode,
 ) -> Result<bool, String> {
     let cert_path = cert_dir.join(format!("{}.fullchain.pem", domain));
     let key_path = cert_dir.join(format!("{}.privkey.pem", domain));
 
     if !cert_path.exists() || !key_path.exists() {
         log::info!("No certificate found for {}, provisioning...", domain);
-        provision_certificate(domain, cert_dir, email, staging, subdomains).await?;
+        provision_certificate(domain, cert_dir, email, staging, subdomains, mode).await?;
         return Ok(true);
     }
 
@@ -912,7 +1186,7 @@ pub async fn check_and_renew(
                 domain,
                 subdomains.len()
             );
-            provision_certificate(domain, cert_dir, email, staging, subdomains).await?;
+            provision_certificate(domain, cert_dir, email, staging, subdomains, mode).await?;
             return Ok(true);
         }
     }
@@ -934,7 +1208,7 @@ pub async fn check_and_renew(
             domain,
             age.as_secs() / (24 * 60 * 60)
         );
-        provision_certificate(domain, cert_dir, email, staging, subdomains).await?;
+        provision_certificate(domain, cert_dir, email, staging, subdomains, mode).await?;
         return Ok(true);
     }
 
@@ -947,11 +1221,64 @@ pub async fn check_and_renew(
     Ok(false)
 }
 
+/// Run a renewal on demand (`tls renew`). With `force` the certificate is
+/// re-provisioned regardless of its age, otherwise the usual age/SAN check applies.
+/// Updates the status tracker and hot-reloads the proxy TLS config after a renewal.
+/// Returns true if a new certificate was obtained.
+pub async fn renew_now(
+    domain: &str,
+    cert_dir: &Path,
+    email: &str,
+    staging: bool,
+    mode: &ChallengeMode,
+    force: bool,
+) -> Result<bool, String> {
+    // Keep the SANs of the background task, a bare-domain cert would drop subdomains
+    let subdomains = match get_or_init_status().read() {
+        Ok(info) if info.domain == domain => info.subdomains.clone(),
+        _ => Vec::new(),
+    };
+    if subdomains.is_empty() {
+        init_status(domain, &subdomains, cert_dir);
+    }
+
+    update_status(AcmeState::Provisioning, None);
+    let result = if force {
+        provision_certificate(domain, cert_dir, email, staging, &subdomains, mode)
+            .await
+            .map(|_| true)
+    } else {
+        check_and_renew(domain, cert_dir, email, staging, 30, &subdomains, mode).await
+    };
+
+    match result {
+        Ok(renewed) => {
+            update_status(AcmeState::Success, None);
+            if renewed {
+                log::info!("ACME: Certificate renewed on demand for {}", domain);
+                crate::proxy::handler::reload_proxy_tls(domain);
+            }
+            Ok(renewed)
+        }
+        Err(e) => {
+            update_status(AcmeState::Failed, Some(&e));
+            Err(e)
+        }
+    }
+}
+
 /// Start background ACME provisioning and renewal.
 /// Runs initial check after a short delay (to let proxy start), then every 24h.
 /// After provisioning/renewal, hot-reloads the proxy's TLS config automatically.
 /// If provisioning with subdomains fails, retries with bare domain only.
-pub fn start_acme_background(domain: String, cert_dir: PathBuf, email: String, staging: bool, subdomains: Vec<String>) {
+pub fn start_acme_background(
+    domain: String,
+    cert_dir: PathBuf,
+    email: String,
+    staging: bool,
+    subdomains: Vec<String>,
+    mode: ChallengeMode,
+) {
     init_status(&domain, &subdomains, &cert_dir);
 
     tokio::spawn(async move {
@@ -960,7 +1287,7 @@ pub fn start_acme_background(domain: String, cert_dir: PathBuf, email: String, s
 
         // Initial provisioning/renewal
         update_status(AcmeState::Provisioning, None);
-        let provisioned = match check_and_renew(&domain, &cert_dir, &email, staging, 30, &subdomains).await {
+        let provisioned = match check_and_renew(&domain, &cert_dir, &email, staging, 30, &subdomains, &mode).await {
             Ok(renewed) => {
                 update_status(AcmeState::Success, None);
                 if renewed {
@@ -988,7 +1315,7 @@ pub fn start_acme_background(domain: String, cert_dir: PathBuf, email: String, s
                     // No certificate at all — try bare domain as last resort to get HTTPS working
                     log::info!("ACME: No certificate exists. Trying bare domain only: {}", domain);
                     update_status(AcmeState::Provisioning, None);
-                    match check_and_renew(&domain, &cert_dir, &email, staging, 30, &[]).await {
+                    match check_and_renew(&domain, &cert_dir, &email, staging, 30, &[], &mode).await {
                         Ok(renewed) => {
                             update_status(AcmeState::Success, None);
                             if renewed {
@@ -1015,7 +1342,7 @@ pub fn start_acme_background(domain: String, cert_dir: PathBuf, email: String, s
             log::info!("ACME: Will retry in 60 seconds...");
             tokio::time::sleep(tokio::time::Duration::from_secs(60)).await;
             update_status(AcmeState::Provisioning, None);
-            match check_and_renew(&domain, &cert_dir, &email, staging, 30, &subdomains).await {
+            match check_and_renew(&domain, &cert_dir, &email, staging, 30, &subdomains, &mode).await {
                 Ok(true) => {
                     update_status(AcmeState::Success, None);
                     log::info!("ACME: Certificate provisioned on retry for {}", domain);
@@ -1038,7 +1365,7 @@ pub fn start_acme_background(domain: String, cert_dir: PathBuf, email: String, s
         loop {
             interval.tick().await;
             update_status(AcmeState::Provisioning, None);
-            match check_and_renew(&domain, &cert_dir, &email, staging, 30, &subdomains).await {
+            match check_and_renew(&domain, &cert_dir, &email, staging, 30, &subdomains, &mode).await {
                 Ok(true) => {
                     update_status(AcmeState::Success, None);
                     log::info!("ACME: Certificate renewed for {}", domain);
@@ -1056,3 +1383,45 @@ pub fn start_acme_background(domain: String, cert_dir: PathBuf, email: String, s
         }
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_dns_hook_times_out() {
+        let timeout = std::time::Duration::from_millis(200);
+        let started = std::time::Instant::now();
+        let err = run_dns_hook("sleep 5", "present", "example.com", "n", "v", timeout)
+            .await
+            .unwrap_err();
+        assert!(err.contains("timed out"));
+        assert!(started.elapsed() < std::time::Duration::from_secs(4));
+
+        assert!(
+            run_dns_hook("true", "cleanup", "example.com", "n", "v", timeout)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_dns01_record_and_mode() {
+        let (name, value) = dns01_record("*.example.com", "token.thumb");
+        assert_eq!(name, "_acme-challenge.example.com");
+        assert_eq!(value, "lCdftpJCXapaZHkBGIZbcubeKW8RmA0jSG_aZN01zbU");
+
+        assert_eq!(ChallengeMode::from_config("http-01", "hook"), ChallengeMode::Http01);
+        assert_eq!(
+            ChallengeMode::from_config("DNS-01", " "),
+            ChallengeMode::Dns01 { hook: None }
+        );
+        assert_eq!(
+            ChallengeMode::from_config("dns-01", "./dns.sh"),
+            ChallengeMode::Dns01 {
+                hook: Some("./dns.sh".to_string())
+            }
+        );
+    }
+}
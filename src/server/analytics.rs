@@ -22,6 +22,8 @@ pub struct AnalyticsTracker {
 struct DayData {
     total_views: u64,
     total_downloads: u64,
+    #[serde(default)]
+    slow_requests: u64,
     unique_ips: HashSet<String>,
     page_counts: HashMap<String, u64>,
     download_counts: HashMap<String, u64>,
@@ -200,6 +202,16 @@ fn hash_ip(ip: &str) -> String {
     format!("{:x}", hasher.finish())
 }
 
+/// Count a request above `logging.slow_request_ms`. Unlike `track_request`
+/// this is not filtered: a slow health check is still slow.
+pub fn track_slow_request() {
+    let analytics = get_analytics();
+    if let Ok(mut tracker) = analytics.write() {
+        let date = Local::now().format("%Y-%m-%d").to_string();
+        tracker.days.entry(date).or_default().slow_requests += 1;
+    }
+}
+
 /// Get analytics summary as JSON for the API endpoint.
 pub fn get_summary() -> serde_json::Value {
     let analytics = get_analytics();
@@ -242,16 +254,13 @@ pub fn get_summary() -> serde_json::Value {
     })
 }
 
-fn build_period_summary(
-    tracker: &AnalyticsTracker,
-    today: &str,
-    days: i64,
-) -> serde_json::Value {
-    let today_date = NaiveDate::parse_from_str(today, "%Y-%m-%d")
-        .unwrap_or_else(|_| Local::now().date_naive());
+fn build_period_summary(tracker: &AnalyticsTracker, today: &str, days: i64) -> serde_json::Value {
+    let today_date =
+        NaiveDate::parse_from_str(today, "%Y-%m-%d").unwrap_or_else(|_| Local::now().date_naive());
 
     let mut total_views = 0u64;
     let mut total_downloads = 0u64;
+    let mut slow_requests = 0u64;
     let mut all_ips: HashSet<String> = HashSet::new();
     let mut page_totals: HashMap<String, u64> = HashMap::new();
     let mut download_totals: HashMap<String, u64> = HashMap::new();
@@ -263,6 +272,7 @@ fn build_period_summary(
         if let Some(day) = tracker.days.get(&date) {
             total_views += day.total_views;
             total_downloads += day.total_downloads;
+            slow_requests += day.slow_requests;
             all_ips.extend(day.unique_ips.iter().cloned());
             for (path, count) in &day.page_counts {
                 *page_totals.entry(path.clone()).or_default() += count;
@@ -274,7 +284,7 @@ fn build_period_summary(
     }
 
     let mut pages: Vec<_> = page_totals.into_iter().collect();
-    pages.sort_by(|a, b| b.1.cmp(&a.1));
+    pages.sort_by_key(|p| std::cmp::Reverse(p.1));
     let top_pages: Vec<serde_json::Value> = pages
         .into_iter()
         .take(10)
@@ -282,7 +292,7 @@ fn build_period_summary(
         .collect();
 
     let mut downloads: Vec<_> = download_totals.into_iter().collect();
-    downloads.sort_by(|a, b| b.1.cmp(&a.1));
+    downloads.sort_by_key(|d| std::cmp::Reverse(d.1));
     let top_downloads: Vec<serde_json::Value> = downloads
         .into_iter()
         .take(10)
@@ -293,6 +303,7 @@ fn build_period_summary(
         "page_views": total_views,
         "unique_visitors": all_ips.len(),
         "downloads": total_downloads,
+        "slow_requests": slow_requests,
         "top_pages": top_pages,
         "top_downloads": top_downloads,
     })
@@ -303,8 +314,8 @@ fn build_subdomain_summary(
     today: &str,
     days: i64,
 ) -> serde_json::Value {
-    let today_date = NaiveDate::parse_from_str(today, "%Y-%m-%d")
-        .unwrap_or_else(|_| Local::now().date_naive());
+    let today_date =
+        NaiveDate::parse_from_str(today, "%Y-%m-%d").unwrap_or_else(|_| Local::now().date_naive());
 
     let mut views: HashMap<String, u64> = HashMap::new();
     let mut ips: HashMap<String, HashSet<String>> = HashMap::new();
@@ -334,7 +345,7 @@ fn build_subdomain_summary(
 }
 
 fn get_analytics_path() -> std::path::PathBuf {
-    crate::core::helpers::get_base_dir()
+    crate::core::paths::base_dir()
         .map(|b| b.join(".rss").join("analytics.json"))
         .unwrap_or_else(|_| std::path::PathBuf::from(".rss/analytics.json"))
 }
@@ -474,13 +485,14 @@ var P='today';
 var refreshTimer=30;
 document.querySelectorAll('.tab').forEach(function(t){t.addEventListener('click',function(){document.querySelectorAll('.tab').forEach(function(x){x.classList.remove('active')});t.classList.add('active');P=t.dataset.p;render()})});
 function render(){var p=D[P]||D.today||{};
-var views=p.page_views||0;var uniq=p.unique_visitors||0;var dls=p.downloads||0;
+var views=p.page_views||0;var uniq=p.unique_visitors||0;var dls=p.downloads||0;var slow=p.slow_requests||0;
 var vpu=uniq>0?Math.round(views/uniq):0;
 document.getElementById('cards').innerHTML=
 '<div class="card"><div class="lbl">Page Views</div><div class="val purple">'+fmt(views)+'</div><div class="sub">Total tracked requests</div></div>'+
 '<div class="card"><div class="lbl">Unique Visitors</div><div class="val green">'+fmt(uniq)+'</div><div class="sub">By unique IP address</div></div>'+
 '<div class="card"><div class="lbl">Downloads</div><div class="val blue">'+fmt(dls)+'</div><div class="sub">Binary downloads</div></div>'+
-'<div class="card"><div class="lbl">Views / Visitor</div><div class="val orange">'+fmt(vpu)+'</div><div class="sub">Avg. engagement</div></div>';
+'<div class="card"><div class="lbl">Views / Visitor</div><div class="val orange">'+fmt(vpu)+'</div><div class="sub">Avg. engagement</div></div>'+
+'<div class="card"><div class="lbl">Slow Requests</div><div class="val orange">'+fmt(slow)+'</div><div class="sub">Above slow_request_ms</div></div>';
 renderChart();renderPages(p);renderDownloads(p);renderSubs()}
 function renderChart(){var h=D.hourly_traffic||[];
 var el=document.getElementById('chart');
@@ -525,7 +537,10 @@ mod tests {
         assert!(!is_trackable_request("/.rss/style.css", "Mozilla/5.0"));
         assert!(!is_trackable_request("/rss.js", "Mozilla/5.0"));
         assert!(!is_trackable_request("/ws/hot-reload", "Mozilla/5.0"));
-        assert!(!is_trackable_request("/.well-known/acme-challenge/xxx", "Mozilla/5.0"));
+        assert!(!is_trackable_request(
+            "/.well-known/acme-challenge/xxx",
+            "Mozilla/5.0"
+        ));
     }
 
     #[test]
@@ -80,6 +80,7 @@ pub async fn info_handler(data: web::Data<ServerDataWithConfig>) -> ActixResult<
             { "path": "/api/stats", "method": "GET", "description": "Request statistics", "type": "api" },
             { "path": "/api/logs", "method": "GET", "description": "Live server logs", "type": "api" },
             { "path": "/api/logs/raw", "method": "GET", "description": "Raw log data (JSON)", "type": "api" },
+            { "path": "/api/logs/stream", "method": "GET", "description": "Live log lines (Server-Sent Events)", "type": "api" },
             { "path": "/api/health", "method": "GET", "description": "Health check", "type": "api" },
             { "path": "/ws/hot-reload", "method": "GET", "description": "WebSocket hot reload", "type": "websocket" }
         ]
@@ -124,11 +125,30 @@ pub async fn metrics_handler(data: web::Data<ServerDataWithConfig>) -> ActixResu
             "file_size_bytes": log_file_size,
             "enabled": true
         },
+        "memory": memory_metrics(),
         "endpoints_count": 10,
         "last_updated": uptime
     })))
 }
 
+/// Process footprint for `/api/metrics`; needs the `memory` feature
+#[cfg(feature = "memory")]
+fn memory_metrics() -> serde_json::Value {
+    json!({
+        "enabled": true,
+        "process_rss_bytes": crate::memory::process_rss_bytes(),
+        "process_vms_bytes": crate::memory::process_vms_bytes(),
+        "total_ram_bytes": crate::memory::total_ram_bytes(),
+        "thread_count": crate::memory::process_thread_count(),
+        "registered_bytes": crate::memory::total_bytes()
+    })
+}
+
+#[cfg(not(feature = "memory"))]
+fn memory_metrics() -> serde_json::Value {
+    json!({ "enabled": false })
+}
+
 pub async fn stats_handler(data: web::Data<ServerDataWithConfig>) -> ActixResult<HttpResponse> {
     let server_dir = format!("www/{}-[{}]", data.server.name, data.server.port);
 
@@ -149,6 +169,7 @@ pub async fn stats_handler(data: web::Data<ServerDataWithConfig>) -> ActixResult
         "performance_warnings": stats.performance_warnings,
         "avg_response_time_ms": stats.avg_response_time,
         "max_response_time_ms": stats.max_response_time,
+        "slow_requests": stats.slow_requests,
         "total_bytes_sent": stats.total_bytes_sent,
         "uptime_seconds": SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs(),
         "hot_reload_status": "active"
@@ -272,7 +293,7 @@ pub async fn upload_file(
         return Ok(HttpResponse::BadRequest().json(json!({"error": "Path required"})));
     }
 
-    let base_dir = crate::core::helpers::get_base_dir().map_err(|e| {
+    let base_dir = crate::core::paths::base_dir().map_err(|e| {
         actix_web::error::ErrorInternalServerError(format!("Base dir error: {}", e))
     })?;
     let server_dir = base_dir
@@ -328,7 +349,7 @@ pub async fn list_files(
     data: web::Data<ServerDataWithConfig>,
     query: web::Query<std::collections::HashMap<String, String>>,
 ) -> ActixResult<HttpResponse> {
-    let base_dir = crate::core::helpers::get_base_dir().map_err(|e| {
+    let base_dir = crate::core::paths::base_dir().map_err(|e| {
         actix_web::error::ErrorInternalServerError(format!("Base dir error: {}", e))
     })?;
     let server_dir = base_dir
@@ -398,7 +419,7 @@ pub async fn delete_file(
         return Ok(HttpResponse::Forbidden().json(json!({"error": "Path traversal blocked"})));
     }
 
-    let base_dir = crate::core::helpers::get_base_dir().map_err(|e| {
+    let base_dir = crate::core::paths::base_dir().map_err(|e| {
         actix_web::error::ErrorInternalServerError(format!("Base dir error: {}", e))
     })?;
     let server_dir = base_dir
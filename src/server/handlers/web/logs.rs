@@ -3,14 +3,23 @@ use crate::server::logging::ServerLogger;
 use actix_web::{web, HttpRequest, HttpResponse, Result as ActixResult};
 use serde_json::json;
 use std::path::PathBuf;
+use std::time::Duration;
 use tokio::fs;
 use tokio::io::{AsyncBufReadExt, BufReader};
 
+/// Upper bound for `?lines=N` on `/api/logs/raw`
+const MAX_TAIL_LINES: usize = 5000;
+
 pub async fn logs_raw_handler(
     req: HttpRequest,
     data: web::Data<ServerDataWithConfig>,
+    query: web::Query<std::collections::HashMap<String, String>>,
 ) -> ActixResult<HttpResponse> {
-    let base_dir = crate::core::helpers::get_base_dir().map_err(|e| {
+    if query.contains_key("lines") || query.contains_key("since") {
+        return logs_tail_response(&data, &query).await;
+    }
+
+    let base_dir = crate::core::paths::base_dir().map_err(|e| {
         log::error!("Failed to get base directory: {}", e);
         actix_web::error::ErrorInternalServerError("Internal server error")
     })?;
@@ -156,7 +165,25 @@ pub async fn logs_handler(data: web::Data<ServerDataWithConfig>) -> ActixResult<
            line-height: 1.3;
        }}
    </style>
-   <script>setInterval(function() {{ location.reload(); }}, 5000);</script>
+   <script>
+       document.addEventListener('DOMContentLoaded', function() {{
+           var container = document.getElementById('log-container');
+           var source = new EventSource('/api/logs/stream');
+           source.onmessage = function(event) {{
+               var text = event.data;
+               try {{
+                   var entry = JSON.parse(event.data);
+                   text = [entry.timestamp, entry.method, entry.path, entry.status_code || entry.status || '']
+                       .join(' ');
+               }} catch (e) {{}}
+               var div = document.createElement('div');
+               div.className = 'log-entry';
+               div.textContent = text;
+               container.appendChild(div);
+               container.scrollTop = container.scrollHeight;
+           }};
+       }});
+   </script>
 </head>
 <body>
    <div class="header">
@@ -168,7 +195,7 @@ pub async fn logs_handler(data: web::Data<ServerDataWithConfig>) -> ActixResult<
            <p><a href="/" class="back-link">← Back to main page</a></p>
        </div>
    </div>
-   <div class="log-container">
+   <div class="log-container" id="log-container">
        <div class="log-entry">Server Directory: {}</div>
        <div class="log-entry">HTTP: http://127.0.0.1:{}</div>
        <div class="log-entry">Proxy: https://{}.localhost:{}</div>
@@ -178,8 +205,7 @@ pub async fn logs_handler(data: web::Data<ServerDataWithConfig>) -> ActixResult<
        <div class="log-entry">Hot Reload: WebSocket active on /ws/hot-reload</div>
        <div class="log-entry">File Watcher: Monitoring www directory for changes</div>
        <div class="log-entry">Configuration: Loaded from rush.toml</div>
-       <div class="log-entry">--- REAL LOG ENTRIES WOULD APPEAR HERE ---</div>
-       <div class="log-entry">Live logging with rotation, security alerts, and performance monitoring</div>
+       <div class="log-entry">--- LIVE ENTRIES (/api/logs/stream) ---</div>
    </div>
 </body>
 </html>"#,
@@ -209,52 +235,321 @@ async fn read_log_entries_from_offset(
     file_path: &PathBuf,
     offset: u64,
 ) -> Result<Vec<serde_json::Value>, Box<dyn std::error::Error + Send + Sync>> {
-    let file = fs::File::open(file_path).await?;
-    let mut reader = BufReader::new(file);
+    const MAX_LINES_PER_REQUEST: usize = 100;
+
+    let (lines, _) = read_appended_lines(file_path, offset, MAX_LINES_PER_REQUEST).await?;
+    Ok(lines.iter().map(|line| line_to_entry(line)).collect())
+}
+
+/// `?lines=N` (last N entries) and/or `?since=<unix|RFC 3339|YYYY-MM-DD HH:MM:SS>`,
+/// read backwards from the end and continued into rotated archives.
+async fn logs_tail_response(
+    data: &ServerDataWithConfig,
+    query: &std::collections::HashMap<String, String>,
+) -> ActixResult<HttpResponse> {
+    let lines = match query.get("lines") {
+        Some(value) => match value.parse::<usize>() {
+            Ok(n) if n > 0 => n.min(MAX_TAIL_LINES),
+            _ => {
+                return Ok(HttpResponse::BadRequest()
+                    .json(json!({ "error": "lines must be a positive number" })))
+            }
+        },
+        None => MAX_TAIL_LINES,
+    };
+    let since = match query.get("since") {
+        Some(value) => match parse_since(value) {
+            Some(ts) => Some(ts),
+            None => {
+                return Ok(HttpResponse::BadRequest().json(json!({
+                    "error": "since must be a unix timestamp or a date/time"
+                })))
+            }
+        },
+        None => None,
+    };
+
+    let files = ServerLogger::new(&data.server.name, data.server.port)
+        .and_then(|logger| logger.list_log_files())
+        .map_err(|e| {
+            log::error!("Failed to list log files: {}", e);
+            actix_web::error::ErrorInternalServerError("Failed to access log files")
+        })?;
+
+    let tail = tokio::task::spawn_blocking(move || tail_log_files(&files, lines, since))
+        .await
+        .map_err(|e| {
+            log::error!("Log tail task failed: {}", e);
+            actix_web::error::ErrorInternalServerError("Failed to read log entries")
+        })?;
+
+    let entries: Vec<serde_json::Value> = tail.lines.iter().map(|l| line_to_entry(l)).collect();
+    Ok(HttpResponse::Ok().json(json!({
+        "new_entries": entries,
+        "total_lines": entries.len(),
+        "files_read": tail.files_read,
+        "truncated": tail.truncated,
+        "status": "success"
+    })))
+}
+
+struct LogTail {
+    /// Oldest first
+    lines: Vec<String>,
+    files_read: usize,
+    /// More matching lines existed than `max_lines`
+    truncated: bool,
+}
+
+/// Collects up to `max_lines` newest lines (newer than `since`, if given) from
+/// `files`, which are ordered newest first (current log, then `.1`, `.2`, ...).
+fn tail_log_files(files: &[PathBuf], max_lines: usize, since: Option<i64>) -> LogTail {
+    let mut newest_first = Vec::new();
+    let mut files_read = 0;
+    let mut truncated = false;
+
+    'files: for file in files {
+        files_read += 1;
+        let lines = match read_lines_reversed(file) {
+            Ok(lines) => lines,
+            Err(e) => {
+                log::warn!("Skipping unreadable log {}: {}", file.display(), e);
+                continue;
+            }
+        };
+        for line in lines {
+            if let Some(since) = since {
+                // Entries are chronological: the first older one ends the scan
+                if entry_unix_time(&line).is_some_and(|ts| ts < since) {
+                    break 'files;
+                }
+            }
+            if newest_first.len() == max_lines {
+                truncated = true;
+                break 'files;
+            }
+            newest_first.push(line);
+        }
+    }
+
+    newest_first.reverse();
+    LogTail {
+        lines: newest_first,
+        files_read,
+        truncated,
+    }
+}
+
+/// Lines of one log file, newest first. Plain files are read backwards one
+/// block at a time as the iterator advances, so stopping early reads only the
+/// tail; gzip archives cannot seek and are decoded whole.
+fn read_lines_reversed(
+    path: &std::path::Path,
+) -> std::io::Result<Box<dyn Iterator<Item = String>>> {
+    use std::io::Read;
+
+    if path.extension().is_some_and(|ext| ext == "gz") {
+        let mut content = String::new();
+        flate2::read::GzDecoder::new(std::fs::File::open(path)?).read_to_string(&mut content)?;
+        let lines: Vec<String> = content
+            .lines()
+            .rev()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| l.trim().to_string())
+            .collect();
+        return Ok(Box::new(lines.into_iter()));
+    }
 
+    let file = std::fs::File::open(path)?;
+    let position = file.metadata()?.len();
+    Ok(Box::new(ReverseLines {
+        file,
+        position,
+        carry: Vec::new(),
+        pending: Vec::new(),
+    }))
+}
+
+/// Non-empty lines of a plain file from the end, one block read per refill
+struct ReverseLines {
+    file: std::fs::File,
+    /// Start of the part not read yet
+    position: u64,
+    /// Start of a line that continues in the previous block
+    carry: Vec<u8>,
+    /// Lines of the last block read, oldest first (taken from the back)
+    pending: Vec<String>,
+}
+
+impl ReverseLines {
+    const BLOCK: u64 = 64 * 1024;
+
+    /// Reads blocks until `pending` has lines; false at the start of the file
+    fn refill(&mut self) -> std::io::Result<bool> {
+        use std::io::{Read, Seek, SeekFrom};
+
+        while self.pending.is_empty() {
+            if self.position == 0 {
+                return Ok(false);
+            }
+            let read_size = Self::BLOCK.min(self.position);
+            self.position -= read_size;
+            self.file.seek(SeekFrom::Start(self.position))?;
+            let mut block = vec![0u8; read_size as usize];
+            self.file.read_exact(&mut block)?;
+            block.extend_from_slice(&self.carry);
+
+            // Everything before the first newline may continue in the previous block
+            let split = if self.position > 0 {
+                block.iter().position(|b| *b == b'\n').map(|i| i + 1)
+            } else {
+                Some(0)
+            };
+            let Some(split) = split else {
+                self.carry = block;
+                continue;
+            };
+            self.carry = block[..split].to_vec();
+
+            self.pending = String::from_utf8_lossy(&block[split..])
+                .lines()
+                .filter(|l| !l.trim().is_empty())
+                .map(|l| l.trim().to_string())
+                .collect();
+        }
+        Ok(true)
+    }
+}
+
+impl Iterator for ReverseLines {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        match self.refill() {
+            Ok(true) => self.pending.pop(),
+            Ok(false) => None,
+            Err(e) => {
+                log::warn!("Stopped reading log backwards: {}", e);
+                self.position = 0;
+                self.carry.clear();
+                None
+            }
+        }
+    }
+}
+
+/// Unix seconds of a log line: `timestamp_unix` (native) or `timestamp`
+fn entry_unix_time(line: &str) -> Option<i64> {
+    let value = serde_json::from_str::<serde_json::Value>(line).ok()?;
+    if let Some(ts) = value.get("timestamp_unix").and_then(|v| v.as_i64()) {
+        return Some(ts);
+    }
+    parse_since(value.get("timestamp")?.as_str()?)
+}
+
+fn parse_since(value: &str) -> Option<i64> {
+    let value = value.trim();
+    if let Ok(ts) = value.parse::<i64>() {
+        return Some(ts);
+    }
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Some(dt.timestamp());
+    }
+    [
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%dT%H:%M:%S",
+    ]
+    .iter()
+    .find_map(|fmt| chrono::NaiveDateTime::parse_from_str(value, fmt).ok())
+    .and_then(|naive| naive.and_local_timezone(chrono::Local).earliest())
+    .map(|local| local.timestamp())
+}
+
+/// Reads complete, non-empty lines starting at `offset`. Returns the lines and
+/// the offset just past the last complete line, so a line that is still being
+/// written is picked up whole on the next call.
+async fn read_appended_lines(
+    file_path: &PathBuf,
+    offset: u64,
+    max_lines: usize,
+) -> std::io::Result<(Vec<String>, u64)> {
+    use tokio::io::AsyncSeekExt;
+
+    let mut file = fs::File::open(file_path).await?;
     if offset > 0 {
-        use tokio::io::AsyncSeekExt;
-        let mut file_with_seek = fs::File::open(file_path).await?;
-        file_with_seek
-            .seek(std::io::SeekFrom::Start(offset))
-            .await?;
-        reader = BufReader::new(file_with_seek);
+        file.seek(std::io::SeekFrom::Start(offset)).await?;
     }
+    let mut reader = BufReader::new(file);
 
-    let mut entries = Vec::new();
+    let mut lines = Vec::new();
     let mut line = String::new();
-    let mut lines_read = 0;
-    const MAX_LINES_PER_REQUEST: usize = 100;
+    let mut position = offset;
 
-    while lines_read < MAX_LINES_PER_REQUEST {
+    while lines.len() < max_lines {
         line.clear();
         let bytes_read = reader.read_line(&mut line).await?;
-
-        if bytes_read == 0 {
+        if bytes_read == 0 || !line.ends_with('\n') {
             break;
         }
+        position += bytes_read as u64;
 
         let trimmed_line = line.trim();
-        if trimmed_line.is_empty() {
-            continue;
+        if !trimmed_line.is_empty() {
+            lines.push(trimmed_line.to_string());
         }
+    }
 
-        if let Ok(json_entry) = serde_json::from_str::<serde_json::Value>(trimmed_line) {
-            entries.push(json_entry);
-        } else {
-            entries.push(json!({
-                "timestamp": chrono::Local::now().to_rfc3339(),
-                "timestamp_unix": chrono::Utc::now().timestamp(),
-                "event_type": "PlainText",
-                "message": trimmed_line,
-                "level": "INFO"
-            }));
-        }
+    Ok((lines, position))
+}
 
-        lines_read += 1;
-    }
+fn line_to_entry(line: &str) -> serde_json::Value {
+    serde_json::from_str::<serde_json::Value>(line).unwrap_or_else(|_| {
+        json!({
+            "timestamp": chrono::Local::now().to_rfc3339(),
+            "timestamp_unix": chrono::Utc::now().timestamp(),
+            "event_type": "PlainText",
+            "message": line,
+            "level": "INFO"
+        })
+    })
+}
+
+/// Server-Sent Events: pushes every line `ServerLogger` appends to this
+/// server's log. The stream is dropped by actix when the client disconnects.
+pub async fn logs_stream_handler(
+    data: web::Data<ServerDataWithConfig>,
+) -> ActixResult<HttpResponse> {
+    use actix_web::http::header::{CacheControl, CacheDirective, ContentEncoding};
+    use tokio::sync::broadcast::error::RecvError;
+
+    // Only lines written after the client connected
+    let receiver = ServerLogger::new(&data.server.name, data.server.port)
+        .map_err(|e| {
+            log::error!("Failed to open server log: {}", e);
+            actix_web::error::ErrorInternalServerError("Internal server error")
+        })?
+        .subscribe();
+
+    let stream = futures::stream::unfold(receiver, |mut receiver| async move {
+        // Comment frame every 15s so dead connections are noticed
+        const KEEP_ALIVE: Duration = Duration::from_secs(15);
+
+        let frame = match tokio::time::timeout(KEEP_ALIVE, receiver.recv()).await {
+            Ok(Ok(line)) => format!("data: {}\n\n", line),
+            Ok(Err(RecvError::Lagged(skipped))) => format!(": skipped {} lines\n\n", skipped),
+            Ok(Err(RecvError::Closed)) => return None,
+            Err(_) => ": keep-alive\n\n".to_string(),
+        };
+        Some((Ok::<_, actix_web::Error>(web::Bytes::from(frame)), receiver))
+    });
 
-    Ok(entries)
+    Ok(HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header(CacheControl(vec![CacheDirective::NoCache]))
+        // Keeps the Compress middleware from buffering the stream
+        .insert_header(ContentEncoding::Identity)
+        .streaming(stream))
 }
 
 async fn get_log_stats(
@@ -282,11 +577,19 @@ async fn get_log_stats(
             if log_entry.get("event_type").and_then(|v| v.as_str()) == Some("Request") {
                 total_requests += 1;
 
-                if let Some(ip) = log_entry.get("ip_address").and_then(|v| v.as_str()) {
+                let ip = log_entry
+                    .get("ip_address")
+                    .or_else(|| log_entry.get("remote_ip"))
+                    .and_then(|v| v.as_str());
+                if let Some(ip) = ip {
                     unique_ips.insert(ip.to_string());
                 }
 
-                if let Some(status) = log_entry.get("status_code").and_then(|v| v.as_u64()) {
+                let status = log_entry
+                    .get("status_code")
+                    .or_else(|| log_entry.get("status"))
+                    .and_then(|v| v.as_u64());
+                if let Some(status) = status {
                     if status >= 400 {
                         error_requests += 1;
                     }
@@ -323,3 +626,93 @@ async fn get_log_stats(
         "lines_processed": line_count
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_read_appended_lines_skips_partial_line() {
+        let path = std::env::temp_dir().join(format!("rush-logs-tail-{}.log", std::process::id()));
+        std::fs::write(&path, "first\n\nsecond\nthi").unwrap();
+
+        let (lines, offset) = read_appended_lines(&path, 0, 100).await.unwrap();
+        assert_eq!(lines, vec!["first", "second"]);
+        assert_eq!(offset, "first\n\nsecond\n".len() as u64);
+
+        std::fs::write(&path, "first\n\nsecond\nthird\n").unwrap();
+        let (lines, _) = read_appended_lines(&path, offset, 100).await.unwrap();
+        assert_eq!(lines, vec!["third"]);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_tail_stitches_archives_and_respects_since() {
+        let dir = std::env::temp_dir().join(format!("rush-logs-stitch-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let entry = |ts: i64| format!(r#"{{"timestamp_unix":{},"event_type":"Request"}}"#, ts);
+
+        let current = dir.join("demo-[8080].log");
+        std::fs::write(&current, format!("{}\n{}\n", entry(40), entry(50))).unwrap();
+
+        let archive = dir.join("demo-[8080].1.log.gz");
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        use std::io::Write;
+        encoder
+            .write_all(format!("{}\n{}\n{}\n", entry(10), entry(20), entry(30)).as_bytes())
+            .unwrap();
+        std::fs::write(&archive, encoder.finish().unwrap()).unwrap();
+
+        let files = vec![current, archive];
+        let ts = |tail: &LogTail| -> Vec<i64> {
+            tail.lines
+                .iter()
+                .filter_map(|l| entry_unix_time(l))
+                .collect()
+        };
+
+        let tail = tail_log_files(&files, 3, None);
+        assert_eq!(ts(&tail), vec![30, 40, 50]);
+        assert!(tail.truncated);
+        assert_eq!(tail.files_read, 2);
+
+        let tail = tail_log_files(&files, 100, Some(20));
+        assert_eq!(ts(&tail), vec![20, 30, 40, 50]);
+        assert!(!tail.truncated);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_read_lines_reversed_across_blocks() {
+        let path = std::env::temp_dir().join(format!("rush-logs-rev-{}.log", std::process::id()));
+        let content: String = (0..20_000).map(|i| format!("line-{}\n", i)).collect();
+        std::fs::write(&path, content).unwrap();
+
+        let lines: Vec<String> = read_lines_reversed(&path).unwrap().collect();
+        assert_eq!(lines.len(), 20_000);
+        assert_eq!(lines[0], "line-19999");
+        assert_eq!(lines[19_999], "line-0");
+
+        // Taking the newest lines only reads the last block
+        let file = std::fs::File::open(&path).unwrap();
+        let len = file.metadata().unwrap().len();
+        let mut lazy = ReverseLines {
+            file,
+            position: len,
+            carry: Vec::new(),
+            pending: Vec::new(),
+        };
+        assert_eq!(lazy.next().as_deref(), Some("line-19999"));
+        assert_eq!(lazy.position, len - ReverseLines::BLOCK);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_parse_since_formats() {
+        assert_eq!(parse_since("1700000000"), Some(1_700_000_000));
+        assert_eq!(parse_since("2023-11-14T22:13:20Z"), Some(1_700_000_000));
+        assert!(parse_since("2024-01-01 12:00:00").is_some());
+        assert_eq!(parse_since("yesterday"), None);
+    }
+}
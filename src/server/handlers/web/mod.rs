@@ -12,11 +12,16 @@ pub use templates::*;
 
 use crate::core::config::Config;
 use crate::server::logging::ServerLogger;
-use crate::server::middleware::{ApiKeyAuth, LoggingMiddleware, PinProtection, RateLimiter};
+use crate::server::middleware::{
+    ApiKeyAuth, DashboardAuth, LoggingMiddleware, PinProtection, RateLimiter, RequestRateLimit,
+    RequestRateState,
+};
 use crate::server::tls::TlsManager;
-use crate::server::types::{ServerContext, ServerData, ServerInfo};
+use crate::server::types::{InFlightGuard, ServerContext, ServerData, ServerInfo};
 use crate::server::watchdog::{get_watchdog_manager, ws_hot_reload};
 use actix_cors::Cors;
+use actix_web::dev::Service;
+use actix_web::http::header::{HeaderName, HeaderValue, ACCEPT_ENCODING};
 use actix_web::{middleware, web, App, HttpServer};
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -47,7 +52,7 @@ pub fn create_server_directory_and_files(
     server_name: &str,
     port: u16,
 ) -> crate::core::error::Result<PathBuf> {
-    let base_dir = crate::core::helpers::get_base_dir()?;
+    let base_dir = crate::core::paths::base_dir()?;
 
     let server_dir = base_dir
         .join("www")
@@ -72,32 +77,108 @@ pub fn create_server_directory_and_files(
     Ok(server_dir)
 }
 
+/// Why `create_web_server` could not bring a server up
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StartError {
+    /// Another process already listens on this port
+    AddrInUse(u16),
+    Failed(String),
+}
+
+impl std::fmt::Display for StartError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AddrInUse(port) => write!(f, "Port {} is already in use", port),
+            Self::Failed(msg) => f.write_str(msg),
+        }
+    }
+}
+
+/// Maps a bind error, keeping "address in use" apart so callers can offer a free port
+fn bind_error(port: u16, what: &str, e: std::io::Error) -> StartError {
+    if e.kind() == std::io::ErrorKind::AddrInUse {
+        StartError::AddrInUse(port)
+    } else {
+        StartError::Failed(format!("{} bind failed: {}", what, e))
+    }
+}
+
 pub fn create_web_server(
     ctx: &ServerContext,
     server_info: ServerInfo,
     config: &Config,
-) -> std::result::Result<actix_web::dev::ServerHandle, String> {
+) -> std::result::Result<actix_web::dev::ServerHandle, StartError> {
     create_web_server_with_workers(ctx, server_info, config, None)
 }
 
+/// The server's `--header` pairs; entries edited into an invalid shape in
+/// servers.json are skipped with a warning
+fn custom_headers(server_info: &ServerInfo) -> Vec<(HeaderName, HeaderValue)> {
+    server_info
+        .headers
+        .iter()
+        .filter_map(|(name, value)| {
+            match (
+                HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_str(value),
+            ) {
+                (Ok(name), Ok(value)) => Some((name, value)),
+                _ => {
+                    log::warn!(
+                        "Skipping invalid header '{}: {}' of server {}",
+                        name,
+                        value,
+                        server_info.name
+                    );
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+/// `start --workers` beats the server's own `create --workers`, which beats
+/// `[server] workers`
+pub fn effective_workers(
+    server_info: &ServerInfo,
+    config: &Config,
+    workers_override: Option<usize>,
+) -> usize {
+    workers_override
+        .or(server_info.workers)
+        .unwrap_or(config.server.workers)
+}
+
 pub fn create_web_server_with_workers(
     ctx: &ServerContext,
     server_info: ServerInfo,
     config: &Config,
     workers_override: Option<usize>,
-) -> std::result::Result<actix_web::dev::ServerHandle, String> {
+) -> std::result::Result<actix_web::dev::ServerHandle, StartError> {
     let server_id = server_info.id.clone();
     let server_name = server_info.name.clone();
     let server_port = server_info.port;
-    let servers_clone = Arc::clone(&ctx.servers);
+    let run = ctx.begin_run(&server_id);
+    let ctx_for_thread = ctx.clone();
 
     let server_logger =
         match ServerLogger::new_with_config(&server_name, server_info.port, &config.logging) {
             Ok(logger) => Arc::new(logger),
-            Err(e) => return Err(format!("Logger creation failed: {}", e)),
+            Err(e) => return Err(StartError::Failed(format!("Logger creation failed: {}", e))),
         };
 
-    if let Err(e) = crate::server::watchdog::start_server_watching(&server_name, server_port) {
+    let hot_reload = config.server.hot_reload;
+    if !hot_reload {
+        log::info!("Hot reload disabled, not watching files of {}", server_name);
+    } else if let Err(e) = crate::server::watchdog::start_server_watching(
+        &server_name,
+        server_port,
+        Duration::from_millis(config.server.hot_reload_debounce_ms),
+        crate::server::watchdog::WatchFilter::new(
+            &config.server.hot_reload_include,
+            &config.server.hot_reload_exclude,
+        ),
+    ) {
         log::warn!("Failed to start file watching for {}: {}", server_name, e);
     } else {
         log::info!(
@@ -123,6 +204,9 @@ pub fn create_web_server_with_workers(
         },
         proxy_http_port: get_proxy_http_port(),
         proxy_https_port: get_proxy_https_port(),
+        index_files: config.server.index_files.clone(),
+        env: server_info.env.clone(),
+        hot_reload,
     });
 
     let server_logger_for_app = server_logger.clone();
@@ -136,7 +220,18 @@ pub fn create_web_server_with_workers(
                 &config.server.production_domain,
             ) {
                 Ok(rustls_config) => {
-                    log::info!("TLS certificate loaded for {}:{}", server_name, server_port);
+                    if config.server.use_lets_encrypt {
+                        log::info!("TLS certificate loaded for {}:{}", server_name, server_port);
+                    } else {
+                        // Offline mode: no ACME, so HTTPS relies on the generated cert
+                        log::warn!(
+                            "TLS for {}:{} uses a self-signed certificate ({}.localhost), \
+                             browsers will show a warning until it is trusted",
+                            server_name,
+                            server_port,
+                            server_name
+                        );
+                    }
                     Some(rustls_config)
                 }
                 Err(e) => {
@@ -153,42 +248,86 @@ pub fn create_web_server_with_workers(
         None
     };
 
-    let production_domain = config.server.production_domain.clone();
+    let cors_policy = CorsPolicy::from_config(
+        &config.server.cors_origins,
+        &config.server.production_domain,
+    );
     let api_key = config.server.api_key.clone();
+    let dashboard_auth = DashboardAuth::new(
+        &config.server.dashboard_user,
+        config.server.dashboard_password.clone(),
+    );
     let rate_limit_rps = config.server.rate_limit_rps;
     let rate_limit_enabled = config.server.rate_limit_enabled;
+    let rate_limit_per_min = config.server.rate_limit_per_min;
+    // Created outside the factory so all workers count against one window
+    let request_rate_state = web::Data::new(RequestRateState::default());
     let pin_server_name = server_name.clone();
     let pin_server_port = server_port;
+    let compression = Compression::from_config(&config.server.compression);
+    let in_flight = ctx.in_flight_counter(&server_id);
+    let custom_headers = custom_headers(&server_info);
     let mut http_server = HttpServer::new(move || {
-        let prod_domain = production_domain.clone();
+        let cors_policy = cors_policy.clone();
+        let in_flight = in_flight.clone();
+        let default_headers = custom_headers
+            .iter()
+            .cloned()
+            .fold(middleware::DefaultHeaders::new(), |headers, pair| {
+                headers.add(pair)
+            });
         App::new()
             .app_data(server_data.clone())
             .app_data(web::Data::from(watchdog_manager.clone()))
+            .app_data(request_rate_state.clone())
             .wrap(LoggingMiddleware::new(server_logger_for_app.clone()))
+            .wrap(RequestRateLimit::new(
+                rate_limit_per_min,
+                request_rate_state.clone(),
+                server_logger_for_app.clone(),
+            ))
             .wrap(RateLimiter::new(rate_limit_rps, rate_limit_enabled))
             .wrap(ApiKeyAuth::new(api_key.clone()))
+            .wrap(dashboard_auth.clone())
             .wrap(PinProtection::new(&pin_server_name, pin_server_port))
-            .wrap(middleware::Compress::default())
+            // Per-server headers; a header the handler already set is kept
+            .wrap(default_headers)
+            // "off" leaves Compress out of the chain entirely
+            .wrap(middleware::Condition::new(
+                compression != Compression::Off,
+                middleware::Compress::default(),
+            ))
+            // Narrows Accept-Encoding before Compress negotiates (brotli/gzip only)
+            .wrap_fn(move |mut req, srv| {
+                let accept = req
+                    .headers()
+                    .get(ACCEPT_ENCODING)
+                    .and_then(|value| value.to_str().ok());
+                if let Some(coding) = compression.restrict_accept_encoding(accept) {
+                    req.headers_mut()
+                        .insert(ACCEPT_ENCODING, HeaderValue::from_static(coding));
+                }
+                srv.call(req)
+            })
             .wrap(
                 Cors::default()
                     .allowed_origin_fn(move |origin, _req_head| {
-                        let origin_str = origin.to_str().unwrap_or("");
-                        // Always allow local development
-                        let is_local =
-                            origin_str.contains("127.0.0.1") || origin_str.contains("localhost");
-                        if is_local {
-                            return true;
-                        }
-                        // Allow production domain if configured
-                        if prod_domain != "localhost" {
-                            return origin_str.contains(&prod_domain);
-                        }
-                        false
+                        cors_policy.allows(origin.to_str().unwrap_or(""))
                     })
                     .allow_any_method()
                     .allow_any_header()
                     .max_age(3600),
             )
+            // Outermost: counts every request so a drain can wait for it
+            .wrap_fn(move |req, srv| {
+                let guard = InFlightGuard::enter(&in_flight);
+                let fut = srv.call(req);
+                async move {
+                    let res = fut.await;
+                    drop(guard);
+                    res
+                }
+            })
             // Assets
             .route("/.rss/_reset.css", web::get().to(serve_global_reset_css))
             .route("/.rss/style.css", web::get().to(serve_system_css))
@@ -197,7 +336,7 @@ pub fn create_web_server_with_workers(
             // Font Assets
             .route("/.rss/fonts/{font}", web::get().to(serve_quicksand_font))
             // JavaScript Assets
-            .route("/rss.js", web::get().to(serve_rss_js))
+            .route(DASHBOARD_LOADER_PATH, web::get().to(serve_rss_js))
             .route("/.rss/js/rush-app.js", web::get().to(serve_rush_app_js))
             .route("/.rss/js/rush-api.js", web::get().to(serve_rush_api_js))
             .route("/.rss/js/rush-ui.js", web::get().to(serve_rush_ui_js))
@@ -213,6 +352,7 @@ pub fn create_web_server_with_workers(
             .route("/api/close-browser", web::get().to(close_browser_handler))
             .route("/api/logs", web::get().to(logs_handler))
             .route("/api/logs/raw", web::get().to(logs_raw_handler))
+            .route("/api/logs/stream", web::get().to(logs_stream_handler))
             .route("/api/acme/status", web::get().to(acme_status_handler))
             .route("/api/acme/dashboard", web::get().to(acme_dashboard_handler))
             .route("/api/analytics", web::get().to(analytics_handler))
@@ -231,18 +371,23 @@ pub fn create_web_server_with_workers(
                 "/.well-known/acme-challenge/{token}",
                 web::get().to(acme_challenge_handler),
             )
-            // WebSocket Routes
-            .route("/ws/hot-reload", web::get().to(ws_hot_reload))
+            // Hot reload: page script and WebSocket, left out with server.hot_reload = false
+            .configure(|cfg| {
+                if hot_reload {
+                    cfg.route("/rss.js", web::get().to(serve_rss_js))
+                        .route("/ws/hot-reload", web::get().to(ws_hot_reload));
+                }
+            })
             // Fallback (must be last)
             .default_service(web::route().to(serve_fallback_or_inject))
     })
-    .workers(workers_override.unwrap_or(config.server.workers))
+    .workers(effective_workers(&server_info, config, workers_override))
     .shutdown_timeout(config.server.shutdown_timeout)
     .disable_signals();
 
     http_server = http_server
         .bind((&*config.server.bind_address, server_info.port))
-        .map_err(|e| format!("HTTP bind failed: {}", e))?;
+        .map_err(|e| bind_error(server_info.port, "HTTP", e))?;
 
     if let Some(tls_cfg) = tls_config {
         let https_port = server_port + config.server.https_port_offset;
@@ -264,7 +409,7 @@ pub fn create_web_server_with_workers(
                 );
                 log::info!("Continuing with HTTP only");
                 // http_server was consumed by bind_rustls_021, need to return error
-                return Err(format!("HTTPS bind failed: {}", e));
+                return Err(bind_error(https_port, "HTTPS", e));
             }
         }
     }
@@ -277,6 +422,7 @@ pub fn create_web_server_with_workers(
     let startup_delay = config.server.startup_delay_ms;
     let server_name_for_cleanup = server_name.clone();
     let server_port_for_cleanup = server_port;
+    let server_info_for_cleanup = server_info.clone();
 
     if config.proxy.enabled {
         let proxy_manager = crate::server::shared::get_proxy_manager();
@@ -313,7 +459,7 @@ pub fn create_web_server_with_workers(
         });
     }
 
-    std::thread::spawn(move || {
+    let server_thread = std::thread::spawn(move || {
         // Use single-threaded runtime per server to minimize FD/thread overhead.
         // Actix-web manages its own worker threads separately.
         let rt = match tokio::runtime::Builder::new_current_thread()
@@ -327,11 +473,11 @@ pub fn create_web_server_with_workers(
                     server_id_for_thread,
                     e
                 );
-                if let Ok(mut servers) = servers_clone.write() {
-                    if let Some(server) = servers.get_mut(&server_id_for_thread) {
-                        server.status = crate::server::types::ServerStatus::Failed;
-                    }
-                }
+                ctx_for_thread.set_status_for_run(
+                    &server_id_for_thread,
+                    run,
+                    crate::server::types::ServerStatus::Failed,
+                );
                 return;
             }
         };
@@ -340,45 +486,245 @@ pub fn create_web_server_with_workers(
                 Ok(_) => log::info!("Server {} ended normally", server_id_for_thread),
                 Err(e) => {
                     log::error!("Server {} error: {}", server_id_for_thread, e);
-                    if let Ok(mut servers) = servers_clone.write() {
-                        if let Some(server) = servers.get_mut(&server_id_for_thread) {
-                            server.status = crate::server::types::ServerStatus::Failed;
-                        }
-                    }
+                    ctx_for_thread.set_status_for_run(
+                        &server_id_for_thread,
+                        run,
+                        crate::server::types::ServerStatus::Failed,
+                    );
                 }
             }
 
-            if let Err(e) = crate::server::watchdog::stop_server_watching(
-                &server_name_for_cleanup,
-                server_port_for_cleanup,
-            ) {
-                log::warn!("Failed to stop file watching: {}", e);
-            } else {
-                log::info!(
-                    "File watching stopped for server {}",
-                    server_name_for_cleanup
-                );
+            if hot_reload {
+                if let Err(e) = crate::server::watchdog::stop_server_watching(
+                    &server_name_for_cleanup,
+                    server_port_for_cleanup,
+                ) {
+                    log::warn!("Failed to stop file watching: {}", e);
+                } else {
+                    log::info!(
+                        "File watching stopped for server {}",
+                        server_name_for_cleanup
+                    );
+                }
             }
 
             if let Err(e) = logger_for_cleanup.log_server_stop().await {
                 log::error!("Failed to log server stop: {}", e);
             }
 
-            if let Ok(mut servers) = servers_clone.write() {
-                if let Some(server) = servers.get_mut(&server_id_for_thread) {
-                    server.status = crate::server::types::ServerStatus::Stopped;
-                }
-            }
+            // Skipped when a newer run of this server has started meanwhile
+            ctx_for_thread.set_status_for_run(
+                &server_id_for_thread,
+                run,
+                crate::server::types::ServerStatus::Stopped,
+            );
+
+            // After the handle stopped, however the stop was triggered
+            crate::server::manager::run_on_stop_hook(&server_info_for_cleanup, &logger_for_cleanup)
+                .await;
         });
     });
+    ctx.track_thread(server_thread);
 
     std::thread::sleep(Duration::from_millis(startup_delay));
     Ok(server_handle)
 }
 
+/// Response compression selected by `server.compression`
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Compression {
+    Off,
+    /// Any encoding the client and actix agree on
+    Auto,
+    /// Only this content coding ("br" or "gzip")
+    Only(&'static str),
+}
+
+impl Compression {
+    fn from_config(value: &str) -> Self {
+        match value {
+            "off" => Self::Off,
+            "brotli" => Self::Only("br"),
+            "gzip" => Self::Only("gzip"),
+            _ => Self::Auto,
+        }
+    }
+
+    /// Accept-Encoding to hand to Compress: the configured coding if the
+    /// client accepts it, otherwise "identity". `None` leaves the header as is.
+    fn restrict_accept_encoding(self, accept: Option<&str>) -> Option<&'static str> {
+        let Self::Only(coding) = self else {
+            return None;
+        };
+        let accepted = accept.is_some_and(|value| {
+            value.split(',').any(|item| {
+                let mut parts = item.split(';').map(str::trim);
+                let name = parts.next().unwrap_or_default();
+                let quality = parts
+                    .find_map(|p| p.strip_prefix("q="))
+                    .and_then(|q| q.trim().parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                (name.eq_ignore_ascii_case(coding) || name == "*") && quality > 0.0
+            })
+        });
+        Some(if accepted { coding } else { "identity" })
+    }
+}
+
+/// Cross-origin access selected by `server.cors_origins`. Disallowed origins
+/// still reach the handlers, they just get no Access-Control-* headers and
+/// their OPTIONS preflights are rejected.
+#[derive(Debug, Clone, PartialEq)]
+enum CorsPolicy {
+    Off,
+    /// localhost, *.localhost and 127.0.0.1 on any port (dev default)
+    Local,
+    /// Exactly these origins, lowercased and without a trailing slash
+    Origins(Vec<String>),
+    Any,
+}
+
+impl CorsPolicy {
+    fn from_config(origins: &[String], production_domain: &str) -> Self {
+        if origins.iter().any(|origin| origin.trim() == "*") {
+            Self::Any
+        } else if !origins.is_empty() {
+            Self::Origins(
+                origins
+                    .iter()
+                    .map(|origin| origin.trim().trim_end_matches('/').to_ascii_lowercase())
+                    .collect(),
+            )
+        } else if production_domain == "localhost" {
+            Self::Local
+        } else {
+            Self::Off
+        }
+    }
+
+    fn allows(&self, origin: &str) -> bool {
+        match self {
+            Self::Off => false,
+            Self::Any => true,
+            Self::Origins(allowed) => allowed.iter().any(|a| a.eq_ignore_ascii_case(origin)),
+            Self::Local => {
+                let authority = origin.split_once("://").map_or(origin, |(_, rest)| rest);
+                let host = match authority.rsplit_once(':') {
+                    Some((host, port)) if port.bytes().all(|b| b.is_ascii_digit()) => host,
+                    _ => authority,
+                };
+                host == "localhost" || host.ends_with(".localhost") || host == "127.0.0.1"
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ServerDataWithConfig {
     pub server: ServerData,
     pub proxy_http_port: u16,
     pub proxy_https_port: u16,
+    /// Directory index names from `server.index_files`
+    pub index_files: Vec<String>,
+    /// Per-server `{{KEY}}` replacements for served HTML
+    pub env: std::collections::BTreeMap<String, String>,
+    /// `server.hot_reload`: inject `/rss.js` into served HTML
+    pub hot_reload: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compression_restricts_accept_encoding() {
+        let auto = Compression::from_config("auto");
+        assert_eq!(auto.restrict_accept_encoding(Some("gzip, br")), None);
+
+        let brotli = Compression::from_config("brotli");
+        assert_eq!(brotli, Compression::Only("br"));
+        assert_eq!(
+            brotli.restrict_accept_encoding(Some("gzip, deflate, br")),
+            Some("br")
+        );
+        assert_eq!(
+            brotli.restrict_accept_encoding(Some("gzip, br;q=0")),
+            Some("identity")
+        );
+        assert_eq!(brotli.restrict_accept_encoding(None), Some("identity"));
+
+        let gzip = Compression::from_config("gzip");
+        assert_eq!(gzip.restrict_accept_encoding(Some("*;q=0.5")), Some("gzip"));
+        assert_eq!(gzip.restrict_accept_encoding(Some("br")), Some("identity"));
+        assert_eq!(Compression::from_config("off"), Compression::Off);
+    }
+
+    #[test]
+    fn test_cors_policy_dev_prod_and_explicit_origins() {
+        let dev = CorsPolicy::from_config(&[], "localhost");
+        assert_eq!(dev, CorsPolicy::Local);
+        assert!(dev.allows("http://localhost:3000"));
+        assert!(dev.allows("http://blog.localhost:3000"));
+        assert!(dev.allows("https://127.0.0.1"));
+        assert!(!dev.allows("http://localhost.evil.com"));
+        assert!(!dev.allows("http://127.0.0.1.evil.com:80"));
+
+        // A real production_domain switches the implicit CORS off
+        let prod = CorsPolicy::from_config(&[], "example.com");
+        assert_eq!(prod, CorsPolicy::Off);
+        assert!(!prod.allows("http://localhost:3000"));
+        assert!(!prod.allows("https://example.com"));
+
+        let listed = CorsPolicy::from_config(&["https://App.example.com/".into()], "example.com");
+        assert!(listed.allows("https://app.example.com"));
+        assert!(!listed.allows("http://app.example.com"));
+        assert!(!listed.allows("http://localhost:3000"));
+
+        let any = CorsPolicy::from_config(&["*".into()], "example.com");
+        assert!(any.allows("https://anything.test"));
+    }
+
+    #[test]
+    fn test_effective_workers_precedence() {
+        let mut config = Config::default();
+        config.server.workers = 2;
+        let mut server = ServerInfo::default();
+        assert_eq!(effective_workers(&server, &config, None), 2);
+
+        server.workers = Some(1);
+        assert_eq!(effective_workers(&server, &config, None), 1);
+        assert_eq!(effective_workers(&server, &config, Some(4)), 4);
+
+        // Older registries without the field still load
+        let restored: ServerInfo = serde_json::from_str(
+            r#"{"id":"a","name":"blog","port":8001,"status":"Stopped","created_at":"","created_timestamp":0}"#,
+        )
+        .unwrap();
+        assert_eq!(restored.workers, None);
+    }
+
+    #[test]
+    fn test_custom_headers_skip_invalid_entries() {
+        let mut server = ServerInfo::default();
+        server
+            .headers
+            .insert("x-frame-options".into(), "DENY".into());
+        server.headers.insert("bad name".into(), "x".into());
+        let headers = custom_headers(&server);
+        assert_eq!(headers.len(), 1);
+        assert_eq!(headers[0].0, "x-frame-options");
+        assert_eq!(headers[0].1, "DENY");
+    }
+
+    #[test]
+    fn test_bind_error_detects_addr_in_use() {
+        let taken = std::io::Error::from(std::io::ErrorKind::AddrInUse);
+        assert_eq!(bind_error(8001, "HTTP", taken), StartError::AddrInUse(8001));
+
+        let denied = std::io::Error::from(std::io::ErrorKind::PermissionDenied);
+        assert!(matches!(
+            bind_error(80, "HTTP", denied),
+            StartError::Failed(msg) if msg.starts_with("HTTP bind failed")
+        ));
+    }
 }
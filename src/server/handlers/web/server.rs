@@ -1,6 +1,8 @@
 use super::ServerDataWithConfig;
 use crate::core::helpers::html_escape;
 use actix_web::{web, HttpRequest, HttpResponse, Result as ActixResult};
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
 
 pub async fn serve_fallback_or_inject(
     req: HttpRequest,
@@ -9,7 +11,7 @@ pub async fn serve_fallback_or_inject(
     let path = req.path();
     log::info!("Requested path: {}", path);
 
-    let base_dir = crate::core::helpers::get_base_dir().map_err(|e| {
+    let base_dir = crate::core::paths::base_dir().map_err(|e| {
         log::error!("Failed to get base directory: {}", e);
         actix_web::error::ErrorInternalServerError("Internal server error")
     })?;
@@ -17,18 +19,19 @@ pub async fn serve_fallback_or_inject(
         .join("www")
         .join(format!("{}-[{}]", data.server.name, data.server.port));
 
-    let mut file_path = if path == "/" {
-        server_dir.join("index.html")
-    } else {
-        server_dir.join(path.trim_start_matches('/'))
+    let Some(mut file_path) = resolve_static_path(&server_dir, path) else {
+        log::warn!("Path traversal attempt blocked: {}", path);
+        return Ok(HttpResponse::Forbidden()
+            .content_type("text/plain")
+            .body("Forbidden"));
     };
 
-    // Resolve directory paths to index.html
+    // Resolve directory paths (including "/") to the first existing index file
     if file_path.is_dir() {
-        file_path = file_path.join("index.html");
+        file_path = find_index_file(&file_path, &data.index_files);
     }
 
-    // Path traversal protection: ensure resolved path stays within server_dir
+    // Symlinks can still point outside: check the canonical target as well
     let canonical_server_dir = server_dir
         .canonicalize()
         .unwrap_or_else(|_| server_dir.clone());
@@ -45,20 +48,17 @@ pub async fn serve_fallback_or_inject(
 
     if file_path.exists() {
         if let Some(extension) = file_path.extension() {
-            if extension == "html" {
+            if extension == "html" || extension == "htm" {
                 log::info!("Loading custom HTML file");
                 match tokio::fs::read_to_string(&file_path).await {
-                    Ok(mut html_content) => {
-                        if !html_content.contains("/rss.js") {
-                            html_content = inject_rss_script(html_content);
-                        }
-
+                    Ok(html_content) => {
                         return Ok(HttpResponse::Ok()
                             .content_type("text/html; charset=utf-8")
-                            .body(html_content));
+                            .body(prepare_html(html_content, &data.env, data.hot_reload)));
                     }
                     Err(e) => {
                         log::error!("Failed to read HTML file: {}", e);
+                        return Ok(server_error(&server_dir, &data.env, data.hot_reload).await);
                     }
                 }
             } else {
@@ -89,6 +89,7 @@ pub async fn serve_fallback_or_inject(
                     }
                     Err(e) => {
                         log::error!("Failed to read file: {}", e);
+                        return Ok(server_error(&server_dir, &data.env, data.hot_reload).await);
                     }
                 }
             }
@@ -101,11 +102,7 @@ pub async fn serve_fallback_or_inject(
         let custom_404 = server_dir.join(&settings.custom_404_path);
         if custom_404.exists() {
             if let Ok(html) = tokio::fs::read_to_string(&custom_404).await {
-                let html = if !html.contains("/rss.js") {
-                    inject_rss_script(html)
-                } else {
-                    html
-                };
+                let html = prepare_html(html, &data.env, data.hot_reload);
                 let mut status = if path == "/" {
                     HttpResponse::Ok()
                 } else {
@@ -129,13 +126,42 @@ pub async fn serve_fallback_or_inject(
     }
 }
 
+/// `500.html` from the server directory, or the plain-text error
+async fn server_error(
+    server_dir: &Path,
+    env: &BTreeMap<String, String>,
+    hot_reload: bool,
+) -> HttpResponse {
+    match tokio::fs::read_to_string(server_dir.join("500.html")).await {
+        Ok(html) => HttpResponse::InternalServerError()
+            .content_type("text/html; charset=utf-8")
+            .body(prepare_html(html, env, hot_reload)),
+        Err(_) => HttpResponse::InternalServerError()
+            .content_type("text/plain")
+            .body("Internal server error"),
+    }
+}
+
+/// Injects `/rss.js` (unless the page already loads it or `server.hot_reload`
+/// is off) and fills env placeholders
+fn prepare_html(html: String, env: &BTreeMap<String, String>, hot_reload: bool) -> String {
+    let html = if !hot_reload || html.contains("/rss.js") {
+        html
+    } else {
+        inject_rss_script(html)
+    };
+    apply_env_placeholders(html, env)
+}
+
 async fn serve_system_fallback(
     req: &HttpRequest,
     data: web::Data<ServerDataWithConfig>,
 ) -> ActixResult<HttpResponse> {
     // PIN check for dashboard fallback at /
-    let server_dir =
-        crate::server::settings::ServerSettings::get_server_dir(&data.server.name, data.server.port);
+    let server_dir = crate::server::settings::ServerSettings::get_server_dir(
+        &data.server.name,
+        data.server.port,
+    );
     if let Some(ref dir) = server_dir {
         let settings = crate::server::settings::ServerSettings::load(dir);
         if settings.pin_enabled && !settings.pin_code.is_empty() {
@@ -162,16 +188,98 @@ async fn serve_system_fallback(
         .replace("{{VERSION}}", crate::server::config::get_server_version())
         .replace("{{CREATION_TIME}}", &chrono::Local::now().to_rfc3339());
 
-    let html_with_script = inject_rss_script(html_content);
+    let html_with_script = inject_dashboard_script(html_content, data.hot_reload);
 
     Ok(HttpResponse::Ok()
         .content_type("text/html; charset=utf-8")
         .body(html_with_script))
 }
 
+/// First of `index_files` that exists in `dir`; falls back to the first name
+/// (or `index.html`) so a missing index still resolves to a 404/fallback.
+fn find_index_file(dir: &Path, index_files: &[String]) -> PathBuf {
+    index_files
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|candidate| candidate.is_file())
+        .unwrap_or_else(|| {
+            let fallback = index_files.first().map(String::as_str);
+            dir.join(fallback.unwrap_or("index.html"))
+        })
+}
+
+/// Maps a request path to a file below `server_dir`, percent-decoding it first.
+/// Returns `None` if any component would leave the directory (`..`, encoded
+/// `%2e%2e`, backslash variants, drive prefixes) or the path is not valid UTF-8.
+pub(crate) fn resolve_static_path(server_dir: &Path, request_path: &str) -> Option<PathBuf> {
+    let decoded = percent_decode_utf8(request_path)?;
+    if decoded.contains('\0') {
+        return None;
+    }
+    let relative = decoded.replace('\\', "/");
+
+    let mut resolved = server_dir.to_path_buf();
+    for component in Path::new(relative.trim_start_matches('/')).components() {
+        match component {
+            Component::Normal(part) => resolved.push(part),
+            Component::CurDir => {}
+            Component::ParentDir | Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+    Some(resolved)
+}
+
+fn percent_decode_utf8(input: &str) -> Option<String> {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            // `get` instead of indexing: the next bytes may be inside a multi-byte char
+            let hex = input.get(i + 1..i + 3);
+            if let Some(byte) = hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8(decoded).ok()
+}
+
+/// Replaces `{{KEY}}` with the server's env values; other tokens stay as they are
+fn apply_env_placeholders(mut html: String, env: &BTreeMap<String, String>) -> String {
+    for (key, value) in env {
+        let token = format!("{{{{{}}}}}", key);
+        if html.contains(&token) {
+            html = html.replace(&token, value);
+        }
+    }
+    html
+}
+
 pub fn inject_rss_script(html: String) -> String {
+    inject_script(html, "/rss.js")
+}
+
+/// Dashboard pages need the loader even without hot reload; it is then
+/// served from `/.rss/js/` since `/rss.js` is not registered
+pub fn inject_dashboard_script(html: String, hot_reload: bool) -> String {
+    if hot_reload {
+        inject_rss_script(html)
+    } else {
+        inject_script(html, DASHBOARD_LOADER_PATH)
+    }
+}
+
+/// Route of the dashboard-only copy of `rss.js`
+pub const DASHBOARD_LOADER_PATH: &str = "/.rss/js/rss.js";
+
+fn inject_script(html: String, src: &str) -> String {
     // ES6 module script injection
-    let script_tag = r#"<script defer src="/rss.js"></script>"#;
+    let script_tag = format!(r#"<script defer src="{}"></script>"#, src);
     let css_link = r#"<link rel="stylesheet" href="/.rss/_reset.css">"#;
 
     // Insert CSS into <head>
@@ -198,6 +306,134 @@ pub fn inject_rss_script(html: String) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_apply_env_placeholders() {
+        let env = std::collections::BTreeMap::from([
+            ("API_URL".to_string(), "https://api.example.com".to_string()),
+            ("TITLE".to_string(), "Shop".to_string()),
+        ]);
+        let html = "<title>{{TITLE}}</title><a href=\"{{API_URL}}\">{{TITLE}}</a>{{UNKNOWN}}";
+        assert_eq!(
+            apply_env_placeholders(html.to_string(), &env),
+            "<title>Shop</title><a href=\"https://api.example.com\">Shop</a>{{UNKNOWN}}"
+        );
+        assert_eq!(
+            apply_env_placeholders(html.to_string(), &Default::default()),
+            html
+        );
+    }
+
+    #[tokio::test]
+    async fn test_server_error_page() {
+        use actix_web::http::StatusCode;
+
+        let dir = std::env::temp_dir().join(format!("rush-error-pages-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let env = BTreeMap::from([("TITLE".to_string(), "Shop".to_string())]);
+
+        // No 500.html: plain-text fallback
+        let fallback = server_error(&dir, &env, true).await;
+        assert_eq!(fallback.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        let body = actix_web::body::to_bytes(fallback.into_body())
+            .await
+            .unwrap();
+        assert_eq!(&body[..], b"Internal server error");
+
+        std::fs::write(
+            dir.join("500.html"),
+            "<html><body>{{TITLE}} is down</body></html>",
+        )
+        .unwrap();
+        let page = server_error(&dir, &env, true).await;
+        assert_eq!(page.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        let body = actix_web::body::to_bytes(page.into_body()).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains("Shop is down"));
+        assert!(body.contains("/rss.js"));
+
+        // server.hot_reload = false serves the page untouched
+        let page = server_error(&dir, &env, false).await;
+        let body = actix_web::body::to_bytes(page.into_body()).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains("Shop is down"));
+        assert!(!body.contains("rss.js"));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    // --- find_index_file tests ---
+
+    #[test]
+    fn test_find_index_file_first_existing_wins() {
+        let dir = std::env::temp_dir().join(format!("rush-index-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("index.htm"), "htm").unwrap();
+        std::fs::write(dir.join("default.html"), "default").unwrap();
+
+        let names = vec![
+            "index.html".to_string(),
+            "index.htm".to_string(),
+            "default.html".to_string(),
+        ];
+        assert_eq!(find_index_file(&dir, &names), dir.join("index.htm"));
+
+        std::fs::remove_file(dir.join("index.htm")).unwrap();
+        assert_eq!(find_index_file(&dir, &names), dir.join("default.html"));
+
+        std::fs::remove_file(dir.join("default.html")).unwrap();
+        assert_eq!(find_index_file(&dir, &names), dir.join("index.html"));
+        assert_eq!(find_index_file(&dir, &[]), dir.join("index.html"));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    // --- resolve_static_path tests ---
+
+    #[test]
+    fn test_resolve_static_path_plain() {
+        let dir = Path::new("/srv/www/demo-[8080]");
+        assert_eq!(
+            resolve_static_path(dir, "/css/site.css"),
+            Some(dir.join("css/site.css"))
+        );
+        assert_eq!(
+            resolve_static_path(dir, "/my%20file.txt"),
+            Some(dir.join("my file.txt"))
+        );
+    }
+
+    #[test]
+    fn test_resolve_static_path_rejects_parent_dirs() {
+        let dir = Path::new("/srv/www/demo-[8080]");
+        assert_eq!(resolve_static_path(dir, "/../../etc/passwd"), None);
+        assert_eq!(resolve_static_path(dir, "/assets/../../secret"), None);
+        assert_eq!(resolve_static_path(dir, "/..\\..\\etc\\passwd"), None);
+    }
+
+    #[test]
+    fn test_resolve_static_path_rejects_encoded_traversal() {
+        let dir = Path::new("/srv/www/demo-[8080]");
+        assert_eq!(resolve_static_path(dir, "/%2e%2e/%2e%2e/etc/passwd"), None);
+        assert_eq!(resolve_static_path(dir, "/%2E%2E%2Fetc%2Fpasswd"), None);
+        assert_eq!(resolve_static_path(dir, "/..%5c..%5cwindows"), None);
+        assert_eq!(resolve_static_path(dir, "/index.html%00.png"), None);
+        assert_eq!(resolve_static_path(dir, "/%ff%fe"), None);
+        // Malformed escapes next to multi-byte chars must not panic
+        assert!(resolve_static_path(dir, "/%é").is_some());
+    }
+
+    #[test]
+    fn test_resolve_static_path_absolute_stays_inside() {
+        let dir = Path::new("/srv/www/demo-[8080]");
+        assert_eq!(
+            resolve_static_path(dir, "//etc/passwd"),
+            Some(dir.join("etc/passwd"))
+        );
+        assert_eq!(
+            resolve_static_path(dir, "/%2Fetc%2Fpasswd"),
+            Some(dir.join("etc/passwd"))
+        );
+    }
+
     // --- html_escape tests ---
 
     #[test]
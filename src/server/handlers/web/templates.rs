@@ -6,8 +6,10 @@ pub async fn serve_system_dashboard(
     data: web::Data<ServerDataWithConfig>,
 ) -> ActixResult<HttpResponse> {
     // Check PIN protection
-    let server_dir =
-        crate::server::settings::ServerSettings::get_server_dir(&data.server.name, data.server.port);
+    let server_dir = crate::server::settings::ServerSettings::get_server_dir(
+        &data.server.name,
+        data.server.port,
+    );
     if let Some(ref dir) = server_dir {
         let settings = crate::server::settings::ServerSettings::load(dir);
         if settings.pin_enabled && !settings.pin_code.is_empty() {
@@ -33,7 +35,10 @@ pub async fn serve_system_dashboard(
         .replace("{{VERSION}}", crate::server::config::get_server_version())
         .replace("{{CREATION_TIME}}", &chrono::Local::now().to_rfc3339());
 
-    let html_with_script = crate::server::handlers::web::server::inject_rss_script(html_content);
+    let html_with_script = crate::server::handlers::web::server::inject_dashboard_script(
+        html_content,
+        data.hot_reload,
+    );
 
     Ok(HttpResponse::Ok()
         .content_type("text/html; charset=utf-8")
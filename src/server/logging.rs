@@ -4,27 +4,100 @@ use crate::core::prelude::*;
 use actix_web::HttpMessage;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
 use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::broadcast;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ServerLogEntry {
     pub timestamp: String,
+    #[serde(default)]
     pub timestamp_unix: u64,
     pub event_type: LogEventType,
+    // Aliases let stats read lines written in the flat JSON format too
+    #[serde(alias = "remote_ip")]
     pub ip_address: String,
     pub user_agent: Option<String>,
     pub method: String,
     pub path: String,
+    #[serde(alias = "status")]
     pub status_code: Option<u16>,
     pub response_time_ms: Option<u64>,
     pub bytes_sent: Option<u64>,
     pub referer: Option<String>,
     pub query_string: Option<String>,
+    #[serde(default)]
     pub headers: HashMap<String, String>,
     pub session_id: Option<String>,
 }
 
+/// Line format of the per-server log file (`logging.format`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormat {
+    /// Full `ServerLogEntry` per line
+    #[default]
+    Native,
+    /// Flat access-log object per line for Loki/ELK ingestion
+    Json,
+}
+
+impl LogFormat {
+    pub fn from_config(value: &str) -> Self {
+        match value.trim().to_lowercase().as_str() {
+            "json" | "jsonl" => Self::Json,
+            _ => Self::Native,
+        }
+    }
+}
+
+/// One JSON Lines record in `LogFormat::Json`
+#[derive(Debug, Serialize)]
+struct JsonLogLine<'a> {
+    timestamp: String,
+    event_type: LogEventType,
+    method: &'a str,
+    path: &'a str,
+    status: Option<u16>,
+    response_time_ms: Option<u64>,
+    remote_ip: &'a str,
+    bytes_sent: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    alert: Option<String>,
+}
+
+impl<'a> From<&'a ServerLogEntry> for JsonLogLine<'a> {
+    fn from(entry: &'a ServerLogEntry) -> Self {
+        // RFC 3339 with offset is what log shippers parse without extra config
+        let timestamp =
+            chrono::NaiveDateTime::parse_from_str(&entry.timestamp, "%Y-%m-%d %H:%M:%S%.3f")
+                .ok()
+                .and_then(|naive| naive.and_local_timezone(chrono::Local).single())
+                .map(|local| local.to_rfc3339_opts(chrono::SecondsFormat::Millis, false))
+                .unwrap_or_else(|| entry.timestamp.clone());
+
+        let alert = entry.headers.get("alert_reason").map(|reason| {
+            match entry.headers.get("alert_details") {
+                Some(details) => format!("{}: {}", reason, details),
+                None => reason.clone(),
+            }
+        });
+
+        Self {
+            timestamp,
+            event_type: entry.event_type,
+            method: &entry.method,
+            path: &entry.path,
+            status: entry.status_code,
+            response_time_ms: entry.response_time_ms,
+            remote_ip: &entry.ip_address,
+            bytes_sent: entry.bytes_sent,
+            alert,
+        }
+    }
+}
+
 // Copy trait needed for use after move in write_alert_entry
 #[derive(Debug, Serialize, Deserialize, Clone, Copy)]
 pub enum LogEventType {
@@ -63,12 +136,76 @@ impl Default for LogRotationConfig {
     }
 }
 
+/// Lines a subscriber may fall behind before it starts skipping
+const LINE_CHANNEL_CAPACITY: usize = 1024;
+
+/// State of one log file shared by every `ServerLogger` for it (the writer
+/// and the ones handlers create).
+#[derive(Debug)]
+struct SharedLogState {
+    /// Every appended line, for live viewers (`/api/logs/stream`)
+    lines: broadcast::Sender<String>,
+    /// Successful requests seen, drives the 1-in-N sampling
+    sampled_seen: AtomicU64,
+    /// Requests sampled out of the file (and alerts with security logging
+    /// off); still counted by `get_request_stats`
+    unlogged: Mutex<UnloggedRequests>,
+}
+
+/// Distinct client IPs remembered for sampled-out requests. Past it new IPs
+/// are no longer added, so `unique_ips` becomes a lower bound instead of the
+/// set growing with every client of a long-running server.
+const UNLOGGED_IPS_CAP: usize = 10_000;
+
+/// Totals for requests that were counted but not written (this process only)
+#[derive(Debug, Default)]
+struct UnloggedRequests {
+    count: u64,
+    bytes_sent: u64,
+    response_time_sum: u64,
+    response_time_max: u64,
+    ips: std::collections::HashSet<String>,
+    security_alerts: u64,
+}
+
+impl UnloggedRequests {
+    fn record_ip(&mut self, ip: String) {
+        if self.ips.len() < UNLOGGED_IPS_CAP {
+            self.ips.insert(ip);
+        }
+    }
+}
+
+static SHARED_LOG_STATES: OnceLock<Mutex<HashMap<PathBuf, Arc<SharedLogState>>>> = OnceLock::new();
+
+fn shared_state(log_file_path: &Path) -> Arc<SharedLogState> {
+    let states = SHARED_LOG_STATES.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut states = states.lock().unwrap_or_else(|e| e.into_inner());
+    states
+        .entry(log_file_path.to_path_buf())
+        .or_insert_with(|| {
+            Arc::new(SharedLogState {
+                lines: broadcast::channel(LINE_CHANNEL_CAPACITY).0,
+                sampled_seen: AtomicU64::new(0),
+                unlogged: Mutex::new(UnloggedRequests::default()),
+            })
+        })
+        .clone()
+}
+
 pub struct ServerLogger {
     log_file_path: PathBuf,
     config: LogRotationConfig,
+    format: LogFormat,
     log_requests: bool,
     log_security: bool,
     log_performance: bool,
+    // Serializes rotate + append so concurrent requests never rotate twice
+    // or write into a file that is being renamed
+    write_guard: tokio::sync::Mutex<()>,
+    sample_rate: u32,
+    slow_request_ms: u64,
+    shared: Arc<SharedLogState>,
 }
 
 impl ServerLogger {
@@ -77,7 +214,7 @@ impl ServerLogger {
         port: u16,
         logging_config: &LoggingConfig,
     ) -> Result<Self> {
-        let base_dir = crate::core::helpers::get_base_dir()?;
+        let base_dir = crate::core::paths::base_dir()?;
 
         let log_file_path = base_dir
             .join(".rss")
@@ -89,11 +226,16 @@ impl ServerLogger {
         }
 
         Ok(Self {
+            shared: shared_state(&log_file_path),
             log_file_path,
             config: LogRotationConfig::from(logging_config),
+            format: LogFormat::from_config(&logging_config.format),
             log_requests: logging_config.log_requests,
             log_security: logging_config.log_security_alerts,
             log_performance: logging_config.log_performance,
+            sample_rate: logging_config.sample_rate.max(1),
+            slow_request_ms: logging_config.slow_request_ms,
+            write_guard: tokio::sync::Mutex::new(()),
         })
     }
 
@@ -110,7 +252,28 @@ impl ServerLogger {
         self.write_system_entry(LogEventType::ServerStop).await
     }
 
+    /// Records a `create --on-stop` hook run with its outcome and output
+    pub async fn log_stop_hook(&self, command: &str, outcome: &str, output: &str) -> Result<()> {
+        let headers = HashMap::from([
+            ("hook_command".to_string(), command.to_string()),
+            ("hook_outcome".to_string(), outcome.to_string()),
+            ("hook_output".to_string(), output.to_string()),
+        ]);
+        self.write_system_entry_with(LogEventType::ServerStop, "HOOK", headers)
+            .await
+    }
+
     async fn write_system_entry(&self, event_type: LogEventType) -> Result<()> {
+        self.write_system_entry_with(event_type, "SYSTEM", HashMap::new())
+            .await
+    }
+
+    async fn write_system_entry_with(
+        &self,
+        event_type: LogEventType,
+        method: &str,
+        headers: HashMap<String, String>,
+    ) -> Result<()> {
         let entry = ServerLogEntry {
             timestamp: chrono::Local::now()
                 .format("%Y-%m-%d %H:%M:%S%.3f")
@@ -122,14 +285,14 @@ impl ServerLogger {
             event_type,
             ip_address: "127.0.0.1".to_string(),
             user_agent: None,
-            method: "SYSTEM".to_string(),
+            method: method.to_string(),
             path: "/".to_string(),
             status_code: None,
             response_time_ms: None,
             bytes_sent: None,
             referer: None,
             query_string: None,
-            headers: HashMap::new(),
+            headers,
             session_id: None,
         };
         self.write_log_entry(entry).await
@@ -217,6 +380,12 @@ impl ServerLogger {
     // Alert logging
     pub async fn log_security_alert(&self, ip: &str, reason: &str, details: &str) -> Result<()> {
         if !self.log_security {
+            // Not written, but still part of the stats
+            self.shared
+                .unlogged
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .security_alerts += 1;
             return Ok(());
         }
         self.write_alert_entry(LogEventType::SecurityAlert, ip, reason, details, None)
@@ -287,10 +456,14 @@ impl ServerLogger {
     }
 
     pub async fn write_log_entry(&self, entry: ServerLogEntry) -> Result<()> {
+        let _guard = self.write_guard.lock().await;
         self.check_and_rotate_if_needed().await?;
 
-        let json_line = serde_json::to_string(&entry)
-            .map_err(|e| AppError::Validation(format!("Failed to serialize log entry: {}", e)))?;
+        let json_line = match self.format {
+            LogFormat::Native => serde_json::to_string(&entry),
+            LogFormat::Json => serde_json::to_string(&JsonLogLine::from(&entry)),
+        }
+        .map_err(|e| AppError::Validation(format!("Failed to serialize log entry: {}", e)))?;
 
         let mut file = tokio::fs::OpenOptions::new()
             .create(true)
@@ -304,9 +477,56 @@ impl ServerLogger {
             .await
             .map_err(AppError::Io)?;
         file.flush().await.map_err(AppError::Io)?;
+
+        // Err only means nobody is listening
+        let _ = self.shared.lines.send(json_line);
         Ok(())
     }
 
+    /// Receives each line appended from now on, in the on-disk format
+    pub fn subscribe(&self) -> broadcast::Receiver<String> {
+        self.shared.lines.subscribe()
+    }
+
+    /// Writes a request entry subject to `sample_rate`. Sampled-out requests
+    /// are only counted so `get_request_stats` still sees every request;
+    /// slow requests are always written.
+    pub async fn log_sampled_request(&self, entry: ServerLogEntry) -> Result<()> {
+        let slow = entry
+            .response_time_ms
+            .is_some_and(|ms| self.is_slow_request(ms));
+        if slow || self.should_write_request(entry.status_code) {
+            return self.write_log_entry(entry).await;
+        }
+
+        let mut unlogged = self
+            .shared
+            .unlogged
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let response_time = entry.response_time_ms.unwrap_or(0);
+        unlogged.count += 1;
+        unlogged.bytes_sent += entry.bytes_sent.unwrap_or(0);
+        unlogged.response_time_sum += response_time;
+        unlogged.response_time_max = unlogged.response_time_max.max(response_time);
+        unlogged.record_ip(entry.ip_address);
+        Ok(())
+    }
+
+    /// True above `logging.slow_request_ms` (never when the threshold is 0)
+    pub fn is_slow_request(&self, response_time_ms: u64) -> bool {
+        self.slow_request_ms > 0 && response_time_ms > self.slow_request_ms
+    }
+
+    /// Errors (and entries without a status) are always written
+    fn should_write_request(&self, status_code: Option<u16>) -> bool {
+        if self.sample_rate <= 1 || status_code.is_none_or(|status| status >= 400) {
+            return true;
+        }
+        let seen = self.shared.sampled_seen.fetch_add(1, Ordering::Relaxed);
+        seen.is_multiple_of(u64::from(self.sample_rate))
+    }
+
     async fn check_and_rotate_if_needed(&self) -> Result<()> {
         if !self.log_file_path.exists() {
             return Ok(());
@@ -332,15 +552,23 @@ impl ServerLogger {
             None => return Ok(()),
         };
 
-        // Rotate existing archives
-        for i in (1..self.config.max_archive_files).rev() {
-            let old_path = parent_dir.join(format!("{}.{}.log.gz", base_name, i));
-            let new_path = parent_dir.join(format!("{}.{}.log.gz", base_name, i + 1));
+        if self.config.max_archive_files == 0 {
+            return tokio::fs::remove_file(base_path)
+                .await
+                .map_err(AppError::Io);
+        }
 
-            if old_path.exists() {
-                tokio::fs::rename(&old_path, &new_path)
-                    .await
-                    .map_err(AppError::Io)?;
+        // Rotate existing archives (compressed or not, compression may have been toggled)
+        for i in (1..self.config.max_archive_files).rev() {
+            for ext in ["log.gz", "log"] {
+                let old_path = parent_dir.join(format!("{}.{}.{}", base_name, i, ext));
+                let new_path = parent_dir.join(format!("{}.{}.{}", base_name, i + 1, ext));
+
+                if old_path.exists() {
+                    tokio::fs::rename(&old_path, &new_path)
+                        .await
+                        .map_err(AppError::Io)?;
+                }
             }
         }
 
@@ -354,16 +582,19 @@ impl ServerLogger {
             self.compress_log_file(&archive_path).await?;
         }
 
-        // Cleanup old files
-        let cleanup_path = parent_dir.join(format!(
-            "{}.{}.log.gz",
-            base_name,
-            self.config.max_archive_files + 1
-        ));
-        if cleanup_path.exists() {
-            tokio::fs::remove_file(&cleanup_path)
-                .await
-                .map_err(AppError::Io)?;
+        // Cleanup archives beyond the retention count
+        for ext in ["log.gz", "log"] {
+            let cleanup_path = parent_dir.join(format!(
+                "{}.{}.{}",
+                base_name,
+                self.config.max_archive_files + 1,
+                ext
+            ));
+            if cleanup_path.exists() {
+                tokio::fs::remove_file(&cleanup_path)
+                    .await
+                    .map_err(AppError::Io)?;
+            }
         }
 
         Ok(())
@@ -438,20 +669,24 @@ impl ServerLogger {
     pub async fn get_request_stats(&self) -> Result<ServerStats> {
         use tokio::io::{AsyncBufReadExt, BufReader};
 
-        if !self.log_file_path.exists() {
-            return Ok(ServerStats::default());
-        }
-
-        let file = tokio::fs::File::open(&self.log_file_path)
-            .await
-            .map_err(AppError::Io)?;
-        let mut reader = BufReader::new(file).lines();
-
         let mut stats = ServerStats::default();
         let mut unique_ips = std::collections::HashSet::new();
         let mut response_times = Vec::new();
 
-        while let Some(line) = reader.next_line().await.map_err(AppError::Io)? {
+        // Nothing written yet; in-memory counters may still hold data
+        let mut lines = if self.log_file_path.exists() {
+            let file = tokio::fs::File::open(&self.log_file_path)
+                .await
+                .map_err(AppError::Io)?;
+            Some(BufReader::new(file).lines())
+        } else {
+            None
+        };
+
+        while let Some(line) = match lines.as_mut() {
+            Some(reader) => reader.next_line().await.map_err(AppError::Io)?,
+            None => None,
+        } {
             if let Ok(entry) = serde_json::from_str::<ServerLogEntry>(&line) {
                 match entry.event_type {
                     LogEventType::Request => {
@@ -465,6 +700,9 @@ impl ServerLogger {
                         }
                         if let Some(rt) = entry.response_time_ms {
                             response_times.push(rt);
+                            if self.is_slow_request(rt) {
+                                stats.slow_requests += 1;
+                            }
                         }
                         if let Some(bytes) = entry.bytes_sent {
                             stats.total_bytes_sent += bytes;
@@ -477,22 +715,40 @@ impl ServerLogger {
             }
         }
 
-        stats.unique_ips = unique_ips.len() as u64;
-        if !response_times.is_empty() {
-            stats.avg_response_time =
-                response_times.iter().sum::<u64>() / response_times.len() as u64;
-            stats.max_response_time = *response_times.iter().max().unwrap_or(&0);
+        let mut response_time_sum: u64 = response_times.iter().sum();
+        let mut response_count = response_times.len() as u64;
+        stats.max_response_time = response_times.iter().copied().max().unwrap_or(0);
+
+        // Add what sampling kept out of the file
+        {
+            let unlogged = self
+                .shared
+                .unlogged
+                .lock()
+                .unwrap_or_else(|e| e.into_inner());
+            stats.total_requests += unlogged.count;
+            stats.total_bytes_sent += unlogged.bytes_sent;
+            response_time_sum += unlogged.response_time_sum;
+            response_count += unlogged.count;
+            stats.max_response_time = stats.max_response_time.max(unlogged.response_time_max);
+            unique_ips.extend(unlogged.ips.iter().cloned());
+            stats.security_alerts += unlogged.security_alerts;
         }
 
+        stats.unique_ips = unique_ips.len() as u64;
+        stats.avg_response_time = response_time_sum.checked_div(response_count).unwrap_or(0);
+
         Ok(stats)
     }
 
     pub fn get_config_summary(&self) -> String {
         format!(
-            "Log Config: {}MB max, {} archives, compression: {}, requests: {}, security: {}, performance: {}",
+            "Log Config: {}MB max, {} archives, compression: {}, format: {:?}, sample 1/{}, requests: {}, security: {}, performance: {}",
             self.config.max_file_size_bytes / 1024 / 1024,
             self.config.max_archive_files,
             self.config.compress_archives,
+            self.format,
+            self.sample_rate,
             self.log_requests,
             self.log_security,
             self.log_performance
@@ -510,4 +766,253 @@ pub struct ServerStats {
     pub total_bytes_sent: u64,
     pub avg_response_time: u64,
     pub max_response_time: u64,
+    /// Requests above `logging.slow_request_ms`
+    pub slow_requests: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_line_is_flat_and_readable_by_stats() {
+        let entry = ServerLogEntry {
+            timestamp: "2024-05-01 12:30:00.250".to_string(),
+            timestamp_unix: 1_714_566_600,
+            event_type: LogEventType::Request,
+            ip_address: "10.0.0.7".to_string(),
+            user_agent: Some("curl/8".to_string()),
+            method: "GET".to_string(),
+            path: "/index.html".to_string(),
+            status_code: Some(200),
+            response_time_ms: Some(12),
+            bytes_sent: Some(512),
+            referer: None,
+            query_string: None,
+            headers: HashMap::new(),
+            session_id: None,
+        };
+
+        let line = serde_json::to_string(&JsonLogLine::from(&entry)).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["remote_ip"], "10.0.0.7");
+        assert_eq!(value["status"], 200);
+        assert_eq!(value["bytes_sent"], 512);
+        assert!(value["timestamp"]
+            .as_str()
+            .unwrap()
+            .starts_with("2024-05-01T12:30:00.250"));
+        assert!(value.get("alert").is_none());
+
+        let parsed: ServerLogEntry = serde_json::from_str(&line).unwrap();
+        assert_eq!(parsed.ip_address, "10.0.0.7");
+        assert_eq!(parsed.status_code, Some(200));
+    }
+
+    #[tokio::test]
+    async fn test_rotation_keeps_max_archives() {
+        let dir = std::env::temp_dir().join(format!("rush-log-rotate-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let logger = ServerLogger {
+            shared: shared_state(&dir.join("demo-[8080].log")),
+            log_file_path: dir.join("demo-[8080].log"),
+            config: LogRotationConfig {
+                max_file_size_bytes: 64,
+                max_archive_files: 2,
+                compress_archives: false,
+            },
+            format: LogFormat::Native,
+            log_requests: true,
+            log_security: true,
+            log_performance: true,
+            sample_rate: 1,
+            slow_request_ms: 0,
+            write_guard: tokio::sync::Mutex::new(()),
+        };
+
+        for _ in 0..6 {
+            logger.log_server_start().await.unwrap();
+        }
+
+        assert!(dir.join("demo-[8080].1.log").exists());
+        assert!(dir.join("demo-[8080].2.log").exists());
+        assert!(!dir.join("demo-[8080].3.log").exists());
+        assert_eq!(logger.list_log_files().unwrap().len(), 3);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    fn test_logger(path: &Path, sample_rate: u32, slow_request_ms: u64) -> ServerLogger {
+        ServerLogger {
+            shared: shared_state(path),
+            log_file_path: path.to_path_buf(),
+            config: LogRotationConfig::default(),
+            format: LogFormat::Native,
+            log_requests: true,
+            log_security: true,
+            log_performance: true,
+            sample_rate,
+            slow_request_ms,
+            write_guard: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    fn request(status: u16, ip: &str, response_time_ms: u64) -> ServerLogEntry {
+        ServerLogEntry {
+            timestamp: "2024-05-01 12:30:00.250".to_string(),
+            timestamp_unix: 1_714_566_600,
+            event_type: LogEventType::Request,
+            ip_address: ip.to_string(),
+            user_agent: None,
+            method: "GET".to_string(),
+            path: "/".to_string(),
+            status_code: Some(status),
+            response_time_ms: Some(response_time_ms),
+            bytes_sent: Some(100),
+            referer: None,
+            query_string: None,
+            headers: HashMap::new(),
+            session_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sampling_keeps_errors_and_counts_everything() {
+        let dir = std::env::temp_dir().join(format!("rush-log-sample-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("demo-[8080].log");
+        let logger = test_logger(&path, 3, 0);
+
+        for i in 0..6 {
+            logger
+                .log_sampled_request(request(200, &format!("10.0.0.{}", i), 10))
+                .await
+                .unwrap();
+        }
+        logger
+            .log_sampled_request(request(500, "10.0.0.9", 10))
+            .await
+            .unwrap();
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(written.lines().count(), 3);
+        assert!(written.contains("\"status_code\":500"));
+
+        let stats = logger.get_request_stats().await.unwrap();
+        assert_eq!(stats.total_requests, 7);
+        assert_eq!(stats.error_requests, 1);
+        assert_eq!(stats.unique_ips, 7);
+        assert_eq!(stats.total_bytes_sent, 700);
+        assert_eq!(stats.avg_response_time, 10);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_security_alerts_counted_without_security_log() {
+        let dir = std::env::temp_dir().join(format!("rush-log-alerts-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("demo-[8081].log");
+        let mut logger = test_logger(&path, 1, 0);
+        logger.log_security = false;
+
+        logger
+            .log_security_alert("10.0.0.1", "Rate limit exceeded", "GET /")
+            .await
+            .unwrap();
+        logger
+            .log_security_alert("10.0.0.1", "Rate limit exceeded", "GET /")
+            .await
+            .unwrap();
+
+        assert!(!path.exists() || std::fs::read_to_string(&path).unwrap().is_empty());
+        let stats = logger.get_request_stats().await.unwrap();
+        assert_eq!(stats.security_alerts, 2);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_slow_requests_bypass_sampling_and_are_counted() {
+        let dir = std::env::temp_dir().join(format!("rush-log-slow-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("demo-[8080].log");
+        let logger = test_logger(&path, 100, 500);
+
+        assert!(!logger.is_slow_request(500));
+        assert!(logger.is_slow_request(501));
+        assert!(!test_logger(&path, 1, 0).is_slow_request(60_000));
+
+        // First request is always sampled in; the next two would be dropped
+        logger
+            .log_sampled_request(request(200, "10.0.0.1", 20))
+            .await
+            .unwrap();
+        logger
+            .log_sampled_request(request(200, "10.0.0.2", 20))
+            .await
+            .unwrap();
+        logger
+            .log_sampled_request(request(200, "10.0.0.3", 1200))
+            .await
+            .unwrap();
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(written.lines().count(), 2);
+        assert!(written.contains("\"response_time_ms\":1200"));
+
+        let stats = logger.get_request_stats().await.unwrap();
+        assert_eq!(stats.total_requests, 3);
+        assert_eq!(stats.slow_requests, 1);
+        assert_eq!(stats.max_response_time, 1200);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_unlogged_ips_are_capped() {
+        let mut unlogged = UnloggedRequests::default();
+        for i in 0..UNLOGGED_IPS_CAP + 50 {
+            unlogged.record_ip(format!("10.0.{}.{}", i / 256, i % 256));
+        }
+        assert_eq!(unlogged.ips.len(), UNLOGGED_IPS_CAP);
+    }
+
+    #[test]
+    fn test_log_format_from_config() {
+        assert_eq!(LogFormat::from_config("JSON"), LogFormat::Json);
+        assert_eq!(LogFormat::from_config("native"), LogFormat::Native);
+        assert_eq!(LogFormat::from_config("text"), LogFormat::Native);
+    }
+
+    #[tokio::test]
+    async fn test_subscribers_receive_appended_lines() {
+        let dir = std::env::temp_dir().join(format!("rush-log-stream-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("demo-[8080].log");
+        let logger = ServerLogger {
+            shared: shared_state(&path),
+            log_file_path: path.clone(),
+            config: LogRotationConfig {
+                max_file_size_bytes: 1024 * 1024,
+                max_archive_files: 1,
+                compress_archives: false,
+            },
+            format: LogFormat::Native,
+            log_requests: true,
+            log_security: true,
+            log_performance: true,
+            sample_rate: 1,
+            slow_request_ms: 0,
+            write_guard: tokio::sync::Mutex::new(()),
+        };
+
+        let mut own = logger.subscribe();
+        // A second logger for the same file (as handlers create) shares the channel
+        let mut other = shared_state(&path).lines.subscribe();
+
+        logger.log_server_start().await.unwrap();
+
+        let line = own.recv().await.unwrap();
+        assert!(line.contains("ServerStart"));
+        assert_eq!(other.recv().await.unwrap(), line);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 }
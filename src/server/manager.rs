@@ -1,16 +1,40 @@
 use crate::core::prelude::*;
-use crate::server::types::{ServerContext, ServerInfo};
+use crate::server::logging::ServerLogger;
+use crate::server::types::{ServerContext, ServerInfo, ServerStatus};
+use std::time::{Duration, Instant};
+
+/// Longest an `on_stop` hook may run before it is killed
+pub const ON_STOP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Hook output kept in the server log
+const HOOK_OUTPUT_LIMIT: usize = 4000;
 
 #[derive(Debug, Default)]
 pub struct ServerManager {
     ctx: ServerContext,
 }
 
+/// Outcome of `ServerManager::stop_server_graceful`
+#[derive(Debug, Clone)]
+pub struct DrainReport {
+    pub server: ServerInfo,
+    /// False if the deadline passed and the stop was forced
+    pub drained: bool,
+    /// Requests still in flight when the server was stopped
+    pub remaining: usize,
+    pub waited: Duration,
+}
+
 impl ServerManager {
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Manager over an existing (shared) context
+    pub fn with_context(ctx: ServerContext) -> Self {
+        Self { ctx }
+    }
+
     pub fn get_server_info(&self, identifier: &str) -> Result<ServerInfo> {
         let servers = read_lock(&self.ctx.servers, "servers")?;
         let server = crate::server::utils::validation::find_server(&servers, identifier)?;
@@ -20,4 +44,313 @@ impl ServerManager {
     pub fn get_context(&self) -> &ServerContext {
         &self.ctx
     }
+
+    /// Snapshot of all managed servers, ordered by port
+    pub fn list_servers(&self) -> Result<Vec<ServerInfo>> {
+        let servers = read_lock(&self.ctx.servers, "servers")?;
+        let mut list: Vec<ServerInfo> = servers.values().cloned().collect();
+        list.sort_by_key(|s| s.port);
+        Ok(list)
+    }
+
+    /// Validates `name` and checks that no server already uses it. Compared
+    /// case-insensitively because it is also the proxy subdomain.
+    pub fn ensure_name_available(&self, name: &str) -> Result<()> {
+        crate::server::utils::validation::validate_server_name(name)?;
+        let servers = read_lock(&self.ctx.servers, "servers")?;
+        if servers.values().any(|s| s.name.eq_ignore_ascii_case(name)) {
+            return Err(AppError::Validation(get_translation(
+                "server.error.name_taken",
+                &[name],
+            )));
+        }
+        Ok(())
+    }
+
+    /// First port in `start..=end` (no end: up to 65535) that no server uses
+    /// and that can be bound on `bind_address`. Errors once the range is exhausted.
+    pub fn find_free_port(&self, start: u16, end: Option<u16>, bind_address: &str) -> Result<u16> {
+        let end = end.unwrap_or(u16::MAX);
+        if start > end {
+            return Err(AppError::Validation(format!(
+                "Invalid port range: {}-{}",
+                start, end
+            )));
+        }
+
+        let used_ports: std::collections::HashSet<u16> = read_lock(&self.ctx.servers, "servers")?
+            .values()
+            .map(|s| s.port)
+            .collect();
+
+        (start..=end)
+            .find(|port| {
+                !used_ports.contains(port)
+                    && crate::server::utils::port::is_port_available(*port, bind_address)
+            })
+            .ok_or_else(|| {
+                AppError::Validation(format!("No available ports in range {}-{}", start, end))
+            })
+    }
+
+    /// Registers `info` (created stopped) and sets up its www directory. The
+    /// caller persists it; the id must not be in use yet.
+    pub fn create_server(&self, info: ServerInfo) -> Result<ServerInfo> {
+        if read_lock(&self.ctx.servers, "servers")?.contains_key(&info.id) {
+            return Err(AppError::Validation(format!(
+                "Server ID {} already exists",
+                info.id
+            )));
+        }
+
+        crate::server::handlers::web::create_server_directory_and_files(&info.name, info.port)
+            .map_err(|e| {
+                AppError::Validation(format!("Failed to create server directory: {}", e))
+            })?;
+
+        let info = ServerInfo {
+            status: ServerStatus::Stopped,
+            ..info
+        };
+        write_lock(&self.ctx.servers, "servers")?.insert(info.id.clone(), info.clone());
+        Ok(info)
+    }
+
+    /// Moves a stopped server to `port`, renaming its www directory along
+    /// with it. The caller persists the new port.
+    pub fn move_to_port(&self, id: &str, port: u16) -> Result<ServerInfo> {
+        let mut servers = write_lock(&self.ctx.servers, "servers")?;
+        let server = servers
+            .get_mut(id)
+            .ok_or_else(|| AppError::Validation(format!("Server '{}' not found", id)))?;
+
+        let dirs = (
+            crate::server::settings::ServerSettings::get_server_dir(&server.name, server.port),
+            crate::server::settings::ServerSettings::get_server_dir(&server.name, port),
+        );
+        if let (Some(old_dir), Some(new_dir)) = dirs {
+            if old_dir.exists() && !new_dir.exists() {
+                std::fs::rename(&old_dir, &new_dir)?;
+            }
+        }
+
+        server.port = port;
+        Ok(server.clone())
+    }
+
+    /// Stops accepting connections, waits up to `max_wait` for in-flight
+    /// requests to finish, then stops the server (forced if still busy).
+    pub async fn stop_server_graceful(
+        &self,
+        identifier: &str,
+        max_wait: Duration,
+    ) -> Result<DrainReport> {
+        const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+        let server = self.get_server_info(identifier)?;
+        if server.status != ServerStatus::Running {
+            return Err(AppError::Validation(format!(
+                "Server '{}' is not active (Status: {})",
+                server.name, server.status
+            )));
+        }
+
+        // Stopped from here on, like any other stop; the drain is shutdown
+        let handle = self.ctx.begin_stop(&server.id).ok_or_else(|| {
+            AppError::Validation(format!("Server '{}' has no running handle", server.name))
+        })?;
+        crate::server::shared::persist_server_update(&server.id, ServerStatus::Stopped).await;
+
+        let started = Instant::now();
+        handle.pause().await;
+
+        let deadline = started + max_wait;
+        let mut remaining = self.ctx.in_flight_count(&server.id);
+        while remaining > 0 && Instant::now() < deadline {
+            tokio::time::sleep(POLL_INTERVAL).await;
+            remaining = self.ctx.in_flight_count(&server.id);
+        }
+
+        let drained = remaining == 0;
+        if drained {
+            handle.stop(true).await;
+        } else {
+            log::warn!(
+                "Server {} still had {} request(s) after {}s drain, forcing stop",
+                server.id,
+                remaining,
+                max_wait.as_secs()
+            );
+            handle.stop(false).await;
+        }
+
+        if let Ok(mut in_flight) = self.ctx.in_flight.write() {
+            in_flight.remove(&server.id);
+        }
+
+        Ok(DrainReport {
+            server,
+            drained,
+            remaining,
+            waited: started.elapsed(),
+        })
+    }
+}
+
+/// How an `on_stop` hook ended
+#[derive(Debug, Clone, PartialEq)]
+pub enum HookOutcome {
+    /// Exit code (`None` if killed by a signal) and combined stdout/stderr
+    Exited {
+        code: Option<i32>,
+        output: String,
+    },
+    TimedOut,
+    Failed(String),
+}
+
+impl std::fmt::Display for HookOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Exited {
+                code: Some(code), ..
+            } => write!(f, "exit {}", code),
+            Self::Exited { code: None, .. } => f.write_str("killed by signal"),
+            Self::TimedOut => write!(f, "timed out after {}s", ON_STOP_TIMEOUT.as_secs()),
+            Self::Failed(error) => write!(f, "failed to start: {}", error),
+        }
+    }
+}
+
+/// Runs the server's `on_stop` command, if any, once the actix server has
+/// stopped, and records it in the server log. RSS_SERVER_NAME and
+/// RSS_SERVER_PORT are set; the working directory is the server's www dir.
+pub async fn run_on_stop_hook(server: &ServerInfo, logger: &ServerLogger) {
+    let Some(command) = server.on_stop.as_deref() else {
+        return;
+    };
+    let dir = crate::server::settings::ServerSettings::get_server_dir(&server.name, server.port)
+        .filter(|dir| dir.is_dir());
+    let env = [
+        ("RSS_SERVER_NAME", server.name.clone()),
+        ("RSS_SERVER_PORT", server.port.to_string()),
+    ];
+
+    let outcome = run_hook(command, dir.as_deref(), &env, ON_STOP_TIMEOUT).await;
+    match &outcome {
+        HookOutcome::Exited { code: Some(0), .. } => {
+            log::info!("on_stop hook of {} finished", server.name)
+        }
+        other => log::warn!("on_stop hook of {}: {}", server.name, other),
+    }
+
+    let output = match &outcome {
+        HookOutcome::Exited { output, .. } => output.as_str(),
+        _ => "",
+    };
+    if let Err(e) = logger
+        .log_stop_hook(command, &outcome.to_string(), output)
+        .await
+    {
+        log::error!("Failed to log on_stop hook: {}", e);
+    }
+}
+
+async fn run_hook(
+    command: &str,
+    dir: Option<&std::path::Path>,
+    env: &[(&str, String)],
+    timeout: Duration,
+) -> HookOutcome {
+    #[cfg(windows)]
+    let mut cmd = {
+        let mut cmd = tokio::process::Command::new("cmd");
+        cmd.arg("/C").arg(command);
+        cmd
+    };
+    #[cfg(not(windows))]
+    let mut cmd = {
+        let mut cmd = tokio::process::Command::new("sh");
+        cmd.arg("-c").arg(command);
+        cmd
+    };
+    cmd.stdin(std::process::Stdio::null()).kill_on_drop(true);
+    if let Some(dir) = dir {
+        cmd.current_dir(dir);
+    }
+    for (key, value) in env {
+        cmd.env(key, value);
+    }
+
+    match tokio::time::timeout(timeout, cmd.output()).await {
+        Err(_) => HookOutcome::TimedOut,
+        Ok(Err(e)) => HookOutcome::Failed(e.to_string()),
+        Ok(Ok(output)) => {
+            let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+            text.push_str(&String::from_utf8_lossy(&output.stderr));
+            let mut text = text.trim().to_string();
+            if text.len() > HOOK_OUTPUT_LIMIT {
+                let mut cut = HOOK_OUTPUT_LIMIT;
+                while !text.is_char_boundary(cut) {
+                    cut -= 1;
+                }
+                text.truncate(cut);
+                text.push_str("...");
+            }
+            HookOutcome::Exited {
+                code: output.status.code(),
+                output: text,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_run_hook_output_env_and_timeout() {
+        let env = [("RSS_SERVER_NAME", "blog".to_string())];
+        let outcome = run_hook(
+            "echo flushed $RSS_SERVER_NAME; echo oops >&2; exit 3",
+            None,
+            &env,
+            Duration::from_secs(5),
+        )
+        .await;
+        assert_eq!(
+            outcome,
+            HookOutcome::Exited {
+                code: Some(3),
+                output: "flushed blog\noops".to_string()
+            }
+        );
+
+        let hung = run_hook("sleep 5", None, &[], Duration::from_millis(100)).await;
+        assert_eq!(hung, HookOutcome::TimedOut);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_exit_waits_for_on_stop_hook() {
+        let marker = std::env::temp_dir().join(format!("rush-on-stop-{}", std::process::id()));
+        let _ = std::fs::remove_file(&marker);
+        let command = format!("sleep 0.3; touch '{}'", marker.display());
+
+        // Like a server thread: the hook runs after its server stopped
+        let ctx = crate::server::types::ServerContext::default();
+        ctx.track_thread(std::thread::spawn(move || {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap()
+                .block_on(run_hook(&command, None, &[], ON_STOP_TIMEOUT));
+        }));
+
+        assert!(ctx.join_threads(Duration::from_secs(5)).await);
+        assert!(marker.exists());
+        std::fs::remove_file(&marker).ok();
+    }
 }
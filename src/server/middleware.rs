@@ -2,12 +2,14 @@ use crate::core::api_key::ApiKey;
 use actix_web::{
     body::EitherBody,
     dev::{Service, ServiceRequest, ServiceResponse, Transform},
-    Error, HttpResponse,
+    web, Error, HttpResponse,
 };
+use base64::Engine;
 use futures_util::future::LocalBoxFuture;
 use std::{
-    collections::{HashMap, VecDeque},
+    collections::{BTreeSet, HashMap, VecDeque},
     future::{ready, Ready},
+    net::{IpAddr, SocketAddr},
     sync::{Arc, Mutex},
     time::Instant,
 };
@@ -126,6 +128,17 @@ where
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(0);
 
+            if server_logger.is_slow_request(response_time) {
+                log::warn!(
+                    "Slow request: {} {} -> {} in {}ms",
+                    method,
+                    path,
+                    status,
+                    response_time
+                );
+                crate::server::analytics::track_slow_request();
+            }
+
             // Analytics: only track if NOT proxied (proxy handler tracks with real client IP)
             let is_proxied = headers.contains_key("x-forwarded-for") || headers.contains_key("x-real-ip");
             let analytics_path = path.clone();
@@ -158,7 +171,7 @@ where
                 session_id: None,
             };
 
-            if let Err(e) = server_logger.write_log_entry(entry).await {
+            if let Err(e) = server_logger.log_sampled_request(entry).await {
                 log::error!("Failed to log request: {}", e);
             }
 
@@ -177,7 +190,8 @@ fn percent_decode(input: &str) -> String {
     let mut i = 0;
     while i < bytes.len() {
         if bytes[i] == b'%' && i + 2 < bytes.len() {
-            if let Ok(byte) = u8::from_str_radix(&input[i + 1..i + 3], 16) {
+            let hex = input.get(i + 1..i + 3).unwrap_or("");
+            if let Ok(byte) = u8::from_str_radix(hex, 16) {
                 result.push(byte as char);
                 i += 3;
                 continue;
@@ -431,6 +445,227 @@ where
     }
 }
 
+// =============================================================================
+// Per-minute Request Limit Middleware
+// =============================================================================
+
+const REQUEST_WINDOW: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Clients tracked at most; the least recently seen one makes room
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+#[derive(Default)]
+struct ClientWindow {
+    hits: VecDeque<Instant>,
+    last_seen: Option<Instant>,
+    alerted_at: Option<Instant>,
+}
+
+#[derive(Default)]
+struct RateClients {
+    windows: HashMap<IpAddr, ClientWindow>,
+    /// `(last_seen, ip)` for every tracked client, oldest first
+    by_last_seen: BTreeSet<(Instant, IpAddr)>,
+}
+
+impl RateClients {
+    /// The window for `ip`, evicting the least recently seen client when the
+    /// map is full, and marked as seen at `now`
+    fn touch(&mut self, ip: IpAddr, now: Instant) -> &mut ClientWindow {
+        if !self.windows.contains_key(&ip) && self.windows.len() >= MAX_TRACKED_CLIENTS {
+            if let Some((_, oldest)) = self.by_last_seen.pop_first() {
+                self.windows.remove(&oldest);
+            }
+        }
+        let window = self.windows.entry(ip).or_default();
+        if let Some(previous) = window.last_seen.replace(now) {
+            self.by_last_seen.remove(&(previous, ip));
+        }
+        self.by_last_seen.insert((now, ip));
+        window
+    }
+}
+
+/// Sliding-window request counters per client IP, shared across workers via
+/// `web::Data`.
+#[derive(Default)]
+pub struct RequestRateState {
+    clients: Mutex<RateClients>,
+}
+
+impl RequestRateState {
+    /// Records a request from `ip` at `now`; false once `limit` requests fall
+    /// inside the last minute. Rejected requests are not recorded.
+    pub fn allow(&self, ip: IpAddr, limit: u32, now: Instant) -> bool {
+        let Ok(mut clients) = self.clients.lock() else {
+            return true; // If lock fails, allow the request
+        };
+        let window_start = now.checked_sub(REQUEST_WINDOW);
+        let timestamps = &mut clients.touch(ip, now).hits;
+        if let Some(start) = window_start {
+            while timestamps.front().is_some_and(|t| *t < start) {
+                timestamps.pop_front();
+            }
+        }
+
+        if timestamps.len() >= limit as usize {
+            false
+        } else {
+            timestamps.push_back(now);
+            true
+        }
+    }
+
+    /// True at most once per window for `ip`, so a flood of rejected
+    /// requests writes one security alert instead of one per request
+    pub fn take_alert(&self, ip: IpAddr, now: Instant) -> bool {
+        let Ok(mut clients) = self.clients.lock() else {
+            return false;
+        };
+        let Some(window) = clients.windows.get_mut(&ip) else {
+            return false;
+        };
+        let due = window
+            .alerted_at
+            .is_none_or(|at| now.saturating_duration_since(at) >= REQUEST_WINDOW);
+        if due {
+            window.alerted_at = Some(now);
+        }
+        due
+    }
+
+    #[cfg(test)]
+    fn tracked(&self) -> usize {
+        self.clients
+            .lock()
+            .map_or(0, |clients| clients.windows.len())
+    }
+}
+
+/// The IP a request is counted against: the TCP peer, or the forwarded
+/// client address when the peer is a local reverse proxy. Forwarding headers
+/// from anyone else are ignored, since they can be set to any value.
+fn rate_limit_ip(req: &ServiceRequest) -> IpAddr {
+    let Some(peer) = req.peer_addr().map(|addr| addr.ip()) else {
+        return IpAddr::from([0, 0, 0, 0]);
+    };
+    if !peer.is_loopback() {
+        return peer;
+    }
+    req.connection_info()
+        .realip_remote_addr()
+        .and_then(parse_ip)
+        .unwrap_or(peer)
+}
+
+/// `1.2.3.4`, `1.2.3.4:80`, `::1` or `[::1]:80`
+fn parse_ip(addr: &str) -> Option<IpAddr> {
+    addr.parse::<IpAddr>()
+        .ok()
+        .or_else(|| addr.parse::<SocketAddr>().ok().map(|addr| addr.ip()))
+}
+
+/// Limits every request to `limit_per_min` per client IP; a no-op at 0.
+pub struct RequestRateLimit {
+    limit_per_min: u32,
+    state: web::Data<RequestRateState>,
+    server_logger: Arc<crate::server::logging::ServerLogger>,
+}
+
+impl RequestRateLimit {
+    pub fn new(
+        limit_per_min: u32,
+        state: web::Data<RequestRateState>,
+        server_logger: Arc<crate::server::logging::ServerLogger>,
+    ) -> Self {
+        Self {
+            limit_per_min,
+            state,
+            server_logger,
+        }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RequestRateLimit
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = RequestRateLimitService<S>;
+    type Future = Ready<std::result::Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequestRateLimitService {
+            service,
+            limit_per_min: self.limit_per_min,
+            state: self.state.clone(),
+            server_logger: self.server_logger.clone(),
+        }))
+    }
+}
+
+pub struct RequestRateLimitService<S> {
+    service: S,
+    limit_per_min: u32,
+    state: web::Data<RequestRateState>,
+    server_logger: Arc<crate::server::logging::ServerLogger>,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestRateLimitService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, std::result::Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        if self.limit_per_min == 0 {
+            let fut = self.service.call(req);
+            return Box::pin(async move { fut.await.map(|res| res.map_into_left_body()) });
+        }
+
+        let ip = rate_limit_ip(&req);
+        let now = Instant::now();
+        if self.state.allow(ip, self.limit_per_min, now) {
+            let fut = self.service.call(req);
+            return Box::pin(async move { fut.await.map(|res| res.map_into_left_body()) });
+        }
+
+        if self.state.take_alert(ip, now) {
+            let logger = self.server_logger.clone();
+            let details = format!(
+                "{} {} (limit {}/min)",
+                req.method(),
+                req.path(),
+                self.limit_per_min
+            );
+            tokio::spawn(async move {
+                let _ = logger
+                    .log_security_alert(&ip.to_string(), "Rate limit exceeded", &details)
+                    .await;
+            });
+        }
+
+        let response = HttpResponse::TooManyRequests()
+            .insert_header(("Retry-After", "60"))
+            .json(serde_json::json!({
+                "error": "Too Many Requests",
+                "message": "Rate limit exceeded. Try again later.",
+                "retry_after": 60
+            }));
+        Box::pin(async move { Ok(req.into_response(response).map_into_right_body()) })
+    }
+}
+
 // =============================================================================
 // PIN Protection Middleware
 // =============================================================================
@@ -561,10 +796,188 @@ where
     }
 }
 
+// =============================================================================
+// Dashboard Basic Auth Middleware
+// =============================================================================
+
+/// HTTP Basic Auth on `/.rss/` and `/api/` from `server.dashboard_user` /
+/// `dashboard_password`; user files stay public. A no-op unless both are set.
+#[derive(Clone)]
+pub struct DashboardAuth {
+    user: String,
+    password: ApiKey,
+}
+
+impl DashboardAuth {
+    pub fn new(user: &str, password: ApiKey) -> Self {
+        Self {
+            user: user.to_string(),
+            password,
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        !self.user.is_empty() && !self.password.is_empty()
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for DashboardAuth
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = DashboardAuthService<S>;
+    type Future = Ready<std::result::Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(DashboardAuthService {
+            service,
+            auth: self.clone(),
+        }))
+    }
+}
+
+pub struct DashboardAuthService<S> {
+    service: S,
+    auth: DashboardAuth,
+}
+
+/// Paths behind the dashboard login; `/api/health` stays open for probes,
+/// and the reset stylesheet, its fonts and the favicon that public pages
+/// link stay open too
+fn needs_dashboard_auth(path: &str) -> bool {
+    const PUBLIC: [&str; 3] = ["/api/health", "/.rss/_reset.css", "/.rss/favicon.svg"];
+    (path.starts_with("/.rss/") || path.starts_with("/api/"))
+        && !PUBLIC.contains(&path)
+        && !path.starts_with("/.rss/fonts/")
+}
+
+/// Checks an `Authorization: Basic <base64 user:password>` header value
+fn basic_auth_matches(header: Option<&str>, user: &str, password: &ApiKey) -> bool {
+    let Some(encoded) = header.and_then(|value| value.strip_prefix("Basic ")) else {
+        return false;
+    };
+    let Ok(decoded) = base64::engine::general_purpose::STANDARD.decode(encoded.trim()) else {
+        return false;
+    };
+    let Ok(credentials) = String::from_utf8(decoded) else {
+        return false;
+    };
+    match credentials.split_once(':') {
+        // Verify the password either way so a wrong user costs the same time
+        Some((given_user, given_password)) => {
+            password.verify(given_password) & (given_user == user)
+        }
+        None => false,
+    }
+}
+
+impl<S, B> Service<ServiceRequest> for DashboardAuthService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, std::result::Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let authorized = !self.auth.is_enabled()
+            || !needs_dashboard_auth(req.path())
+            || basic_auth_matches(
+                req.headers()
+                    .get(actix_web::http::header::AUTHORIZATION)
+                    .and_then(|v| v.to_str().ok()),
+                &self.auth.user,
+                &self.auth.password,
+            );
+
+        if authorized {
+            let fut = self.service.call(req);
+            return Box::pin(async move { fut.await.map(|res| res.map_into_left_body()) });
+        }
+
+        let response = HttpResponse::Unauthorized()
+            .insert_header((
+                actix_web::http::header::WWW_AUTHENTICATE,
+                "Basic realm=\"rush-sync dashboard\", charset=\"UTF-8\"",
+            ))
+            .json(serde_json::json!({
+                "error": "Unauthorized",
+                "message": "Dashboard credentials required."
+            }));
+        Box::pin(async move { Ok(req.into_response(response).map_into_right_body()) })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // --- dashboard basic auth tests ---
+
+    #[test]
+    fn test_basic_auth_matches_credentials() {
+        let password = ApiKey::from_toml("s3cret");
+        let header = |raw: &str| {
+            format!(
+                "Basic {}",
+                base64::engine::general_purpose::STANDARD.encode(raw)
+            )
+        };
+        assert!(basic_auth_matches(
+            Some(&header("admin:s3cret")),
+            "admin",
+            &password
+        ));
+        assert!(!basic_auth_matches(
+            Some(&header("admin:wrong")),
+            "admin",
+            &password
+        ));
+        assert!(!basic_auth_matches(
+            Some(&header("root:s3cret")),
+            "admin",
+            &password
+        ));
+        assert!(!basic_auth_matches(Some("Basic !!!"), "admin", &password));
+        assert!(!basic_auth_matches(None, "admin", &password));
+
+        // Passwords may be stored as --hash-key hashes like api_key
+        let hashed = ApiKey::from_toml(&crate::core::api_key::hash_api_key("s3cret"));
+        assert!(basic_auth_matches(
+            Some(&header("admin:s3cret")),
+            "admin",
+            &hashed
+        ));
+    }
+
+    #[test]
+    fn test_dashboard_auth_scope() {
+        assert!(needs_dashboard_auth("/.rss/"));
+        assert!(needs_dashboard_auth("/api/servers"));
+        assert!(!needs_dashboard_auth("/api/health"));
+        // Linked from public pages by the injected head and error templates
+        assert!(!needs_dashboard_auth("/.rss/_reset.css"));
+        assert!(!needs_dashboard_auth("/.rss/favicon.svg"));
+        // Declared by @font-face in _reset.css
+        assert!(!needs_dashboard_auth("/.rss/fonts/Kenyan_Coffee_Rg.otf"));
+        assert!(needs_dashboard_auth("/.rss/fonts"));
+        assert!(needs_dashboard_auth("/.rss/style.css"));
+        assert!(needs_dashboard_auth("/.rss/js/rush-app.js"));
+        assert!(!needs_dashboard_auth("/index.html"));
+        assert!(!needs_dashboard_auth("/ws/hot-reload"));
+        assert!(!DashboardAuth::new("", ApiKey::from_toml("x")).is_enabled());
+        assert!(!DashboardAuth::new("admin", ApiKey::empty()).is_enabled());
+    }
+
     // --- percent_decode tests ---
 
     #[test]
@@ -659,4 +1072,84 @@ mod tests {
         assert!(!is_suspicious_path("/file.name.html"));
         assert!(!is_suspicious_path("/.rss/favicon.svg"));
     }
+
+    // --- RequestRateState tests ---
+
+    #[test]
+    fn test_request_rate_sliding_window() {
+        let state = RequestRateState::default();
+        let start = Instant::now();
+        let (a, b) = (IpAddr::from([10, 0, 0, 1]), IpAddr::from([10, 0, 0, 2]));
+
+        assert!(state.allow(a, 2, start));
+        assert!(state.allow(a, 2, start + std::time::Duration::from_secs(10)));
+        assert!(!state.allow(a, 2, start + std::time::Duration::from_secs(30)));
+        // Other clients have their own window
+        assert!(state.allow(b, 2, start + std::time::Duration::from_secs(30)));
+        // The first request has left the window
+        assert!(state.allow(a, 2, start + std::time::Duration::from_secs(61)));
+        assert!(!state.allow(a, 2, start + std::time::Duration::from_secs(62)));
+    }
+
+    #[test]
+    fn test_request_rate_evicts_least_recently_seen() {
+        let state = RequestRateState::default();
+        let start = Instant::now();
+        let limited = IpAddr::from([192, 168, 0, 1]);
+        assert!(state.allow(limited, 1, start));
+
+        // A flood of distinct clients inside the window keeps the map capped
+        for i in 0..MAX_TRACKED_CLIENTS as u32 {
+            let at = start + std::time::Duration::from_millis(u64::from(i) + 1);
+            assert!(state.allow(IpAddr::from(i.to_be_bytes()), 1, at));
+        }
+        assert_eq!(state.tracked(), MAX_TRACKED_CLIENTS);
+
+        // The oldest client was evicted to make room, so it starts over
+        let later = start + std::time::Duration::from_secs(20);
+        assert!(state.allow(limited, 1, later));
+        assert_eq!(state.tracked(), MAX_TRACKED_CLIENTS);
+    }
+
+    #[test]
+    fn test_request_rate_alert_once_per_window() {
+        let state = RequestRateState::default();
+        let start = Instant::now();
+        let ip = IpAddr::from([10, 0, 0, 1]);
+        assert!(state.allow(ip, 1, start));
+        assert!(!state.allow(ip, 1, start));
+
+        assert!(state.take_alert(ip, start));
+        assert!(!state.take_alert(ip, start + std::time::Duration::from_secs(30)));
+        assert!(state.take_alert(ip, start + std::time::Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn test_rate_limit_ip_ignores_forwarded_from_remote_peers() {
+        use actix_web::test::TestRequest;
+
+        let remote = TestRequest::default()
+            .peer_addr("203.0.113.7:4000".parse().unwrap())
+            .insert_header(("X-Forwarded-For", "1.2.3.4"))
+            .to_srv_request();
+        assert_eq!(rate_limit_ip(&remote), IpAddr::from([203, 0, 113, 7]));
+
+        let proxied = TestRequest::default()
+            .peer_addr("127.0.0.1:4000".parse().unwrap())
+            .insert_header(("X-Forwarded-For", "2001:db8::1"))
+            .to_srv_request();
+        assert_eq!(
+            rate_limit_ip(&proxied),
+            "2001:db8::1".parse::<IpAddr>().unwrap()
+        );
+
+        let v6 = TestRequest::default()
+            .peer_addr("[2001:db8::2]:4000".parse().unwrap())
+            .to_srv_request();
+        assert_eq!(rate_limit_ip(&v6), "2001:db8::2".parse::<IpAddr>().unwrap());
+        assert_eq!(
+            parse_ip("[::1]:80"),
+            Some(IpAddr::from([0u16, 0, 0, 0, 0, 0, 0, 1]))
+        );
+    }
 }
@@ -2,7 +2,7 @@
 use crate::core::prelude::*;
 use crate::server::types::{ServerInfo, ServerStatus};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -16,6 +16,14 @@ pub struct PersistentServerInfo {
     pub auto_start: bool,
     pub last_started: Option<String>,
     pub start_count: u32,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workers: Option<usize>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_stop: Option<String>,
 }
 
 impl From<ServerInfo> for PersistentServerInfo {
@@ -30,6 +38,10 @@ impl From<ServerInfo> for PersistentServerInfo {
             auto_start: false,
             last_started: None,
             start_count: 0,
+            env: info.env,
+            workers: info.workers,
+            headers: info.headers,
+            on_stop: info.on_stop,
         }
     }
 }
@@ -43,10 +55,80 @@ impl From<PersistentServerInfo> for ServerInfo {
             status: info.status,
             created_at: info.created_at,
             created_timestamp: info.created_timestamp,
+            env: info.env,
+            workers: info.workers,
+            headers: info.headers,
+            on_stop: info.on_stop,
         }
     }
 }
 
+impl PersistentServerInfo {
+    /// Unix timestamp of the last start, or of creation if never started
+    pub fn last_activity_timestamp(&self) -> u64 {
+        self.last_started
+            .as_deref()
+            .and_then(|ts| chrono::NaiveDateTime::parse_from_str(ts, "%Y-%m-%d %H:%M:%S").ok())
+            .and_then(|dt| dt.and_local_timezone(chrono::Local).earliest())
+            .map(|dt| dt.timestamp().max(0) as u64)
+            .unwrap_or(self.created_timestamp)
+            .max(self.created_timestamp)
+    }
+}
+
+/// Portable snapshot of the registry, written by `server export` and read
+/// by `server import`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RegistryExport {
+    pub version: u32,
+    pub exported_at: String,
+    pub servers: Vec<PersistentServerInfo>,
+}
+
+impl RegistryExport {
+    pub const VERSION: u32 = 1;
+
+    /// Entries sorted by creation; runtime state (status, start history) is
+    /// reset since it does not carry over to another machine
+    pub fn new(servers: impl IntoIterator<Item = PersistentServerInfo>) -> Self {
+        let mut servers: Vec<PersistentServerInfo> = servers
+            .into_iter()
+            .map(|s| PersistentServerInfo {
+                status: ServerStatus::Stopped,
+                last_started: None,
+                start_count: 0,
+                ..s
+            })
+            .collect();
+        servers.sort_by_key(|s| s.created_timestamp);
+
+        Self {
+            version: Self::VERSION,
+            exported_at: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            servers,
+        }
+    }
+
+    /// Parses an export file; a plain registry file (`servers.list`) is
+    /// accepted as well
+    pub fn from_json(content: &str) -> Result<Self> {
+        if let Ok(servers) = serde_json::from_str::<Vec<PersistentServerInfo>>(content) {
+            return Ok(Self::new(servers));
+        }
+
+        let export: Self = serde_json::from_str(content)
+            .map_err(|e| AppError::Validation(format!("Invalid server export: {}", e)))?;
+        if export.version > Self::VERSION {
+            return Err(AppError::Validation(format!(
+                "Server export version {} is not supported (max {})",
+                export.version,
+                Self::VERSION
+            )));
+        }
+        Ok(export)
+    }
+}
+
 pub struct ServerRegistry {
     file_path: PathBuf,
     /// Mutex to serialize all file operations (prevents race conditions on concurrent writes)
@@ -55,7 +137,7 @@ pub struct ServerRegistry {
 
 impl ServerRegistry {
     pub fn new() -> Result<Self> {
-        let base_dir = crate::core::helpers::get_base_dir()?;
+        let base_dir = crate::core::paths::base_dir()?;
 
         let file_path = base_dir.join(".rss").join("servers.list");
 
@@ -169,6 +251,17 @@ impl ServerRegistry {
         .await
     }
 
+    pub async fn update_server_port(
+        &self,
+        server_id: &str,
+        port: u16,
+    ) -> Result<HashMap<String, PersistentServerInfo>> {
+        self.update_server(server_id, |server| {
+            server.port = port;
+        })
+        .await
+    }
+
     pub async fn add_server(
         &self,
         server_info: ServerInfo,
@@ -200,11 +293,7 @@ impl ServerRegistry {
         let mut servers = self.load_servers().await?;
         let initial_count = servers.len();
 
-        servers.retain(|_, s| match cleanup_type {
-            CleanupType::Stopped => s.status != ServerStatus::Stopped,
-            CleanupType::Failed => s.status != ServerStatus::Failed,
-            CleanupType::All => s.status == ServerStatus::Running,
-        });
+        servers.retain(|_, s| !cleanup_type.matches(s.status));
 
         let removed_count = initial_count - servers.len();
         if removed_count > 0 {
@@ -214,6 +303,21 @@ impl ServerRegistry {
         Ok((servers, removed_count))
     }
 
+    /// Removes the given server ids, returns how many were actually present
+    pub async fn remove_servers(&self, server_ids: &[String]) -> Result<usize> {
+        let _lock = self.write_lock.lock().await;
+        let mut servers = self.load_servers().await?;
+        let initial_count = servers.len();
+
+        servers.retain(|id, _| !server_ids.contains(id));
+
+        let removed_count = initial_count - servers.len();
+        if removed_count > 0 {
+            self.save_servers(&servers).await?;
+        }
+        Ok(removed_count)
+    }
+
     pub fn get_auto_start_servers(
         &self,
         servers: &HashMap<String, PersistentServerInfo>,
@@ -227,7 +331,7 @@ impl ServerRegistry {
 
     // Directory cleanup utilities
     pub async fn cleanup_server_directory(&self, server_name: &str, port: u16) -> Result<()> {
-        let base_dir = crate::core::helpers::get_base_dir()?;
+        let base_dir = crate::core::paths::base_dir()?;
 
         let server_dir = base_dir
             .join("www")
@@ -241,7 +345,7 @@ impl ServerRegistry {
     }
 
     pub fn list_www_directories(&self) -> Result<Vec<PathBuf>> {
-        let base_dir = crate::core::helpers::get_base_dir()?;
+        let base_dir = crate::core::paths::base_dir()?;
 
         let www_dir = base_dir.join("www");
         if !www_dir.exists() {
@@ -260,9 +364,20 @@ impl ServerRegistry {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum CleanupType {
     Stopped,
     Failed,
     All,
 }
+
+impl CleanupType {
+    /// True if a server in `status` is removed by this cleanup
+    pub fn matches(&self, status: ServerStatus) -> bool {
+        match self {
+            Self::Stopped => status == ServerStatus::Stopped,
+            Self::Failed => status == ServerStatus::Failed,
+            Self::All => status != ServerStatus::Running,
+        }
+    }
+}
@@ -4,14 +4,32 @@ use actix_web::{web, App, HttpRequest, HttpResponse, HttpServer};
 pub struct HttpRedirectServer {
     port: u16,
     target_port: u16,
+    challenge_only: bool,
 }
 
 impl HttpRedirectServer {
     pub fn new(port: u16, target_port: u16) -> Self {
-        Self { port, target_port }
+        Self {
+            port,
+            target_port,
+            challenge_only: false,
+        }
+    }
+
+    /// Serves ACME HTTP-01 challenges only; every other request gets a 404.
+    pub fn challenge_only(port: u16) -> Self {
+        Self {
+            port,
+            target_port: 443,
+            challenge_only: true,
+        }
     }
 
-    async fn redirect_handler(req: HttpRequest, target_port: web::Data<u16>) -> HttpResponse {
+    async fn redirect_handler(
+        req: HttpRequest,
+        target_port: web::Data<u16>,
+        challenge_only: web::Data<bool>,
+    ) -> HttpResponse {
         let path = req.uri().path();
 
         // ACME challenges must be served directly (Let's Encrypt HTTP-01 validation)
@@ -27,6 +45,10 @@ impl HttpRedirectServer {
             }
         }
 
+        if *challenge_only.get_ref() {
+            return HttpResponse::NotFound().finish();
+        }
+
         let host = req
             .headers()
             .get("host")
@@ -56,21 +78,30 @@ impl HttpRedirectServer {
             .finish()
     }
 
-    pub async fn run(self) -> Result<()> {
-        log::info!("HTTP redirect server starting on port {}", self.port);
-        log::info!("Redirecting to HTTPS port {}", self.target_port);
+    /// Binds the listener and returns the server without awaiting it.
+    pub fn bind(self) -> Result<actix_web::dev::Server> {
+        if self.challenge_only {
+            log::info!("ACME challenge server starting on port {}", self.port);
+        } else {
+            log::info!("HTTP redirect server starting on port {}", self.port);
+            log::info!("Redirecting to HTTPS port {}", self.target_port);
+        }
 
         let target_port = self.target_port;
+        let challenge_only = self.challenge_only;
 
-        HttpServer::new(move || {
+        Ok(HttpServer::new(move || {
             App::new()
                 .app_data(web::Data::new(target_port))
+                .app_data(web::Data::new(challenge_only))
                 .default_service(web::route().to(Self::redirect_handler))
         })
         .bind(("0.0.0.0", self.port))
         .map_err(|e| AppError::Validation(format!("Port {} bind failed: {}", self.port, e)))?
-        .run()
-        .await
-        .map_err(AppError::Io)
+        .run())
+    }
+
+    pub async fn run(self) -> Result<()> {
+        self.bind()?.await.map_err(AppError::Io)
     }
 }
@@ -40,9 +40,7 @@ impl ServerSettings {
         let path = Self::settings_path(server_dir);
         if path.exists() {
             match std::fs::read_to_string(&path) {
-                Ok(content) => {
-                    serde_json::from_str(&content).unwrap_or_default()
-                }
+                Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
                 Err(e) => {
                     log::warn!("Failed to read settings: {}", e);
                     Self::default()
@@ -56,14 +54,13 @@ impl ServerSettings {
     /// Save settings to the server directory
     pub fn save(&self, server_dir: &Path) -> Result<(), std::io::Error> {
         let path = Self::settings_path(server_dir);
-        let content = serde_json::to_string_pretty(self)
-            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+        let content = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
         std::fs::write(&path, content)
     }
 
     /// Get the server directory path from server name and port
     pub fn get_server_dir(server_name: &str, port: u16) -> Option<PathBuf> {
-        let base_dir = crate::core::helpers::get_base_dir().ok()?;
+        let base_dir = crate::core::paths::base_dir().ok()?;
         Some(
             base_dir
                 .join("www")
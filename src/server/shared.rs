@@ -189,7 +189,7 @@ pub async fn initialize_server_system() -> crate::core::error::Result<()> {
         // ACME runs AFTER proxy is ready (5s delay), provisions cert, then hot-reloads proxy TLS.
         // No manual restart needed — new connections automatically use the LE certificate.
         if config.server.use_lets_encrypt && config.server.production_domain != "localhost" {
-            let cert_dir = crate::core::helpers::get_base_dir()
+            let cert_dir = crate::core::paths::base_dir()
                 .map(|b| b.join(&config.server.cert_dir))
                 .unwrap_or_else(|_| std::path::PathBuf::from(&config.server.cert_dir));
 
@@ -217,6 +217,10 @@ pub async fn initialize_server_system() -> crate::core::error::Result<()> {
                 config.server.acme_email.clone(),
                 false,
                 subdomains,
+                crate::server::acme::ChallengeMode::from_config(
+                    &config.server.acme_challenge,
+                    &config.server.acme_dns_hook,
+                ),
             );
             log::info!(
                 "ACME: Background provisioning + auto hot-reload started for {}",
@@ -270,6 +274,13 @@ pub async fn shutdown_all_servers_on_exit() -> crate::core::error::Result<()> {
             .await;
     }
 
+    // Server threads run their on_stop hooks after the stop; each hook is
+    // bounded by ON_STOP_TIMEOUT, so this only waits for hooks still running
+    let hook_wait = shutdown_timeout + crate::server::manager::ON_STOP_TIMEOUT;
+    if !context.join_threads(hook_wait).await {
+        log::warn!("Exiting before all on_stop hooks finished");
+    }
+
     // Save analytics data before exit
     crate::server::analytics::save_analytics_on_shutdown();
 
@@ -354,13 +365,17 @@ pub async fn get_server_system_stats() -> serde_json::Value {
             "workers_per_server": config.server.workers,
             "shutdown_timeout_sec": config.server.shutdown_timeout,
             "startup_delay_ms": config.server.startup_delay_ms,
+            "compression": config.server.compression,
+            "rate_limit_per_min": config.server.rate_limit_per_min,
             "logging": {
                 "max_file_size_mb": config.logging.max_file_size_mb,
                 "max_archives": config.logging.max_archive_files,
                 "compression": config.logging.compress_archives,
                 "request_logging": config.logging.log_requests,
                 "security_alerts": config.logging.log_security_alerts,
-                "performance_monitoring": config.logging.log_performance
+                "performance_monitoring": config.logging.log_performance,
+                "sample_rate": config.logging.sample_rate,
+                "slow_request_ms": config.logging.slow_request_ms
             }
         }
     })
@@ -15,7 +15,7 @@ pub struct TlsManager {
 
 impl TlsManager {
     pub fn new(cert_dir: &str, validity_days: u32) -> Result<Self> {
-        let base_dir = crate::core::helpers::get_base_dir()?;
+        let base_dir = crate::core::paths::base_dir()?;
 
         let cert_path = base_dir.join(cert_dir);
         fs::create_dir_all(&cert_path).map_err(AppError::Io)?;
@@ -39,8 +39,18 @@ impl TlsManager {
         let cert_file = self.get_cert_path(server_name, port);
         let key_file = self.get_key_path(server_name, port);
 
-        // Generate certificate if it doesn't exist
-        if !cert_file.exists() || !key_file.exists() {
+        // Generate the self-signed certificate if it doesn't exist or has expired
+        let expired = self
+            .get_certificate_info(server_name, port)
+            .is_some_and(|info| info.is_expired());
+        if expired {
+            log::info!(
+                "Self-signed certificate for {}:{} expired, regenerating",
+                server_name,
+                port
+            );
+        }
+        if expired || !cert_file.exists() || !key_file.exists() {
             self.generate_certificate_with_domain(server_name, port, production_domain)?;
         }
 
@@ -149,7 +159,11 @@ impl TlsManager {
             fs::set_permissions(&key_file, perms).map_err(AppError::Io)?;
         }
 
-        log::info!("TLS certificate generated with CN: {}", common_name);
+        log::info!(
+            "Self-signed TLS certificate generated with CN: {} (valid {} days)",
+            common_name,
+            self.validity_days
+        );
         log::info!("Certificate: {:?}", cert_file);
         log::info!("Private Key: {:?}", key_file);
 
@@ -257,7 +271,7 @@ impl TlsManager {
             }
         }
 
-        certificates.sort_by(|a, b| b.created.cmp(&a.created));
+        certificates.sort_by_key(|c| std::cmp::Reverse(c.created));
         Ok(certificates)
     }
 
@@ -271,7 +285,11 @@ impl TlsManager {
             let cert_chain = match self.load_certificates(&cert_file) {
                 Ok(c) => c,
                 Err(e) => {
-                    log::error!("LE cert corrupt for {}: {} — deleting for re-provision", domain, e);
+                    log::error!(
+                        "LE cert corrupt for {}: {} — deleting for re-provision",
+                        domain,
+                        e
+                    );
                     let _ = fs::remove_file(&cert_file);
                     let _ = fs::remove_file(&key_file);
                     return Err(e);
@@ -280,7 +298,11 @@ impl TlsManager {
             let private_key = match self.load_private_key(&key_file) {
                 Ok(k) => k,
                 Err(e) => {
-                    log::error!("LE key corrupt for {}: {} — deleting for re-provision", domain, e);
+                    log::error!(
+                        "LE key corrupt for {}: {} — deleting for re-provision",
+                        domain,
+                        e
+                    );
                     let _ = fs::remove_file(&cert_file);
                     let _ = fs::remove_file(&key_file);
                     return Err(e);
@@ -298,7 +320,8 @@ impl TlsManager {
                     // Delete both files so ACME will re-provision on the next cycle.
                     log::error!(
                         "LE cert/key mismatch for {}: {} — deleting for re-provision",
-                        domain, e
+                        domain,
+                        e
                     );
                     let _ = fs::remove_file(&cert_file);
                     let _ = fs::remove_file(&key_file);
@@ -1,7 +1,8 @@
 // src/server/types.rs
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServerInfo {
@@ -11,6 +12,20 @@ pub struct ServerInfo {
     pub status: ServerStatus,
     pub created_at: String,
     pub created_timestamp: u64,
+    /// `{{KEY}}` replacements for served HTML files (`create --env KEY=VALUE`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    /// Actix workers for this server (`create --workers N`); `None` uses
+    /// `[server] workers`. 1 handles one request at a time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workers: Option<usize>,
+    /// Extra response headers (`create --header "Name: value"`), added where
+    /// a response does not set the header itself
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    /// Shell command run after the server stopped (`create --on-stop "cmd"`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_stop: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
@@ -48,6 +63,10 @@ impl Default for ServerInfo {
             status: ServerStatus::Stopped,
             created_at: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
             created_timestamp: now,
+            env: BTreeMap::new(),
+            workers: None,
+            headers: BTreeMap::new(),
+            on_stop: None,
         }
     }
 }
@@ -62,9 +81,186 @@ pub struct ServerData {
 
 pub type ServerMap = Arc<RwLock<HashMap<String, ServerInfo>>>;
 pub type ServerHandles = Arc<RwLock<HashMap<String, actix_web::dev::ServerHandle>>>;
+pub type InFlightMap = Arc<RwLock<HashMap<String, Arc<AtomicUsize>>>>;
+pub type RunMap = Arc<RwLock<HashMap<String, u64>>>;
+pub type ServerThreads = Arc<Mutex<Vec<std::thread::JoinHandle<()>>>>;
 
 #[derive(Debug, Clone, Default)]
 pub struct ServerContext {
     pub servers: ServerMap,
     pub handles: ServerHandles,
+    /// Requests currently being handled, per server id (used for draining)
+    pub in_flight: InFlightMap,
+    /// Latest run of each server id, bumped on every start
+    pub runs: RunMap,
+    /// Threads of started servers; they run the `on_stop` hook after the
+    /// server stopped, so exit waits for them
+    pub threads: ServerThreads,
+}
+
+impl ServerContext {
+    /// Counter for `server_id`, created on first use
+    pub fn in_flight_counter(&self, server_id: &str) -> Arc<AtomicUsize> {
+        let mut map = self.in_flight.write().unwrap_or_else(|e| e.into_inner());
+        map.entry(server_id.to_string()).or_default().clone()
+    }
+
+    pub fn in_flight_count(&self, server_id: &str) -> usize {
+        self.in_flight
+            .read()
+            .ok()
+            .and_then(|map| map.get(server_id).map(|c| c.load(Ordering::SeqCst)))
+            .unwrap_or(0)
+    }
+
+    /// Shared first step of every stop: takes the handle out, so the server
+    /// cannot be stopped twice, and marks it Stopped right away, so it is not
+    /// listed as running while it shuts down or drains
+    pub fn begin_stop(&self, server_id: &str) -> Option<actix_web::dev::ServerHandle> {
+        let handle = self
+            .handles
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(server_id);
+        if let Some(server) = self
+            .servers
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .get_mut(server_id)
+        {
+            server.status = ServerStatus::Stopped;
+        }
+        handle
+    }
+
+    /// Keeps a server thread to wait for on exit; finished ones are dropped
+    pub fn track_thread(&self, thread: std::thread::JoinHandle<()>) {
+        let mut threads = self.threads.lock().unwrap_or_else(|e| e.into_inner());
+        threads.retain(|thread| !thread.is_finished());
+        threads.push(thread);
+    }
+
+    /// Waits up to `timeout` for every tracked server thread, including the
+    /// `on_stop` hooks they run. False if some were still running.
+    pub async fn join_threads(&self, timeout: std::time::Duration) -> bool {
+        let threads = std::mem::take(&mut *self.threads.lock().unwrap_or_else(|e| e.into_inner()));
+        if threads.is_empty() {
+            return true;
+        }
+        let joined = tokio::task::spawn_blocking(move || {
+            for thread in threads {
+                let _ = thread.join();
+            }
+        });
+        tokio::time::timeout(timeout, joined).await.is_ok()
+    }
+
+    /// Starts a new run of `server_id`; earlier runs stop owning its status
+    pub fn begin_run(&self, server_id: &str) -> u64 {
+        let mut runs = self.runs.write().unwrap_or_else(|e| e.into_inner());
+        let run = runs.entry(server_id.to_string()).or_default();
+        *run += 1;
+        *run
+    }
+
+    /// Sets the status only while `run` is the latest run of `server_id`, so
+    /// a server thread still winding down after `stop; start` leaves the new
+    /// run alone. Returns whether the status was written.
+    pub fn set_status_for_run(&self, server_id: &str, run: u64, status: ServerStatus) -> bool {
+        let current = self
+            .runs
+            .read()
+            .ok()
+            .and_then(|runs| runs.get(server_id).copied());
+        if current != Some(run) {
+            return false;
+        }
+        match self.servers.write() {
+            Ok(mut servers) => match servers.get_mut(server_id) {
+                Some(server) => {
+                    server.status = status;
+                    true
+                }
+                None => false,
+            },
+            Err(_) => false,
+        }
+    }
+}
+
+/// Counts one request as in flight until dropped (also on cancellation)
+pub struct InFlightGuard(Arc<AtomicUsize>);
+
+impl InFlightGuard {
+    pub fn enter(counter: &Arc<AtomicUsize>) -> Self {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Self(counter.clone())
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_flight_guard_counts_until_dropped() {
+        let ctx = ServerContext::default();
+        let counter = ctx.in_flight_counter("srv");
+
+        let first = InFlightGuard::enter(&counter);
+        let second = InFlightGuard::enter(&ctx.in_flight_counter("srv"));
+        assert_eq!(ctx.in_flight_count("srv"), 2);
+
+        drop(first);
+        drop(second);
+        assert_eq!(ctx.in_flight_count("srv"), 0);
+        assert_eq!(ctx.in_flight_count("unknown"), 0);
+    }
+
+    #[test]
+    fn test_stale_run_leaves_status_alone() {
+        let ctx = ServerContext::default();
+        let server: ServerInfo = serde_json::from_str(
+            r#"{"id":"srv","name":"blog","port":8001,"status":"Running","created_at":"","created_timestamp":0}"#,
+        )
+        .unwrap();
+        ctx.servers.write().unwrap().insert("srv".into(), server);
+
+        // stop; start: the old thread finishes after the new run began
+        let old_run = ctx.begin_run("srv");
+        let new_run = ctx.begin_run("srv");
+        assert!(!ctx.set_status_for_run("srv", old_run, ServerStatus::Stopped));
+        assert_eq!(
+            ctx.servers.read().unwrap()["srv"].status,
+            ServerStatus::Running
+        );
+
+        assert!(ctx.set_status_for_run("srv", new_run, ServerStatus::Stopped));
+        assert_eq!(
+            ctx.servers.read().unwrap()["srv"].status,
+            ServerStatus::Stopped
+        );
+    }
+
+    #[test]
+    fn test_begin_stop_marks_stopped_without_handle() {
+        let ctx = ServerContext::default();
+        let server: ServerInfo = serde_json::from_str(
+            r#"{"id":"srv","name":"blog","port":8001,"status":"Running","created_at":"","created_timestamp":0}"#,
+        )
+        .unwrap();
+        ctx.servers.write().unwrap().insert("srv".into(), server);
+
+        assert!(ctx.begin_stop("srv").is_none());
+        assert_eq!(
+            ctx.servers.read().unwrap()["srv"].status,
+            ServerStatus::Stopped
+        );
+    }
 }
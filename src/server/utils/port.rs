@@ -35,27 +35,9 @@ pub fn is_port_available(port: u16, bind_address: &str) -> bool {
 
 pub fn find_next_available_port(config: &Config) -> Result<u16> {
     let ctx = crate::server::shared::get_shared_context();
-    let servers = crate::core::helpers::read_lock(&ctx.servers, "servers")?;
-    let mut used_ports: Vec<u16> = servers.values().map(|s| s.port).collect();
-    used_ports.sort();
-
-    let mut candidate_port = config.server.port_range_start;
-    let max_port = config.server.port_range_end;
-
-    loop {
-        if candidate_port > max_port {
-            return Err(AppError::Validation(format!(
-                "No available ports in range {}-{}",
-                config.server.port_range_start, config.server.port_range_end
-            )));
-        }
-
-        if !used_ports.contains(&candidate_port)
-            && is_port_available(candidate_port, &config.server.bind_address)
-        {
-            return Ok(candidate_port);
-        }
-
-        candidate_port += 1;
-    }
+    crate::server::ServerManager::with_context(ctx.clone()).find_free_port(
+        config.server.port_range_start,
+        Some(config.server.port_range_end),
+        &config.server.bind_address,
+    )
 }
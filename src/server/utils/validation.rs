@@ -38,13 +38,18 @@ pub fn validate_server_name(name: &str) -> Result<()> {
     if name.len() > 50 {
         return Err(AppError::Validation("Server name too long".to_string()));
     }
+    // The name doubles as proxy subdomain ({name}.localhost), so keep it DNS-safe
     if !name
         .chars()
-        .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
     {
         return Err(AppError::Validation(
-            "Server name can only contain alphanumeric characters, hyphens and underscores"
-                .to_string(),
+            "Server name can only contain lowercase letters (a-z), digits and hyphens".to_string(),
+        ));
+    }
+    if name.starts_with('-') || name.ends_with('-') {
+        return Err(AppError::Validation(
+            "Server name cannot start or end with a hyphen".to_string(),
         ));
     }
     Ok(())
@@ -6,7 +6,8 @@ use actix_web_actors::ws;
 use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
+use std::path::Path;
+use std::sync::{mpsc, Arc, RwLock};
 use std::time::Duration;
 use tokio::sync::broadcast;
 
@@ -21,10 +22,89 @@ pub struct FileChangeEvent {
     pub file_extension: Option<String>,
 }
 
+/// Which changed files trigger a reload, from `server.hot_reload_include` /
+/// `hot_reload_exclude`. Globs are matched against the path relative to the
+/// server directory: `*` and `?` stay within one segment, `**` spans any
+/// number of them, and a pattern without `/` is tried on every segment
+/// (so `*.css` matches `css/site.css` and `.*` skips anything in `.git/`).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WatchFilter {
+    /// Empty: every file not excluded
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+}
+
+impl WatchFilter {
+    pub fn new(include: &[String], exclude: &[String]) -> Self {
+        Self {
+            include: include.to_vec(),
+            exclude: exclude.to_vec(),
+        }
+    }
+
+    pub fn allows(&self, relative: &Path) -> bool {
+        let path = relative.to_string_lossy().replace('\\', "/");
+        let hit = |pattern: &String| path_matches(pattern, &path);
+        !self.exclude.iter().any(hit) && (self.include.is_empty() || self.include.iter().any(hit))
+    }
+}
+
+fn path_matches(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.trim().trim_start_matches('/');
+    if pattern.contains('/') {
+        let pattern: Vec<&str> = pattern.split('/').collect();
+        let path: Vec<&str> = path.split('/').collect();
+        segments_match(&pattern, &path)
+    } else {
+        path.split('/').any(|segment| glob_match(pattern, segment))
+    }
+}
+
+fn segments_match(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| segments_match(rest, &path[skip..])),
+        Some((first, rest)) => path.split_first().is_some_and(|(segment, tail)| {
+            glob_match(first, segment) && segments_match(rest, tail)
+        }),
+    }
+}
+
+/// `*` and `?` within a single path segment
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
 #[derive(Debug)]
 pub struct WatchdogManager {
     watchers: Arc<RwLock<HashMap<String, RecommendedWatcher>>>,
     sender: broadcast::Sender<FileChangeEvent>,
+    /// Open hot-reload WebSockets per "name:port"
+    clients: Arc<RwLock<HashMap<String, usize>>>,
 }
 
 impl Default for WatchdogManager {
@@ -33,6 +113,7 @@ impl Default for WatchdogManager {
         Self {
             watchers: Arc::new(RwLock::new(HashMap::new())),
             sender,
+            clients: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 }
@@ -46,8 +127,17 @@ impl WatchdogManager {
         self.sender.subscribe()
     }
 
-    pub fn start_watching(&self, server_name: &str, port: u16) -> Result<()> {
-        let base_dir = crate::core::helpers::get_base_dir()?;
+    /// Watches the server directory for files `filter` allows; changes within
+    /// `debounce` of each other go out as one reload event (`Duration::ZERO`
+    /// sends every change)
+    pub fn start_watching(
+        &self,
+        server_name: &str,
+        port: u16,
+        debounce: Duration,
+        filter: WatchFilter,
+    ) -> Result<()> {
+        let base_dir = crate::core::paths::base_dir()?;
 
         let watch_path = base_dir
             .join("www")
@@ -63,11 +153,20 @@ impl WatchdogManager {
         let server_key = format!("{}:{}", server_name, port);
         let sender = self.sender.clone();
         let server_name_owned = server_name.to_owned();
+        let (changes_tx, changes_rx) = mpsc::channel();
+        let root = watch_path.clone();
 
         let mut watcher =
             notify::recommended_watcher(move |res: notify::Result<Event>| match res {
                 Ok(event) => {
-                    if let Err(e) = handle_file_event(&event, &server_name_owned, port, &sender) {
+                    if let Err(e) = handle_file_event(
+                        &event,
+                        &server_name_owned,
+                        port,
+                        &root,
+                        &filter,
+                        &changes_tx,
+                    ) {
                         log::error!("Error handling file event: {}", e);
                     }
                 }
@@ -79,6 +178,9 @@ impl WatchdogManager {
             .watch(&watch_path, RecursiveMode::Recursive)
             .map_err(|e| AppError::Validation(format!("Failed to start watching: {}", e)))?;
 
+        // Ends on its own once the watcher (and with it the sending side) is dropped
+        std::thread::spawn(move || forward_debounced(&changes_rx, &sender, debounce));
+
         let mut watchers = self.watchers.write().unwrap_or_else(|p| p.into_inner());
         watchers.insert(server_key.clone(), watcher);
 
@@ -110,13 +212,95 @@ impl WatchdogManager {
         let watchers = self.watchers.read().unwrap_or_else(|p| p.into_inner());
         watchers.keys().cloned().collect()
     }
+
+    fn client_connected(&self, server_key: &str) {
+        let mut clients = self.clients.write().unwrap_or_else(|p| p.into_inner());
+        *clients.entry(server_key.to_string()).or_default() += 1;
+    }
+
+    fn client_disconnected(&self, server_key: &str) {
+        let mut clients = self.clients.write().unwrap_or_else(|p| p.into_inner());
+        if let Some(count) = clients.get_mut(server_key) {
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                clients.remove(server_key);
+            }
+        }
+    }
+
+    pub fn connected_clients(&self, server_name: &str, port: u16) -> usize {
+        let clients = self.clients.read().unwrap_or_else(|p| p.into_inner());
+        clients
+            .get(&format!("{}:{}", server_name, port))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Tells the server's open pages to reload without a file change;
+    /// returns how many were connected (nothing is sent when none are)
+    pub fn send_reload(&self, server_name: &str, port: u16) -> usize {
+        let clients = self.connected_clients(server_name, port);
+        if clients > 0 {
+            let _ = self.sender.send(FileChangeEvent {
+                event_type: "reload".to_string(),
+                file_path: String::new(),
+                server_name: server_name.to_string(),
+                port,
+                timestamp: std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_secs(),
+                file_extension: None,
+            });
+        }
+        clients
+    }
+}
+
+/// Waits for the next change and everything following it until `window`
+/// passes without one; returns the latest change and how many came in
+fn coalesce_changes(
+    changes: &mpsc::Receiver<FileChangeEvent>,
+    window: Duration,
+) -> Option<(FileChangeEvent, usize)> {
+    let mut latest = changes.recv().ok()?;
+    let mut count = 1;
+    if !window.is_zero() {
+        while let Ok(next) = changes.recv_timeout(window) {
+            latest = next;
+            count += 1;
+        }
+    }
+    Some((latest, count))
+}
+
+fn forward_debounced(
+    changes: &mpsc::Receiver<FileChangeEvent>,
+    sender: &broadcast::Sender<FileChangeEvent>,
+    window: Duration,
+) {
+    while let Some((event, count)) = coalesce_changes(changes, window) {
+        if count > 1 {
+            log::info!(
+                "Hot reload for {}:{}: coalesced {} file events",
+                event.server_name,
+                event.port,
+                count
+            );
+        }
+        if let Err(e) = sender.send(event) {
+            log::error!("Failed to send file change event: {}", e);
+        }
+    }
 }
 
 fn handle_file_event(
     event: &Event,
     server_name: &str,
     port: u16,
-    sender: &broadcast::Sender<FileChangeEvent>,
+    root: &Path,
+    filter: &WatchFilter,
+    sender: &mpsc::Sender<FileChangeEvent>,
 ) -> Result<()> {
     // Only process relevant events
     let event_type = match event.kind {
@@ -127,16 +311,9 @@ fn handle_file_event(
     };
 
     for path in &event.paths {
-        // Skip temporary files and backups
-        if let Some(file_name) = path.file_name() {
-            let name = file_name.to_string_lossy();
-            if name.starts_with('.')
-                || name.ends_with('~')
-                || name.contains(".tmp")
-                || name.contains(".swp")
-            {
-                continue;
-            }
+        let relative = path.strip_prefix(root).unwrap_or(path);
+        if !filter.allows(relative) {
+            continue;
         }
 
         let file_extension = path
@@ -144,17 +321,6 @@ fn handle_file_event(
             .and_then(|ext| ext.to_str())
             .map(|s| s.to_string());
 
-        // Only web-relevant file types
-        if let Some(ref ext) = file_extension {
-            if ![
-                "html", "css", "js", "json", "txt", "md", "svg", "png", "jpg", "jpeg", "gif", "ico",
-            ]
-            .contains(&ext.as_str())
-            {
-                continue;
-            }
-        }
-
         let change_event = FileChangeEvent {
             event_type: event_type.to_string(),
             file_path: path.to_string_lossy().to_string(),
@@ -179,6 +345,9 @@ fn handle_file_event(
 pub struct HotReloadWs {
     receiver: Option<broadcast::Receiver<FileChangeEvent>>,
     server_filter: Option<String>, // Format: "name:port"
+    manager: Arc<WatchdogManager>,
+    /// The serving server ("name:port"), counted while connected
+    server_key: Option<String>,
 }
 
 impl Actor for HotReloadWs {
@@ -186,6 +355,9 @@ impl Actor for HotReloadWs {
 
     fn started(&mut self, ctx: &mut Self::Context) {
         log::debug!("WebSocket connection established for hot reload");
+        if let Some(ref key) = self.server_key {
+            self.manager.client_connected(key);
+        }
 
         if let Some(mut receiver) = self.receiver.take() {
             let addr = ctx.address();
@@ -213,6 +385,12 @@ impl Actor for HotReloadWs {
             ctx.ping(b"");
         });
     }
+
+    fn stopped(&mut self, _ctx: &mut Self::Context) {
+        if let Some(ref key) = self.server_key {
+            self.manager.client_disconnected(key);
+        }
+    }
 }
 
 impl StreamHandler<std::result::Result<ws::Message, ws::ProtocolError>> for HotReloadWs {
@@ -279,10 +457,16 @@ pub async fn ws_hot_reload(
             }
         })
         .map(|s| s.to_string());
+    let server_key = req
+        .app_data::<web::Data<crate::server::handlers::web::ServerDataWithConfig>>()
+        .map(|own| format!("{}:{}", own.server.name, own.server.port));
 
+    // Without ?server= a page only reloads for changes of the server serving it
     let ws_actor = HotReloadWs {
         receiver: Some(data.subscribe()),
-        server_filter,
+        server_filter: server_filter.or_else(|| server_key.clone()),
+        manager: data.into_inner(),
+        server_key,
     };
 
     ws::start(ws_actor, &req, stream)
@@ -296,10 +480,102 @@ pub fn get_watchdog_manager() -> &'static Arc<WatchdogManager> {
     WATCHDOG_MANAGER.get_or_init(|| Arc::new(WatchdogManager::new()))
 }
 
-pub fn start_server_watching(server_name: &str, port: u16) -> Result<()> {
-    get_watchdog_manager().start_watching(server_name, port)
+pub fn start_server_watching(
+    server_name: &str,
+    port: u16,
+    debounce: Duration,
+    filter: WatchFilter,
+) -> Result<()> {
+    get_watchdog_manager().start_watching(server_name, port, debounce, filter)
 }
 
 pub fn stop_server_watching(server_name: &str, port: u16) -> Result<()> {
     get_watchdog_manager().stop_watching(server_name, port)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(path: &str) -> FileChangeEvent {
+        FileChangeEvent {
+            event_type: "modified".into(),
+            file_path: path.into(),
+            server_name: "blog".into(),
+            port: 8001,
+            timestamp: 0,
+            file_extension: Some("html".into()),
+        }
+    }
+
+    #[test]
+    fn test_coalesce_changes_within_window() {
+        let (tx, rx) = mpsc::channel();
+        for path in ["a.html", "b.css", "c.js"] {
+            tx.send(change(path)).unwrap();
+        }
+        let (latest, count) = coalesce_changes(&rx, Duration::from_millis(50)).unwrap();
+        assert_eq!(count, 3);
+        assert_eq!(latest.file_path, "c.js");
+
+        // Without a window every change stands alone
+        tx.send(change("d.html")).unwrap();
+        tx.send(change("e.html")).unwrap();
+        let (latest, count) = coalesce_changes(&rx, Duration::ZERO).unwrap();
+        assert_eq!((latest.file_path.as_str(), count), ("d.html", 1));
+
+        drop(tx);
+        assert_eq!(coalesce_changes(&rx, Duration::ZERO).unwrap().1, 1);
+        assert!(coalesce_changes(&rx, Duration::ZERO).is_none());
+    }
+
+    #[test]
+    fn test_send_reload_counts_connected_clients() {
+        let manager = WatchdogManager::new();
+        let mut events = manager.subscribe();
+        assert_eq!(manager.send_reload("blog", 8001), 0);
+        assert!(events.try_recv().is_err());
+
+        manager.client_connected("blog:8001");
+        manager.client_connected("blog:8001");
+        manager.client_connected("shop:8002");
+        assert_eq!(manager.send_reload("blog", 8001), 2);
+        let event = events.try_recv().unwrap();
+        assert_eq!(event.event_type, "reload");
+        assert_eq!((event.server_name.as_str(), event.port), ("blog", 8001));
+
+        manager.client_disconnected("blog:8001");
+        manager.client_disconnected("blog:8001");
+        assert_eq!(manager.connected_clients("blog", 8001), 0);
+        assert_eq!(manager.connected_clients("shop", 8002), 1);
+    }
+
+    #[test]
+    fn test_watch_filter_include_exclude() {
+        let config = crate::core::config::ServerConfig::default();
+        let filter = WatchFilter::new(&config.hot_reload_include, &config.hot_reload_exclude);
+        assert!(filter.allows(Path::new("index.html")));
+        assert!(filter.allows(Path::new("css/site.css")));
+        assert!(filter.allows(Path::new("js/app.js")));
+        assert!(!filter.allows(Path::new("logs/server.log")));
+        assert!(!filter.allows(Path::new(".DS_Store")));
+        assert!(!filter.allows(Path::new(".git/index.html")));
+        assert!(!filter.allows(Path::new("index.html.swp")));
+        assert!(!filter.allows(Path::new("index.html~")));
+
+        let custom = WatchFilter::new(
+            &["assets/**/*.png".into(), "*.html".into()],
+            &["drafts/**".into()],
+        );
+        assert!(custom.allows(Path::new("assets/img/icons/logo.png")));
+        assert!(custom.allows(Path::new("assets/logo.png")));
+        assert!(!custom.allows(Path::new("logo.png")));
+        assert!(!custom.allows(Path::new("drafts/post.html")));
+        assert!(custom.allows(Path::new("blog/post.html")));
+
+        // No include patterns: everything not excluded
+        assert!(WatchFilter::new(&[], &["*.log".into()]).allows(Path::new("data.bin")));
+        assert!(glob_match("a*b?c", "axxbyc"));
+        assert!(!glob_match("a*b?c", "axxbc"));
+    }
+}
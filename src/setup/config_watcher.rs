@@ -0,0 +1,209 @@
+// src/setup/config_watcher.rs - Reload rush.toml while the TUI is running
+use crate::core::prelude::*;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::path::{Path, PathBuf};
+use tokio::sync::mpsc;
+
+/// Quiet time after the last write before a reload, so an editor's
+/// save (truncate + write, or write temp + rename) counts once
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+pub struct ConfigWatcher {
+    // Dropping the watcher stops the notifications
+    _watcher: RecommendedWatcher,
+    events: mpsc::UnboundedReceiver<()>,
+}
+
+impl ConfigWatcher {
+    /// Watches the directory of `path` (editors often replace the file, which
+    /// would end a watch on the file itself) and reports changes to `path`.
+    pub fn start(path: &Path) -> Result<Self> {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_os_string())
+            .ok_or_else(|| AppError::Validation(format!("Invalid config path: {:?}", path)))?;
+        let dir = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+
+        // Debounced on a thread of its own: `changed` is awaited in a select!
+        // next to the UI tick, so it has to stay cancel-safe
+        let (raw_tx, raw_rx) = std::sync::mpsc::channel();
+        let (tx, events) = mpsc::unbounded_channel();
+        std::thread::spawn(move || forward_debounced(raw_rx, tx));
+
+        let mut watcher =
+            notify::recommended_watcher(move |res: notify::Result<Event>| match res {
+                Ok(event) => {
+                    let relevant =
+                        matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_))
+                            && event
+                                .paths
+                                .iter()
+                                .any(|p| p.file_name() == Some(file_name.as_os_str()));
+                    if relevant {
+                        let _ = raw_tx.send(());
+                    }
+                }
+                Err(e) => log::warn!("Config watch error: {:?}", e),
+            })
+            .map_err(|e| AppError::Validation(format!("Failed to create watcher: {}", e)))?;
+
+        watcher
+            .watch(&dir, RecursiveMode::NonRecursive)
+            .map_err(|e| AppError::Validation(format!("Failed to start watching: {}", e)))?;
+
+        log::debug!("Watching config file {}", path.display());
+        Ok(Self {
+            _watcher: watcher,
+            events,
+        })
+    }
+
+    /// Resolves once per burst of changes, after `DEBOUNCE` without another
+    /// one. Cancel-safe: dropping the future loses nothing.
+    pub async fn changed(&mut self) -> Option<()> {
+        self.events.recv().await
+    }
+}
+
+/// Sends one message per burst of raw events; ends with the watcher, which
+/// owns the raw sender
+fn forward_debounced(raw: std::sync::mpsc::Receiver<()>, tx: mpsc::UnboundedSender<()>) {
+    while raw.recv().is_ok() {
+        while raw.recv_timeout(DEBOUNCE).is_ok() {}
+        if tx.send(()).is_err() {
+            break;
+        }
+    }
+}
+
+/// Settings that differ between the running and the reloaded config, named
+/// like their rush.toml keys. Empty for our own saves, which write what is
+/// already in memory.
+pub fn changed_settings(current: &Config, reloaded: &Config) -> Vec<&'static str> {
+    let checks = [
+        ("language", current.language != reloaded.language),
+        (
+            "fallback",
+            current.language_fallback != reloaded.language_fallback,
+        ),
+        (
+            "current_theme",
+            current.current_theme_name != reloaded.current_theme_name,
+        ),
+        ("theme", current.theme != reloaded.theme),
+        (
+            "typewriter_delay",
+            current.typewriter_delay != reloaded.typewriter_delay,
+        ),
+        (
+            "max_messages",
+            current.max_messages != reloaded.max_messages,
+        ),
+        ("max_history", current.max_history != reloaded.max_history),
+        ("typewriter", current.typewriter != reloaded.typewriter),
+        (
+            "input_max_length",
+            current.input_max_length != reloaded.input_max_length,
+        ),
+        ("poll_rate", current.poll_rate != reloaded.poll_rate),
+        ("input_poll_ms", current.input_poll != reloaded.input_poll),
+        (
+            "key_interval_ms",
+            current.key_interval != reloaded.key_interval,
+        ),
+        (
+            "resize_throttle_ms",
+            current.resize_throttle != reloaded.resize_throttle,
+        ),
+        (
+            "mouse_capture",
+            current.mouse_capture != reloaded.mouse_capture,
+        ),
+        ("wrap_lines", current.wrap_lines != reloaded.wrap_lines),
+        (
+            "show_timestamps",
+            current.show_timestamps != reloaded.show_timestamps,
+        ),
+        (
+            "archive_overflow",
+            current.archive_overflow != reloaded.archive_overflow,
+        ),
+        ("ansi_colors", current.ansi_colors != reloaded.ansi_colors),
+        ("log_level", current.log_level != reloaded.log_level),
+        ("show_banner", current.show_banner != reloaded.show_banner),
+        ("banner", current.banner != reloaded.banner),
+        ("keybindings", current.keybindings != reloaded.keybindings),
+    ];
+    checks
+        .into_iter()
+        .filter(|(_, changed)| *changed)
+        .map(|(name, _)| name)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changed_settings() {
+        let current = Config::default();
+        assert!(changed_settings(&current, &current.clone()).is_empty());
+
+        let mut reloaded = current.clone();
+        reloaded.language = "de".to_string();
+        reloaded.max_history = current.max_history + 10;
+        assert_eq!(
+            changed_settings(&current, &reloaded),
+            vec!["language", "max_history"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_watcher_debounces_saves() {
+        let dir = std::env::temp_dir().join(format!("rush-config-watch-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("rush.toml");
+        std::fs::write(&path, "[general]\n").unwrap();
+
+        let mut watcher = ConfigWatcher::start(&path).unwrap();
+        for i in 0..3 {
+            std::fs::write(&path, format!("[general]\nmax_history = {}\n", i)).unwrap();
+        }
+        std::fs::write(dir.join("other.toml"), "x = 1\n").unwrap();
+
+        let first = tokio::time::timeout(Duration::from_secs(5), watcher.changed()).await;
+        assert_eq!(first.ok().flatten(), Some(()));
+        // The burst was collapsed into the one notification above
+        let second = tokio::time::timeout(Duration::from_millis(800), watcher.changed()).await;
+        assert!(second.is_err());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_changed_survives_cancellation() {
+        let dir = std::env::temp_dir().join(format!("rush-config-cancel-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("rush.toml");
+        std::fs::write(&path, "[general]\n").unwrap();
+
+        let mut watcher = ConfigWatcher::start(&path).unwrap();
+        std::fs::write(&path, "[general]\nmax_history = 5\n").unwrap();
+
+        // Like the UI loop: a 16ms tick keeps cancelling the wait
+        let mut seen = false;
+        for _ in 0..300 {
+            if let Ok(Some(())) =
+                tokio::time::timeout(Duration::from_millis(16), watcher.changed()).await
+            {
+                seen = true;
+                break;
+            }
+        }
+        assert!(seen);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
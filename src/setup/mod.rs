@@ -1,2 +1,4 @@
 // src/setup/mod.rs
+pub mod config_watcher;
 pub mod setup_toml;
+pub mod wizard;
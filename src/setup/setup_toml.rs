@@ -1,6 +1,7 @@
 // src/setup/setup_toml.rs - Cleaned and optimized
 use crate::core::prelude::*;
 use std::path::PathBuf;
+use std::sync::OnceLock;
 use tokio::fs;
 
 // Consolidated DEFAULT_CONFIG - All sections in one place
@@ -10,11 +11,22 @@ typewriter_delay = 5
 input_max_length = 100
 max_history = 30
 poll_rate = 16
+input_poll_ms = 99           # Terminal event poll timeout (higher = less idle CPU)
+key_interval_ms = 16         # Drop key events closer together than this
+resize_throttle_ms = 50      # Drop resize events closer together than this
+mouse_capture = true         # Mouse wheel scrolls the output (false = native text selection)
+wrap_lines = true            # Wrap long output lines (false = clip, Shift+Left/Right scrolls)
+show_timestamps = false      # Prefix output messages with their HH:MM:SS arrival time
+archive_overflow = false     # Append messages dropped over max_messages to .rss/session.log
+ansi_colors = false          # Keep ANSI colors from subprocess output (false = strip them)
 log_level = "info"
 current_theme = "dark"
+show_banner = true           # Startup banner in the output pane (--no-banner skips it once)
+# banner = "Rush Sync {{VERSION}} - {{SERVERS}} servers, {{MEMORY}}"   # Custom banner ({{THEME}}, {{LANGUAGE}} too)
 
 [language]
 current = "en"
+# fallback = ["de"]          # Tried in order for missing keys; "en" always comes last
 
 # =====================================================
 # SERVER CONFIGURATION
@@ -26,7 +38,7 @@ port_range_end = 8100        # Maximum port for auto-allocation
 max_concurrent = 100         # Maximum simultaneous servers
 shutdown_timeout = 5         # Graceful shutdown timeout (seconds)
 startup_delay_ms = 500       # Delay after server creation (milliseconds)
-workers = 1                  # Actix workers per server
+workers = 1                  # Actix workers per server (create --workers N overrides per server)
 auto_open_browser = true     # Automatically open browser
 bind_address = "127.0.0.1"   # Server bind address ("0.0.0.0" for public access)
 
@@ -34,21 +46,38 @@ bind_address = "127.0.0.1"   # Server bind address ("0.0.0.0" for public access)
 enable_https = true          # Enable HTTPS support
 https_port_offset = 1000     # HTTPS port = HTTP port + offset
 cert_dir = ".rss/certs"      # Certificate storage directory
-auto_cert = true             # Generate certificates automatically
+auto_cert = true             # Generate self-signed certificates (offline HTTPS, renewed on expiry)
 cert_validity_days = 365     # Certificate validity (days)
 
 # Production Settings
 use_lets_encrypt = false     # Use Let's Encrypt (requires public domain)
 production_domain = "localhost"  # Production domain name
 acme_email = ""              # Email for Let's Encrypt notifications (optional)
+acme_challenge = "http-01"   # ACME challenge: "http-01" (port 80) or "dns-01" (TXT record)
+acme_dns_hook = ""           # DNS-01 hook command (empty = print TXT record for manual entry)
 
 # Security: plaintext, hash ($hmac-sha256$...), or RSS_API_KEY env var
 # Generate hash: rush-sync --hash-key <your-key>
 api_key = ""                 # API key for /api/*, /.rss/*, /ws/* endpoints (empty = no auth)
+# CORS: origins that get Access-Control-* headers (incl. OPTIONS preflight), ["*"] = any.
+# Empty: localhost origins while production_domain = "localhost" (dev), no CORS otherwise (prod)
+cors_origins = []
+# Dashboard login: HTTP Basic Auth on /.rss/ and /api/* (not on served files), off unless both are set.
+# The password may be a --hash-key hash like api_key
+dashboard_user = ""
+dashboard_password = ""
 
 # Rate Limiting
 rate_limit_rps = 100         # Max requests per second per IP for /api/* endpoints
 rate_limit_enabled = true    # Enable rate limiting
+rate_limit_per_min = 0       # Max requests per minute per IP on all paths (0 = off), 429 beyond
+index_files = ["index.html", "index.htm"]   # Directory index names, first existing wins
+compression = "auto"         # auto | brotli | gzip | off (off: no compression middleware)
+hot_reload = true            # Watch files, serve /ws/hot-reload and inject /rss.js (false: HTML served as is)
+hot_reload_debounce_ms = 300 # Changes within this window trigger one reload (0 = reload on every change)
+# Globs relative to the server directory; "*.css" matches at any depth, "**" spans directories
+hot_reload_include = ["*.html", "*.htm", "*.css", "*.js"]   # Only these trigger a reload ([] = all)
+hot_reload_exclude = [".*", "*~", "*.tmp", "*.swp"]         # Never trigger a reload (e.g. "logs/**")
 
 # =====================================================
 # REVERSE PROXY CONFIGURATION
@@ -59,7 +88,10 @@ port = 3000                     # Proxy listening port
 https_port_offset = 443         # Added for HTTPS offset
 bind_address = "127.0.0.1"      # Proxy bind address
 health_check_interval = 30      # Health check interval (seconds)
-timeout_ms = 5000               # Request timeout (milliseconds)
+path_routing = false            # Also route localhost/<name>/* (no wildcard DNS needed)
+connect_timeout_ms = 2000       # Backend connect timeout (milliseconds)
+read_timeout_ms = 30000         # Wait for backend response headers (milliseconds)
+retries = 3                     # Retries for GET/HEAD when a backend refuses (e.g. restarting)
 
 # For production use:
 # port = 80                  # Standard HTTP Port
@@ -75,6 +107,31 @@ compress_archives = true     # GZIP compression for archives
 log_requests = true          # Enable request logging
 log_security_alerts = true  # Enable security monitoring
 log_performance = true       # Enable performance metrics
+format = "native"            # "json" = flat JSON Lines for Loki/ELK
+sample_rate = 1              # Log 1 in N successful requests (errors always)
+slow_request_ms = 1000       # WARN + always log slower requests (0 = off)
+
+# =====================================================
+# TYPEWRITER EFFECT
+# =====================================================
+[typewriter]
+force_instant_lines = 5      # Show messages with more lines instantly
+force_instant_bytes = 200    # Show messages longer than this instantly
+
+# =====================================================
+# KEY BINDINGS
+# =====================================================
+# Override the default keys: "<modifiers+key>" = "<Action>"
+# Actions: MoveLeft, MoveRight, MoveToStart, MoveToEnd, Backspace, Delete,
+# Submit, Cancel, Quit, ClearLine, CopySelection, PasteBuffer, HistorySearch,
+# ScrollUp, ScrollDown, PageUp, PageDown, ScrollToTop, ScrollToBottom,
+# SearchNext, SearchPrev, ScrollLeft, ScrollRight, NoAction (unbinds)
+# Chords: space-separated keys pressed within 1s, e.g. "ctrl+x ctrl+s" = "Submit"
+# [keybindings]
+# "ctrl+a" = "MoveToStart"
+# "ctrl+e" = "MoveToEnd"
+# "ctrl+k" = "ClearLine"
+# "alt+k" = "ScrollUp"
 
 # =====================================================
 # THEME DEFINITIONS
@@ -124,6 +181,20 @@ input_cursor = "UNDERSCORE"
 input_cursor_color = "White"
 "#;
 
+/// Set once from `--config <path>`; replaces discovery for load and save
+static CONFIG_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Pins the config to exactly `path`. Returns false if an override was already set.
+pub fn set_config_override(path: PathBuf) -> bool {
+    CONFIG_OVERRIDE
+        .set(crate::core::paths::absolutize(path))
+        .is_ok()
+}
+
+pub fn config_override() -> Option<&'static PathBuf> {
+    CONFIG_OVERRIDE.get()
+}
+
 pub async fn ensure_config_exists() -> Result<PathBuf> {
     let config_path = get_primary_config_path()?;
 
@@ -134,7 +205,14 @@ pub async fn ensure_config_exists() -> Result<PathBuf> {
 
     // Create config file if it doesn't exist
     if !config_path.exists() {
-        fs::write(&config_path, DEFAULT_CONFIG)
+        // Keeps a language detected from the system locale on first launch
+        let language = crate::i18n::get_current_language().to_lowercase();
+        let content = DEFAULT_CONFIG.replacen(
+            "current = \"en\"",
+            &format!("current = \"{}\"", language),
+            1,
+        );
+        fs::write(&config_path, content)
             .await
             .map_err(AppError::Io)?;
 
@@ -151,15 +229,17 @@ pub async fn ensure_config_exists() -> Result<PathBuf> {
 }
 
 pub fn get_config_paths() -> Vec<PathBuf> {
+    if let Some(path) = config_override() {
+        return vec![path.clone()];
+    }
+
     let mut paths = Vec::new();
 
-    if let Ok(exe_path) = std::env::current_exe() {
-        if let Some(base_dir) = exe_path.parent() {
-            // Primary locations (in order of preference)
-            paths.push(base_dir.join(".rss/rush.toml"));
-            paths.push(base_dir.join("rush.toml"));
-            paths.push(base_dir.join("config/rush.toml"));
-        }
+    if let Ok(base_dir) = crate::core::paths::base_dir() {
+        // Primary locations (in order of preference)
+        paths.push(base_dir.join(".rss/rush.toml"));
+        paths.push(base_dir.join("rush.toml"));
+        paths.push(base_dir.join("config/rush.toml"));
     }
 
     // Development fallbacks
@@ -173,10 +253,10 @@ pub fn get_config_paths() -> Vec<PathBuf> {
 }
 
 fn get_primary_config_path() -> Result<PathBuf> {
-    let exe_path = std::env::current_exe().map_err(AppError::Io)?;
-    let base_dir = exe_path
-        .parent()
-        .ok_or_else(|| AppError::Validation(get_translation("system.config.dir_error", &[])))?;
+    if let Some(path) = config_override() {
+        return Ok(path.clone());
+    }
 
+    let base_dir = crate::core::paths::base_dir()?;
     Ok(base_dir.join(".rss/rush.toml"))
 }
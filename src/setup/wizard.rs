@@ -0,0 +1,198 @@
+// src/setup/wizard.rs - First-run setup before the TUI starts
+use crate::commands::theme::ThemeSystem;
+use crate::core::prelude::*;
+use crate::setup::setup_toml::{ensure_config_exists, get_config_paths};
+use std::io::{BufRead, IsTerminal, Write};
+
+/// First run = no rush.toml in any of the search paths. Must be checked
+/// before anything loads the config, since loading writes a default rush.toml.
+pub fn is_first_run() -> bool {
+    first_run_in(&get_config_paths())
+}
+
+fn first_run_in(paths: &[std::path::PathBuf]) -> bool {
+    !paths.iter().any(|path| path.exists())
+}
+
+/// Runs the wizard when `first_run` (from `is_first_run` at startup) is set
+/// and stdin is interactive; otherwise a no-op
+pub async fn run_if_first_run(first_run: bool) -> Result<()> {
+    if !first_run || !std::io::stdin().is_terminal() {
+        return Ok(());
+    }
+    run().await
+}
+
+async fn run() -> Result<()> {
+    let path = ensure_config_exists().await?;
+    let mut config = Config::from_file(&path).await?;
+
+    println!(
+        "\n{}\n",
+        get_translation(
+            "system.setup.wizard.header",
+            &[crate::core::constants::VERSION]
+        )
+    );
+
+    let start = prompt(&get_translation("system.setup.wizard.skip_prompt", &[]))?;
+    if start.eq_ignore_ascii_case("s") || start.eq_ignore_ascii_case("skip") {
+        println!("{}", get_translation("system.setup.wizard.skipped", &[]));
+        return Ok(());
+    }
+
+    // Language first, so the remaining questions are asked in it
+    let languages = crate::i18n::get_available_languages();
+    let language = choose(
+        &get_translation("system.setup.wizard.language", &[]),
+        &languages,
+        &config.language,
+    )?;
+    if let Err(e) = crate::i18n::set_language(&language) {
+        log::warn!("Wizard: could not switch language: {}", e);
+    }
+
+    let themes = ThemeSystem::load()
+        .map(|system| system.get_available_names())
+        .unwrap_or_default();
+    let theme = if themes.is_empty() {
+        config.current_theme_name.clone()
+    } else {
+        choose(
+            &get_translation("system.setup.wizard.theme", &[]),
+            &themes,
+            &config.current_theme_name,
+        )?
+    };
+
+    let proxy = confirm(
+        &get_translation("system.setup.wizard.proxy", &[]),
+        config.proxy.enabled,
+    )?;
+    let https = confirm(
+        &get_translation("system.setup.wizard.https", &[]),
+        config.server.enable_https,
+    )?;
+
+    config.language = language;
+    config.current_theme_name = theme;
+    config.proxy.enabled = proxy;
+    config.server.enable_https = https;
+    config.save().await?;
+
+    println!(
+        "\n{}\n",
+        get_translation("system.setup.wizard.done", &[&path.display().to_string()])
+    );
+    Ok(())
+}
+
+fn prompt(question: &str) -> Result<String> {
+    print!("{} ", question);
+    std::io::stdout().flush().map_err(AppError::Io)?;
+
+    let mut line = String::new();
+    std::io::stdin()
+        .lock()
+        .read_line(&mut line)
+        .map_err(AppError::Io)?;
+    Ok(line.trim().to_string())
+}
+
+fn choose(label: &str, options: &[String], default: &str) -> Result<String> {
+    println!("\n{}:", label);
+    for (i, option) in options.iter().enumerate() {
+        let marker = if option.eq_ignore_ascii_case(default) {
+            "*"
+        } else {
+            " "
+        };
+        println!("  {}{:>2}) {}", marker, i + 1, option);
+    }
+
+    loop {
+        let input = prompt(&format!("[{}]", default))?;
+        match parse_choice(&input, options, default) {
+            Some(choice) => return Ok(choice),
+            None => println!("{}", get_translation("system.setup.wizard.invalid", &[])),
+        }
+    }
+}
+
+fn confirm(question: &str, default: bool) -> Result<bool> {
+    let hint = if default { "[Y/n]" } else { "[y/N]" };
+    loop {
+        let input = prompt(&format!("\n{} {}", question, hint))?;
+        match parse_yes_no(&input, default) {
+            Some(answer) => return Ok(answer),
+            None => println!("{}", get_translation("system.setup.wizard.invalid", &[])),
+        }
+    }
+}
+
+/// Empty input keeps the default; accepts a 1-based number or an option name
+fn parse_choice(input: &str, options: &[String], default: &str) -> Option<String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Some(default.to_string());
+    }
+    if let Ok(index) = input.parse::<usize>() {
+        return options.get(index.checked_sub(1)?).cloned();
+    }
+    options
+        .iter()
+        .find(|option| option.eq_ignore_ascii_case(input))
+        .cloned()
+}
+
+fn parse_yes_no(input: &str, default: bool) -> Option<bool> {
+    match input.trim().to_lowercase().as_str() {
+        "" => Some(default),
+        "y" | "yes" | "j" | "ja" => Some(true),
+        "n" | "no" | "nein" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_run_detected_before_config_is_written() {
+        let dir = std::env::temp_dir().join(format!("rush-wizard-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let paths = vec![dir.join(".rss/rush.toml"), dir.join("rush.toml")];
+
+        // No rush.toml yet: the flag taken at startup says first run ...
+        let first_run = first_run_in(&paths);
+        assert!(first_run);
+
+        // ... and stays so after server init writes the default config
+        std::fs::write(dir.join("rush.toml"), "[general]\n").unwrap();
+        assert!(!first_run_in(&paths));
+        assert!(first_run);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_parse_choice() {
+        let options = vec!["de".to_string(), "en".to_string()];
+        assert_eq!(parse_choice("", &options, "en").as_deref(), Some("en"));
+        assert_eq!(parse_choice("1", &options, "en").as_deref(), Some("de"));
+        assert_eq!(parse_choice("EN", &options, "de").as_deref(), Some("en"));
+        assert_eq!(parse_choice("0", &options, "en"), None);
+        assert_eq!(parse_choice("3", &options, "en"), None);
+        assert_eq!(parse_choice("fr", &options, "en"), None);
+    }
+
+    #[test]
+    fn test_parse_yes_no() {
+        assert_eq!(parse_yes_no("", true), Some(true));
+        assert_eq!(parse_yes_no("", false), Some(false));
+        assert_eq!(parse_yes_no("J", false), Some(true));
+        assert_eq!(parse_yes_no("no", true), Some(false));
+        assert_eq!(parse_yes_no("maybe", true), None);
+    }
+}
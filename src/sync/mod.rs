@@ -1,7 +1,11 @@
 pub mod profiles;
 pub mod transport;
 
-pub use profiles::{parse_user_host, validate_profile_name, RemoteProfile, RemoteProfileStore};
+pub use profiles::{
+    parse_user_host, validate_profile_name, RemoteProfile, RemoteProfileStore, SyncDirection,
+    SyncFailure, SyncHistory,
+};
 pub use transport::{
-    git_pull, restart_service, run_remote_command, sync_pull, sync_push, test_connection,
+    cached_sessions, disconnect, git_pull, restart_service, run_remote_command, sync_pull,
+    sync_push, test_connection, CachedSession,
 };
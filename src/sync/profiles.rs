@@ -1,4 +1,4 @@
-use crate::core::helpers::get_base_dir;
+use crate::core::paths::base_dir;
 use crate::core::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
@@ -14,6 +14,9 @@ pub struct RemoteProfile {
     pub remote_path: String,
     #[serde(default)]
     pub identity_file: Option<String>,
+    /// Short name accepted wherever the profile name is
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
 }
 
 fn default_remote_port() -> u16 {
@@ -76,18 +79,73 @@ impl RemoteProfile {
             port,
             remote_path,
             identity_file,
+            alias: None,
         })
     }
 
+    pub fn with_alias(mut self, alias: Option<String>) -> Self {
+        self.alias = alias;
+        self
+    }
+
     pub fn ssh_target(&self) -> String {
         format!("{}@{}", self.user, self.host)
     }
 }
 
+/// Direction of a file sync, as recorded in the sync history
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncDirection {
+    Push,
+    Pull,
+}
+
+impl SyncDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SyncDirection::Push => "push",
+            SyncDirection::Pull => "pull",
+        }
+    }
+}
+
+/// Last sync outcomes of one profile (timestamps are local time)
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct SyncHistory {
+    /// Last successful push
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_push: Option<String>,
+    /// Last successful pull
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_pull: Option<String>,
+    /// Most recent failure; cleared by a later success in the same direction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<SyncFailure>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SyncFailure {
+    pub at: String,
+    pub direction: String,
+    pub message: String,
+}
+
+impl SyncHistory {
+    /// Newest successful push or pull
+    pub fn last_success(&self) -> Option<&str> {
+        match (&self.last_push, &self.last_pull) {
+            (Some(push), Some(pull)) => Some(push.max(pull)),
+            (push, pull) => push.as_deref().or(pull.as_deref()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 struct ProfilesFile {
     #[serde(default)]
     profiles: HashMap<String, RemoteProfile>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    history: HashMap<String, SyncHistory>,
 }
 
 #[derive(Debug, Clone)]
@@ -97,7 +155,7 @@ pub struct RemoteProfileStore {
 
 impl RemoteProfileStore {
     pub fn new() -> Result<Self> {
-        let base_dir = get_base_dir()?;
+        let base_dir = base_dir()?;
         Ok(Self {
             path: base_dir.join(".rss").join("remotes.toml"),
         })
@@ -120,13 +178,44 @@ impl RemoteProfileStore {
     }
 
     pub fn get(&self, name: &str) -> Result<RemoteProfile> {
+        self.resolve(name).map(|(_, profile)| profile)
+    }
+
+    /// Looks a profile up by name, alias or `user@host` (if exactly one
+    /// profile targets it) and returns its canonical name
+    pub fn resolve(&self, name_or_alias: &str) -> Result<(String, RemoteProfile)> {
         let file = self.load_file()?;
-        file.profiles.get(name).cloned().ok_or_else(|| {
-            AppError::Validation(format!(
-                "Remote profile '{}' not found. Use 'remote list' to inspect configured remotes.",
-                name
-            ))
-        })
+        if let Some(profile) = file.profiles.get(name_or_alias) {
+            return Ok((name_or_alias.to_string(), profile.clone()));
+        }
+        if let Some((name, profile)) = file
+            .profiles
+            .iter()
+            .find(|(_, p)| p.alias.as_deref() == Some(name_or_alias))
+        {
+            return Ok((name.clone(), profile.clone()));
+        }
+        if let Ok((user, host)) = parse_user_host(name_or_alias) {
+            let mut matches = file
+                .profiles
+                .iter()
+                .filter(|(_, p)| p.user == user && p.host == host);
+            match (matches.next(), matches.next()) {
+                (Some((name, profile)), None) => return Ok((name.clone(), profile.clone())),
+                (Some(_), Some(_)) => {
+                    return Err(AppError::Validation(format!(
+                        "Several remotes target '{}'. Use the profile name or alias.",
+                        name_or_alias
+                    )))
+                }
+                _ => {}
+            }
+        }
+
+        Err(AppError::Validation(format!(
+            "Remote profile '{}' not found. Use 'remote list' to inspect configured remotes.",
+            name_or_alias
+        )))
     }
 
     pub fn exists(&self, name: &str) -> Result<bool> {
@@ -138,17 +227,92 @@ impl RemoteProfileStore {
         validate_profile_name(name)?;
 
         let mut file = self.load_file()?;
+        let others = || {
+            file.profiles
+                .iter()
+                .filter(|(other, _)| other.as_str() != name)
+        };
+
+        if let Some((owner, _)) = others().find(|(_, p)| p.alias.as_deref() == Some(name)) {
+            return Err(AppError::Validation(format!(
+                "'{}' is already the alias of remote '{}'",
+                name, owner
+            )));
+        }
+        if let Some(alias) = &profile.alias {
+            validate_profile_name(alias)?;
+            if alias == name {
+                return Err(AppError::Validation(
+                    "Alias must differ from the profile name".to_string(),
+                ));
+            }
+            if file.profiles.contains_key(alias) {
+                return Err(AppError::Validation(format!(
+                    "Alias '{}' collides with an existing remote name",
+                    alias
+                )));
+            }
+            if let Some((owner, _)) = others().find(|(_, p)| p.alias.as_ref() == Some(alias)) {
+                return Err(AppError::Validation(format!(
+                    "Alias '{}' is already used by remote '{}'",
+                    alias, owner
+                )));
+            }
+        }
+
         file.profiles.insert(name.to_string(), profile);
         self.save_file(&file)
     }
 
-    pub fn remove(&self, name: &str) -> Result<()> {
+    /// Removes a profile (by name or alias) and its sync history; returns the
+    /// canonical name
+    pub fn remove(&self, name_or_alias: &str) -> Result<String> {
+        let (name, _) = self.resolve(name_or_alias)?;
         let mut file = self.load_file()?;
-        if file.profiles.remove(name).is_none() {
-            return Err(AppError::Validation(format!(
-                "Remote profile '{}' not found",
-                name
-            )));
+        file.profiles.remove(&name);
+        file.history.remove(&name);
+        self.save_file(&file)?;
+        Ok(name)
+    }
+
+    pub fn history(&self, name: &str) -> Result<SyncHistory> {
+        let file = self.load_file()?;
+        Ok(file.history.get(name).cloned().unwrap_or_default())
+    }
+
+    /// Records the outcome of a push/pull for `name`; errors keep only their
+    /// first line
+    pub fn record_sync(
+        &self,
+        name: &str,
+        direction: SyncDirection,
+        outcome: std::result::Result<(), &str>,
+    ) -> Result<()> {
+        let mut file = self.load_file()?;
+        let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        let history = file.history.entry(name.to_string()).or_default();
+
+        match outcome {
+            Ok(()) => {
+                match direction {
+                    SyncDirection::Push => history.last_push = Some(now),
+                    SyncDirection::Pull => history.last_pull = Some(now),
+                }
+                if history
+                    .last_error
+                    .as_ref()
+                    .is_some_and(|e| e.direction == direction.as_str())
+                {
+                    history.last_error = None;
+                }
+            }
+            Err(message) => {
+                history.last_error = Some(SyncFailure {
+                    at: now,
+                    direction: direction.as_str().to_string(),
+                    message: message.lines().next().unwrap_or("").trim().to_string(),
+                });
+            }
         }
         self.save_file(&file)
     }
@@ -195,6 +359,12 @@ pub fn validate_profile_name(name: &str) -> Result<()> {
             "Remote profile name may only contain a-z, A-Z, 0-9, '-' and '_'".to_string(),
         ));
     }
+    // A leading '-' would read as a flag on the command line
+    if !name.starts_with(|c: char| c.is_ascii_alphanumeric()) {
+        return Err(AppError::Validation(
+            "Remote profile name must start with a letter or digit".to_string(),
+        ));
+    }
     Ok(())
 }
 
@@ -316,4 +486,105 @@ mod tests {
         let _ = std::fs::remove_file(file_path);
         let _ = std::fs::remove_dir_all(temp_dir);
     }
+
+    #[test]
+    fn validate_profile_name_rejects_leading_dash() {
+        assert!(validate_profile_name("-prod").is_err());
+        assert!(validate_profile_name("_prod").is_err());
+        assert!(validate_profile_name("prod-1").is_ok());
+    }
+
+    #[test]
+    fn aliases_resolve_and_must_be_unique() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "rush-sync-alias-{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos()
+        ));
+        let store = RemoteProfileStore::with_path(temp_dir.join("remotes.toml"));
+        let profile = |host: &str| {
+            RemoteProfile::new("deploy".into(), host.into(), "/opt".into(), 22, None)
+                .expect("profile")
+        };
+
+        store
+            .upsert(
+                "production",
+                profile("prod.example.com").with_alias(Some("p".into())),
+            )
+            .expect("save");
+        store
+            .upsert("staging", profile("stage.example.com"))
+            .expect("save");
+
+        assert_eq!(store.resolve("p").expect("alias").0, "production");
+        assert_eq!(
+            store.resolve("deploy@stage.example.com").expect("target").0,
+            "staging"
+        );
+        assert!(store.resolve("nope").is_err());
+
+        // Collisions with names and aliases of other profiles
+        let with_alias = |alias: &str| profile("stage.example.com").with_alias(Some(alias.into()));
+        assert!(store.upsert("staging", with_alias("p")).is_err());
+        assert!(store.upsert("staging", with_alias("production")).is_err());
+        assert!(store.upsert("staging", with_alias("staging")).is_err());
+        assert!(store.upsert("p", profile("other.example.com")).is_err());
+        assert!(store.upsert("staging", with_alias("s")).is_ok());
+
+        assert_eq!(store.remove("s").expect("remove"), "staging");
+        assert!(!store.exists("staging").expect("exists"));
+
+        let _ = std::fs::remove_dir_all(temp_dir);
+    }
+
+    #[test]
+    fn sync_history_roundtrip() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "rush-sync-history-{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos()
+        ));
+        let store = RemoteProfileStore::with_path(temp_dir.join("remotes.toml"));
+        let profile =
+            RemoteProfile::new("u".into(), "h".into(), "/opt".into(), 22, None).expect("profile");
+        store.upsert("prod", profile).expect("save");
+        assert_eq!(
+            store.history("prod").expect("history"),
+            SyncHistory::default()
+        );
+
+        store
+            .record_sync("prod", SyncDirection::Push, Err("rsync failed\ndetails"))
+            .expect("record");
+        let history = store.history("prod").expect("history");
+        assert!(history.last_success().is_none());
+        let error = history.last_error.expect("error");
+        assert_eq!(error.direction, "push");
+        assert_eq!(error.message, "rsync failed");
+
+        // A failed push survives a successful pull, but not a successful push
+        store
+            .record_sync("prod", SyncDirection::Pull, Ok(()))
+            .expect("record");
+        assert!(store.history("prod").expect("history").last_error.is_some());
+        store
+            .record_sync("prod", SyncDirection::Push, Ok(()))
+            .expect("record");
+        let history = store.history("prod").expect("history");
+        assert!(history.last_error.is_none());
+        assert!(history.last_push.is_some() && history.last_success().is_some());
+
+        store.remove("prod").expect("remove");
+        assert_eq!(
+            store.history("prod").expect("history"),
+            SyncHistory::default()
+        );
+
+        let _ = std::fs::remove_dir_all(temp_dir);
+    }
 }
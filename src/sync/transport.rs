@@ -1,10 +1,34 @@
+use crate::core::helpers::{collect_files, format_bytes};
 use crate::core::prelude::*;
 use crate::sync::profiles::RemoteProfile;
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
+use std::sync::Mutex;
 
 const DEFAULT_EXCLUDES: &[&str] = &[".git", ".rss", "target", ".DS_Store"];
 
+/// Idle seconds after which OpenSSH tears down a shared master connection
+pub const SSH_CONTROL_PERSIST_SECS: u64 = 300;
+
+/// Shared SSH connections opened this session, keyed by `user@host:port`
+static SSH_SESSIONS: Mutex<BTreeMap<String, SshSession>> = Mutex::new(BTreeMap::new());
+
+#[derive(Debug, Clone)]
+struct SshSession {
+    target: String,
+    port: u16,
+    control_path: PathBuf,
+    last_used: Instant,
+}
+
+/// A cached SSH connection as reported by `sync status`/`sync disconnect`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedSession {
+    pub key: String,
+    pub idle: Duration,
+}
+
 #[derive(Debug)]
 struct ProcessResult {
     stdout: String,
@@ -54,12 +78,57 @@ pub fn run_remote_command(profile: &RemoteProfile, command: &str) -> Result<Stri
     Ok(format_process_output("Remote command executed", &output))
 }
 
+/// Files a sync would touch, as reported by a dry run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncPreview {
+    /// Relative path and size of every file that would be transferred
+    pub transfers: Vec<(String, u64)>,
+    /// Paths that would be removed on the destination (`--delete` only)
+    pub deletions: Vec<String>,
+}
+
+impl SyncPreview {
+    pub fn total_bytes(&self) -> u64 {
+        self.transfers.iter().map(|(_, size)| size).sum()
+    }
+
+    pub fn render(&self) -> String {
+        if self.transfers.is_empty() && self.deletions.is_empty() {
+            return "Nothing to do, destination is up to date".to_string();
+        }
+
+        let mut out = format!(
+            "Would transfer {} file{} ({})",
+            self.transfers.len(),
+            if self.transfers.len() == 1 { "" } else { "s" },
+            format_bytes(self.total_bytes())
+        );
+        for (path, size) in &self.transfers {
+            out.push_str(&format!("\n  + {} ({})", path, format_bytes(*size)));
+        }
+        if !self.deletions.is_empty() {
+            out.push_str(&format!(
+                "\nWould delete {} file{}",
+                self.deletions.len(),
+                if self.deletions.len() == 1 { "" } else { "s" }
+            ));
+            for path in &self.deletions {
+                out.push_str(&format!("\n  - {}", path));
+            }
+        }
+        out
+    }
+}
+
 pub fn sync_push(
     profile: &RemoteProfile,
     local_path: &Path,
     delete: bool,
     dry_run: bool,
 ) -> Result<String> {
+    if dry_run {
+        return Ok(preview_push(profile, local_path, delete)?.render());
+    }
     if !local_path.exists() {
         return Err(AppError::Validation(format!(
             "Local path '{}' does not exist",
@@ -70,13 +139,8 @@ pub fn sync_push(
     ensure_remote_directory(profile)?;
 
     if tool_available("rsync", "--version") {
-        sync_push_rsync(profile, local_path, delete, dry_run)
+        sync_push_rsync(profile, local_path, delete)
     } else {
-        if dry_run {
-            return Err(AppError::Validation(
-                "Dry-run is only supported with rsync (rsync not found in PATH)".to_string(),
-            ));
-        }
         sync_push_scp(profile, local_path)
     }
 }
@@ -87,20 +151,65 @@ pub fn sync_pull(
     delete: bool,
     dry_run: bool,
 ) -> Result<String> {
+    if dry_run {
+        return Ok(preview_pull(profile, local_path, delete)?.render());
+    }
     ensure_local_directory(local_path)?;
 
     if tool_available("rsync", "--version") {
-        sync_pull_rsync(profile, local_path, delete, dry_run)
+        sync_pull_rsync(profile, local_path, delete)
     } else {
-        if dry_run {
-            return Err(AppError::Validation(
-                "Dry-run is only supported with rsync (rsync not found in PATH)".to_string(),
-            ));
-        }
         sync_pull_scp(profile, local_path)
     }
 }
 
+/// Files `sync_push` would transfer/delete, without changing anything.
+/// Without rsync the scp fallback copies everything, so the preview is the
+/// local file list.
+pub fn preview_push(
+    profile: &RemoteProfile,
+    local_path: &Path,
+    delete: bool,
+) -> Result<SyncPreview> {
+    if !local_path.exists() {
+        return Err(AppError::Validation(format!(
+            "Local path '{}' does not exist",
+            local_path.display()
+        )));
+    }
+
+    if tool_available("rsync", "--version") {
+        let mut args = rsync_push_args(profile, local_path, delete);
+        args.splice(0..0, rsync_preview_args());
+        let output = run_process("rsync", &args, false)?;
+        Ok(parse_rsync_itemized(&output.stdout))
+    } else {
+        Ok(SyncPreview {
+            transfers: local_file_list(local_path),
+            deletions: Vec::new(),
+        })
+    }
+}
+
+/// Files `sync_pull` would transfer/delete, without changing anything
+/// (needs rsync)
+pub fn preview_pull(
+    profile: &RemoteProfile,
+    local_path: &Path,
+    delete: bool,
+) -> Result<SyncPreview> {
+    if !tool_available("rsync", "--version") {
+        return Err(AppError::Validation(
+            "Dry-run for pull is only supported with rsync (rsync not found in PATH)".to_string(),
+        ));
+    }
+
+    let mut args = rsync_pull_args(profile, local_path, delete);
+    args.splice(0..0, rsync_preview_args());
+    let output = run_process("rsync", &args, false)?;
+    Ok(parse_rsync_itemized(&output.stdout))
+}
+
 pub fn restart_service(profile: &RemoteProfile, service_name: &str) -> Result<String> {
     let name = service_name.trim();
     if name.is_empty() {
@@ -144,17 +253,8 @@ pub fn git_pull(profile: &RemoteProfile, branch: Option<&str>) -> Result<String>
     run_remote_command(profile, &cmd)
 }
 
-fn sync_push_rsync(
-    profile: &RemoteProfile,
-    local_path: &Path,
-    delete: bool,
-    dry_run: bool,
-) -> Result<String> {
-    ensure_tool_available("rsync", "--version")?;
-
-    let mut args = Vec::<String>::new();
-    args.push("-az".to_string());
-    args.push("--human-readable".to_string());
+fn rsync_push_args(profile: &RemoteProfile, local_path: &Path, delete: bool) -> Vec<String> {
+    let mut args = vec!["-az".to_string(), "--human-readable".to_string()];
 
     for exclude in DEFAULT_EXCLUDES {
         args.push("--exclude".to_string());
@@ -165,10 +265,6 @@ fn sync_push_rsync(
         args.push("--delete".to_string());
     }
 
-    if dry_run {
-        args.push("--dry-run".to_string());
-    }
-
     args.push("-e".to_string());
     args.push(rsync_ssh_transport(profile));
     args.push(rsync_source_arg(local_path));
@@ -177,39 +273,16 @@ fn sync_push_rsync(
         profile.ssh_target(),
         escape_remote_path(&profile.remote_path)
     ));
-
-    let output = run_process("rsync", &args, false)?;
-    Ok(format!(
-        "{}\n{}",
-        if dry_run {
-            "Sync push dry-run via rsync"
-        } else {
-            "Sync push completed via rsync"
-        },
-        format_process_output("rsync", &output)
-    ))
+    args
 }
 
-fn sync_pull_rsync(
-    profile: &RemoteProfile,
-    local_path: &Path,
-    delete: bool,
-    dry_run: bool,
-) -> Result<String> {
-    ensure_tool_available("rsync", "--version")?;
-
-    let mut args = Vec::<String>::new();
-    args.push("-az".to_string());
-    args.push("--human-readable".to_string());
+fn rsync_pull_args(profile: &RemoteProfile, local_path: &Path, delete: bool) -> Vec<String> {
+    let mut args = vec!["-az".to_string(), "--human-readable".to_string()];
 
     if delete {
         args.push("--delete".to_string());
     }
 
-    if dry_run {
-        args.push("--dry-run".to_string());
-    }
-
     args.push("-e".to_string());
     args.push(rsync_ssh_transport(profile));
     args.push(format!(
@@ -218,15 +291,87 @@ fn sync_pull_rsync(
         escape_remote_path(&profile.remote_path)
     ));
     args.push(rsync_source_arg(local_path));
+    args
+}
+
+/// Dry run with one itemized line per change: `<flags> <size> <path>`
+fn rsync_preview_args() -> Vec<String> {
+    vec!["--dry-run".to_string(), "--out-format=%i %l %n".to_string()]
+}
+
+/// Reads `--out-format=%i %l %n` lines. Sent/received files (`<f...`/`>f...`)
+/// are transfers, `*deleting` lines deletions; directories and attribute-only
+/// changes are skipped.
+fn parse_rsync_itemized(output: &str) -> SyncPreview {
+    let mut preview = SyncPreview::default();
+
+    for line in output.lines() {
+        let Some((flags, rest)) = line.split_once(' ') else {
+            continue;
+        };
+        let rest = rest.trim_start();
+        let (size, path) = match rest.split_once(' ') {
+            Some((size, path)) if size.parse::<u64>().is_ok() => {
+                (size.parse::<u64>().unwrap_or(0), path)
+            }
+            _ => (0, rest),
+        };
+        if path.is_empty() {
+            continue;
+        }
+
+        if flags == "*deleting" {
+            preview.deletions.push(path.to_string());
+        } else if flags.starts_with(['<', '>']) && flags.chars().nth(1) == Some('f') {
+            preview.transfers.push((path.to_string(), size));
+        }
+    }
+    preview
+}
+
+/// Files below `local_path` relative to it, minus `DEFAULT_EXCLUDES`
+fn local_file_list(local_path: &Path) -> Vec<(String, u64)> {
+    if local_path.is_file() {
+        let size = local_path.metadata().map(|m| m.len()).unwrap_or(0);
+        let name = local_path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        return vec![(name, size)];
+    }
+
+    let mut files: Vec<(String, u64)> = collect_files(local_path)
+        .into_iter()
+        .filter_map(|(path, size)| {
+            let relative = path.strip_prefix(local_path).ok()?;
+            let excluded = relative
+                .components()
+                .any(|c| DEFAULT_EXCLUDES.contains(&c.as_os_str().to_string_lossy().as_ref()));
+            (!excluded).then(|| (relative.to_string_lossy().replace('\\', "/"), size))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+fn sync_push_rsync(profile: &RemoteProfile, local_path: &Path, delete: bool) -> Result<String> {
+    ensure_tool_available("rsync", "--version")?;
 
+    let args = rsync_push_args(profile, local_path, delete);
     let output = run_process("rsync", &args, false)?;
     Ok(format!(
-        "{}\n{}",
-        if dry_run {
-            "Sync pull dry-run via rsync"
-        } else {
-            "Sync pull completed via rsync"
-        },
+        "Sync push completed via rsync\n{}",
+        format_process_output("rsync", &output)
+    ))
+}
+
+fn sync_pull_rsync(profile: &RemoteProfile, local_path: &Path, delete: bool) -> Result<String> {
+    ensure_tool_available("rsync", "--version")?;
+
+    let args = rsync_pull_args(profile, local_path, delete);
+    let output = run_process("rsync", &args, false)?;
+    Ok(format!(
+        "Sync pull completed via rsync\n{}",
         format_process_output("rsync", &output)
     ))
 }
@@ -297,6 +442,7 @@ fn base_connection_args(profile: &RemoteProfile, port_flag: &str) -> Vec<String>
     args.push("BatchMode=yes".to_string());
     args.push("-o".to_string());
     args.push("ConnectTimeout=30".to_string());
+    args.extend(multiplex_args(profile));
     args
 }
 
@@ -316,9 +462,194 @@ fn rsync_ssh_transport(profile: &RemoteProfile) -> String {
             shell_quote(&identity.display().to_string())
         ));
     }
+    for arg in multiplex_args(profile) {
+        transport.push(' ');
+        transport.push_str(&shell_quote(&arg));
+    }
     transport
 }
 
+fn session_key(profile: &RemoteProfile) -> String {
+    format!("{}:{}", profile.ssh_target(), profile.port)
+}
+
+/// Longest control socket path handed to ssh: `sockaddr_un` holds 104 bytes
+/// on macOS and ssh binds under a name with a 17-byte temporary suffix first
+#[cfg(unix)]
+const MAX_CONTROL_PATH: usize = 86;
+
+/// Control socket of the shared connection for `profile`, in the private
+/// `control_dir`. `None` disables connection sharing.
+#[cfg(unix)]
+fn control_path(profile: &RemoteProfile) -> Option<PathBuf> {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    session_key(profile).hash(&mut hasher);
+    profile.identity_file.hash(&mut hasher);
+    let path = control_dir()?.join(format!("rss-ssh-{:016x}", hasher.finish()));
+
+    if path.as_os_str().len() > MAX_CONTROL_PATH {
+        log::warn!(
+            "SSH control socket path too long, not sharing connections: {}",
+            path.display()
+        );
+        return None;
+    }
+    Some(path)
+}
+
+/// Per-user directory for control sockets: `$XDG_RUNTIME_DIR/rush-sync`, else
+/// `.rss/ssh` in the data dir. Anyone who can create the socket first could
+/// take over the session, so it is only used if it passes `check_private_dir`.
+#[cfg(unix)]
+fn control_dir() -> Option<PathBuf> {
+    let dir = match std::env::var_os("XDG_RUNTIME_DIR").filter(|v| !v.is_empty()) {
+        Some(runtime) => PathBuf::from(runtime).join("rush-sync"),
+        None => crate::core::paths::base_dir().ok()?.join(".rss/ssh"),
+    };
+    match create_private_dir(&dir).and_then(|()| check_private_dir(&dir)) {
+        Ok(()) => Some(dir),
+        Err(e) => {
+            log::warn!("Not sharing SSH connections: {}", e);
+            None
+        }
+    }
+}
+
+/// Creates `dir` with mode 0700 unless it exists already
+#[cfg(unix)]
+fn create_private_dir(dir: &Path) -> Result<()> {
+    use std::os::unix::fs::DirBuilderExt;
+    if let Some(parent) = dir.parent() {
+        std::fs::create_dir_all(parent).map_err(AppError::Io)?;
+    }
+    match std::fs::DirBuilder::new().mode(0o700).create(dir) {
+        Err(e) if e.kind() != std::io::ErrorKind::AlreadyExists => Err(AppError::Io(e)),
+        _ => Ok(()),
+    }
+}
+
+/// A real directory (no symlink), owned by the current user, closed to
+/// everyone else
+#[cfg(unix)]
+fn check_private_dir(dir: &Path) -> Result<()> {
+    use std::os::unix::fs::MetadataExt;
+    let meta = std::fs::symlink_metadata(dir).map_err(AppError::Io)?;
+    // SAFETY: geteuid has no preconditions and cannot fail
+    let uid = unsafe { libc::geteuid() };
+
+    if !meta.file_type().is_dir() {
+        Err(AppError::Validation(format!(
+            "{} is not a directory",
+            dir.display()
+        )))
+    } else if meta.uid() != uid {
+        Err(AppError::Validation(format!(
+            "{} is owned by uid {}, not {}",
+            dir.display(),
+            meta.uid(),
+            uid
+        )))
+    } else if meta.mode() & 0o077 != 0 {
+        Err(AppError::Validation(format!(
+            "{} is accessible by other users (mode {:o})",
+            dir.display(),
+            meta.mode() & 0o777
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// OpenSSH connection sharing: the first call for a profile opens a master
+/// connection that later ssh/scp/rsync calls reuse until it has been idle for
+/// `SSH_CONTROL_PERSIST_SECS`.
+#[cfg(unix)]
+fn multiplex_args(profile: &RemoteProfile) -> Vec<String> {
+    let Some(control_path) = control_path(profile) else {
+        return Vec::new();
+    };
+    if let Ok(mut sessions) = SSH_SESSIONS.lock() {
+        sessions.insert(
+            session_key(profile),
+            SshSession {
+                target: profile.ssh_target(),
+                port: profile.port,
+                control_path: control_path.clone(),
+                last_used: Instant::now(),
+            },
+        );
+    }
+
+    vec![
+        "-o".to_string(),
+        "ControlMaster=auto".to_string(),
+        "-o".to_string(),
+        format!("ControlPath={}", control_path.display()),
+        "-o".to_string(),
+        format!("ControlPersist={}", SSH_CONTROL_PERSIST_SECS),
+    ]
+}
+
+/// Connection sharing needs Unix domain sockets
+#[cfg(not(unix))]
+fn multiplex_args(_profile: &RemoteProfile) -> Vec<String> {
+    Vec::new()
+}
+
+/// Shared connections that are still open (socket present, not idle past
+/// `SSH_CONTROL_PERSIST_SECS`). Stale entries are dropped.
+pub fn cached_sessions() -> Vec<CachedSession> {
+    let persist = Duration::from_secs(SSH_CONTROL_PERSIST_SECS);
+    let Ok(mut sessions) = SSH_SESSIONS.lock() else {
+        return Vec::new();
+    };
+    sessions.retain(|_, s| s.control_path.exists() && s.last_used.elapsed() < persist);
+    sessions
+        .iter()
+        .map(|(key, s)| CachedSession {
+            key: key.clone(),
+            idle: s.last_used.elapsed(),
+        })
+        .collect()
+}
+
+/// Closes shared connections: the one for `profile`, or all of them. Returns
+/// the `user@host:port` keys that had an open connection.
+pub fn disconnect(profile: Option<&RemoteProfile>) -> Vec<String> {
+    let drained: Vec<(String, SshSession)> = match SSH_SESSIONS.lock() {
+        Ok(mut sessions) => match profile {
+            Some(profile) => sessions
+                .remove_entry(&session_key(profile))
+                .into_iter()
+                .collect(),
+            None => std::mem::take(&mut *sessions).into_iter().collect(),
+        },
+        Err(_) => Vec::new(),
+    };
+
+    drained
+        .into_iter()
+        .filter(|(_, session)| session.control_path.exists())
+        .map(|(key, session)| {
+            let args = vec![
+                "-O".to_string(),
+                "exit".to_string(),
+                "-o".to_string(),
+                format!("ControlPath={}", session.control_path.display()),
+                "-p".to_string(),
+                session.port.to_string(),
+                session.target.clone(),
+            ];
+            if let Err(e) = run_process("ssh", &args, true) {
+                log::warn!("Closing SSH connection {} failed: {}", key, e);
+            }
+            let _ = std::fs::remove_file(&session.control_path);
+            key
+        })
+        .collect()
+}
+
 fn expanded_identity(profile: &RemoteProfile) -> Option<PathBuf> {
     profile
         .identity_file
@@ -436,6 +767,64 @@ fn format_process_output(prefix: &str, result: &ProcessResult) -> String {
 mod tests {
     use super::*;
 
+    #[cfg(unix)]
+    #[test]
+    fn multiplex_args_share_one_socket_per_profile() {
+        let profile = RemoteProfile::new(
+            "deploy".into(),
+            "example.com".into(),
+            "/srv/www".into(),
+            2222,
+            None,
+        )
+        .unwrap();
+        let other = RemoteProfile::new(
+            "deploy".into(),
+            "other.example.com".into(),
+            "/srv/www".into(),
+            22,
+            None,
+        )
+        .unwrap();
+
+        let path = control_path(&profile).unwrap();
+        let args = ssh_base_args(&profile);
+        let control = format!("ControlPath={}", path.display());
+        assert!(args.contains(&control));
+        assert!(args.contains(&"ControlMaster=auto".to_string()));
+        assert!(rsync_ssh_transport(&profile).contains(&control));
+        assert_ne!(Some(path.clone()), control_path(&other));
+        assert!(path.as_os_str().len() <= MAX_CONTROL_PATH);
+        assert!(check_private_dir(path.parent().unwrap()).is_ok());
+
+        // No master was started, so there is nothing to close
+        assert!(disconnect(Some(&profile)).is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn control_dir_must_be_private() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = std::env::temp_dir().join(format!("rss-ssh-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        create_private_dir(&dir).unwrap();
+        assert!(check_private_dir(&dir).is_ok());
+
+        std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o755)).unwrap();
+        assert!(check_private_dir(&dir).is_err());
+
+        // A symlink to a private dir is still refused
+        std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o700)).unwrap();
+        let link = dir.with_extension("link");
+        let _ = std::fs::remove_file(&link);
+        std::os::unix::fs::symlink(&dir, &link).unwrap();
+        assert!(check_private_dir(&link).is_err());
+
+        let _ = std::fs::remove_file(&link);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn shell_quote_simple() {
         assert_eq!(shell_quote("hello"), "'hello'");
@@ -489,6 +878,55 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[test]
+    fn parse_rsync_itemized_lists_files_and_deletions() {
+        let output = "cd+++++++++ 4096 assets/\n\
+                      <f+++++++++ 1200 assets/app.js\n\
+                      <f.st...... 512 index.html\n\
+                      .f...p..... 10 unchanged.txt\n\
+                      *deleting   old file.css\n";
+        let preview = parse_rsync_itemized(output);
+        assert_eq!(
+            preview.transfers,
+            vec![
+                ("assets/app.js".to_string(), 1200),
+                ("index.html".to_string(), 512)
+            ]
+        );
+        assert_eq!(preview.deletions, vec!["old file.css".to_string()]);
+        assert_eq!(preview.total_bytes(), 1712);
+
+        let rendered = preview.render();
+        assert!(rendered.contains("Would transfer 2 files"));
+        assert!(rendered.contains("- old file.css"));
+    }
+
+    #[test]
+    fn local_file_list_skips_default_excludes() {
+        let root = std::env::temp_dir().join(format!(
+            "rush-sync-preview-{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos()
+        ));
+        std::fs::create_dir_all(root.join("css")).unwrap();
+        std::fs::create_dir_all(root.join(".git")).unwrap();
+        std::fs::write(root.join("index.html"), "hello").unwrap();
+        std::fs::write(root.join("css/site.css"), "body{}").unwrap();
+        std::fs::write(root.join(".git/HEAD"), "ref").unwrap();
+
+        assert_eq!(
+            local_file_list(&root),
+            vec![
+                ("css/site.css".to_string(), 6),
+                ("index.html".to_string(), 5)
+            ]
+        );
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
     #[test]
     fn rsync_source_arg_dir_trailing_slash() {
         let tmp = std::env::temp_dir();
@@ -93,6 +93,11 @@ static DISPLAY_COLOR_MAP: LazyLock<HashMap<&'static str, Color>> = LazyLock::new
     map.insert("Started", Color::Green);
     map.insert("STARTED", Color::Green);
 
+    // ACME / TLS status
+    map.insert("SUCCESS", Color::Green);
+    map.insert("PROVISIONING", Color::Yellow);
+    map.insert("IDLE", Color::Yellow);
+
     map
 });
 
@@ -132,15 +137,48 @@ impl AppColor {
         Self::from_category(&level.to_string())
     }
 
+    /// Accepts named colors, `#rrggbb` and `rgb(r,g,b)` (truecolor).
     pub fn from_string(color_str: &str) -> crate::core::error::Result<Self> {
         let normalized = color_str.trim().to_lowercase();
         let color = COLOR_MAP
             .get(normalized.as_str())
             .copied()
+            .or_else(|| Self::parse_truecolor(&normalized))
             .ok_or_else(|| AppError::Validation(format!("Invalid color: {}", color_str)))?;
         Ok(Self(color))
     }
 
+    fn parse_truecolor(value: &str) -> Option<Color> {
+        if let Some(hex) = value.strip_prefix('#') {
+            if hex.len() != 6 || !hex.is_ascii() {
+                return None;
+            }
+            let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+            let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+            let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+            return Some(Color::Rgb(r, g, b));
+        }
+
+        let inner = value.strip_prefix("rgb(")?.strip_suffix(')')?;
+        let parts: Vec<u8> = inner
+            .split(',')
+            .map(|p| p.trim().parse::<u8>())
+            .collect::<std::result::Result<_, _>>()
+            .ok()?;
+        match parts.as_slice() {
+            [r, g, b] => Some(Color::Rgb(*r, *g, *b)),
+            _ => None,
+        }
+    }
+
+    /// Exact RGB bytes for truecolor values, `None` for named colors.
+    pub fn to_rgb(&self) -> Option<(u8, u8, u8)> {
+        match self.0 {
+            Color::Rgb(r, g, b) => Some((r, g, b)),
+            _ => None,
+        }
+    }
+
     /// Returns the resolved color along with the lookup duration for profiling.
     pub fn from_display_text_with_timing(display_text: &str) -> (Self, std::time::Duration) {
         let start = std::time::Instant::now();
@@ -218,3 +256,33 @@ impl Default for AppColor {
         Self(Color::Gray)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_named_colors_unchanged() {
+        assert_eq!(
+            Color::from(AppColor::from_string("LightBlue").unwrap()),
+            Color::LightBlue
+        );
+        assert_eq!(AppColor::from_string("white").unwrap().to_rgb(), None);
+    }
+
+    #[test]
+    fn test_hex_and_rgb_colors() {
+        let hex = AppColor::from_string("#6c63ff").unwrap();
+        assert_eq!(hex.to_rgb(), Some((0x6c, 0x63, 0xff)));
+
+        let rgb = AppColor::from_string("rgb(108, 99, 255)").unwrap();
+        assert_eq!(rgb, hex);
+    }
+
+    #[test]
+    fn test_invalid_truecolor_rejected() {
+        for value in ["#6c63f", "#gggggg", "rgb(1,2)", "rgb(256,0,0)", "#ffä000"] {
+            assert!(AppColor::from_string(value).is_err(), "{}", value);
+        }
+    }
+}
@@ -1,12 +1,15 @@
 // src/ui/screen.rs
-use crate::commands::{history::HistoryKeyboardHandler, lang::LanguageService, theme::ThemeSystem};
+use crate::commands::{
+    find::FindAction, history::HistoryKeyboardHandler, lang::LanguageService, theme::ThemeSystem,
+};
 use crate::core::prelude::*;
 use crate::input::{
     keyboard::{KeyAction, KeyboardManager},
     state::InputState,
-    AppEvent, EventHandler,
+    AppEvent, EventHandler, EventHandlerConfig,
 };
 use crate::output::display::MessageDisplay;
+use crate::setup::config_watcher::{changed_settings, ConfigWatcher};
 use crate::ui::{
     color::AppColor,
     terminal::TerminalManager,
@@ -18,6 +21,7 @@ use ratatui::{backend::CrosstermBackend, Terminal};
 use std::{
     io::{self, Stdout},
     sync::OnceLock,
+    time::Instant,
 };
 
 #[derive(Clone)]
@@ -29,6 +33,17 @@ static TERMINAL_INFO: OnceLock<TerminalInfo> = OnceLock::new();
 
 pub type TerminalBackend = Terminal<CrosstermBackend<Stdout>>;
 
+/// Columns per Shift+Left/Right press in unwrapped mode
+const HORIZONTAL_SCROLL_STEP: usize = 8;
+
+/// Theme in effect before `theme preview --live`, restored on timeout or keypress
+struct ThemePreview {
+    theme: crate::core::config::Theme,
+    theme_name: String,
+    typewriter_delay: Duration,
+    until: Instant,
+}
+
 pub struct ScreenManager {
     terminal: TerminalBackend,
     pub message_display: MessageDisplay,
@@ -38,12 +53,20 @@ pub struct ScreenManager {
     events: EventHandler,
     keyboard_manager: KeyboardManager,
     waiting_for_restart_confirmation: bool,
+    // Global typewriter delay from [general], restored when a theme has no override
+    base_typewriter_delay: Duration,
     progress_rx: tokio::sync::mpsc::UnboundedReceiver<String>,
+    // Reloads rush.toml on save; None if the watch could not be set up
+    config_watcher: Option<ConfigWatcher>,
+    theme_preview: Option<ThemePreview>,
 }
 
 impl ScreenManager {
     pub async fn new(config: &Config) -> Result<Self> {
+        crate::commands::session::command::mark_session_start();
+
         let mut terminal_mgr = TerminalManager::new().await?;
+        terminal_mgr.set_mouse_capture(config.mouse_capture)?;
         terminal_mgr.setup().await?;
 
         let backend = CrosstermBackend::new(io::stdout());
@@ -53,23 +76,64 @@ impl ScreenManager {
         // Initialize progress channel for non-blocking bulk commands
         let progress_rx = crate::input::init_progress_channel();
 
+        let config_watcher = config
+            .path()
+            .and_then(|path| match ConfigWatcher::start(path) {
+                Ok(watcher) => Some(watcher),
+                Err(e) => {
+                    log::warn!("Config hot-reload disabled: {}", e);
+                    None
+                }
+            });
+
         let mut screen_manager = Self {
             terminal,
             terminal_mgr,
             message_display: MessageDisplay::new(config, size.width, size.height),
             input_state: InputState::new(config),
             config: config.clone(),
-            events: EventHandler::new(config.poll_rate),
-            keyboard_manager: KeyboardManager::new(),
+            events: EventHandler::new(EventHandlerConfig::from(config)),
+            keyboard_manager: KeyboardManager::from_config(config),
             waiting_for_restart_confirmation: false,
+            base_typewriter_delay: config.typewriter_delay,
             progress_rx,
+            config_watcher,
+            theme_preview: None,
         };
 
-        let version = crate::core::constants::VERSION;
-        let startup_msg = get_command_translation("system.startup.version", &[version]);
-        screen_manager
-            .message_display
-            .add_message_instant(startup_msg);
+        // Restore the typewriter speed of the persisted theme
+        if let Ok(system) = ThemeSystem::load() {
+            if let Some(def) = system.get_theme(&config.current_theme_name) {
+                screen_manager.apply_theme_typewriter_delay(def);
+                screen_manager
+                    .message_display
+                    .update_config(&screen_manager.config);
+            }
+        }
+
+        if config.show_banner {
+            let stats = BannerStats::collect().await;
+            let banner = match &config.banner {
+                Some(template) => render_banner(template, config, &stats),
+                None => format!(
+                    "{}\n{}",
+                    get_command_translation(
+                        "system.startup.version",
+                        &[crate::core::constants::VERSION],
+                    ),
+                    get_translation(
+                        "system.startup.summary",
+                        &[
+                            &config.current_theme_name,
+                            &config.language,
+                            &stats.servers.to_string(),
+                            &stats.memory(),
+                        ],
+                    )
+                ),
+            };
+            screen_manager.message_display.add_message_instant(banner);
+        }
 
         Ok(screen_manager)
     }
@@ -106,6 +170,9 @@ impl ScreenManager {
                         self.message_display.add_message_instant(msg);
                     }
                 }
+                Some(()) = next_config_change(&mut self.config_watcher) => {
+                    self.reload_config().await;
+                }
             }
             self.render().await?;
         };
@@ -114,6 +181,12 @@ impl ScreenManager {
     }
 
     async fn handle_input(&mut self, key: KeyEvent) -> Result<bool> {
+        // Any key ends a live theme preview; the key itself is swallowed
+        if self.theme_preview.is_some() {
+            self.end_theme_preview();
+            return Ok(false);
+        }
+
         // History handling
         if HistoryKeyboardHandler::get_history_action(&key).is_some() {
             if let Some(input) = self.input_state.handle_input(key) {
@@ -142,10 +215,41 @@ impl ScreenManager {
                     .handle_scroll(ScrollDirection::PageDown, 0);
                 Ok(false)
             }
+            KeyAction::ScrollToTop => {
+                self.message_display
+                    .handle_scroll(ScrollDirection::ToTop, 0);
+                Ok(false)
+            }
+            // Back at the bottom, new messages are followed again
+            KeyAction::ScrollToBottom => {
+                self.message_display
+                    .handle_scroll(ScrollDirection::ToBottom, 0);
+                Ok(false)
+            }
+            // Only moves while wrapping is off (`wrap off`)
+            KeyAction::ScrollLeft => {
+                self.message_display
+                    .handle_scroll(ScrollDirection::Left, HORIZONTAL_SCROLL_STEP);
+                Ok(false)
+            }
+            KeyAction::ScrollRight => {
+                self.message_display
+                    .handle_scroll(ScrollDirection::Right, HORIZONTAL_SCROLL_STEP);
+                Ok(false)
+            }
+            KeyAction::SearchNext => {
+                self.message_display.search_step(true);
+                Ok(false)
+            }
+            KeyAction::SearchPrev => {
+                self.message_display.search_step(false);
+                Ok(false)
+            }
+            KeyAction::ChordPending => Ok(false),
             KeyAction::Submit => self.handle_submit(key).await,
             KeyAction::Quit => Ok(true),
-            _ => {
-                if let Some(input) = self.input_state.handle_input(key) {
+            action => {
+                if let Some(input) = self.forward_input(key, action) {
                     self.process_special_input(&input).await;
                 }
                 Ok(false)
@@ -153,9 +257,19 @@ impl ScreenManager {
         }
     }
 
+    /// Hands a key to the input line. A completed chord is passed as its
+    /// action, since its last key alone means something else.
+    fn forward_input(&mut self, key: KeyEvent, action: KeyAction) -> Option<String> {
+        if self.keyboard_manager.chord_completed() {
+            self.input_state.handle_action(action)
+        } else {
+            self.input_state.handle_input(key)
+        }
+    }
+
     async fn handle_submit(&mut self, key: KeyEvent) -> Result<bool> {
         use crate::core::constants::*;
-        let Some(input) = self.input_state.handle_input(key) else {
+        let Some(input) = self.forward_input(key, KeyAction::Submit) else {
             return Ok(false);
         };
 
@@ -206,32 +320,105 @@ impl ScreenManager {
             return true;
         }
 
+        // Temporary theme preview
+        if let Some(processed) = self.process_theme_preview(input) {
+            self.message_display.add_message_instant(processed);
+            return true;
+        }
+
+        // Line wrapping (`wrap`)
+        if let Some(mode) = input.strip_prefix(crate::core::constants::SIG_WRAP) {
+            self.config.wrap_lines = match mode {
+                "on" => true,
+                "off" => false,
+                _ => !self.config.wrap_lines,
+            };
+            self.message_display.update_config(&self.config);
+            let key = if self.config.wrap_lines {
+                "screen.wrap.on"
+            } else {
+                "screen.wrap.off"
+            };
+            self.message_display
+                .add_message_instant(get_translation(key, &[]));
+            return true;
+        }
+
+        // Output search (`find`)
+        if let Some(action) = FindAction::from_signal(input) {
+            self.process_output_search(action);
+            return true;
+        }
+
         false
     }
 
-    async fn process_theme_update(&mut self, message: &str) -> Option<String> {
-        use crate::core::constants::*;
-        if !message.starts_with(SIG_LIVE_THEME_UPDATE) {
-            return None;
-        }
+    /// Runs a `find` action and reports the position. The status line never
+    /// repeats the query, so it cannot match itself.
+    fn process_output_search(&mut self, action: FindAction) {
+        let display = &mut self.message_display;
+        let search = match action {
+            FindAction::Clear => {
+                display.clear_search();
+                display.add_message_instant(get_translation("screen.search.cleared", &[]));
+                return;
+            }
+            FindAction::Query {
+                text,
+                case_sensitive,
+            } => {
+                display.search(&text, case_sensitive);
+                display.active_search().cloned()
+            }
+            FindAction::Next => display.search_step(true).cloned(),
+            FindAction::Prev => display.search_step(false).cloned(),
+            FindAction::ToggleCase => display.toggle_search_case().cloned(),
+        };
 
-        let parts: Vec<&str> = message.split(SIG_THEME_MSG_SEP).collect();
-        if parts.len() != 2 {
-            return None;
+        let Some(search) = search else {
+            display.add_message_instant(get_translation("screen.search.inactive", &[]));
+            return;
+        };
+        if search.match_count() == 0 {
+            display.add_message_instant(get_translation("screen.search.no_matches", &[]));
+            return;
         }
 
-        let theme_name = parts[0].replace(SIG_LIVE_THEME_UPDATE, "");
-        let display_msg = parts[1];
+        let mode_key = if search.case_sensitive {
+            "screen.search.case_sensitive"
+        } else {
+            "screen.search.case_insensitive"
+        };
+        display.add_message_instant(get_translation(
+            "screen.search.status",
+            &[
+                &search.current_position().to_string(),
+                &search.match_count().to_string(),
+                &get_translation(mode_key, &[]),
+            ],
+        ));
+        // The status line re-enabled auto-scroll; jump back to the match
+        display.scroll_to_search_match();
+    }
+
+    async fn process_theme_update(&mut self, message: &str) -> Option<String> {
+        use crate::core::constants::SIG_LIVE_THEME_UPDATE;
+        let (theme_name, display_msg) = split_theme_signal(message, SIG_LIVE_THEME_UPDATE)?;
+        let theme_name = theme_name.to_string();
 
         // Load and apply theme
         let theme_system = ThemeSystem::load().ok()?;
         let theme_def = theme_system.get_theme(&theme_name)?;
         let new_theme = self.create_theme(theme_def).ok()?;
 
+        // A committed theme replaces any running preview
+        self.theme_preview = None;
+
         // Backup state, update config, restore state
         let backup = self.input_state.export_state();
         self.config.theme = new_theme;
         self.config.current_theme_name = theme_name;
+        self.apply_theme_typewriter_delay(theme_def);
 
         self.message_display.clear_messages();
         self.message_display.update_config(&self.config);
@@ -242,21 +429,151 @@ impl ScreenManager {
         Some(display_msg.to_string())
     }
 
+    /// Applies a theme for `THEME_PREVIEW_SECS` without saving it. Previewing
+    /// again while a preview runs keeps the original snapshot.
+    fn process_theme_preview(&mut self, message: &str) -> Option<String> {
+        use crate::core::constants::{SIG_THEME_PREVIEW, THEME_PREVIEW_SECS};
+        let (theme_name, display_msg) = split_theme_signal(message, SIG_THEME_PREVIEW)?;
+
+        let theme_system = ThemeSystem::load().ok()?;
+        let theme_def = theme_system.get_theme(theme_name)?;
+        let new_theme = self.create_theme(theme_def).ok()?;
+
+        let until = Instant::now() + Duration::from_secs(THEME_PREVIEW_SECS);
+        match self.theme_preview.as_mut() {
+            Some(preview) => preview.until = until,
+            None => {
+                self.theme_preview = Some(ThemePreview {
+                    theme: self.config.theme.clone(),
+                    theme_name: self.config.current_theme_name.clone(),
+                    typewriter_delay: self.config.typewriter_delay,
+                    until,
+                })
+            }
+        }
+
+        self.config.theme = new_theme;
+        self.config.current_theme_name = theme_name.to_string();
+        self.apply_theme_typewriter_delay(theme_def);
+        self.refresh_theme();
+
+        Some(display_msg.to_string())
+    }
+
+    /// Restores the theme that was active before the live preview
+    fn end_theme_preview(&mut self) {
+        let Some(preview) = self.theme_preview.take() else {
+            return;
+        };
+        self.config.theme = preview.theme;
+        self.config.current_theme_name = preview.theme_name;
+        self.config.typewriter_delay = preview.typewriter_delay;
+        self.refresh_theme();
+
+        self.message_display.add_message_instant(get_translation(
+            "screen.theme.preview_ended",
+            &[&self.config.current_theme_name.to_uppercase()],
+        ));
+    }
+
+    /// Pushes `self.config` colors/cursor to the output and rebuilds the input
+    /// widget, keeping what was typed
+    fn refresh_theme(&mut self) {
+        self.message_display.update_config(&self.config);
+        let backup = self.input_state.export_state();
+        self.input_state = InputState::new(&self.config);
+        self.input_state.import_state(backup);
+    }
+
     fn create_theme(
         &self,
         def: &crate::commands::theme::ThemeDefinition,
     ) -> Result<crate::core::config::Theme> {
-        Ok(crate::core::config::Theme {
-            input_text: AppColor::from_string(&def.input_text)?,
-            input_bg: AppColor::from_string(&def.input_bg)?,
-            output_text: AppColor::from_string(&def.output_text)?,
-            output_bg: AppColor::from_string(&def.output_bg)?,
-            input_cursor_prefix: def.input_cursor_prefix.clone(),
-            input_cursor_color: AppColor::from_string(&def.input_cursor_color)?,
-            input_cursor: def.input_cursor.clone(),
-            output_cursor: def.output_cursor.clone(),
-            output_cursor_color: AppColor::from_string(&def.output_cursor_color)?,
-        })
+        crate::core::config::Theme::from_definition(def)
+    }
+
+    /// Uses the theme's `typewriter_delay_ms` if set, otherwise the global delay.
+    fn apply_theme_typewriter_delay(&mut self, def: &crate::commands::theme::ThemeDefinition) {
+        self.config.typewriter_delay = def
+            .typewriter_delay_ms
+            .map(Duration::from_millis)
+            .unwrap_or(self.base_typewriter_delay);
+    }
+
+    /// Applies an edited rush.toml. A file that fails to parse is reported
+    /// and the running config is kept.
+    async fn reload_config(&mut self) {
+        let Some(path) = self.config.path().map(|p| p.to_path_buf()) else {
+            return;
+        };
+
+        let reloaded = match Config::from_file(&path).await {
+            Ok(config) => config,
+            Err(e) => {
+                self.message_display.add_message_instant(get_translation(
+                    "screen.config.reload_failed",
+                    &[&e.to_string()],
+                ));
+                return;
+            }
+        };
+
+        let changes = changed_settings(&self.config, &reloaded);
+        if changes.is_empty() {
+            return;
+        }
+
+        // The file's theme wins over a running preview
+        self.theme_preview = None;
+
+        crate::core::crash::set_config_summary(&reloaded);
+        if reloaded.log_level != self.config.log_level {
+            crate::commands::log_level::LogLevelManager::apply_config(&reloaded);
+        }
+
+        let language_changed = reloaded.language != self.config.language
+            || reloaded.language_fallback != self.config.language_fallback;
+        let timing = EventHandlerConfig::from(&reloaded);
+        let timing_changed = timing != EventHandlerConfig::from(&self.config);
+        let theme_name = reloaded.current_theme_name.clone();
+        self.base_typewriter_delay = reloaded.typewriter_delay;
+        if reloaded.keybindings != self.config.keybindings {
+            self.keyboard_manager.set_bindings(&reloaded.keybindings);
+        }
+        self.config = reloaded;
+
+        if let Err(e) = self
+            .terminal_mgr
+            .set_mouse_capture(self.config.mouse_capture)
+        {
+            log::warn!("Config reload: mouse capture not applied: {}", e);
+        }
+
+        // New poll/tick timings only take effect in fresh event loops
+        if timing_changed {
+            self.events.shutdown().await;
+            self.events = EventHandler::new(timing);
+        }
+
+        if language_changed {
+            if let Err(e) = LanguageService::new()
+                .load_and_apply_from_config(&self.config)
+                .await
+            {
+                log::warn!("Config reload: language not applied: {}", e);
+            }
+        }
+
+        // Re-applies colors, cursor and typewriter speed, keeping the input state
+        if let Err(e) = self.switch_theme_safely(&theme_name).await {
+            log::warn!("Config reload: theme not applied: {}", e);
+            self.message_display.update_config(&self.config);
+        }
+
+        self.message_display.add_message_instant(get_translation(
+            "screen.config.reloaded",
+            &[&changes.join(", ")],
+        ));
     }
 
     async fn handle_restart(&mut self, input: &str) {
@@ -281,6 +598,13 @@ impl ScreenManager {
     }
 
     async fn handle_tick(&mut self) -> Result<()> {
+        if self
+            .theme_preview
+            .as_ref()
+            .is_some_and(|preview| Instant::now() >= preview.until)
+        {
+            self.end_theme_preview();
+        }
         self.message_display.update_typewriter();
         self.input_state.tick();
         Ok(())
@@ -292,36 +616,39 @@ impl ScreenManager {
         let viewport_ok = self.message_display.viewport().is_usable();
         let output_area = self.message_display.viewport().output_area();
         let input_area = self.message_display.viewport().input_area();
+        let horizontal_offset = self.message_display.viewport().horizontal_offset();
 
-        let (messages, config, layout, cursor_state) =
+        let (messages, config, layout, cursor_state, search) =
             self.message_display.create_output_widget_for_rendering();
 
         self.terminal.draw(|frame| {
             let size = frame.size();
 
-            // Emergency cases with i18n
+            // Emergency cases with i18n: keep the input line alive so the
+            // user can still type (e.g. `exit`) while the layout is collapsed
             if size.width < 10 || size.height < 5 {
-                let widget = ratatui::widgets::Paragraph::new(get_translation(
-                    "screen.render.terminal_too_small",
-                    &[],
-                ))
-                .block(ratatui::widgets::Block::default());
-                frame.render_widget(widget, size);
+                Self::render_fallback(
+                    frame,
+                    input_widget,
+                    cursor_pos,
+                    Some(get_translation("screen.render.terminal_too_small", &[])),
+                );
                 return;
             }
 
             if !viewport_ok || !output_area.is_valid() || !input_area.is_valid() {
-                let widget = ratatui::widgets::Paragraph::new(get_translation(
-                    "screen.render.viewport_error",
-                    &[],
-                ))
-                .block(ratatui::widgets::Block::default());
-                frame.render_widget(widget, size);
+                Self::render_fallback(
+                    frame,
+                    input_widget,
+                    cursor_pos,
+                    Some(get_translation("screen.render.viewport_error", &[])),
+                );
                 return;
             }
 
             // Check bounds
             if Self::exceeds_bounds(&output_area, &input_area, size) {
+                Self::render_fallback(frame, input_widget, cursor_pos, None);
                 return;
             }
 
@@ -331,6 +658,8 @@ impl ScreenManager {
                 layout,
                 &config,
                 cursor_state,
+                search.as_ref(),
+                horizontal_offset,
             );
 
             frame.render_widget(output_widget, output_area.as_rect());
@@ -350,6 +679,55 @@ impl ScreenManager {
         Ok(())
     }
 
+    /// Minimal frame for a collapsed layout: the input line at the bottom and,
+    /// if there is room left, a notice above it
+    fn render_fallback(
+        frame: &mut ratatui::Frame,
+        input_widget: ratatui::widgets::Paragraph<'_>,
+        cursor_pos: Option<(u16, u16)>,
+        notice: Option<String>,
+    ) {
+        let (notice_area, input_rect) = Self::fallback_layout(frame.size());
+
+        if let (Some(area), Some(text)) = (notice_area, notice) {
+            let widget =
+                ratatui::widgets::Paragraph::new(text).block(ratatui::widgets::Block::default());
+            frame.render_widget(widget, area);
+        }
+
+        if input_rect.width == 0 || input_rect.height == 0 {
+            return;
+        }
+        frame.render_widget(input_widget, input_rect);
+
+        if let Some((x, y)) = cursor_pos {
+            let (cx, cy) = (input_rect.x + 3 + x, input_rect.y + 1 + y);
+            if cx < input_rect.right() && cy < input_rect.bottom() {
+                frame.set_cursor(cx, cy);
+            }
+        }
+    }
+
+    /// Splits `size` into an optional notice area and an input area of up to
+    /// three rows at the bottom
+    fn fallback_layout(
+        size: ratatui::layout::Rect,
+    ) -> (Option<ratatui::layout::Rect>, ratatui::layout::Rect) {
+        let input_height = size.height.min(3);
+        let notice_height = size.height - input_height;
+        let input = ratatui::layout::Rect {
+            x: size.x,
+            y: size.y + notice_height,
+            width: size.width,
+            height: input_height,
+        };
+        let notice = (notice_height > 0 && size.width > 0).then_some(ratatui::layout::Rect {
+            height: notice_height,
+            ..size
+        });
+        (notice, input)
+    }
+
     fn exceeds_bounds(
         output: &crate::ui::viewport::LayoutArea,
         input: &crate::ui::viewport::LayoutArea,
@@ -401,6 +779,10 @@ impl ScreenManager {
     }
 
     fn get_rgb(&self, color: &AppColor) -> (u8, u8, u8) {
+        if let Some(rgb) = color.to_rgb() {
+            return rgb;
+        }
+
         match color.to_name() {
             "black" => (0, 0, 0),
             "red" => (255, 0, 0),
@@ -432,14 +814,19 @@ impl ScreenManager {
 
         self.terminal_mgr.cleanup().await?;
         self.terminal_mgr = TerminalManager::new().await?;
+        self.terminal_mgr
+            .set_mouse_capture(self.config.mouse_capture)?;
         self.terminal_mgr.setup().await?;
 
         let backend = CrosstermBackend::new(io::stdout());
         self.terminal = Terminal::new(backend)?;
         let size = self.terminal.size()?;
 
+        // Messages start over; unsent input and history survive the restart
+        let backup = self.input_state.export_state();
         self.message_display = MessageDisplay::new(&self.config, size.width, size.height);
         self.input_state = InputState::new(&self.config);
+        self.input_state.import_state(backup);
         self.waiting_for_restart_confirmation = false;
 
         self.message_display
@@ -464,6 +851,7 @@ impl ScreenManager {
 
         self.config.theme = theme;
         self.config.current_theme_name = theme_name.to_string();
+        self.apply_theme_typewriter_delay(def);
         self.message_display.update_config(&self.config);
 
         self.input_state = InputState::new(&self.config);
@@ -489,3 +877,137 @@ impl ScreenManager {
         .collect()
     }
 }
+
+/// Next debounced rush.toml change; never resolves without a watcher
+async fn next_config_change(watcher: &mut Option<ConfigWatcher>) -> Option<()> {
+    match watcher {
+        Some(watcher) => watcher.changed().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Splits `<signal><theme>__MESSAGE__<text>` into theme name and text
+fn split_theme_signal<'a>(message: &'a str, signal: &str) -> Option<(&'a str, &'a str)> {
+    message
+        .strip_prefix(signal)?
+        .split_once(crate::core::constants::SIG_THEME_MSG_SEP)
+}
+
+/// Persisted server count and process memory for the startup banner
+struct BannerStats {
+    servers: usize,
+    /// `None` where the platform doesn't expose it
+    rss_bytes: Option<u64>,
+}
+
+impl BannerStats {
+    async fn collect() -> Self {
+        let registry = crate::server::shared::get_persistent_registry();
+        let servers = match registry.load_servers().await {
+            Ok(servers) => servers.len(),
+            Err(e) => {
+                log::warn!("Banner: server registry not readable: {}", e);
+                0
+            }
+        };
+        let rss_bytes = crate::core::helpers::process_rss_bytes();
+
+        Self { servers, rss_bytes }
+    }
+
+    fn memory(&self) -> String {
+        self.rss_bytes
+            .map(crate::core::helpers::format_bytes)
+            .unwrap_or_else(|| "-".to_string())
+    }
+}
+
+/// Fills the banner tokens from the current config
+fn render_banner(template: &str, config: &Config, stats: &BannerStats) -> String {
+    template
+        .replace("{{VERSION}}", crate::core::constants::VERSION)
+        .replace("{{THEME}}", &config.current_theme_name)
+        .replace("{{LANGUAGE}}", &config.language)
+        .replace("{{SERVERS}}", &stats.servers.to_string())
+        .replace("{{MEMORY}}", &stats.memory())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fallback_layout_keeps_input_line() {
+        let rect = |x, y, width, height| ratatui::layout::Rect {
+            x,
+            y,
+            width,
+            height,
+        };
+
+        let (notice, input) = ScreenManager::fallback_layout(rect(0, 0, 8, 4));
+        assert_eq!(notice, Some(rect(0, 0, 8, 1)));
+        assert_eq!(input, rect(0, 1, 8, 3));
+
+        // No room for the notice: only the input line remains
+        let (notice, input) = ScreenManager::fallback_layout(rect(0, 0, 30, 2));
+        assert_eq!(notice, None);
+        assert_eq!(input, rect(0, 0, 30, 2));
+
+        let (notice, input) = ScreenManager::fallback_layout(rect(0, 0, 0, 0));
+        assert_eq!(notice, None);
+        assert_eq!(input.height, 0);
+    }
+
+    #[test]
+    fn test_split_theme_signal() {
+        use crate::core::constants::{SIG_LIVE_THEME_UPDATE, SIG_THEME_PREVIEW};
+        assert_eq!(
+            split_theme_signal(
+                "__THEME_PREVIEW__dark__MESSAGE__Previewing",
+                SIG_THEME_PREVIEW
+            ),
+            Some(("dark", "Previewing"))
+        );
+        assert_eq!(
+            split_theme_signal("__THEME_PREVIEW__dark__MESSAGE__x", SIG_LIVE_THEME_UPDATE),
+            None
+        );
+        assert_eq!(
+            split_theme_signal("__THEME_PREVIEW__dark", SIG_THEME_PREVIEW),
+            None
+        );
+    }
+
+    #[test]
+    fn test_render_banner_tokens() {
+        let config = Config::default();
+        let stats = BannerStats {
+            servers: 0,
+            rss_bytes: None,
+        };
+        let banner = render_banner("Rush {{VERSION}} [{{THEME}}/{{LANGUAGE}}]", &config, &stats);
+        assert_eq!(
+            banner,
+            format!(
+                "Rush {} [dark/{}]",
+                crate::core::constants::VERSION,
+                crate::i18n::DEFAULT_LANGUAGE
+            )
+        );
+
+        let stats = BannerStats {
+            servers: 3,
+            rss_bytes: Some(2048),
+        };
+        assert_eq!(
+            render_banner("{{SERVERS}} servers, {{MEMORY}}", &config, &stats),
+            "3 servers, 2.0 KB"
+        );
+        let stats = BannerStats {
+            servers: 0,
+            rss_bytes: None,
+        };
+        assert_eq!(render_banner("{{MEMORY}}", &config, &stats), "-");
+    }
+}
@@ -14,6 +14,7 @@ use std::io::Stdout;
 pub struct TerminalManager {
     stdout: Stdout,
     raw_mode_enabled: bool,
+    mouse_capture: bool,
 }
 
 impl TerminalManager {
@@ -21,9 +22,46 @@ impl TerminalManager {
         Ok(Self {
             stdout: io::stdout(),
             raw_mode_enabled: false,
+            mouse_capture: true,
         })
     }
 
+    /// Mouse capture gives wheel scrolling but takes over text selection in
+    /// most terminals. Applied immediately if the terminal is already set up.
+    pub fn set_mouse_capture(&mut self, enabled: bool) -> Result<()> {
+        if self.raw_mode_enabled && enabled != self.mouse_capture {
+            if enabled {
+                Self::enable_mouse(&mut self.stdout)?;
+            } else {
+                Self::disable_mouse(&mut self.stdout)?;
+            }
+        }
+        self.mouse_capture = enabled;
+        Ok(())
+    }
+
+    fn enable_mouse(stdout: &mut Stdout) -> Result<()> {
+        execute!(
+            stdout,
+            crossterm::style::Print("\x1B[?1000h"),
+            crossterm::style::Print("\x1B[?1002h"),
+            crossterm::style::Print("\x1B[?1015h"),
+            crossterm::style::Print("\x1B[?1006h")
+        )?;
+        Ok(())
+    }
+
+    fn disable_mouse(stdout: &mut Stdout) -> Result<()> {
+        execute!(
+            stdout,
+            crossterm::style::Print("\x1B[?1000l"),
+            crossterm::style::Print("\x1B[?1002l"),
+            crossterm::style::Print("\x1B[?1015l"),
+            crossterm::style::Print("\x1B[?1006l")
+        )?;
+        Ok(())
+    }
+
     pub async fn setup(&mut self) -> Result<()> {
         self.enable_full_raw_mode().await?;
         execute!(
@@ -42,14 +80,10 @@ impl TerminalManager {
     async fn enable_full_raw_mode(&mut self) -> Result<()> {
         enable_raw_mode()?;
         self.raw_mode_enabled = true;
-        execute!(
-            self.stdout,
-            crossterm::style::Print("\x1B[?1000h"),
-            crossterm::style::Print("\x1B[?1002h"),
-            crossterm::style::Print("\x1B[?1015h"),
-            crossterm::style::Print("\x1B[?1006h"),
-            crossterm::style::Print("\x1B[?1049h")
-        )?;
+        if self.mouse_capture {
+            Self::enable_mouse(&mut self.stdout)?;
+        }
+        execute!(self.stdout, crossterm::style::Print("\x1B[?1049h"))?;
         Ok(())
     }
 
@@ -99,14 +133,8 @@ impl TerminalManager {
             return Ok(());
         }
 
-        execute!(
-            self.stdout,
-            crossterm::style::Print("\x1B[?1000l"),
-            crossterm::style::Print("\x1B[?1002l"),
-            crossterm::style::Print("\x1B[?1015l"),
-            crossterm::style::Print("\x1B[?1006l"),
-            crossterm::style::Print("\x1B[?1049l")
-        )?;
+        Self::disable_mouse(&mut self.stdout)?;
+        execute!(self.stdout, crossterm::style::Print("\x1B[?1049l"))?;
 
         disable_raw_mode()?;
         self.raw_mode_enabled = false;
@@ -8,6 +8,12 @@ pub struct Viewport {
     window_height: usize,
     scroll_offset: usize,
     auto_scroll_enabled: bool,
+    // Unwrapped mode: widest line and the columns scrolled past on the left
+    wrap_lines: bool,
+    content_width: usize,
+    horizontal_offset: usize,
+    // Columns in front of each line that message text never uses (timestamps)
+    gutter_width: usize,
     min_terminal_height: u16,
     min_terminal_width: u16,
 }
@@ -44,6 +50,8 @@ pub enum ScrollDirection {
     ToBottom,
     PageUp,
     PageDown,
+    Left,
+    Right,
 }
 
 impl LayoutArea {
@@ -81,6 +89,10 @@ impl Viewport {
             window_height: 0,
             scroll_offset: 0,
             auto_scroll_enabled: true,
+            wrap_lines: true,
+            content_width: 0,
+            horizontal_offset: 0,
+            gutter_width: 0,
             min_terminal_height: 10,
             min_terminal_width: 40,
         };
@@ -198,6 +210,56 @@ impl Viewport {
         self.scroll_down(self.window_height.saturating_sub(1).max(1));
     }
 
+    // Horizontal scrolling, only while lines are not wrapped
+    pub fn set_wrap_lines(&mut self, wrap: bool) {
+        self.wrap_lines = wrap;
+        self.horizontal_offset = 0;
+    }
+
+    pub fn wraps_lines(&self) -> bool {
+        self.wrap_lines
+    }
+
+    pub fn set_gutter_width(&mut self, width: usize) {
+        self.gutter_width = width;
+    }
+
+    pub fn gutter_width(&self) -> usize {
+        self.gutter_width
+    }
+
+    /// Columns per line for message text; unbounded while lines are not wrapped
+    pub fn text_width(&self) -> usize {
+        if !self.wrap_lines {
+            return usize::MAX;
+        }
+        (self.output_area.width as usize)
+            .saturating_sub(2 + self.gutter_width)
+            .max(10)
+    }
+
+    pub fn horizontal_offset(&self) -> usize {
+        self.horizontal_offset
+    }
+
+    pub fn update_content_width(&mut self, width: usize) {
+        self.content_width = width;
+        self.horizontal_offset = self.horizontal_offset.min(self.max_horizontal_offset());
+    }
+
+    pub fn scroll_left(&mut self, columns: usize) {
+        self.horizontal_offset = self.horizontal_offset.saturating_sub(columns);
+    }
+
+    pub fn scroll_right(&mut self, columns: usize) {
+        if !self.wrap_lines {
+            self.horizontal_offset = self
+                .horizontal_offset
+                .saturating_add(columns)
+                .min(self.max_horizontal_offset());
+        }
+    }
+
     // Content and auto-scroll management
     pub fn update_content_height(&mut self, new_content_height: usize) {
         self.content_height = new_content_height;
@@ -309,6 +371,8 @@ impl Viewport {
                     ScrollDirection::ToBottom => self.scroll_to_bottom(),
                     ScrollDirection::PageUp => self.page_up(),
                     ScrollDirection::PageDown => self.page_down(),
+                    ScrollDirection::Left => self.scroll_left(amount),
+                    ScrollDirection::Right => self.scroll_right(amount),
                 }
                 true
             }
@@ -324,6 +388,11 @@ impl Viewport {
         self.content_height.saturating_sub(self.window_height)
     }
 
+    fn max_horizontal_offset(&self) -> usize {
+        self.content_width
+            .saturating_sub((self.output_area.width as usize).saturating_sub(2 + self.gutter_width))
+    }
+
     fn is_at_bottom(&self) -> bool {
         let max_offset = self.max_scroll_offset();
         self.scroll_offset >= max_offset || max_offset == 0
@@ -37,6 +37,15 @@ fn test_registry() {
     assert!(registry.find_command("version").is_some());
 }
 
+#[test]
+fn test_registry_suggest() {
+    let registry = create_default_registry();
+    assert_eq!(registry.suggest("hepl"), Some("help"));
+    assert_eq!(registry.suggest("vresion"), Some("version"));
+    assert_eq!(registry.suggest("xyzxyzxyz"), None);
+    assert_eq!(registry.suggest(""), None);
+}
+
 // Command-Trait safety: all registered commands must have name, description, and matches
 #[test]
 fn test_all_commands_have_metadata() {
@@ -147,6 +156,167 @@ async fn test_config_default_values() {
     assert!(config.server.shutdown_timeout > 0);
 }
 
+#[test]
+fn test_typewriter_force_instant_thresholds() {
+    let mut typewriter = rush_sync_server::Config::default().typewriter;
+    assert_eq!(typewriter.force_instant_lines, 5);
+    assert_eq!(typewriter.force_instant_bytes, 200);
+
+    let six_lines = "a\nb\nc\nd\ne\nf";
+    assert!(typewriter.forces_instant(six_lines));
+    assert!(typewriter.forces_instant(&"x".repeat(201)));
+    assert!(!typewriter.forces_instant("short message"));
+
+    typewriter.force_instant_lines = 10;
+    assert!(!typewriter.forces_instant(six_lines));
+}
+
+#[test]
+fn test_event_handler_config_defaults() {
+    use rush_sync_server::input::EventHandlerConfig;
+    use std::time::Duration;
+
+    let timing = EventHandlerConfig::default();
+    assert_eq!(timing.tick_rate, Duration::from_millis(16));
+    assert_eq!(timing.poll_interval, Duration::from_millis(99));
+    assert_eq!(timing.key_interval, Duration::from_millis(16));
+    assert_eq!(timing.resize_interval, Duration::from_millis(50));
+
+    let mut config = rush_sync_server::Config::default();
+    config.input_poll = Duration::from_millis(250);
+    assert_eq!(
+        EventHandlerConfig::from(&config).poll_interval,
+        Duration::from_millis(250)
+    );
+}
+
+#[test]
+fn test_viewport_jump_to_bottom_resumes_auto_scroll() {
+    use rush_sync_server::ui::viewport::Viewport;
+
+    let mut viewport = Viewport::new(80, 24);
+    viewport.update_content_height(200);
+    viewport.scroll_to_top();
+    assert!(!viewport.is_auto_scroll_enabled());
+
+    viewport.scroll_to_bottom();
+    assert!(viewport.is_auto_scroll_enabled());
+}
+
+#[test]
+fn test_viewport_horizontal_scroll_only_unwrapped() {
+    use rush_sync_server::ui::viewport::Viewport;
+
+    let mut viewport = Viewport::new(80, 24);
+    viewport.update_content_width(300);
+    viewport.scroll_right(8);
+    assert_eq!(viewport.horizontal_offset(), 0);
+
+    viewport.set_wrap_lines(false);
+    viewport.scroll_right(8);
+    assert_eq!(viewport.horizontal_offset(), 8);
+    viewport.scroll_right(10_000);
+    assert!(viewport.horizontal_offset() < 300);
+    viewport.scroll_left(10_000);
+    assert_eq!(viewport.horizontal_offset(), 0);
+}
+
+#[test]
+fn test_horizontal_scroll_keeps_marker_colors() {
+    use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+    use rush_sync_server::output::display::{create_output_widget, MessageDisplay};
+    use rush_sync_server::ui::viewport::ScrollDirection;
+    use rush_sync_server::Config;
+
+    let mut config = Config::default();
+    config.wrap_lines = false;
+    let mut display = MessageDisplay::new(&config, 80, 24);
+    display.add_message_instant(format!("[ERROR] {}", "x".repeat(200)));
+
+    let render = |display: &mut MessageDisplay| {
+        let offset = display.viewport().horizontal_offset();
+        let (messages, config, layout, cursor, search) =
+            display.create_output_widget_for_rendering();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 80, 4));
+        create_output_widget(&messages, layout, &config, cursor, search.as_ref(), offset)
+            .render(buf.area, &mut buf);
+        buf
+    };
+
+    let unscrolled = render(&mut display);
+    display.handle_scroll(ScrollDirection::Right, 2);
+    let scrolled = render(&mut display);
+
+    // "[ERROR]" scrolled by two: "RROR]" still in the marker color
+    assert_eq!(scrolled.get(0, 0).symbol(), "R");
+    assert_eq!(scrolled.get(0, 0).fg, unscrolled.get(2, 0).fg);
+    assert_eq!(scrolled.get(4, 0).symbol(), "]");
+    assert_eq!(scrolled.get(5, 0).fg, unscrolled.get(7, 0).fg);
+}
+
+#[test]
+fn test_viewport_text_width_leaves_timestamp_gutter() {
+    use rush_sync_server::ui::viewport::Viewport;
+
+    let mut viewport = Viewport::new(80, 24);
+    let full = viewport.text_width();
+    viewport.set_gutter_width(9);
+    assert_eq!(viewport.text_width(), full - 9);
+
+    viewport.set_wrap_lines(false);
+    assert_eq!(viewport.text_width(), usize::MAX);
+}
+
+#[test]
+fn test_output_search_find_matches() {
+    use rush_sync_server::output::display::find_matches;
+
+    let line = "[INFO] Server started, server ready";
+    assert_eq!(find_matches(line, "server", false), vec![(7, 13), (23, 29)]);
+    assert_eq!(find_matches(line, "server", true), vec![(23, 29)]);
+    assert!(find_matches(line, "", false).is_empty());
+    assert_eq!(find_matches("Größe GRÖSSE größe", "größe", false).len(), 2);
+}
+
+#[test]
+fn test_output_keeps_ansi_colors_when_enabled() {
+    use ratatui::style::{Color, Style};
+    use rush_sync_server::output::display::MessageDisplay;
+    use rush_sync_server::Config;
+
+    let mut config = Config::default();
+    config.ansi_colors = true;
+    let mut display = MessageDisplay::new(&config, 80, 24);
+    display.add_message_instant("  \x1b[32mok\x1b[0m [INFO] done".to_string());
+
+    let line = &display.get_visible_messages()[0];
+    assert_eq!(line.0, "ok [INFO] done");
+    assert_eq!(line.6.len(), line.1);
+    assert_eq!(line.6[0], Style::default().fg(Color::Green));
+    assert_eq!(line.6[3], Style::default());
+
+    let mut plain = MessageDisplay::new(&Config::default(), 80, 24);
+    plain.add_message_instant("\x1b[32mok\x1b[0m".to_string());
+    let line = &plain.get_visible_messages()[0];
+    assert_eq!(line.0, "ok");
+    assert!(line.6.is_empty());
+}
+
+#[test]
+fn test_log_level_filter_is_runtime_adjustable() {
+    use rush_sync_server::commands::log_level::LogLevelManager;
+
+    LogLevelManager::init_with_level(log::LevelFilter::Warn);
+    assert!(LogLevelManager::allows(log::Level::Error));
+    assert!(!LogLevelManager::allows(log::Level::Info));
+
+    LogLevelManager::init_with_level(log::LevelFilter::Trace);
+    assert!(LogLevelManager::allows(log::Level::Trace));
+
+    LogLevelManager::init_with_level(log::LevelFilter::Info);
+    assert_eq!(LogLevelManager::get_current_level(), log::LevelFilter::Info);
+}
+
 // Security: path traversal detection
 #[test]
 fn test_server_name_validation() {
@@ -161,6 +331,80 @@ fn test_server_name_validation() {
     assert!(validate_server_name("a".repeat(65).as_str()).is_err());
 }
 
+// Names double as proxy subdomains: [a-z0-9-], unique case-insensitively
+#[test]
+fn test_server_name_dns_safe_and_unique() {
+    use rush_sync_server::server::types::{ServerContext, ServerInfo, ServerStatus};
+    use rush_sync_server::server::utils::validation::validate_server_name;
+    use rush_sync_server::server::ServerManager;
+
+    assert!(validate_server_name("MyServer").is_err());
+    assert!(validate_server_name("my_server").is_err());
+    assert!(validate_server_name("-blog").is_err());
+    assert!(validate_server_name("blog-").is_err());
+    assert!(validate_server_name("blog.local").is_err());
+
+    let ctx = ServerContext::default();
+    ctx.servers.write().unwrap().insert(
+        "id-1".to_string(),
+        ServerInfo {
+            id: "id-1".to_string(),
+            name: "blog".to_string(),
+            port: 8080,
+            status: ServerStatus::Stopped,
+            created_at: String::new(),
+            created_timestamp: 0,
+            env: Default::default(),
+            workers: None,
+            headers: Default::default(),
+            on_stop: None,
+        },
+    );
+    let manager = ServerManager::with_context(ctx);
+    assert!(manager.ensure_name_available("blog").is_err());
+    assert!(manager.ensure_name_available("shop").is_ok());
+}
+
+#[test]
+fn test_find_free_port_respects_range() {
+    use rush_sync_server::server::types::{ServerContext, ServerInfo, ServerStatus};
+    use rush_sync_server::server::ServerManager;
+
+    // Occupied by another process
+    let listener = std::net::TcpListener::bind(("127.0.0.1", 0)).unwrap();
+    let taken = listener.local_addr().unwrap().port();
+
+    let ctx = ServerContext::default();
+    let manager = ServerManager::with_context(ctx.clone());
+    assert!(manager
+        .find_free_port(taken, Some(taken), "127.0.0.1")
+        .is_err());
+    assert!(manager
+        .find_free_port(9000, Some(8999), "127.0.0.1")
+        .is_err());
+
+    // Reserved by a registered (stopped) server, even though it is bindable
+    drop(listener);
+    ctx.servers.write().unwrap().insert(
+        "id-1".to_string(),
+        ServerInfo {
+            id: "id-1".to_string(),
+            name: "blog".to_string(),
+            port: taken,
+            status: ServerStatus::Stopped,
+            created_at: String::new(),
+            created_timestamp: 0,
+            env: Default::default(),
+            workers: None,
+            headers: Default::default(),
+            on_stop: None,
+        },
+    );
+    assert!(manager
+        .find_free_port(taken, Some(taken), "127.0.0.1")
+        .is_err());
+}
+
 // i18n basic tests
 #[test]
 fn test_translation_missing_key() {
@@ -168,12 +412,51 @@ fn test_translation_missing_key() {
     assert!(result.starts_with("Missing:"));
 }
 
+#[test]
+fn test_missing_translations_are_recorded() {
+    for _ in 0..2 {
+        rush_sync_server::i18n::get_translation("nonexistent.key.recorded", &[]);
+    }
+    let missing = rush_sync_server::i18n::get_missing_translations();
+    let entry = missing
+        .iter()
+        .find(|m| m.key == "nonexistent.key.recorded")
+        .expect("missing key recorded");
+    assert!(entry.count >= 2);
+    assert_eq!(entry.fallback, None);
+}
+
 #[test]
 fn test_available_languages() {
     let languages = rush_sync_server::i18n::get_available_languages();
     assert!(!languages.is_empty());
 }
 
+#[test]
+fn test_fallback_chain_order() {
+    use rush_sync_server::i18n::fallback_chain;
+    let chain = fallback_chain("pt-BR", &["de".into(), "PT".into()]);
+    assert_eq!(chain, vec!["pt", "de", "en"]);
+    assert_eq!(fallback_chain("en", &[]), Vec::<String>::new());
+    assert_eq!(fallback_chain("de", &["de".into()]), vec!["en"]);
+}
+
+#[test]
+fn test_language_from_locale() {
+    use rush_sync_server::i18n::language_from_locale;
+    let available = vec!["EN".to_string(), "DE".to_string(), "PT-BR".to_string()];
+    assert_eq!(
+        language_from_locale("de_DE.UTF-8", &available).as_deref(),
+        Some("de")
+    );
+    assert_eq!(
+        language_from_locale("pt_BR.UTF-8", &available).as_deref(),
+        Some("pt-br")
+    );
+    assert_eq!(language_from_locale("fr_FR", &available), None);
+    assert_eq!(language_from_locale("C.UTF-8", &available), None);
+}
+
 // Handler edge cases
 #[test]
 fn test_empty_input() {
@@ -191,6 +474,37 @@ fn test_whitespace_input() {
     assert!(result.message.is_empty());
 }
 
+#[test]
+fn test_uptime_counts_executed_commands() {
+    let handler = CommandHandler::new();
+    let before = CommandHandler::commands_executed();
+
+    assert!(handler.handle_input("version").success);
+    let result = handler.handle_input("uptime");
+    assert!(result.success);
+    assert!(CommandHandler::commands_executed() >= before + 2);
+    assert!(!handler.handle_input("uptime now").success);
+}
+
+#[test]
+fn test_input_backup_survives_rebuild() {
+    use crossterm::event::{KeyCode, KeyEvent};
+    use rush_sync_server::input::state::InputState;
+    use rush_sync_server::ui::widget::{StatefulWidget, Widget};
+
+    // What `restart` does: export, build a fresh InputState, import
+    let config = rush_sync_server::Config::default();
+    let mut input = InputState::new(&config);
+    for c in "server health".chars() {
+        input.handle_input(KeyEvent::from(KeyCode::Char(c)));
+    }
+    let backup = input.export_state();
+
+    let mut rebuilt = InputState::new(&config);
+    rebuilt.import_state(backup);
+    assert_eq!(rebuilt.export_state().content, "server health");
+}
+
 #[test]
 fn test_long_input_rejected() {
     let handler = CommandHandler::new();
@@ -222,6 +536,9 @@ mod web_handler_tests {
             },
             proxy_http_port: 3000,
             proxy_https_port: 3443,
+            index_files: vec!["index.html".to_string()],
+            env: Default::default(),
+            hot_reload: true,
         })
     }
 
@@ -337,7 +654,7 @@ mod web_handler_tests {
         assert_eq!(resp["port"], 8080);
 
         let endpoints = resp["endpoints"].as_array().unwrap();
-        assert_eq!(endpoints.len(), 10);
+        assert_eq!(endpoints.len(), 11);
     }
 
     #[actix_web::test]
@@ -660,6 +977,9 @@ mod web_handler_tests {
             },
             proxy_http_port: 3000,
             proxy_https_port: 3443,
+            index_files: vec!["index.html".to_string()],
+            env: Default::default(),
+            hot_reload: true,
         });
 
         let app = test::init_service(
@@ -686,7 +1006,10 @@ mod web_handler_tests {
 // =============================================================================
 
 mod proxy_tests {
-    use rush_sync_server::proxy::{ProxyConfig, ProxyManager};
+    use rush_sync_server::proxy::handler::{
+        backend_client, handle_proxy_request, render_route_index,
+    };
+    use rush_sync_server::proxy::{ProxyConfig, ProxyManager, RouteKind};
 
     fn test_proxy_config() -> ProxyConfig {
         ProxyConfig {
@@ -695,7 +1018,10 @@ mod proxy_tests {
             https_port_offset: 443,
             bind_address: "127.0.0.1".to_string(),
             health_check_interval: 30,
-            timeout_ms: 5000,
+            path_routing: false,
+            connect_timeout_ms: 2000,
+            read_timeout_ms: 30_000,
+            retries: 3,
             production_domain: "localhost".to_string(),
             use_lets_encrypt: false,
         }
@@ -710,6 +1036,31 @@ mod proxy_tests {
         assert_eq!(port, Some(8080));
     }
 
+    #[test]
+    fn test_proxy_timeouts_from_toml() {
+        use rush_sync_server::proxy::ProxyConfigToml;
+
+        // Older files still carry the deprecated timeout_ms; it is accepted and dropped
+        let toml = r#"
+enabled = true
+port = 3000
+https_port_offset = 443
+bind_address = "127.0.0.1"
+health_check_interval = 30
+timeout_ms = 5000
+connect_timeout_ms = 0
+read_timeout_ms = 0
+"#;
+        let parsed: ProxyConfigToml = toml::from_str(toml).unwrap();
+        let config = ProxyConfig::from(parsed);
+        assert_eq!(config.connect_timeout_ms, 1);
+        assert_eq!(config.read_timeout_ms, 1);
+
+        let written = toml::to_string(&ProxyConfigToml::from(config)).unwrap();
+        assert!(!written.contains("\ntimeout_ms"));
+        assert!(written.contains("read_timeout_ms = 1"));
+    }
+
     #[tokio::test]
     async fn test_proxy_manager_remove_route() {
         let manager = ProxyManager::new(test_proxy_config());
@@ -759,6 +1110,137 @@ mod proxy_tests {
         assert!(routes.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_proxy_manager_path_routes() {
+        let manager = ProxyManager::new(ProxyConfig {
+            path_routing: true,
+            ..test_proxy_config()
+        });
+        manager.add_route("myapp", "server-1", 8080).await.unwrap();
+
+        // Subdomain routing stays in place next to the path route
+        assert_eq!(manager.get_target_port("myapp").await, Some(8080));
+        let (route, rest) = manager.resolve_path_route("/myapp/a/b?x=1").await.unwrap();
+        assert_eq!(route.target_port, 8080);
+        assert_eq!(route.kind, RouteKind::PathPrefix);
+        assert_eq!(rest, "/a/b?x=1");
+        assert_eq!(manager.resolve_path_route("/myapp").await.unwrap().1, "");
+        assert!(manager.resolve_path_route("/other/").await.is_none());
+        assert!(manager.resolve_path_route("/").await.is_none());
+
+        manager.remove_route("myapp").await.unwrap();
+        assert!(manager.resolve_path_route("/myapp/").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_proxy_manager_path_routes_off_by_default() {
+        let manager = ProxyManager::new(test_proxy_config());
+        manager.add_route("myapp", "server-1", 8080).await.unwrap();
+        assert!(manager.resolve_path_route("/myapp/").await.is_none());
+        assert!(manager.get_path_routes().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_proxy_route_index_reports_reachability() {
+        let manager = ProxyManager::new(ProxyConfig {
+            path_routing: true,
+            ..test_proxy_config()
+        });
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let live_port = listener.local_addr().unwrap().port();
+        let dead_port = unused_port();
+        manager
+            .add_route("live", "server-1", live_port)
+            .await
+            .unwrap();
+        manager
+            .add_route("dead", "server-2", dead_port)
+            .await
+            .unwrap();
+
+        let entries = manager.route_status().await;
+        assert_eq!(entries.len(), 4);
+        for (route, up) in &entries {
+            assert_eq!(*up, route.subdomain == "live", "{}", route.subdomain);
+        }
+
+        let html = render_route_index(&entries, "localhost", ":3000");
+        assert!(html.contains(r#"href="http://live.localhost:3000/""#));
+        assert!(html.contains(r#"href="http://localhost:3000/live/""#));
+        assert!(html.contains(&format!("127.0.0.1:{}", dead_port)));
+        assert!(html.contains("dot down"));
+        assert!(html.contains("dot up"));
+    }
+
+    /// Free local port with nothing listening on it (yet)
+    fn unused_port() -> u16 {
+        let probe = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        probe.local_addr().unwrap().port()
+    }
+
+    fn proxied_get(host: &str) -> hyper::Request<hyper::Body> {
+        hyper::Request::get("/")
+            .header("host", host)
+            .body(hyper::Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_proxy_retries_get_until_backend_is_up() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let config = test_proxy_config();
+        let client = backend_client(&config);
+        let manager = std::sync::Arc::new(ProxyManager::new(config));
+        let port = unused_port();
+        manager.add_route("app", "server-1", port).await.unwrap();
+
+        // Backend comes up while the proxy is still retrying
+        tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(150)).await;
+            let listener = tokio::net::TcpListener::bind(("127.0.0.1", port))
+                .await
+                .unwrap();
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 1024];
+            let _ = stream.read(&mut buf).await;
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 2\r\nconnection: close\r\n\r\nok")
+                .await
+                .unwrap();
+        });
+
+        let remote: std::net::SocketAddr = "127.0.0.1:50000".parse().unwrap();
+        let resp = handle_proxy_request(proxied_get("app.localhost"), manager, client, remote)
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn test_proxy_without_retries_returns_friendly_502() {
+        let config = ProxyConfig {
+            retries: 0,
+            ..test_proxy_config()
+        };
+        let client = backend_client(&config);
+        let manager = std::sync::Arc::new(ProxyManager::new(config));
+        manager
+            .add_route("app", "server-1", unused_port())
+            .await
+            .unwrap();
+
+        let remote: std::net::SocketAddr = "127.0.0.1:50000".parse().unwrap();
+        let resp = handle_proxy_request(proxied_get("app.localhost"), manager, client, remote)
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 502);
+        let body = hyper::body::to_bytes(resp.into_body()).await.unwrap();
+        let body = String::from_utf8_lossy(&body);
+        assert!(body.contains("app.localhost"));
+        assert!(body.contains("1 attempt"));
+    }
+
     #[tokio::test]
     async fn test_proxy_config_defaults() {
         let config = ProxyConfig::default();